@@ -63,4 +63,16 @@ fn example() {
     println!("PDF at x = mean: {}", g.pdf(0.666666));
     println!("PDF at x = mode: {}", g.pdf(0.5));
     println!("");
+
+    let (t, p) = t_test_one(&norm_sample, m as f64);
+    println!("One-sample t-test of Normal sample (H0: mean = {})", m);
+    println!("t-statistic: {}", t);
+    println!("p-value: {}", p);
+    println!("");
+
+    let (t, p) = t_test_two(&norm_sample, &be_sample, false);
+    println!("Welch's t-test of Normal vs Beta samples (H0: same mean)");
+    println!("t-statistic: {}", t);
+    println!("p-value: {}", p);
+    println!("");
 }
@@ -0,0 +1,24 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[cfg(feature = "parallel")]
+fn main() {
+    use std::time::Instant;
+
+    let m = rand(4000, 4000);
+
+    let now = Instant::now();
+    let a = m.fmap(|x| x.sin().exp());
+    println!("fmap     : {:?}", now.elapsed());
+
+    let now = Instant::now();
+    let b = m.par_fmap(|x| x.sin().exp());
+    println!("par_fmap : {:?}", now.elapsed());
+
+    assert_eq!(a, b);
+}
+
+#[cfg(not(feature = "parallel"))]
+fn main() {
+    println!("Run with `--features parallel`");
+}
@@ -0,0 +1,38 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[cfg(feature = "simd")]
+fn main() {
+    use peroxide::util::simd;
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    let n = 4099usize;
+    let iter = 100_000usize;
+    let x = rand(n, 1).data;
+    let y = rand(n, 1).data;
+
+    let now = Instant::now();
+    let mut d1 = 0f64;
+    for _ in 0..iter {
+        d1 = simd::dot(black_box(&x), black_box(&y));
+    }
+    println!("simd dot   : {:?}", now.elapsed());
+
+    let now = Instant::now();
+    let mut d2 = 0f64;
+    for _ in 0..iter {
+        d2 = black_box(&x)
+            .iter()
+            .zip(black_box(&y))
+            .fold(0f64, |s, (a, b)| s + a * b);
+    }
+    println!("scalar dot : {:?}", now.elapsed());
+
+    assert!(nearly_eq(d1, d2));
+}
+
+#[cfg(not(feature = "simd"))]
+fn main() {
+    println!("Run with `--features simd`");
+}
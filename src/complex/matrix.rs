@@ -0,0 +1,405 @@
+//! Complex matrix (`complex` feature)
+//!
+//! # Description
+//! `ComplexMatrix` has the same layout as `Matrix` (1D data with `Shape`), but its entries are `C64`.
+//!
+//! # Examples
+//! ```
+//! #[macro_use]
+//! extern crate peroxide;
+//! use peroxide::fuga::*;
+//! use peroxide::complex::{C64, matrix::*};
+//!
+//! fn main() {
+//!     let re = ml_matrix("1 2;3 4");
+//!     let im = ml_matrix("0 1;-1 0");
+//!     let z = ComplexMatrix::from_parts(&re, &im);
+//!
+//!     assert_eq!(z[(0, 1)], C64::new(2f64, 1f64));
+//!     assert_eq!(z.re(), re);
+//!     assert_eq!(z.im(), im);
+//!     assert_eq!(z.hermitian()[(0, 1)], C64::new(3f64, 1f64));
+//! }
+//! ```
+
+use crate::complex::C64;
+use crate::structure::matrix::{matrix, Matrix, Shape, Shape::*};
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// Complex matrix structure
+#[derive(Debug, Clone, Default)]
+pub struct ComplexMatrix {
+    pub data: Vec<C64>,
+    pub row: usize,
+    pub col: usize,
+    pub shape: Shape,
+}
+
+/// Complex matrix constructor
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+/// use peroxide::complex::{C64, matrix::*};
+///
+/// let a = cmatrix(vec![C64::new(1f64, 1f64); 4], 2, 2, Row);
+/// assert_eq!(a[(1, 1)], C64::new(1f64, 1f64));
+/// ```
+pub fn cmatrix(v: Vec<C64>, r: usize, c: usize, shape: Shape) -> ComplexMatrix {
+    assert_eq!(v.len(), r * c, "Data length should be row * col");
+    ComplexMatrix {
+        data: v,
+        row: r,
+        col: c,
+        shape,
+    }
+}
+
+/// Complex identity matrix
+pub fn ceye(n: usize) -> ComplexMatrix {
+    let mut m = cmatrix(vec![C64::new(0f64, 0f64); n * n], n, n, Row);
+    for i in 0..n {
+        m[(i, i)] = C64::new(1f64, 0f64);
+    }
+    m
+}
+
+impl ComplexMatrix {
+    /// Construct from real & imaginary parts
+    ///
+    /// The shape of `re` is used for the result.
+    pub fn from_parts(re: &Matrix, im: &Matrix) -> Self {
+        assert!(
+            re.row == im.row && re.col == im.col,
+            "Real & imaginary parts should have same size"
+        );
+        let mut z = cmatrix(vec![C64::new(0f64, 0f64); re.row * re.col], re.row, re.col, re.shape);
+        for i in 0..re.row {
+            for j in 0..re.col {
+                z[(i, j)] = C64::new(re[(i, j)], im[(i, j)]);
+            }
+        }
+        z
+    }
+
+    /// Construct from a real matrix
+    pub fn from_real(re: &Matrix) -> Self {
+        let data = re.data.iter().map(|&x| C64::new(x, 0f64)).collect();
+        cmatrix(data, re.row, re.col, re.shape)
+    }
+
+    /// Real part
+    pub fn re(&self) -> Matrix {
+        matrix(
+            self.data.iter().map(|z| z.re).collect(),
+            self.row,
+            self.col,
+            self.shape,
+        )
+    }
+
+    /// Imaginary part
+    pub fn im(&self) -> Matrix {
+        matrix(
+            self.data.iter().map(|z| z.im).collect(),
+            self.row,
+            self.col,
+            self.shape,
+        )
+    }
+
+    /// Extract row
+    pub fn row(&self, index: usize) -> Vec<C64> {
+        assert!(index < self.row);
+        (0..self.col).map(|j| self[(index, j)]).collect()
+    }
+
+    /// Extract column
+    pub fn col(&self, index: usize) -> Vec<C64> {
+        assert!(index < self.col);
+        (0..self.row).map(|i| self[(i, index)]).collect()
+    }
+
+    /// Extract diagonal components
+    pub fn diag(&self) -> Vec<C64> {
+        assert_eq!(self.row, self.col);
+        (0..self.row).map(|i| self[(i, i)]).collect()
+    }
+
+    /// Element-wise complex conjugate
+    pub fn conj(&self) -> Self {
+        cmatrix(
+            self.data.iter().map(|z| z.conj()).collect(),
+            self.row,
+            self.col,
+            self.shape,
+        )
+    }
+
+    /// Transpose (without conjugation)
+    pub fn transpose(&self) -> Self {
+        let shape = match self.shape {
+            Row => Col,
+            Col => Row,
+        };
+        cmatrix(self.data.clone(), self.col, self.row, shape)
+    }
+
+    /// Conjugate transpose
+    pub fn hermitian(&self) -> Self {
+        self.transpose().conj()
+    }
+
+    /// LU decomposition with partial pivoting
+    ///
+    /// # Description
+    /// Returns `(p, lu, sgn)` where `p` is the row permutation,
+    /// `lu` contains unit-lower `L` (below diagonal) and `U` (upper),
+    /// and `sgn` is the sign of `p`.
+    pub fn lu(&self) -> (Vec<usize>, ComplexMatrix, f64) {
+        assert_eq!(self.row, self.col, "Should be square matrix");
+        let n = self.row;
+        let mut a = self.to_row_shape();
+        let mut p = (0..n).collect::<Vec<usize>>();
+        let mut sgn = 1f64;
+
+        for k in 0..n {
+            let mut piv = k;
+            let mut max = a[(k, k)].norm();
+            for i in k + 1..n {
+                let v = a[(i, k)].norm();
+                if v > max {
+                    max = v;
+                    piv = i;
+                }
+            }
+            if piv != k {
+                for j in 0..n {
+                    a.data.swap(k * n + j, piv * n + j);
+                }
+                p.swap(k, piv);
+                sgn = -sgn;
+            }
+            if max == 0f64 {
+                continue;
+            }
+            let akk = a[(k, k)];
+            for i in k + 1..n {
+                let m = a[(i, k)] / akk;
+                a[(i, k)] = m;
+                for j in k + 1..n {
+                    let akj = a[(k, j)];
+                    a[(i, j)] -= m * akj;
+                }
+            }
+        }
+        (p, a, sgn)
+    }
+
+    /// Determinant
+    pub fn det(&self) -> C64 {
+        let (_, lu, sgn) = self.lu();
+        lu.diag().into_iter().fold(C64::new(sgn, 0f64), |acc, d| acc * d)
+    }
+
+    /// Inverse matrix (LU with partial pivoting)
+    pub fn inv(&self) -> Self {
+        let n = self.row;
+        let (p, lu, _) = self.lu();
+        assert!(
+            lu.diag().iter().all(|d| d.norm() > 0f64),
+            "Matrix is singular"
+        );
+        let mut result = cmatrix(vec![C64::new(0f64, 0f64); n * n], n, n, Col);
+        for j in 0..n {
+            // Forward substitution with permuted unit vector
+            let mut y = vec![C64::new(0f64, 0f64); n];
+            for i in 0..n {
+                let mut s = if p[i] == j {
+                    C64::new(1f64, 0f64)
+                } else {
+                    C64::new(0f64, 0f64)
+                };
+                for k in 0..i {
+                    s -= lu[(i, k)] * y[k];
+                }
+                y[i] = s;
+            }
+            // Backward substitution
+            for i in (0..n).rev() {
+                let mut s = y[i];
+                for k in i + 1..n {
+                    s -= lu[(i, k)] * y[k];
+                }
+                y[i] = s / lu[(i, i)];
+            }
+            result.data[j * n..(j + 1) * n].copy_from_slice(&y);
+        }
+        result
+    }
+
+    fn to_row_shape(&self) -> Self {
+        match self.shape {
+            Row => self.clone(),
+            Col => {
+                let mut m = cmatrix(self.data.clone(), self.row, self.col, Row);
+                for i in 0..self.row {
+                    for j in 0..self.col {
+                        m[(i, j)] = self[(i, j)];
+                    }
+                }
+                m
+            }
+        }
+    }
+}
+
+impl Index<(usize, usize)> for ComplexMatrix {
+    type Output = C64;
+
+    fn index(&self, pair: (usize, usize)) -> &C64 {
+        let (i, j) = pair;
+        assert!(i < self.row && j < self.col, "Index out of range");
+        match self.shape {
+            Row => &self.data[i * self.col + j],
+            Col => &self.data[i + j * self.row],
+        }
+    }
+}
+
+impl IndexMut<(usize, usize)> for ComplexMatrix {
+    fn index_mut(&mut self, pair: (usize, usize)) -> &mut C64 {
+        let (i, j) = pair;
+        assert!(i < self.row && j < self.col, "Index out of range");
+        match self.shape {
+            Row => &mut self.data[i * self.col + j],
+            Col => &mut self.data[i + j * self.row],
+        }
+    }
+}
+
+impl PartialEq for ComplexMatrix {
+    fn eq(&self, other: &ComplexMatrix) -> bool {
+        if self.row != other.row || self.col != other.col {
+            return false;
+        }
+        (0..self.row).all(|i| (0..self.col).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
+impl fmt::Display for ComplexMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for i in 0..self.row {
+            let r = self
+                .row(i)
+                .iter()
+                .map(|z| format!("{:.4}", z))
+                .collect::<Vec<String>>()
+                .join("  ");
+            writeln!(f, "r[{}]  {}", i, r)?;
+        }
+        Ok(())
+    }
+}
+
+fn elementwise<F: Fn(C64, C64) -> C64>(a: &ComplexMatrix, b: &ComplexMatrix, f: F) -> ComplexMatrix {
+    assert!(a.row == b.row && a.col == b.col, "Matrices should have same size");
+    let mut c = a.clone();
+    for i in 0..a.row {
+        for j in 0..a.col {
+            c[(i, j)] = f(a[(i, j)], b[(i, j)]);
+        }
+    }
+    c
+}
+
+impl Add<&ComplexMatrix> for &ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn add(self, rhs: &ComplexMatrix) -> ComplexMatrix {
+        elementwise(self, rhs, |x, y| x + y)
+    }
+}
+
+impl Add<ComplexMatrix> for ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn add(self, rhs: ComplexMatrix) -> ComplexMatrix {
+        &self + &rhs
+    }
+}
+
+impl Sub<&ComplexMatrix> for &ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn sub(self, rhs: &ComplexMatrix) -> ComplexMatrix {
+        elementwise(self, rhs, |x, y| x - y)
+    }
+}
+
+impl Sub<ComplexMatrix> for ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn sub(self, rhs: ComplexMatrix) -> ComplexMatrix {
+        &self - &rhs
+    }
+}
+
+impl Neg for ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn neg(self) -> ComplexMatrix {
+        cmatrix(
+            self.data.iter().map(|z| -z).collect(),
+            self.row,
+            self.col,
+            self.shape,
+        )
+    }
+}
+
+/// Matrix multiplication for ComplexMatrix
+impl Mul<&ComplexMatrix> for &ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn mul(self, rhs: &ComplexMatrix) -> ComplexMatrix {
+        assert_eq!(self.col, rhs.row);
+        let mut c = cmatrix(
+            vec![C64::new(0f64, 0f64); self.row * rhs.col],
+            self.row,
+            rhs.col,
+            Row,
+        );
+        for i in 0..self.row {
+            for k in 0..self.col {
+                let aik = self[(i, k)];
+                for j in 0..rhs.col {
+                    c.data[i * rhs.col + j] += aik * rhs[(k, j)];
+                }
+            }
+        }
+        c
+    }
+}
+
+impl Mul<ComplexMatrix> for ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn mul(self, rhs: ComplexMatrix) -> ComplexMatrix {
+        &self * &rhs
+    }
+}
+
+impl Mul<C64> for ComplexMatrix {
+    type Output = ComplexMatrix;
+
+    fn mul(self, rhs: C64) -> ComplexMatrix {
+        cmatrix(
+            self.data.iter().map(|z| z * rhs).collect(),
+            self.row,
+            self.col,
+            self.shape,
+        )
+    }
+}
@@ -2,4 +2,5 @@ use num_complex::Complex;
 
 pub type C64 = Complex<f64>;
 
+pub mod matrix;
 pub mod vector;
@@ -155,36 +155,46 @@ pub use peroxide_num::{ExpLogOps, PowOps, TrigOps};
 
 pub use crate::traits::{
     fp::{FPMatrix, FPVector},
-    general::Algorithm,
-    math::{InnerProduct, LinearOp, MatrixProduct, Norm, Normed, Vector, VectorProduct},
+    general::{Algorithm, Side},
+    math::{InnerProduct, LinearOp, MatrixProduct, Norm, Normed, SquareOp, Vector, VectorProduct},
     mutable::{MutFP, MutMatrix},
     num::Real,
     pointer::{MatrixPtr, Oxide, Redox, RedoxCommon},
     stable::StableFn,
-    sugar::{Scalable, ScalableMut, VecOps, ConvToMat},
+    sugar::{ElementwiseOps, Scalable, ScalableMut, VecOps, ConvToMat},
+};
+
+#[cfg(feature = "parallel")]
+pub use crate::traits::{
+    fp::{ParallelFPMatrix, ParallelFPVector},
+    math::ParallelLinearOp,
 };
 
 #[allow(unused_imports)]
 pub use crate::structure::{
     matrix::*, 
     polynomial::*, 
+    sparse::*,
     vector::*, 
     dataframe::*,
     ad::*,
     //complex::C64,
 };
 
-pub use crate::util::{api::*, low_level::*, non_macro::*, print::*, useful::*, wrapper::*};
+pub use crate::util::{api::*, low_level::*, non_macro::*, npy::*, print::*, useful::*, wrapper::*};
+
+#[cfg(feature = "hdf5")]
+pub use crate::util::hdf5::WithHDF5;
 
 #[allow(unused_imports)]
-pub use crate::statistics::{dist::*, ops::*, rand::*, stat::*};
+pub use crate::statistics::{dist::*, mcmc::*, ops::*, rand::*, resample::*, stat::*, test::*};
 
 #[allow(unused_imports)]
 pub use crate::special::function::*;
 
 #[allow(unused_imports)]
 pub use crate::numerical::{
-    eigen::*, integral::*, interp::*, ode::*, optimize::*, root::*, spline::*, utils::*,
+    deriv::*, eigen::*, fft::*, integral::*, interp::*, iterative::*, ode::*, optimize::*, root::*, signal::*, spline::*, utils::*,
 };
 
 #[allow(unused_imports)]
@@ -203,6 +213,8 @@ pub use paste;
 pub use crate::numerical::integral::Integral::{
     GaussLegendre, 
     NewtonCotes,
+    GaussHermite,
+    GaussLaguerre,
     G7K15,
     G10K21,
     G15K31,
@@ -113,9 +113,13 @@
 //!     ```bash
 //!     cargo add peroxide --features parquet
 //!     ```
-//! 7. All features
+//! 7. HDF5 dependency for Matrix & DataFrame
 //!     ```bash
-//!     cargo add peroxide --features "O3 plot nc csv parquet"
+//!     cargo add peroxide --features hdf5
+//!     ```
+//! 8. All features
+//!     ```bash
+//!     cargo add peroxide --features "O3 plot nc csv parquet hdf5"
 //!     ```
 //!
 //! ## Import all at once
@@ -126,6 +130,8 @@
 //! * [`fuga`](fuga/index.html) : To control numerical algorithms
 //!
 //! To see differences, follow above two links.
+//! Nothing is re-exported at the crate root, so `peroxide` does not pollute your namespace
+//! unless you glob-import one of them. (Each item is also reachable by its module path, e.g. `peroxide::structure::matrix::Matrix`.)
 //!
 //! You can import all functions & structures at once
 //!
@@ -155,6 +161,8 @@
 //!
 //! * If you want to use _QR_, _SVD_, or _Cholesky Decomposition_, you should use the `O3` feature. These decompositions are not implemented in the `default` feature.
 //!
+//! * The `parallel` feature enables multi-threaded variants with [Rayon](https://github.com/rayon-rs/rayon) (`par_matmul`, `par_apply`, `par_fmap`, `par_mean`, ...). `par_matmul(&a, &b)` splits the output rows across threads, and each thread runs the same cache-blocked kernel as `a * b`, so it helps for mid-to-large matrices where `O3` gives little advantage.
+//!
 //! * If you want to save your numerical results, consider using the `parquet` or `nc` features, which correspond to the `parquet` and `netcdf` file formats, respectively. These formats are much more efficient than `csv` and `json`.
 //!
 //! * For plotting, it is recommended to use the `plot` feature. However, if you require more customization, you can use the `parquet` or `nc` feature to export your data in the parquet or netcdf format and then use Python to create the plots.
@@ -180,7 +188,8 @@ extern crate serde;
 
 extern crate rand;
 
-// extern crate json;
+#[cfg(feature = "json")]
+extern crate json;
 
 extern crate order_stat;
 
@@ -191,6 +200,12 @@ extern crate matrixmultiply;
 #[cfg(feature = "nc")]
 extern crate netcdf;
 
+#[cfg(feature = "hdf5")]
+extern crate hdf5;
+
+#[cfg(feature = "parallel")]
+extern crate rayon;
+
 extern crate peroxide_ad;
 
 #[macro_use]
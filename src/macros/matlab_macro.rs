@@ -40,6 +40,10 @@ macro_rules! zeros {
 /// fn main() {
 ///     let a = rand!(2, 2);
 ///     println!("{}", a);  // 2 x 2 random matrix (0 ~ 1)
+///
+///     // With seed
+///     let b = rand!(2, 2; 42);
+///     assert_eq!(b, rand!(2, 2; 42));
 /// }
 /// ```
 #[macro_export]
@@ -61,6 +65,11 @@ macro_rules! rand {
         }
         m
     }};
+
+    ( $m:expr, $n:expr; $seed:expr ) => {{
+        let mut rng = stdrng_from_seed($seed);
+        rand_with_rng($m, $n, &mut rng)
+    }};
 }
 
 /// MATLAB like eye - identity matrix
@@ -105,7 +114,6 @@ macro_rules! eye {
 #[macro_export]
 macro_rules! linspace {
     ( $start:expr, $end:expr, $length: expr) => {{
-        let step = ($end - $start) as f64 / ($length as f64 - 1f64);
-        seq!($start, $end, step)
+        $crate::util::non_macro::linspace($start as f64, $end as f64, $length as usize)
     }};
 }
@@ -71,25 +71,13 @@ macro_rules! c {
 /// fn main() {
 ///     assert_eq!(seq!(1,10,1), c!(1,2,3,4,5,6,7,8,9,10));
 ///     assert_eq!(seq!(1,10,1), seq!(1;10;1));
+///     assert_eq!(seq!(3,1,-1), c!(3,2,1));
 /// }
 /// ```
 #[macro_export]
 macro_rules! seq {
     ( $start:expr, $end:expr, $step:expr ) => {{
-        let s = $start as f64;
-        let e = $end as f64;
-        let step = $step as f64;
-
-        assert!(e > s);
-
-        let factor: f64 = (e - s) / step;
-        let l: usize = factor as usize + 1;
-        let mut v: Vec<f64> = Vec::new();
-
-        for i in 0..l {
-            v.push(s + step * (i as f64));
-        }
-        v
+        $crate::util::non_macro::seq($start as f64, $end as f64, $step as f64)
     }};
     ( $start:expr; $end:expr; $step:expr ) => {
         seq!($start, $end, $step)
@@ -1,3 +1,5 @@
+use crate::structure::matrix::{LeastSquares, LinearAlgebra, Matrix, SolveKind};
+use crate::util::non_macro::{eye, vandermonde};
 use crate::structure::polynomial::Polynomial;
 
 /// Simple Least Square 2D
@@ -50,10 +52,72 @@ pub fn least_square(node_x: Vec<f64>, node_y: Vec<f64>) -> Polynomial {
     Polynomial::new(vec![w1, w0])
 }
 
-// Polynomial Regression
-//pub fn poly_reg(node_x: Vec<f64>, node_y: Vec<f64>) -> Polynomial {
-//    let n = node_x.len();
-//    assert_eq!(n, node_y.len());
-//    let a = matrix(vec![1f64; n], n, 1, Col);
-//
-//}
+/// Polynomial Regression (Least Square)
+///
+/// # Type
+///
+/// `(Vec<f64>, Vec<f64>, usize) -> Polynomial`
+///
+/// # Description
+/// Fit a polynomial of given degree by least square via normal equation (or SVD for `O3`).
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = seq(-2, 2, 1);
+///     let y = x.fmap(|t| 2f64 * t * t - t + 1f64);
+///     let p = poly_reg(x, y, 2);
+///     assert!(p.coef.iter().zip(c!(2, -1, 1)).all(|(a, b)| (a - b).abs() < 1e-10));
+/// }
+/// ```
+pub fn poly_reg(node_x: Vec<f64>, node_y: Vec<f64>, degree: usize) -> Polynomial {
+    let n = node_x.len();
+    assert_eq!(n, node_y.len());
+    assert!(n > degree, "Number of nodes should be larger than degree");
+
+    // Vandermonde matrix (highest degree first)
+    let a = vandermonde(&node_x, degree);
+
+    let coef = a.lstsq(&node_y, false).x;
+    Polynomial::new(coef)
+}
+
+/// Ridge Regression (Tikhonov regularization)
+///
+/// # Type
+///
+/// `(&Matrix, &Vec<f64>, f64) -> Vec<f64>`
+///
+/// # Description
+/// Solve `(XᵀX + λI) β = Xᵀy` (by LU).
+/// `λ > 0` stabilizes ill-conditioned design matrices and shrinks coefficients toward zero.
+/// For `λ = 0`, it is the ordinary least square (`X` should have full column rank).
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = ml_matrix("1 0;1 1;1 2;1 3");
+///     let y = c!(1, 3, 5, 7); // y = 1 + 2x
+///     let beta = ridge_reg(&x, &y, 0f64);
+///     assert!(eq_vec(&beta, &c!(1, 2), 1e-10));
+///
+///     let beta_ridge = ridge_reg(&x, &y, 1f64);
+///     assert!(beta_ridge.norm(Norm::L2) < beta.norm(Norm::L2));
+/// }
+/// ```
+pub fn ridge_reg(x: &Matrix, y: &Vec<f64>, lambda: f64) -> Vec<f64> {
+    assert_eq!(x.row, y.len(), "Number of rows of X should be equal to length of y");
+    assert!(lambda >= 0f64, "lambda should be non-negative");
+    let xt = x.t();
+    let a = &xt * x + eye(x.col) * lambda;
+    let b = &xt * y;
+    a.solve(&b, SolveKind::LU)
+}
@@ -0,0 +1,206 @@
+//! Numerical differentiation of black-box functions
+//!
+//! # Description
+//! For closures over `f64` (where `AD` can not be used), derivatives are approximated by
+//! central differences and improved by Richardson extrapolation (Ridders' method).
+//!
+//! * `derivative(f, x, h)` : `f'(x)`
+//! * `second_derivative(f, x, h)` : `f''(x)`
+//! * `derivative_n(f, x, order, h)` : `f^(n)(x)` for `order = 1, 2, 3, 4`
+//! * `derivative_with_err(f, x, h)`, `derivative_n_with_err(f, x, order, h)` : `(value, estimated error)`
+//! * `complex_step_derivative(f, x)` : `f'(x)` for analytic `f` over `C64` (requires `complex` feature)
+//!
+//! `h` is the initial step size. It is reduced by a constant factor, and the extrapolation stops
+//! when the error estimate begins to grow (due to round-off). So, `h` need not be small -
+//! it should be the scale on which `f` changes appreciably (e.g. `0.1` for `sin`).
+//!
+//! # Examples
+//! ```
+//! use peroxide::fuga::*;
+//!
+//! let df = derivative(|x| x.exp(), 1f64, 0.1);
+//! assert!((df - 1f64.exp()).abs() < 1e-12);
+//!
+//! let d2f = second_derivative(|x| x.powi(3), 2f64, 0.1);
+//! assert!((d2f - 12f64).abs() < 1e-8);
+//! ```
+//!
+//! * Reference : Press, William H., et al. *Numerical Recipes.* 3rd ed. Cambridge University Press, 2007. (Section 5.7)
+
+#[cfg(feature = "complex")]
+use crate::complex::C64;
+
+/// Step reduction factor
+const CON: f64 = 1.4;
+/// Maximum size of extrapolation tableau
+const NTAB: usize = 10;
+/// Stop if the error grows by this factor
+const SAFE: f64 = 2.0;
+
+/// First derivative via central difference & Richardson extrapolation
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let df = derivative(|x| x.sin(), 0.5, 0.1);
+/// assert!((df - 0.5f64.cos()).abs() < 1e-13);
+/// ```
+pub fn derivative<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> f64 {
+    derivative_with_err(f, x, h).0
+}
+
+/// First derivative with estimated error
+///
+/// # Description
+/// Returns `(f'(x), err)`. `err` is the difference between successive extrapolations,
+/// so it grows with round-off or noise in `f`.
+/// For noisy `f`, it indicates the order of magnitude, not a strict bound.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let (df, err) = derivative_with_err(|x| x.exp(), 1f64, 0.1);
+/// assert!((df - 1f64.exp()).abs() <= 10f64 * err);
+/// assert!(err < 1e-12);
+/// ```
+pub fn derivative_with_err<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> (f64, f64) {
+    ridders(|h| (f(x + h) - f(x - h)) / (2f64 * h), h)
+}
+
+/// Second derivative via central difference & Richardson extrapolation
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let d2f = second_derivative(|x| x.sin(), 0.5, 0.1);
+/// assert!((d2f + 0.5f64.sin()).abs() < 1e-9);
+/// ```
+pub fn second_derivative<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> f64 {
+    derivative_n_with_err(f, x, 2, h).0
+}
+
+/// Higher order derivative via central difference & Richardson extrapolation
+///
+/// # Description
+/// Central difference stencils (error `O(h^2)`) are used :
+///
+/// * `order = 1` : `(f(x+h) - f(x-h)) / 2h`
+/// * `order = 2` : `(f(x+h) - 2f(x) + f(x-h)) / h^2`
+/// * `order = 3` : `(f(x+2h) - 2f(x+h) + 2f(x-h) - f(x-2h)) / 2h^3`
+/// * `order = 4` : `(f(x+2h) - 4f(x+h) + 6f(x) - 4f(x-h) + f(x-2h)) / h^4`
+///
+/// Round-off grows as `h^(-order)`, so the attainable accuracy decreases with `order`.
+///
+/// # Panics
+/// `order` is not in `1..=4`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let d3f = derivative_n(|x| x.exp(), 0f64, 3, 0.5);
+/// assert!((d3f - 1f64).abs() < 1e-7);
+/// let d4f = derivative_n(|x| x.sin(), 0.5, 4, 0.5);
+/// assert!((d4f - 0.5f64.sin()).abs() < 1e-5);
+/// ```
+pub fn derivative_n<F: Fn(f64) -> f64>(f: F, x: f64, order: usize, h: f64) -> f64 {
+    derivative_n_with_err(f, x, order, h).0
+}
+
+/// Higher order derivative with estimated error
+///
+/// # Description
+/// Returns `(f^(n)(x), err)`. See `derivative_n` for stencils.
+pub fn derivative_n_with_err<F: Fn(f64) -> f64>(
+    f: F,
+    x: f64,
+    order: usize,
+    h: f64,
+) -> (f64, f64) {
+    if order == 1 {
+        return derivative_with_err(f, x, h);
+    }
+    let fx = f(x);
+    match order {
+        2 => ridders(|h| (f(x + h) - 2f64 * fx + f(x - h)) / (h * h), h),
+        3 => ridders(
+            |h| {
+                (f(x + 2f64 * h) - 2f64 * f(x + h) + 2f64 * f(x - h) - f(x - 2f64 * h))
+                    / (2f64 * h.powi(3))
+            },
+            h,
+        ),
+        4 => ridders(
+            |h| {
+                (f(x + 2f64 * h) - 4f64 * f(x + h) + 6f64 * fx - 4f64 * f(x - h)
+                    + f(x - 2f64 * h))
+                    / h.powi(4)
+            },
+            h,
+        ),
+        _ => panic!("Order of derivative should be 1, 2, 3 or 4"),
+    }
+}
+
+/// First derivative via complex step
+///
+/// # Description
+/// `f'(x) = Im(f(x + ih)) / h + O(h^2)` for `f` analytic and real on the real axis.
+/// There is no subtraction, so `h` can be tiny (here `1e-20`) and the result is accurate to machine precision.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let df = complex_step_derivative(|z| z.exp() * z.sin(), 1f64);
+/// let exact = 1f64.exp() * (1f64.sin() + 1f64.cos());
+/// assert!((df - exact).abs() < 1e-14);
+/// ```
+#[cfg(feature = "complex")]
+pub fn complex_step_derivative<F: Fn(C64) -> C64>(f: F, x: f64) -> f64 {
+    complex_step_derivative_with_step(f, x, 1e-20)
+}
+
+/// First derivative via complex step with specific step size
+#[cfg(feature = "complex")]
+pub fn complex_step_derivative_with_step<F: Fn(C64) -> C64>(f: F, x: f64, h: f64) -> f64 {
+    assert!(h != 0f64, "Step size should be nonzero");
+    f(C64::new(x, h)).im / h
+}
+
+/// Ridders' extrapolation of `d(h) = D + c_1 h^2 + c_2 h^4 + ...` to `h = 0`
+///
+/// Returns `(value, estimated error)`
+fn ridders<G: Fn(f64) -> f64>(d: G, h: f64) -> (f64, f64) {
+    assert!(h != 0f64, "Step size should be nonzero");
+    let con2 = CON * CON;
+    let mut h = h;
+    let mut a = vec![vec![0f64; NTAB]; NTAB];
+    a[0][0] = d(h);
+    let mut ans = a[0][0];
+    let mut err = f64::INFINITY;
+
+    for i in 1..NTAB {
+        h /= CON;
+        a[0][i] = d(h);
+        let mut fac = con2;
+        for j in 1..=i {
+            a[j][i] = (a[j - 1][i] * fac - a[j - 1][i - 1]) / (fac - 1f64);
+            fac *= con2;
+            let errt = (a[j][i] - a[j - 1][i])
+                .abs()
+                .max((a[j][i] - a[j - 1][i - 1]).abs());
+            if errt <= err {
+                err = errt;
+                ans = a[j][i];
+            }
+        }
+        if (a[i][i] - a[i - 1][i - 1]).abs() >= SAFE * err {
+            break;
+        }
+    }
+    (ans, err)
+}
@@ -3,7 +3,10 @@
 //! * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
 
 pub use self::EigenMethod::*;
-use crate::structure::matrix::Matrix;
+#[cfg(feature = "complex")]
+use crate::complex::{matrix::{cmatrix, ComplexMatrix}, C64};
+use crate::structure::matrix::{matrix, Matrix, Shape::Col};
+use crate::traits::math::{Norm, Normed};
 use crate::util::non_macro::eye_shape;
 
 #[derive(Debug, Copy, Clone)]
@@ -11,6 +14,13 @@ pub enum EigenMethod {
     Jacobi,
 }
 
+/// Which end of the spectrum to find (`partial_eigen`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Which {
+    Largest,
+    Smallest,
+}
+
 #[derive(Debug, Clone)]
 pub struct Eigen {
     pub eigenvalue: Vec<f64>,
@@ -192,3 +202,519 @@ fn eigsrt(d: &mut Vec<f64>, v: &mut Matrix) {
         }
     }
 }
+
+// =============================================================================
+// Partial eigen decomposition (Power iteration & Lanczos)
+// =============================================================================
+/// A few extreme eigenpairs of a symmetric matrix
+///
+/// # Description
+/// Find `k` largest (or smallest) eigenvalues & eigenvectors using only matrix-vector products.
+///
+/// * `k <= 3` : Power iteration on the shifted matrix `A + sI` (`Largest`) or `sI - A` (`Smallest`)
+///   where `s = |A|_∞` (Gershgorin bound), with deflation by orthogonalization against found eigenvectors.
+///   Converged when the residual `|Av - λv| <= tol * s`.
+/// * `k > 3` : Lanczos iteration with full reorthogonalization.
+///   Ritz pairs of the tridiagonal matrix are computed by QL with implicit shifts, and converged when `|β_m s_m| <= tol * |θ|`.
+///
+/// `max_iter` is the maximum number of power iterations per eigenpair (or Lanczos steps).
+/// The result has `k` eigenvalues (descending for `Largest`, ascending for `Smallest`) and `n x k` eigenvectors.
+///
+/// # Examples
+/// ```
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = ml_matrix("4 1 0;1 3 1;0 1 2");
+///     let full = eigen(&a, Jacobi);
+///     let part = partial_eigen(&a, 1, Which::Largest, 1e-12, 10000);
+///     assert!((part.eigenvalue[0] - full.eigenvalue[0]).abs() < 1e-10);
+/// }
+/// ```
+pub fn partial_eigen(m: &Matrix, k: usize, which: Which, tol: f64, max_iter: usize) -> Eigen {
+    assert_eq!(m.row, m.col, "Should be square matrix");
+    let n = m.row;
+    assert!(k >= 1 && k <= n, "k should be in 1..=n");
+    let op = |v: &Vec<f64>| m * v;
+    if k <= 3 {
+        let shift = m.norm(Norm::LInf);
+        power_eigen(op, n, k, which, shift, tol, max_iter)
+    } else {
+        lanczos_eigen(op, n, k, which, tol, max_iter)
+    }
+}
+
+/// Deterministic starting vector (not orthogonal to any particular eigenvector in general)
+fn start_vector(n: usize, seed: usize) -> Vec<f64> {
+    let v = (0..n)
+        .map(|i| ((i + 1) as f64 * (1.618 + seed as f64)).sin() + 0.5)
+        .collect::<Vec<f64>>();
+    let norm = v.norm(Norm::L2);
+    v.into_iter().map(|x| x / norm).collect()
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y.iter()).map(|(a, b)| a * b).sum()
+}
+
+/// Orthogonalize `v` against orthonormal `basis` (twice is enough) and return the remaining norm
+fn orthogonalize(v: &mut [f64], basis: &[Vec<f64>]) -> f64 {
+    for _ in 0..2 {
+        for q in basis.iter() {
+            let r = dot(q, v);
+            v.iter_mut().zip(q.iter()).for_each(|(x, y)| *x -= r * y);
+        }
+    }
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Power iteration with deflation
+fn power_eigen<F: Fn(&Vec<f64>) -> Vec<f64>>(
+    op: F,
+    n: usize,
+    k: usize,
+    which: Which,
+    shift: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Eigen {
+    // B = A + sI (Largest) or sI - A (Smallest) is positive semidefinite
+    let sgn = match which {
+        Which::Largest => 1f64,
+        Which::Smallest => -1f64,
+    };
+    let scale = shift.max(f64::MIN_POSITIVE);
+    let mut vals = Vec::with_capacity(k);
+    let mut vecs: Vec<Vec<f64>> = Vec::with_capacity(k);
+    for l in 0..k {
+        let mut v = start_vector(n, l);
+        let norm = orthogonalize(&mut v, &vecs);
+        v.iter_mut().for_each(|x| *x /= norm);
+        let mut lambda = 0f64;
+        for _ in 0..max_iter {
+            let av = op(&v);
+            lambda = dot(&v, &av);
+            let res = av
+                .iter()
+                .zip(v.iter())
+                .map(|(a, x)| (a - lambda * x).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            if res <= tol * scale {
+                break;
+            }
+            let mut w = av
+                .iter()
+                .zip(v.iter())
+                .map(|(a, x)| sgn * a + shift * x)
+                .collect::<Vec<f64>>();
+            let norm = orthogonalize(&mut w, &vecs);
+            if norm == 0f64 {
+                break;
+            }
+            v = w.into_iter().map(|x| x / norm).collect();
+        }
+        vals.push(lambda);
+        vecs.push(v);
+    }
+    Eigen {
+        eigenvalue: vals,
+        eigenvector: matrix(vecs.concat(), n, k, Col),
+    }
+}
+
+/// Lanczos iteration with full reorthogonalization
+fn lanczos_eigen<F: Fn(&Vec<f64>) -> Vec<f64>>(
+    op: F,
+    n: usize,
+    k: usize,
+    which: Which,
+    tol: f64,
+    max_iter: usize,
+) -> Eigen {
+    let mut basis: Vec<Vec<f64>> = vec![start_vector(n, 0)];
+    let mut alpha: Vec<f64> = vec![];
+    let mut beta: Vec<f64> = vec![];
+    let max_step = max_iter.min(n).max(k);
+    let check = (2 * k).max(20);
+
+    loop {
+        // Lanczos step
+        let j = alpha.len();
+        let v = &basis[j];
+        let mut w = op(v);
+        let a = dot(v, &w);
+        alpha.push(a);
+        let b = orthogonalize(&mut w, &basis);
+        let m = alpha.len();
+
+        let t_norm = alpha
+            .iter()
+            .chain(beta.iter())
+            .fold(0f64, |acc, x| acc.max(x.abs()));
+        let breakdown = b <= 1e-12 * t_norm.max(f64::MIN_POSITIVE);
+
+        if m >= k && (m.is_multiple_of(check) || m == max_step || (breakdown && m == n)) {
+            // Ritz pairs
+            let eig = tridiagonal_eigen(&alpha, &beta);
+            let idx = match which {
+                Which::Largest => (0..k).collect::<Vec<usize>>(),
+                Which::Smallest => (0..k).map(|i| m - 1 - i).collect(),
+            };
+            let converged = idx.iter().all(|&i| {
+                (b * eig.eigenvector[(m - 1, i)]).abs()
+                    <= tol * eig.eigenvalue[i].abs().max(t_norm * 1e-3)
+            });
+            if converged || m == max_step {
+                let mut vecs = Vec::with_capacity(n * k);
+                for &i in idx.iter() {
+                    let mut x = vec![0f64; n];
+                    for (l, q) in basis.iter().take(m).enumerate() {
+                        let c = eig.eigenvector[(l, i)];
+                        x.iter_mut().zip(q.iter()).for_each(|(y, z)| *y += c * z);
+                    }
+                    vecs.extend(x);
+                }
+                return Eigen {
+                    eigenvalue: idx.iter().map(|&i| eig.eigenvalue[i]).collect(),
+                    eigenvector: matrix(vecs, n, k, Col),
+                };
+            }
+        }
+
+        // Next Lanczos vector (restart with a new direction on breakdown)
+        if breakdown {
+            let mut seed = m;
+            loop {
+                let mut u = start_vector(n, seed);
+                let norm = orthogonalize(&mut u, &basis);
+                if norm > 1e-8 {
+                    basis.push(u.into_iter().map(|x| x / norm).collect());
+                    break;
+                }
+                seed += 1;
+            }
+            beta.push(0f64);
+        } else {
+            basis.push(w.into_iter().map(|x| x / b).collect());
+            beta.push(b);
+        }
+    }
+}
+
+/// Eigen decomposition of symmetric tridiagonal matrix (QL with implicit shifts)
+///
+/// * `d` : Diagonal, `e` : Off-diagonal (`e.len() >= d.len() - 1`)
+/// * Eigenvalues are sorted in descending order
+///
+/// * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
+fn tridiagonal_eigen(d: &[f64], e: &[f64]) -> Eigen {
+    let n = d.len();
+    let mut d = d.to_vec();
+    let mut e = e.iter().take(n - 1).cloned().collect::<Vec<f64>>();
+    e.push(0f64);
+    let mut z = eye_shape(n, Col);
+    let sign = |a: f64, b: f64| if b >= 0f64 { a.abs() } else { -a.abs() };
+
+    for l in 0..n {
+        let mut iter = 0usize;
+        loop {
+            let mut m = l;
+            while m < n - 1 {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iter += 1;
+            assert!(iter < 60, "Too many iterations in tridiagonal_eigen");
+            let mut g = (d[l + 1] - d[l]) / (2f64 * e[l]);
+            let mut r = g.hypot(1f64);
+            g = d[m] - d[l] + e[l] / (g + sign(r, g));
+            let (mut s, mut c, mut p) = (1f64, 1f64, 0f64);
+            let mut early = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0f64 {
+                    d[i + 1] -= p;
+                    e[m] = 0f64;
+                    early = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2f64 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for k in 0..n {
+                    let f = z[(k, i + 1)];
+                    z[(k, i + 1)] = s * z[(k, i)] + c * f;
+                    z[(k, i)] = c * z[(k, i)] - s * f;
+                }
+            }
+            if early {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0f64;
+        }
+    }
+    eigsrt(&mut d, &mut z);
+    Eigen {
+        eigenvalue: d,
+        eigenvector: z,
+    }
+}
+
+// =============================================================================
+// Non-symmetric eigenvalues (Hessenberg + shifted QR)
+// =============================================================================
+/// Eigenvalues of a general real matrix (`complex` feature)
+///
+/// # Description
+/// Reduce to upper Hessenberg form by elimination, then apply the
+/// Francis double shifted QR algorithm.
+/// Eigenvalues are returned as `n x 1` `ComplexMatrix` (use `.data` for `Vec<C64>`).
+/// Complex eigenvalues appear as conjugate pairs.
+///
+/// * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+/// use peroxide::numerical::eigen::complex_eigenvalues;
+///
+/// let a = ml_matrix("0 -1;1 0");
+/// let ev = complex_eigenvalues(&a);
+/// assert_eq!((ev.row, ev.col), (2, 1));
+/// let mut ev = ev.data;
+/// ev.sort_by(|x, y| x.im.partial_cmp(&y.im).unwrap());
+/// assert!(nearly_eq(ev[0].im, -1f64) && nearly_eq(ev[1].im, 1f64));
+/// assert!(ev[0].re.abs() < 1e-12 && ev[1].re.abs() < 1e-12);
+/// ```
+#[cfg(feature = "complex")]
+pub fn complex_eigenvalues(m: &Matrix) -> ComplexMatrix {
+    assert_eq!(m.row, m.col, "Should be square matrix");
+    let n = m.row;
+    let mut a = m.to_vec();
+    elmhes(&mut a);
+    for i in 2..n {
+        for j in 0..i - 1 {
+            a[i][j] = 0f64;
+        }
+    }
+    cmatrix(hqr(&mut a), n, 1, Col)
+}
+
+/// Reduction to Hessenberg form by elimination
+#[cfg(feature = "complex")]
+fn elmhes(a: &mut [Vec<f64>]) {
+    let n = a.len();
+    for m in 1..n.saturating_sub(1) {
+        let mut x = 0f64;
+        let mut i = m;
+        for j in m..n {
+            if a[j][m - 1].abs() > x.abs() {
+                x = a[j][m - 1];
+                i = j;
+            }
+        }
+        if i != m {
+            for j in m - 1..n {
+                let t = a[i][j];
+                a[i][j] = a[m][j];
+                a[m][j] = t;
+            }
+            for row in a.iter_mut() {
+                row.swap(i, m);
+            }
+        }
+        if x != 0f64 {
+            for i in m + 1..n {
+                let mut y = a[i][m - 1];
+                if y != 0f64 {
+                    y /= x;
+                    a[i][m - 1] = y;
+                    for j in m..n {
+                        a[i][j] -= y * a[m][j];
+                    }
+                    for row in a.iter_mut() {
+                        row[m] += y * row[i];
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Eigenvalues of upper Hessenberg matrix
+#[cfg(feature = "complex")]
+fn hqr(a: &mut [Vec<f64>]) -> Vec<C64> {
+    let n = a.len();
+    let mut wri = vec![C64::new(0f64, 0f64); n];
+    let sign = |a: f64, b: f64| if b >= 0f64 { a.abs() } else { -a.abs() };
+
+    let mut anorm = 0f64;
+    for i in 0..n {
+        for j in i.saturating_sub(1)..n {
+            anorm += a[i][j].abs();
+        }
+    }
+
+    let mut nn = n as isize - 1;
+    let mut t = 0f64;
+    let (mut p, mut q, mut r): (f64, f64, f64);
+    let (mut x, mut y, mut z, mut w): (f64, f64, f64, f64);
+    while nn >= 0 {
+        let nu = nn as usize;
+        let mut its = 0usize;
+        loop {
+            let mut l = nu;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0f64 {
+                    s = anorm;
+                }
+                if a[l][l - 1].abs() <= f64::EPSILON * s {
+                    a[l][l - 1] = 0f64;
+                    break;
+                }
+                l -= 1;
+            }
+            let nu = nn as usize;
+            x = a[nu][nu];
+            if l == nu {
+                wri[nu] = C64::new(x + t, 0f64);
+                nn -= 1;
+            } else {
+                y = a[nu - 1][nu - 1];
+                w = a[nu][nu - 1] * a[nu - 1][nu];
+                if l == nu - 1 {
+                    p = 0.5 * (y - x);
+                    q = p * p + w;
+                    z = q.abs().sqrt();
+                    x += t;
+                    if q >= 0f64 {
+                        z = p + sign(z, p);
+                        wri[nu - 1] = C64::new(x + z, 0f64);
+                        wri[nu] = wri[nu - 1];
+                        if z != 0f64 {
+                            wri[nu] = C64::new(x - w / z, 0f64);
+                        }
+                    } else {
+                        wri[nu] = C64::new(x + p, -z);
+                        wri[nu - 1] = wri[nu].conj();
+                    }
+                    nn -= 2;
+                } else {
+                    assert!(its < 30, "Too many iterations in hqr");
+                    if its == 10 || its == 20 {
+                        t += x;
+                        for i in 0..nu + 1 {
+                            a[i][i] -= x;
+                        }
+                        let s = a[nu][nu - 1].abs() + a[nu - 1][nu - 2].abs();
+                        x = 0.75 * s;
+                        y = x;
+                        w = -0.4375 * s * s;
+                    }
+                    its += 1;
+                    let mut m = nu - 2;
+                    loop {
+                        z = a[m][m];
+                        r = x - z;
+                        let s = y - z;
+                        p = (r * s - w) / a[m + 1][m] + a[m][m + 1];
+                        q = a[m + 1][m + 1] - z - r - s;
+                        r = a[m + 2][m + 1];
+                        let s = p.abs() + q.abs() + r.abs();
+                        p /= s;
+                        q /= s;
+                        r /= s;
+                        if m == l {
+                            break;
+                        }
+                        let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                        let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                        if u <= f64::EPSILON * v {
+                            break;
+                        }
+                        m -= 1;
+                    }
+                    for i in m..nu - 1 {
+                        a[i + 2][i] = 0f64;
+                        if i != m {
+                            a[i + 2][i - 1] = 0f64;
+                        }
+                    }
+                    for k in m..nu {
+                        if k != m {
+                            p = a[k][k - 1];
+                            q = a[k + 1][k - 1];
+                            r = 0f64;
+                            if k + 1 != nu {
+                                r = a[k + 2][k - 1];
+                            }
+                            x = p.abs() + q.abs() + r.abs();
+                            if x != 0f64 {
+                                p /= x;
+                                q /= x;
+                                r /= x;
+                            }
+                        }
+                        let s = sign((p * p + q * q + r * r).sqrt(), p);
+                        if s != 0f64 {
+                            if k == m {
+                                if l != m {
+                                    a[k][k - 1] = -a[k][k - 1];
+                                }
+                            } else {
+                                a[k][k - 1] = -s * x;
+                            }
+                            p += s;
+                            x = p / s;
+                            y = q / s;
+                            z = r / s;
+                            q /= p;
+                            r /= p;
+                            for j in k..nu + 1 {
+                                p = a[k][j] + q * a[k + 1][j];
+                                if k + 1 != nu {
+                                    p += r * a[k + 2][j];
+                                    a[k + 2][j] -= p * z;
+                                }
+                                a[k + 1][j] -= p * y;
+                                a[k][j] -= p * x;
+                            }
+                            let mmin = if nu < k + 3 { nu } else { k + 3 };
+                            for i in l..mmin + 1 {
+                                p = x * a[i][k] + y * a[i][k + 1];
+                                if k + 1 != nu {
+                                    p += z * a[i][k + 2];
+                                    a[i][k + 2] -= p * r;
+                                }
+                                a[i][k + 1] -= p * q;
+                                a[i][k] -= p;
+                            }
+                        }
+                    }
+                }
+            }
+            if (l as isize) + 1 >= nn {
+                break;
+            }
+        }
+    }
+    wri
+}
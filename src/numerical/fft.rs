@@ -0,0 +1,378 @@
+//! Fast Fourier Transform
+//!
+//! # Description
+//! Discrete Fourier transform with the convention
+//!
+//! $$ X_k = \sum_{n=0}^{N-1} x_n e^{-2\pi i k n / N} $$
+//!
+//! and the inverse normalized by $1/N$.
+//! Complex numbers are represented by `(re, im)` tuples, so this module does not require the `complex` feature.
+//!
+//! * Power-of-two lengths : iterative radix-2 Cooley-Tukey
+//! * Other lengths : Bluestein's algorithm (chirp-z transform via radix-2 convolution)
+//!
+//! # Available functions
+//!
+//! * `fft(x: &[f64]) -> Vec<(f64, f64)>` : DFT of real signal
+//! * `fft_complex(x: &[(f64, f64)]) -> Vec<(f64, f64)>` : DFT of complex signal
+//! * `ifft(x: &[(f64, f64)]) -> Vec<(f64, f64)>` : Inverse DFT
+//! * `rfft(x: &[f64]) -> Vec<(f64, f64)>` : Non-negative frequency terms of real signal (`n/2 + 1` terms)
+//! * `irfft(x: &[(f64, f64)], n: usize) -> Vec<f64>` : Inverse of `rfft`
+//! * `fft2(m: &Matrix) -> (Matrix, Matrix)` : 2D DFT (real part, imaginary part)
+//! * `ifft2(re: &Matrix, im: &Matrix) -> (Matrix, Matrix)` : 2D inverse DFT
+//! * `fftfreq(n: usize, dt: f64) -> Vec<f64>` : Sample frequencies
+//! * `periodogram(x: &[f64], dt: f64) -> (Vec<f64>, Vec<f64>)` : One-sided power spectral density
+//!
+//! # Examples
+//!
+//! ```
+//! #[macro_use]
+//! extern crate peroxide;
+//! use peroxide::fuga::*;
+//!
+//! fn main() {
+//!     let x = vec![1f64, 2f64, 3f64, 4f64];
+//!     let y = fft(&x);
+//!     assert_eq!(y[0], (10f64, 0f64));
+//!     assert_eq!(y[2], (-2f64, 0f64));
+//!
+//!     let z = ifft(&y);
+//!     for (a, b) in x.iter().zip(z.iter()) {
+//!         assert!((a - b.0).abs() < 1e-12);
+//!     }
+//! }
+//! ```
+
+use crate::structure::matrix::{matrix, Matrix, Shape::Row};
+use std::f64::consts::PI;
+
+/// DFT of real signal
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = vec![1f64, 0f64, -1f64, 0f64];
+/// let y = fft(&x);
+/// assert_eq!(y, vec![(0f64, 0f64), (2f64, 0f64), (0f64, 0f64), (2f64, 0f64)]);
+/// ```
+pub fn fft(x: &[f64]) -> Vec<(f64, f64)> {
+    let z = x.iter().map(|&t| (t, 0f64)).collect::<Vec<_>>();
+    fft_complex(&z)
+}
+
+/// DFT of complex signal
+pub fn fft_complex(x: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut z = x.to_vec();
+    transform(&mut z, false);
+    z
+}
+
+/// Inverse DFT (normalized by `1/n`)
+pub fn ifft(x: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut z = x.to_vec();
+    transform(&mut z, true);
+    let n = z.len() as f64;
+    z.iter().map(|&(re, im)| (re / n, im / n)).collect()
+}
+
+/// Non-negative frequency terms of DFT of real signal
+///
+/// # Description
+/// For real input, $X_{N-k} = \overline{X_k}$, so only `n/2 + 1` terms are returned.
+/// For even `n`, the signal is packed into a complex signal of length `n/2`,
+/// which halves the work of the transform.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = vec![1f64, 2f64, 3f64, 4f64, 5f64, 6f64];
+/// let y = rfft(&x);
+/// let z = fft(&x);
+/// assert_eq!(y.len(), 4);
+/// for k in 0 .. 4 {
+///     assert!((y[k].0 - z[k].0).abs() < 1e-12);
+///     assert!((y[k].1 - z[k].1).abs() < 1e-12);
+/// }
+/// ```
+pub fn rfft(x: &[f64]) -> Vec<(f64, f64)> {
+    let n = x.len();
+    if n < 2 || !n.is_multiple_of(2) {
+        let mut y = fft(x);
+        y.truncate(n / 2 + 1);
+        return y;
+    }
+    let h = n / 2;
+    let z = x.chunks_exact(2).map(|c| (c[0], c[1])).collect::<Vec<_>>();
+    let zf = fft_complex(&z);
+    let mut y = vec![(0f64, 0f64); h + 1];
+    for (k, yk) in y.iter_mut().enumerate() {
+        let a = zf[k % h];
+        let b = conj(zf[(h - k % h) % h]);
+        // Even & odd parts of the packed signal
+        let e = ((a.0 + b.0) / 2f64, (a.1 + b.1) / 2f64);
+        let o = ((a.1 - b.1) / 2f64, -(a.0 - b.0) / 2f64);
+        *yk = add(e, mul(twiddle(k, n, false), o));
+    }
+    y
+}
+
+/// Inverse of `rfft`
+///
+/// # Description
+/// `n` is the length of the original real signal.
+pub fn irfft(x: &[(f64, f64)], n: usize) -> Vec<f64> {
+    assert_eq!(x.len(), n / 2 + 1, "Length of input should be n/2 + 1");
+    let mut z = vec![(0f64, 0f64); n];
+    for k in 0..n {
+        z[k] = if k < x.len() { x[k] } else { conj(x[n - k]) };
+    }
+    ifft(&z).into_iter().map(|t| t.0).collect()
+}
+
+/// 2D DFT of real matrix
+///
+/// # Description
+/// Returns `(re, im)` - real & imaginary parts of the transform.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let m = ml_matrix("1 2;3 4");
+///     let (re, im) = fft2(&m);
+///     assert_eq!(re, ml_matrix("10 -2;-4 0"));
+///     assert_eq!(im, zeros(2, 2));
+/// }
+/// ```
+pub fn fft2(m: &Matrix) -> (Matrix, Matrix) {
+    let z = (0..m.row)
+        .flat_map(|i| (0..m.col).map(move |j| (m[(i, j)], 0f64)))
+        .collect::<Vec<_>>();
+    split(transform2(z, m.row, m.col, false), m.row, m.col)
+}
+
+/// 2D inverse DFT
+///
+/// # Description
+/// Takes real & imaginary parts and returns `(re, im)` of the inverse transform.
+pub fn ifft2(re: &Matrix, im: &Matrix) -> (Matrix, Matrix) {
+    assert!(
+        re.row == im.row && re.col == im.col,
+        "Real & imaginary parts should have same size"
+    );
+    let (r, c) = (re.row, re.col);
+    let z = (0..r)
+        .flat_map(|i| (0..c).map(move |j| (re[(i, j)], im[(i, j)])))
+        .collect::<Vec<_>>();
+    let n = (r * c) as f64;
+    let w = transform2(z, r, c, true)
+        .into_iter()
+        .map(|(a, b)| (a / n, b / n))
+        .collect();
+    split(w, r, c)
+}
+
+/// Sample frequencies of DFT
+///
+/// # Description
+/// Same order as the output of `fft` : `[0, 1, ..., n/2 - 1, -n/2, ..., -1] / (n dt)` for even `n`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let f = fftfreq(5, 0.1);
+/// assert_eq!(f, vec![0f64, 2f64, 4f64, -4f64, -2f64]);
+/// ```
+pub fn fftfreq(n: usize, dt: f64) -> Vec<f64> {
+    let d = n as f64 * dt;
+    (0..n)
+        .map(|k| {
+            if k < n.div_ceil(2) {
+                k as f64 / d
+            } else {
+                (k as f64 - n as f64) / d
+            }
+        })
+        .collect()
+}
+
+/// One-sided power spectral density
+///
+/// # Description
+/// Returns `(freq, psd)` for non-negative frequencies.
+/// `psd` is normalized such that `sum(psd) * df` equals the mean square of `x`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+/// use std::f64::consts::PI;
+///
+/// let dt = 0.01;
+/// let x = (0 .. 200).map(|i| (2f64 * PI * 10f64 * i as f64 * dt).sin()).collect::<Vec<f64>>();
+/// let (f, p) = periodogram(&x, dt);
+/// let k = p.arg_max();
+/// assert_eq!(f[k], 10f64);
+/// ```
+pub fn periodogram(x: &[f64], dt: f64) -> (Vec<f64>, Vec<f64>) {
+    let n = x.len();
+    let y = rfft(x);
+    let freq = (0..y.len()).map(|k| k as f64 / (n as f64 * dt)).collect();
+    let psd = y
+        .iter()
+        .enumerate()
+        .map(|(k, &(re, im))| {
+            let p = (re * re + im * im) * dt / n as f64;
+            if k == 0 || (n.is_multiple_of(2) && k == n / 2) {
+                p
+            } else {
+                2f64 * p
+            }
+        })
+        .collect();
+    (freq, psd)
+}
+
+// =============================================================================
+// Backend
+// =============================================================================
+fn transform(x: &mut Vec<(f64, f64)>, inverse: bool) {
+    let n = x.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(x, inverse);
+    } else {
+        *x = bluestein(x, inverse);
+    }
+}
+
+/// Iterative radix-2 Cooley-Tukey (in-place)
+fn radix2(x: &mut [(f64, f64)], inverse: bool) {
+    let n = x.len();
+    // Bit reversal permutation
+    let mut j = 0usize;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            x.swap(i, j);
+        }
+    }
+    // Butterflies
+    let mut len = 2usize;
+    while len <= n {
+        let w = twiddle(1, len, inverse);
+        for start in (0..n).step_by(len) {
+            let mut wk = (1f64, 0f64);
+            for k in 0..len / 2 {
+                let u = x[start + k];
+                let v = mul(x[start + k + len / 2], wk);
+                x[start + k] = add(u, v);
+                x[start + k + len / 2] = sub(u, v);
+                wk = mul(wk, w);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Bluestein's algorithm for arbitrary length
+fn bluestein(x: &[(f64, f64)], inverse: bool) -> Vec<(f64, f64)> {
+    let n = x.len();
+    let m = (2 * n - 1).next_power_of_two();
+    // Chirp : w_k = exp(-i pi k^2 / n) (k^2 is reduced modulo 2n for accuracy)
+    let sign = if inverse { 1f64 } else { -1f64 };
+    let chirp = (0..n)
+        .map(|k| {
+            let k2 = (k * k) % (2 * n);
+            let theta = sign * PI * k2 as f64 / n as f64;
+            (theta.cos(), theta.sin())
+        })
+        .collect::<Vec<_>>();
+
+    let mut a = vec![(0f64, 0f64); m];
+    let mut b = vec![(0f64, 0f64); m];
+    for k in 0..n {
+        a[k] = mul(x[k], chirp[k]);
+    }
+    b[0] = conj(chirp[0]);
+    for k in 1..n {
+        b[k] = conj(chirp[k]);
+        b[m - k] = conj(chirp[k]);
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (u, v) in a.iter_mut().zip(b.iter()) {
+        *u = mul(*u, *v);
+    }
+    radix2(&mut a, true);
+
+    let scale = m as f64;
+    (0..n)
+        .map(|k| {
+            let c = (a[k].0 / scale, a[k].1 / scale);
+            mul(c, chirp[k])
+        })
+        .collect()
+}
+
+/// 2D transform of row-major data
+fn transform2(mut z: Vec<(f64, f64)>, r: usize, c: usize, inverse: bool) -> Vec<(f64, f64)> {
+    for row in z.chunks_exact_mut(c) {
+        let mut v = row.to_vec();
+        transform(&mut v, inverse);
+        row.copy_from_slice(&v);
+    }
+    for j in 0..c {
+        let mut v = (0..r).map(|i| z[i * c + j]).collect::<Vec<_>>();
+        transform(&mut v, inverse);
+        for (i, t) in v.into_iter().enumerate() {
+            z[i * c + j] = t;
+        }
+    }
+    z
+}
+
+fn split(z: Vec<(f64, f64)>, r: usize, c: usize) -> (Matrix, Matrix) {
+    let (re, im): (Vec<f64>, Vec<f64>) = z.into_iter().unzip();
+    (matrix(re, r, c, Row), matrix(im, r, c, Row))
+}
+
+#[inline]
+fn twiddle(k: usize, n: usize, inverse: bool) -> (f64, f64) {
+    let sign = if inverse { 1f64 } else { -1f64 };
+    let theta = sign * 2f64 * PI * k as f64 / n as f64;
+    (theta.cos(), theta.sin())
+}
+
+#[inline]
+fn add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+#[inline]
+fn sub(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+#[inline]
+fn mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+#[inline]
+fn conj(a: (f64, f64)) -> (f64, f64) {
+    (a.0, -a.1)
+}
@@ -1,11 +1,16 @@
+use crate::numerical::eigen::{eigen, EigenMethod::Jacobi};
 use crate::structure::polynomial::{lagrange_polynomial, Calculus};
 use crate::traits::fp::FPVector;
-use crate::util::non_macro::seq;
+use crate::util::non_macro::{seq, zeros};
+use anyhow::{bail, Result};
+use rand::prelude::*;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Integral {
     GaussLegendre(usize),
     NewtonCotes(usize),
+    GaussHermite(usize),
+    GaussLaguerre(usize),
     G7K15(f64, u32),
     G10K21(f64, u32),
     G15K31(f64, u32),
@@ -25,6 +30,8 @@ impl Integral {
         match self {
             Integral::GaussLegendre(n) => *n,
             Integral::NewtonCotes(n) => *n,
+            Integral::GaussHermite(n) => *n,
+            Integral::GaussLaguerre(n) => *n,
             _ => panic!("This method does not have a fixed number of nodes."),
         }
     }
@@ -143,8 +150,12 @@ impl Integral {
 ///
 /// # Method
 ///
-/// * Gauss-Legendre Quadrature (up to order 30) : `GaussLegendre(usize)`
+/// * Gauss-Legendre Quadrature : `GaussLegendre(usize)`
 /// * Newton-Cotes Quadrature: `NewtonCotes(usize)`
+/// * Gauss-Hermite Quadrature : `GaussHermite(usize)`
+///     * Only for `(a,b) = (-∞, ∞)`
+/// * Gauss-Laguerre Quadrature : `GaussLaguerre(usize)`
+///     * Only for `(a, ∞)` or `(-∞, b)`
 /// * Gauss-Kronrod Quadrature
 ///     * `G7K15(tol, max_iter)`
 ///     * `G10K21`
@@ -159,6 +170,20 @@ impl Integral {
 ///     * `G20K41R`
 ///     * `G25K51R`
 ///     * `G30K61R`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+/// use std::f64::consts::PI;
+///
+/// // ∫ exp(-x²) cos(x) dx = √π exp(-1/4)
+/// let s = integrate(|x| (-x * x).exp() * x.cos(), (f64::NEG_INFINITY, f64::INFINITY), GaussHermite(20));
+/// assert!((s - PI.sqrt() * (-0.25f64).exp()).abs() < 1e-12);
+///
+/// // ∫_1^∞ exp(-2x) dx = exp(-2) / 2
+/// let s = integrate(|x| (-2f64 * x).exp(), (1f64, f64::INFINITY), GaussLaguerre(20));
+/// assert!((s - (-2f64).exp() / 2f64).abs() < 1e-10);
+/// ```
 pub fn integrate<F>(f: F, (a, b): (f64, f64), method: Integral) -> f64
 where
     F: Fn(f64) -> f64 + Copy,
@@ -166,10 +191,185 @@ where
     match method {
         Integral::GaussLegendre(n) => gauss_legendre_quadrature(f, n, (a, b)),
         Integral::NewtonCotes(n) => newton_cotes_quadrature(f, n, (a, b)),
+        Integral::GaussHermite(_) | Integral::GaussLaguerre(_) => {
+            let (x, w) = weighted_nodes(method, (a, b)).unwrap();
+            weighted_sum(f, &x, &w)
+        }
         method => gauss_kronrod_quadrature(f, (a,b), method),
     }
 }
 
+/// Double integration
+///
+/// # Description
+/// `∫_{ax}^{bx} ∫_{ay}^{by} f(x, y) dy dx` by nested 1D quadrature (`integrate`).
+/// The same `method` is used for both axes (infinite bounds follow the rules of `integrate`).
+/// For `GaussHermite` & `GaussLaguerre`, nodes are computed once per axis, not per inner integral.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Second moment of Uniform([0, 1]²) : E[x² + y²] = 2 / 3
+/// let s = integrate2d(|x, y| x * x + y * y, (0f64, 1f64), (0f64, 1f64), GaussLegendre(5));
+/// assert!((s - 2f64 / 3f64).abs() < 1e-14);
+/// ```
+pub fn integrate2d<F>(f: F, (ax, bx): (f64, f64), (ay, by): (f64, f64), method: Integral) -> f64
+where
+    F: Fn(f64, f64) -> f64,
+{
+    let f = &f;
+    match (weighted_nodes(method, (ax, bx)), weighted_nodes(method, (ay, by))) {
+        (Some((x, wx)), Some((y, wy))) => {
+            weighted_sum(|s| weighted_sum(|t| f(s, t), &y, &wy), &x, &wx)
+        }
+        _ => integrate(
+            move |x| integrate(move |y| f(x, y), (ay, by), method),
+            (ax, bx),
+            method,
+        ),
+    }
+}
+
+/// Triple integration
+///
+/// # Description
+/// `∫_{ax}^{bx} ∫_{ay}^{by} ∫_{az}^{bz} f(x, y, z) dz dy dx` by nested 1D quadrature (`integrate`).
+/// The same `method` is used for all axes.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // (∫_0^1 x dx) (∫_0^2 y dy) (∫_0^3 z dz) = 1/2 · 2 · 9/2
+/// let s = integrate3d(|x, y, z| x * y * z, (0f64, 1f64), (0f64, 2f64), (0f64, 3f64), GaussLegendre(2));
+/// assert!((s - 4.5).abs() < 1e-12);
+/// ```
+pub fn integrate3d<F>(
+    f: F,
+    (ax, bx): (f64, f64),
+    (ay, by): (f64, f64),
+    (az, bz): (f64, f64),
+    method: Integral,
+) -> f64
+where
+    F: Fn(f64, f64, f64) -> f64,
+{
+    let f = &f;
+    match (
+        weighted_nodes(method, (ax, bx)),
+        weighted_nodes(method, (ay, by)),
+        weighted_nodes(method, (az, bz)),
+    ) {
+        (Some((x, wx)), Some((y, wy)), Some((z, wz))) => weighted_sum(
+            |s| weighted_sum(|t| weighted_sum(|u| f(s, t, u), &z, &wz), &y, &wy),
+            &x,
+            &wx,
+        ),
+        _ => integrate(
+            move |x| integrate2d(move |y, z| f(x, y, z), (ay, by), (az, bz), method),
+            (ax, bx),
+            method,
+        ),
+    }
+}
+
+/// Nodes & weights of `GaussHermite` or `GaussLaguerre` for plain `∫ f(x) dx` on `(a, b)`
+///
+/// # Description
+/// The weight function is folded into the weights (`w_i exp(x_i²)` or `w_i exp(t_i)`).
+/// This is done in log scale, since `exp(x_i²)` overflows for the outer nodes of large `n`
+/// while `w_i` underflows.
+/// Returns `None` for other methods.
+fn weighted_nodes(method: Integral, (a, b): (f64, f64)) -> Option<(Vec<f64>, Vec<f64>)> {
+    match method {
+        Integral::GaussHermite(n) => {
+            assert!(
+                a == f64::NEG_INFINITY && b == f64::INFINITY,
+                "Gauss-Hermite quadrature is only for (-inf, inf)"
+            );
+            let (x, w) = gauss_hermite_nodes(n);
+            let w = x
+                .iter()
+                .zip(w.iter())
+                .map(|(&x, &w)| (w.ln() + x * x).exp())
+                .collect();
+            Some((x, w))
+        }
+        Integral::GaussLaguerre(n) => {
+            let (t, w) = gauss_laguerre_nodes(n);
+            let w = t
+                .iter()
+                .zip(w.iter())
+                .map(|(&t, &w)| (w.ln() + t).exp())
+                .collect();
+            let x = match (a.is_finite(), b.is_finite()) {
+                (true, false) if b > 0f64 => t.iter().map(|&t| a + t).collect(),
+                (false, true) if a < 0f64 => t.iter().map(|&t| b - t).collect(),
+                _ => panic!("Gauss-Laguerre quadrature is only for (a, inf) or (-inf, b)"),
+            };
+            Some((x, w))
+        }
+        _ => None,
+    }
+}
+
+/// `Σ w_i f(x_i)`
+fn weighted_sum<F: Fn(f64) -> f64>(f: F, x: &[f64], w: &[f64]) -> f64 {
+    x.iter().zip(w.iter()).map(|(&x, &w)| w * f(x)).sum()
+}
+
+/// Monte Carlo integration over a hyperrectangle
+///
+/// # Description
+/// Estimate `∫ f(x) dx` over `bounds` (`(a_i, b_i)` for each dimension) from `n` uniform samples.
+///
+/// * Estimate : `V · mean(f(x_k))` (`V = Π (b_i - a_i)`)
+/// * Standard error : `V · sd(f(x_k)) / √n`, which shrinks like `1 / √n` regardless of the dimension
+///
+/// `seed` is used for `StdRng` (`None` for a seed from `thread_rng`).
+///
+/// # Panics
+/// * `bounds` is empty
+/// * `n < 2`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // ∫_{[0, 1]^10} Σ x_i dx = 5
+/// let (s, se) = monte_carlo_integrate(|x| x.iter().sum(), &[(0f64, 1f64); 10], 100_000, Some(42));
+/// assert!((s - 5f64).abs() < 4f64 * se);
+/// assert!(se < 0.01);
+/// ```
+pub fn monte_carlo_integrate<F>(f: F, bounds: &[(f64, f64)], n: usize, seed: Option<u64>) -> (f64, f64)
+where
+    F: Fn(&[f64]) -> f64,
+{
+    assert!(!bounds.is_empty(), "bounds should not be empty");
+    assert!(n > 1, "Number of samples should be larger than 1");
+
+    let mut rng = StdRng::seed_from_u64(seed.unwrap_or_else(|| thread_rng().gen()));
+    let volume = bounds.iter().map(|(a, b)| b - a).product::<f64>();
+
+    // Welford's online mean & variance
+    let mut x = vec![0f64; bounds.len()];
+    let mut mean = 0f64;
+    let mut m2 = 0f64;
+    for k in 1..=n {
+        for (xi, (a, b)) in x.iter_mut().zip(bounds) {
+            *xi = a + (b - a) * rng.gen::<f64>();
+        }
+        let y = f(&x);
+        let delta = y - mean;
+        mean += delta / k as f64;
+        m2 += delta * (y - mean);
+    }
+    let var = m2 / (n - 1) as f64;
+
+    (volume * mean, volume.abs() * (var / n as f64).sqrt())
+}
+
 /// Newton Cotes Quadrature
 pub fn newton_cotes_quadrature<F>(f: F, n: usize, (a, b): (f64, f64)) -> f64
 where
@@ -189,7 +389,7 @@ where
 /// # Type
 /// * `f, n, (a,b) -> f64`
 ///     * `f`: Numerical function (`Fn(f64) -> f64`)
-///     * `n`: Order of Legendre polynomial (up to 16)
+///     * `n`: Order of Legendre polynomial (tabulated for 2 to 30, otherwise computed by `gauss_legendre_nodes`)
 ///     * `(a,b)`: Interval of integration
 ///
 /// # Reference
@@ -262,6 +462,378 @@ where
     (b - a) / 2f64 * unit_kronrod_quadrature(|x| f(x * (b-a) / 2f64 + (a + b) / 2f64), n)   
 }
 
+/// Gauss Hermite Quadrature
+///
+/// # Description
+/// `∫_{-∞}^{∞} f(x) exp(-x²) dx ≈ Σ w_i f(x_i)`
+///
+/// Exact for polynomial `f` of degree up to `2n - 1`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+/// use std::f64::consts::PI;
+///
+/// // E[X²] for X ~ N(0, 1/2) : ∫ x² exp(-x²) dx / √π = 1/2
+/// let s = gauss_hermite_quadrature(|x| x * x, 5) / PI.sqrt();
+/// assert!((s - 0.5).abs() < 1e-14);
+/// ```
+pub fn gauss_hermite_quadrature<F>(f: F, n: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let (x, w) = gauss_hermite_nodes(n);
+    x.iter().zip(w.iter()).map(|(&x, &w)| w * f(x)).sum()
+}
+
+/// Gauss Laguerre Quadrature
+///
+/// # Description
+/// `∫_0^∞ f(x) exp(-x) dx ≈ Σ w_i f(x_i)`
+///
+/// Exact for polynomial `f` of degree up to `2n - 1`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // ∫ x³ exp(-x) dx = 3!
+/// let s = gauss_laguerre_quadrature(|x| x.powi(3), 3);
+/// assert!((s - 6f64).abs() < 1e-12);
+/// ```
+pub fn gauss_laguerre_quadrature<F>(f: F, n: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let (x, w) = gauss_laguerre_nodes(n);
+    x.iter().zip(w.iter()).map(|(&x, &w)| w * f(x)).sum()
+}
+
+// =============================================================================
+// Gaussian quadrature nodes
+// =============================================================================
+/// Nodes & weights of Gauss-Legendre quadrature on `[-1, 1]`
+///
+/// # Description
+/// Returns `(nodes, weights)` for arbitrary `n` (nodes in ascending order).
+/// Each root of `P_n` is found by Newton iteration from `cos(π (i + 3/4) / (n + 1/2))`,
+/// and `w_i = 2 / ((1 - x_i²) P_n'(x_i)²)`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let (x, w) = gauss_legendre_nodes(3);
+/// assert!((x[2] - 0.6f64.sqrt()).abs() < 1e-15);
+/// assert!((w[1] - 8f64 / 9f64).abs() < 1e-15);
+///
+/// // ∫_{-1}^{1} x^98 dx = 2 / 99
+/// let (x, w) = gauss_legendre_nodes(50);
+/// let s: f64 = x.iter().zip(w.iter()).map(|(x, w)| w * x.powi(98)).sum();
+/// assert!((s - 2f64 / 99f64).abs() < 1e-14);
+/// ```
+pub fn gauss_legendre_nodes(n: usize) -> (Vec<f64>, Vec<f64>) {
+    assert!(n > 0, "Number of nodes should be positive");
+    let nf = n as f64;
+    let mut x = vec![0f64; n];
+    let mut w = vec![0f64; n];
+    for i in 0..n.div_ceil(2) {
+        let mut z = (std::f64::consts::PI * (i as f64 + 0.75) / (nf + 0.5)).cos();
+        let mut dp = 0f64;
+        for _ in 0..100 {
+            // P_n(z) & P_{n-1}(z) by recurrence
+            let (mut p0, mut p1) = (1f64, z);
+            for k in 2..=n {
+                let kf = k as f64;
+                let p2 = ((2f64 * kf - 1f64) * z * p1 - (kf - 1f64) * p0) / kf;
+                p0 = p1;
+                p1 = p2;
+            }
+            let (p, p_prev) = if n == 1 { (z, 1f64) } else { (p1, p0) };
+            dp = nf * (z * p - p_prev) / (z * z - 1f64);
+            let dz = p / dp;
+            z -= dz;
+            if dz.abs() < 1e-16 {
+                break;
+            }
+        }
+        let weight = 2f64 / ((1f64 - z * z) * dp * dp);
+        x[i] = -z;
+        x[n - 1 - i] = z;
+        w[i] = weight;
+        w[n - 1 - i] = weight;
+    }
+    (x, w)
+}
+
+/// Nodes & weights of Gauss-Hermite quadrature (weight `exp(-x²)` on `(-∞, ∞)`)
+///
+/// # Description
+/// Returns `(nodes, weights)` for arbitrary `n` (nodes in ascending order).
+///
+/// * Initial nodes : eigenvalues of the Jacobi matrix (`J[k, k+1] = √((k+1) / 2)`, Golub-Welsch)
+/// * Each node is refined by Newton iteration on the orthonormal Hermite recurrence,
+///   and `w_i = 2 / h_n'(x_i)²`, which keeps tiny weights of outer nodes accurate.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+/// use std::f64::consts::PI;
+///
+/// let (x, w) = gauss_hermite_nodes(2);
+/// assert!((x[1] - 0.5f64.sqrt()).abs() < 1e-14);
+/// assert!((w[0] - PI.sqrt() / 2f64).abs() < 1e-14);
+/// ```
+pub fn gauss_hermite_nodes(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let diag = vec![0f64; n];
+    let off = (1..n).map(|k| (k as f64 / 2f64).sqrt()).collect::<Vec<f64>>();
+    let pi_quarter = std::f64::consts::PI.powf(-0.25);
+
+    // (h_n(z), h_n'(z)) of orthonormal Hermite polynomial
+    let hermite = |z: f64| {
+        let (mut p0, mut p1) = (0f64, pi_quarter);
+        for j in 1..=n {
+            let jf = j as f64;
+            let p2 = z * (2f64 / jf).sqrt() * p1 - ((jf - 1f64) / jf).sqrt() * p0;
+            p0 = p1;
+            p1 = p2;
+        }
+        (p1, (2f64 * n as f64).sqrt() * p0)
+    };
+
+    let mut x = jacobi_matrix_eigenvalues(&diag, &off);
+    let w = x
+        .iter_mut()
+        .map(|z| {
+            let dp = newton_refine(z, hermite);
+            2f64 / (dp * dp)
+        })
+        .collect();
+    (x, w)
+}
+
+/// Nodes & weights of Gauss-Laguerre quadrature (weight `exp(-x)` on `[0, ∞)`)
+///
+/// # Description
+/// Returns `(nodes, weights)` for arbitrary `n` (nodes in ascending order).
+///
+/// * Initial nodes : eigenvalues of the Jacobi matrix (`J[k, k] = 2k + 1`, `J[k, k+1] = k + 1`, Golub-Welsch)
+/// * Each node is refined by Newton iteration on the Laguerre recurrence,
+///   and `w_i = 1 / (x_i L_n'(x_i)²)`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let (x, w) = gauss_laguerre_nodes(2);
+/// assert!((x[0] - (2f64 - 2f64.sqrt())).abs() < 1e-14);
+/// assert!((w[0] - (2f64 + 2f64.sqrt()) / 4f64).abs() < 1e-14);
+/// ```
+pub fn gauss_laguerre_nodes(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let diag = (0..n).map(|k| 2f64 * k as f64 + 1f64).collect::<Vec<f64>>();
+    let off = (1..n).map(|k| k as f64).collect::<Vec<f64>>();
+    let nf = n as f64;
+
+    // (L_n(z), L_n'(z))
+    let laguerre = |z: f64| {
+        let (mut p0, mut p1) = (0f64, 1f64);
+        for j in 0..n {
+            let jf = j as f64;
+            let p2 = ((2f64 * jf + 1f64 - z) * p1 - jf * p0) / (jf + 1f64);
+            p0 = p1;
+            p1 = p2;
+        }
+        (p1, nf * (p1 - p0) / z)
+    };
+
+    let mut x = jacobi_matrix_eigenvalues(&diag, &off);
+    let w = x
+        .iter_mut()
+        .map(|z| {
+            let dp = newton_refine(z, laguerre);
+            1f64 / (*z * dp * dp)
+        })
+        .collect();
+    (x, w)
+}
+
+/// Eigenvalues (ascending) of symmetric tridiagonal Jacobi matrix
+///
+/// * `diag` : diagonal (`n`)
+/// * `off` : off-diagonal (`n - 1`)
+fn jacobi_matrix_eigenvalues(diag: &[f64], off: &[f64]) -> Vec<f64> {
+    let n = diag.len();
+    assert!(n > 0, "Number of nodes should be positive");
+    let mut j = zeros(n, n);
+    for (i, &a) in diag.iter().enumerate() {
+        j[(i, i)] = a;
+    }
+    for (i, &b) in off.iter().enumerate() {
+        j[(i, i + 1)] = b;
+        j[(i + 1, i)] = b;
+    }
+    let mut val = eigen(&j, Jacobi).eigenvalue;
+    val.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    val
+}
+
+/// Refine a root `z` of `p` by Newton iteration (`p : z -> (p(z), p'(z))`), and return `p'(z)`
+fn newton_refine<F: Fn(f64) -> (f64, f64)>(z: &mut f64, p: F) -> f64 {
+    let mut dp = 0f64;
+    for _ in 0..10 {
+        let (value, d) = p(*z);
+        dp = d;
+        let dz = value / d;
+        *z -= dz;
+        if dz.abs() <= 1e-15 * z.abs().max(1f64) {
+            break;
+        }
+    }
+    dp
+}
+
+// =============================================================================
+// Integration of sampled data
+// =============================================================================
+/// Error for integration of sampled data
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SampledIntegralError {
+    DifferentLength,
+    TooFewPoints,
+}
+
+impl std::fmt::Display for SampledIntegralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            SampledIntegralError::DifferentLength => write!(f, "x and y must have the same length"),
+            SampledIntegralError::TooFewPoints => write!(f, "At least 2 points are required to integrate"),
+        }
+    }
+}
+
+impl std::error::Error for SampledIntegralError {}
+
+fn check_samples(x: &[f64], y: &[f64]) -> Result<()> {
+    if x.len() != y.len() {
+        bail!(SampledIntegralError::DifferentLength);
+    }
+    if y.len() < 2 {
+        bail!(SampledIntegralError::TooFewPoints);
+    }
+    Ok(())
+}
+
+/// Trapezoidal rule for sampled data
+///
+/// # Description
+/// `x` can be non-uniformly spaced (not necessarily increasing).
+///
+/// # Errors
+/// * `x` and `y` have different length
+/// * Fewer than 2 points
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let x = vec![0f64, 1f64, 3f64];
+///     let y = vec![0f64, 1f64, 3f64];
+///     assert_eq!(trapz(&x, &y)?, 4.5);
+///     assert!(trapz(&x, &y[..2]).is_err());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn trapz(x: &[f64], y: &[f64]) -> Result<f64> {
+    check_samples(x, y)?;
+    Ok(x.windows(2)
+        .zip(y.windows(2))
+        .map(|(x, y)| (x[1] - x[0]) * (y[0] + y[1]) / 2f64)
+        .sum())
+}
+
+/// Cumulative trapezoidal rule for sampled data
+///
+/// # Description
+/// Running integral `I[i] = int_{x[0]}^{x[i]} y dx` (same length as `x`, `I[0] = 0`)
+///
+/// # Errors
+/// Same as `trapz`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let x = vec![0f64, 1f64, 3f64];
+///     let y = vec![0f64, 1f64, 3f64];
+///     assert_eq!(cumtrapz(&x, &y)?, vec![0f64, 0.5, 4.5]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn cumtrapz(x: &[f64], y: &[f64]) -> Result<Vec<f64>> {
+    check_samples(x, y)?;
+    let mut result = Vec::with_capacity(y.len());
+    let mut s = 0f64;
+    result.push(s);
+    for (x, y) in x.windows(2).zip(y.windows(2)) {
+        s += (x[1] - x[0]) * (y[0] + y[1]) / 2f64;
+        result.push(s);
+    }
+    Ok(result)
+}
+
+/// Composite Simpson's rule for uniformly sampled data
+///
+/// # Description
+/// * Odd number of points (even number of intervals) : Simpson's 1/3 rule
+/// * Even number of points : Simpson's 1/3 rule for all but the last 3 intervals,
+///   and Simpson's 3/8 rule for the last 3 intervals
+/// * 2 points : Trapezoidal rule
+///
+/// # Errors
+/// Fewer than 2 points
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     // Exact for cubic polynomials
+///     let y = seq(0, 1, 0.25).fmap(|x| x.powi(3));
+///     assert!((simpson(&y, 0.25)? - 0.25).abs() < 1e-15);
+///     let y = seq(0, 1, 0.2).fmap(|x| x.powi(3));
+///     assert!((simpson(&y, 0.2)? - 0.25).abs() < 1e-15);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn simpson(y: &[f64], dx: f64) -> Result<f64> {
+    let n = y.len();
+    if n < 2 {
+        bail!(SampledIntegralError::TooFewPoints);
+    }
+    if n == 2 {
+        return Ok(dx * (y[0] + y[1]) / 2f64);
+    }
+
+    // Simpson's 1/3 rule on y[0..=m] (m is even)
+    let m = if n.is_multiple_of(2) { n - 4 } else { n - 1 };
+    let mut s = 0f64;
+    for i in (0..m).step_by(2) {
+        s += y[i] + 4f64 * y[i + 1] + y[i + 2];
+    }
+    s *= dx / 3f64;
+
+    // Simpson's 3/8 rule on y[n-4..n]
+    if n.is_multiple_of(2) {
+        s += 3f64 * dx / 8f64 * (y[n - 4] + 3f64 * y[n - 3] + 3f64 * y[n - 2] + y[n - 1]);
+    }
+    Ok(s)
+}
+
 // =============================================================================
 // Gauss Legendre Backends
 // =============================================================================
@@ -269,7 +841,12 @@ fn unit_gauss_legendre_quadrature<F>(f: F, n: usize) -> f64
 where
     F: Fn(f64) -> f64,
 {
-    let (a, x) = gauss_legendre_table(n);
+    let (a, x) = if (2..=30).contains(&n) {
+        gauss_legendre_table(n)
+    } else {
+        let (x, a) = gauss_legendre_nodes(n);
+        (a, x)
+    };
     let mut s = 0f64;
     for i in 0..a.len() {
         s += a[i] * f(x[i]);
@@ -310,7 +887,7 @@ fn gauss_legendre_table(n: usize) -> (Vec<f64>, Vec<f64>) {
         28 => &LEGENDRE_ROOT_28[..],
         29 => &LEGENDRE_ROOT_29[..],
         30 => &LEGENDRE_ROOT_30[..],
-        _ => panic!("Legendre quadrature is limited up to n = 16"),
+        _ => unreachable!(),
     };
 
     let ref_weight: &[f64] = match n {
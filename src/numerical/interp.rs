@@ -1,6 +1,17 @@
+//! Interpolation
+//!
+//! * `chebyshev_nodes` : Chebyshev nodes on an interval
+//! * `LinearInterp` : Piecewise linear interpolation with `Extrapolation` policy
+//!
+//! For polynomial interpolation, see `lagrange_polynomial` (`structure::polynomial`),
+//! and for splines, see `numerical::spline`.
+
 #[allow(unused_imports)]
 use crate::structure::polynomial::*;
 
+use anyhow::{bail, Result};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::convert::Into;
 use std::f64::consts::PI;
 
@@ -16,3 +27,204 @@ where
     }
     return v;
 }
+
+// =============================================================================
+// Piecewise linear interpolation
+// =============================================================================
+/// Extrapolation policy of `LinearInterp`
+///
+/// * `Error` : Panic for `x` outside of nodes
+/// * `Clamp` : Use the value of the nearest end node
+/// * `Linear` : Extend the first (last) segment
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Extrapolation {
+    Error,
+    Clamp,
+    Linear,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum InterpError {
+    NotEnoughNodes,
+    NotEqualNodes,
+    NotSortedNodes,
+}
+
+impl std::fmt::Display for InterpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InterpError::NotEnoughNodes => write!(f, "x has less than 2 elements"),
+            InterpError::NotEqualNodes => write!(f, "x and y have different lengths"),
+            InterpError::NotSortedNodes => write!(f, "x should be strictly increasing (sorted & unique)"),
+        }
+    }
+}
+
+/// Piecewise linear interpolation
+///
+/// # Description
+/// Connect nodes `(x_i, y_i)` by line segments.
+/// Outside of `[x_0, x_{n-1}]`, the value is given by `Extrapolation`.
+///
+/// * `eval(x)` : Binary search of the segment (`O(log n)`)
+/// * `eval_vec(xs)` : If `xs` is sorted, one pass over nodes & `xs` (`O(n + m)`),
+///   otherwise same as `eval` for each element
+/// * `inverse(y)` : `x` such that `f(x) = y` (`y` nodes should be strictly monotone)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let f = LinearInterp::new(c!(0, 1, 3), c!(0, 2, 3), Extrapolation::Linear)?;
+///
+///     assert_eq!(f.eval(0.5), 1f64);
+///     assert_eq!(f.eval(2f64), 2.5);
+///     assert_eq!(f.eval(-1f64), -2f64);
+///     assert_eq!(f.eval_vec(&c!(0, 1, 2, 3)), c!(0, 2, 2.5, 3));
+///     assert_eq!(f.inverse(2.5), 2f64);
+///
+///     let g = LinearInterp::new(c!(0, 1, 3), c!(0, 2, 3), Extrapolation::Clamp)?;
+///     assert_eq!(g.eval(10f64), 3f64);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearInterp {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    extrap: Extrapolation,
+}
+
+impl LinearInterp {
+    /// Create piecewise linear interpolation
+    ///
+    /// # Errors
+    /// * `x` has less than 2 elements
+    /// * `x` and `y` have different lengths
+    /// * `x` is not strictly increasing
+    pub fn new(x: Vec<f64>, y: Vec<f64>, extrap: Extrapolation) -> Result<Self> {
+        if x.len() != y.len() {
+            bail!(InterpError::NotEqualNodes);
+        }
+        if x.len() < 2 {
+            bail!(InterpError::NotEnoughNodes);
+        }
+        if !x.windows(2).all(|w| w[0] < w[1]) {
+            bail!(InterpError::NotSortedNodes);
+        }
+        Ok(LinearInterp { x, y, extrap })
+    }
+
+    /// Nodes `(x, y)`
+    pub fn nodes(&self) -> (&Vec<f64>, &Vec<f64>) {
+        (&self.x, &self.y)
+    }
+
+    /// Extrapolation policy
+    pub fn extrapolation(&self) -> Extrapolation {
+        self.extrap
+    }
+
+    /// Evaluate at `x`
+    ///
+    /// # Panics
+    /// `x` is outside of nodes with `Extrapolation::Error`
+    pub fn eval(&self, x: f64) -> f64 {
+        let n = self.x.len();
+        let i = self.x.partition_point(|&t| t <= x).clamp(1, n - 1);
+        lerp(&self.x, &self.y, self.extrap, i, x)
+    }
+
+    /// Evaluate at each element of `xs`
+    ///
+    /// # Description
+    /// If `xs` is sorted (non-decreasing), segments are found by a single forward pass
+    /// (e.g. resampling of a time series). The result is identical to `eval` for each element.
+    ///
+    /// # Panics
+    /// Some element of `xs` is outside of nodes with `Extrapolation::Error`
+    pub fn eval_vec(&self, xs: &[f64]) -> Vec<f64> {
+        if !xs.windows(2).all(|w| w[0] <= w[1]) {
+            return xs.iter().map(|&x| self.eval(x)).collect();
+        }
+        let n = self.x.len();
+        let mut i = 1usize;
+        xs.iter()
+            .map(|&x| {
+                while i < n - 1 && self.x[i] <= x {
+                    i += 1;
+                }
+                lerp(&self.x, &self.y, self.extrap, i, x)
+            })
+            .collect()
+    }
+
+    /// Inverse interpolation
+    ///
+    /// # Description
+    /// Find `x` such that `eval(x) = y` by swapping the roles of nodes.
+    /// Extrapolation policy is applied to `y` outside of `[min y_i, max y_i]`.
+    ///
+    /// # Panics
+    /// * `y` nodes are not strictly monotone
+    /// * `y` is outside of nodes with `Extrapolation::Error`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     // Decreasing data
+    ///     let f = LinearInterp::new(c!(0, 1, 2), c!(10, 6, 4), Extrapolation::Error)?;
+    ///     assert_eq!(f.inverse(8f64), 0.5);
+    ///     assert_eq!(f.eval(f.inverse(5f64)), 5f64);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn inverse(&self, y: f64) -> f64 {
+        let n = self.y.len();
+        let increasing = self.y.windows(2).all(|w| w[0] < w[1]);
+        let decreasing = self.y.windows(2).all(|w| w[0] > w[1]);
+        assert!(
+            increasing || decreasing,
+            "inverse requires strictly monotone y"
+        );
+        let i = if increasing {
+            self.y.partition_point(|&t| t <= y)
+        } else {
+            self.y.partition_point(|&t| t >= y)
+        };
+        lerp(&self.y, &self.x, self.extrap, i.clamp(1, n - 1), y)
+    }
+}
+
+/// Linear interpolation on segment `(i-1, i)` of monotone `xs`
+fn lerp(xs: &[f64], ys: &[f64], extrap: Extrapolation, i: usize, x: f64) -> f64 {
+    let n = xs.len();
+    let (first, last) = (xs[0], xs[n - 1]);
+    let (lo, hi) = if first < last { (first, last) } else { (last, first) };
+    if x < lo || x > hi {
+        match extrap {
+            Extrapolation::Error => panic!("{} is out of range [{}, {}]", x, lo, hi),
+            Extrapolation::Clamp => {
+                let near_first = (x < lo) == (first < last);
+                return if near_first { ys[0] } else { ys[n - 1] };
+            }
+            Extrapolation::Linear => (),
+        }
+    }
+    if x == xs[i] {
+        return ys[i];
+    }
+    let (x0, x1, y0, y1) = (xs[i - 1], xs[i], ys[i - 1], ys[i]);
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
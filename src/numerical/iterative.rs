@@ -0,0 +1,612 @@
+//! Iterative linear solvers
+//!
+//! # Description
+//! Krylov subspace methods for large (sparse) linear systems `A x = b`.
+//! `A` is only accessed through `LinearOp`, so both `Matrix` and `SPMatrix` can be used.
+//!
+//! * `cg` : Conjugate Gradient (symmetric positive definite `A`)
+//! * `bicgstab` : BiCGSTAB (general `A`)
+//! * `gmres` : Restarted GMRES(m) (general `A`)
+//!
+//! Classical stationary methods for dense `Matrix` (e.g. diagonally dominant `A`) are also provided.
+//! They start from `x0` and return `Err(IterativeError::NotConverged(partial))` if `max_iter` is reached.
+//!
+//! * `jacobi_solve` : Jacobi method
+//! * `gauss_seidel` : Gauss-Seidel method
+//! * `sor` : Successive over-relaxation
+//!
+//! `cg` & `bicgstab` accept an optional Jacobi preconditioner (the diagonal of `A`),
+//! and `gmres` accepts an optional right preconditioner given as `SquareOp`.
+//! Matrix-free operators can be used via `FnOp`.
+//! The iteration stops when the relative residual `|b - Ax| / |b|` is less than `tol`.
+//!
+//! # Examples
+//! ```
+//! use peroxide::fuga::*;
+//!
+//! // 1D Laplacian
+//! let n = 100;
+//! let mut rows = vec![];
+//! let mut cols = vec![];
+//! let mut vals = vec![];
+//! for i in 0 .. n {
+//!     rows.push(i); cols.push(i); vals.push(2f64);
+//!     if i > 0 {
+//!         rows.push(i); cols.push(i - 1); vals.push(-1f64);
+//!     }
+//!     if i < n - 1 {
+//!         rows.push(i); cols.push(i + 1); vals.push(-1f64);
+//!     }
+//! }
+//! let a = SPMatrix::from_triplets(&rows, &cols, &vals, (n, n));
+//! let b = vec![1f64; n];
+//!
+//! let sol = cg(&a, &b, 1e-10, 1000, None);
+//! assert!(sol.residual < 1e-10);
+//! assert!(sol.iter <= n);
+//! ```
+//!
+//! * Reference : Saad, Yousef. *Iterative Methods for Sparse Linear Systems.* 2nd ed. SIAM, 2003.
+
+use crate::structure::matrix::Matrix;
+use crate::structure::vector::axpy;
+use crate::traits::math::{InnerProduct, LinearOp, Norm, Normed, SquareOp};
+use std::fmt;
+
+/// Result of iterative solver
+///
+/// * `x` : Solution
+/// * `iter` : Number of iterations
+/// * `residual` : Final relative residual `|b - Ax| / |b|`
+/// * `history` : Relative residual at each iteration (starting with `1` for `x = 0`)
+#[derive(Debug, Clone)]
+pub struct IterativeResult {
+    pub x: Vec<f64>,
+    pub iter: usize,
+    pub residual: f64,
+    pub history: Vec<f64>,
+}
+
+/// Matrix-free linear operator
+///
+/// # Description
+/// Wraps a function `x -> A x` on `R^n` as `SquareOp`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // A = diag(1, 2, 3)
+/// let a = FnOp::new(3, |x: &Vec<f64>| vec![x[0], 2f64 * x[1], 3f64 * x[2]]);
+/// assert_eq!(a.size(), 3);
+/// assert_eq!(a.apply(&vec![1f64, 1f64, 1f64]), vec![1f64, 2f64, 3f64]);
+/// ```
+pub struct FnOp<F: Fn(&Vec<f64>) -> Vec<f64>> {
+    n: usize,
+    f: F,
+}
+
+impl<F: Fn(&Vec<f64>) -> Vec<f64>> FnOp<F> {
+    pub fn new(n: usize, f: F) -> Self {
+        FnOp { n, f }
+    }
+}
+
+impl<F: Fn(&Vec<f64>) -> Vec<f64>> LinearOp<Vec<f64>, Vec<f64>> for FnOp<F> {
+    fn apply(&self, rhs: &Vec<f64>) -> Vec<f64> {
+        (self.f)(rhs)
+    }
+}
+
+impl<F: Fn(&Vec<f64>) -> Vec<f64>> SquareOp for FnOp<F> {
+    fn size(&self) -> usize {
+        self.n
+    }
+}
+
+/// Conjugate Gradient method
+///
+/// # Description
+/// Solve `A x = b` for symmetric positive definite `A`, starting from `x = 0`.
+/// If `jacobi` is given, it is used as the diagonal of `A` for the Jacobi preconditioner.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = ml_matrix("4 1;1 3");
+/// let b = vec![1f64, 2f64];
+/// let d = a.diag();
+/// let sol = cg(&a, &b, 1e-12, 10, Some(&d));
+/// assert!((sol.x[0] - 1f64 / 11f64).abs() < 1e-12);
+/// assert!((sol.x[1] - 7f64 / 11f64).abs() < 1e-12);
+/// ```
+pub fn cg<A: LinearOp<Vec<f64>, Vec<f64>>>(
+    a: &A,
+    b: &Vec<f64>,
+    tol: f64,
+    max_iter: usize,
+    jacobi: Option<&Vec<f64>>,
+) -> IterativeResult {
+    let n = b.len();
+    let b_norm = b.norm(Norm::L2);
+    let mut x = vec![0f64; n];
+    if b_norm == 0f64 {
+        return IterativeResult {
+            x,
+            iter: 0,
+            residual: 0f64,
+            history: vec![0f64],
+        };
+    }
+
+    let mut r = b.clone();
+    let mut z = precondition(&r, jacobi);
+    let mut p = z.clone();
+    let mut rz = r.dot(&z);
+    let mut history = vec![1f64];
+    let mut iter = 0usize;
+    while iter < max_iter {
+        iter += 1;
+        let ap = a.apply(&p);
+        let alpha = rz / p.dot(&ap);
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &ap, &mut r);
+        history.push(r.norm(Norm::L2) / b_norm);
+        if history[iter] < tol {
+            break;
+        }
+        z = precondition(&r, jacobi);
+        let rz_new = r.dot(&z);
+        let beta = rz_new / rz;
+        rz = rz_new;
+        for (pi, zi) in p.iter_mut().zip(z.iter()) {
+            *pi = zi + beta * *pi;
+        }
+    }
+
+    let residual = relative_residual(a, &x, b, b_norm);
+    IterativeResult {
+        x,
+        iter,
+        residual,
+        history,
+    }
+}
+
+/// BiCGSTAB method
+///
+/// # Description
+/// Solve `A x = b` for general (nonsymmetric) `A`, starting from `x = 0`.
+/// If `jacobi` is given, it is used as the diagonal of `A` for the (right) Jacobi preconditioner.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = ml_matrix("4 1 0;-1 3 1;0 -2 5");
+/// let b = vec![1f64, 2f64, 3f64];
+/// let sol = bicgstab(&a, &b, 1e-12, 100, None);
+/// let x = a.solve(&b, SolveKind::LU);
+/// for (s, t) in sol.x.iter().zip(x.iter()) {
+///     assert!((s - t).abs() < 1e-10);
+/// }
+/// ```
+pub fn bicgstab<A: LinearOp<Vec<f64>, Vec<f64>>>(
+    a: &A,
+    b: &Vec<f64>,
+    tol: f64,
+    max_iter: usize,
+    jacobi: Option<&Vec<f64>>,
+) -> IterativeResult {
+    let n = b.len();
+    let b_norm = b.norm(Norm::L2);
+    let mut x = vec![0f64; n];
+    if b_norm == 0f64 {
+        return IterativeResult {
+            x,
+            iter: 0,
+            residual: 0f64,
+            history: vec![0f64],
+        };
+    }
+
+    let mut r = b.clone();
+    let r_hat = r.clone();
+    let mut p = vec![0f64; n];
+    let mut v = vec![0f64; n];
+    let (mut rho, mut alpha, mut omega) = (1f64, 1f64, 1f64);
+    let mut history = vec![1f64];
+    let mut iter = 0usize;
+    while iter < max_iter {
+        iter += 1;
+        let rho_new = r_hat.dot(&r);
+        if rho_new == 0f64 {
+            // Breakdown
+            iter -= 1;
+            break;
+        }
+        let beta = (rho_new / rho) * (alpha / omega);
+        rho = rho_new;
+        for i in 0..n {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+        let y = precondition(&p, jacobi);
+        v = a.apply(&y);
+        alpha = rho / r_hat.dot(&v);
+        axpy(alpha, &y, &mut x);
+        let mut s = r;
+        axpy(-alpha, &v, &mut s);
+        let s_norm = s.norm(Norm::L2) / b_norm;
+        if s_norm < tol {
+            history.push(s_norm);
+            break;
+        }
+        let z = precondition(&s, jacobi);
+        let t = a.apply(&z);
+        omega = t.dot(&s) / t.dot(&t);
+        axpy(omega, &z, &mut x);
+        axpy(-omega, &t, &mut s);
+        r = s;
+        history.push(r.norm(Norm::L2) / b_norm);
+        if history[iter] < tol || omega == 0f64 {
+            break;
+        }
+    }
+
+    let residual = relative_residual(a, &x, b, b_norm);
+    IterativeResult {
+        x,
+        iter,
+        residual,
+        history,
+    }
+}
+
+/// Restarted GMRES(m)
+///
+/// # Description
+/// Solve `A x = b` for general (nonsymmetric) `A`, starting from `x = 0`.
+///
+/// * `restart` : Dimension `m` of Krylov subspace before restart
+/// * `max_iter` : Maximum number of total (inner) iterations
+/// * `precond` : Right preconditioner `M^{-1}` (solve `A M^{-1} u = b`, `x = M^{-1} u`)
+///
+/// The least square problem on the Hessenberg matrix is solved by Givens rotations,
+/// so `history` contains the residual at every inner iteration without extra `apply`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = ml_matrix("4 1 0;-1 3 1;0 -2 5");
+/// let b = vec![1f64, 2f64, 3f64];
+/// let sol = gmres(&a, &b, 3, 1e-12, 10, None);
+/// let x = a.solve(&b, SolveKind::LU);
+/// for (s, t) in sol.x.iter().zip(x.iter()) {
+///     assert!((s - t).abs() < 1e-10);
+/// }
+/// assert!(sol.iter <= 3);
+///
+/// // Matrix-free operator & Jacobi preconditioner
+/// let d = a.diag();
+/// let op = FnOp::new(3, |x: &Vec<f64>| a.apply(x));
+/// let m = FnOp::new(3, |x: &Vec<f64>| x.iter().zip(d.iter()).map(|(s, t)| s / t).collect());
+/// let sol = gmres(&op, &b, 3, 1e-12, 10, Some(&m));
+/// assert!(sol.residual < 1e-12);
+/// ```
+pub fn gmres<A: SquareOp>(
+    a: &A,
+    b: &Vec<f64>,
+    restart: usize,
+    tol: f64,
+    max_iter: usize,
+    precond: Option<&dyn SquareOp>,
+) -> IterativeResult {
+    let n = b.len();
+    assert_eq!(a.size(), n, "Dimension mismatch");
+    assert!(restart > 0, "Restart length should be positive");
+    if let Some(m) = precond {
+        assert_eq!(m.size(), n, "Dimension mismatch of preconditioner");
+    }
+    let m_inv = |v: &Vec<f64>| match precond {
+        None => v.clone(),
+        Some(m) => m.apply(v),
+    };
+
+    let b_norm = b.norm(Norm::L2);
+    let mut x = vec![0f64; n];
+    if b_norm == 0f64 {
+        return IterativeResult {
+            x,
+            iter: 0,
+            residual: 0f64,
+            history: vec![0f64],
+        };
+    }
+
+    let mut history = vec![1f64];
+    let mut iter = 0usize;
+    let mut r = b.clone();
+    while iter < max_iter {
+        let beta = r.norm(Norm::L2);
+        if beta < tol * b_norm {
+            break;
+        }
+        let m = restart.min(max_iter - iter);
+        let mut v: Vec<Vec<f64>> = vec![r.iter().map(|t| t / beta).collect()];
+        // h[j] : j-th column of Hessenberg matrix (length j + 2)
+        let mut h: Vec<Vec<f64>> = Vec::with_capacity(m);
+        let mut cs: Vec<(f64, f64)> = Vec::with_capacity(m);
+        let mut g = vec![0f64; m + 1];
+        g[0] = beta;
+
+        let mut k = 0usize;
+        while k < m {
+            let mut w = a.apply(&m_inv(&v[k]));
+            // Modified Gram-Schmidt
+            let mut hk = vec![0f64; k + 2];
+            for (i, vi) in v.iter().enumerate() {
+                hk[i] = w.dot(vi);
+                axpy(-hk[i], vi, &mut w);
+            }
+            hk[k + 1] = w.norm(Norm::L2);
+
+            // Previous rotations
+            for (i, &(c, s)) in cs.iter().enumerate() {
+                let (p, q) = (hk[i], hk[i + 1]);
+                hk[i] = c * p + s * q;
+                hk[i + 1] = -s * p + c * q;
+            }
+            // New rotation to eliminate hk[k + 1]
+            let denom = hk[k].hypot(hk[k + 1]);
+            let (c, s) = if denom == 0f64 {
+                (1f64, 0f64)
+            } else {
+                (hk[k] / denom, hk[k + 1] / denom)
+            };
+            let h_next = hk[k + 1];
+            hk[k] = denom;
+            hk[k + 1] = 0f64;
+            g[k + 1] = -s * g[k];
+            g[k] *= c;
+            cs.push((c, s));
+            h.push(hk);
+
+            k += 1;
+            iter += 1;
+            history.push(g[k].abs() / b_norm);
+            if history[iter] < tol || h_next == 0f64 {
+                break;
+            }
+            v.push(w.iter().map(|t| t / h_next).collect());
+        }
+
+        // Solve upper triangular system & update x
+        let mut y = vec![0f64; k];
+        for i in (0..k).rev() {
+            let s = (i + 1..k).fold(g[i], |acc, j| acc - h[j][i] * y[j]);
+            y[i] = s / h[i][i];
+        }
+        let mut u = vec![0f64; n];
+        for (vi, yi) in v.iter().zip(y.iter()) {
+            axpy(*yi, vi, &mut u);
+        }
+        axpy(1f64, &m_inv(&u), &mut x);
+
+        let ax = a.apply(&x);
+        r = b.iter().zip(ax.iter()).map(|(bi, axi)| bi - axi).collect();
+    }
+
+    let residual = r.norm(Norm::L2) / b_norm;
+    IterativeResult {
+        x,
+        iter,
+        residual,
+        history,
+    }
+}
+
+// =============================================================================
+// Stationary iterative methods
+// =============================================================================
+/// Error of stationary iterative methods
+///
+/// * `NotConverged` : `max_iter` is reached before `tol` (contains the partial result)
+#[derive(Debug, Clone)]
+pub enum IterativeError {
+    NotConverged(IterativeResult),
+}
+
+impl fmt::Display for IterativeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IterativeError::NotConverged(result) => write!(
+                f,
+                "Not converged after {} iterations (residual: {:e})",
+                result.iter, result.residual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IterativeError {}
+
+/// Jacobi method
+///
+/// # Description
+/// `x_i <- (b_i - sum_{j != i} a_ij x_j) / a_ii` for all `i` simultaneously.
+/// Converges for strictly diagonally dominant `A`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let a = ml_matrix("4 1;1 3");
+///     let b = vec![1f64, 2f64];
+///     let sol = jacobi_solve(&a, &b, &vec![0f64; 2], 1e-12, 100)?;
+///     assert!((sol.x[0] - 1f64 / 11f64).abs() < 1e-12);
+///     assert!((sol.x[1] - 7f64 / 11f64).abs() < 1e-12);
+///     assert_eq!(sol.history.len(), sol.iter + 1);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn jacobi_solve(
+    a: &Matrix,
+    b: &[f64],
+    x0: &[f64],
+    tol: f64,
+    max_iter: usize,
+) -> Result<IterativeResult, IterativeError> {
+    stationary(a, b, x0, tol, max_iter, |x| {
+        let x_old = x.clone();
+        for (i, xi) in x.iter_mut().enumerate() {
+            *xi = (b[i] - off_diag_dot(a, i, &x_old)) / a[(i, i)];
+        }
+    })
+}
+
+/// Gauss-Seidel method
+///
+/// # Description
+/// Same as `jacobi_solve`, but updated components are used immediately (`sor` with `omega = 1`).
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let a = ml_matrix("4 1;1 3");
+///     let b = vec![1f64, 2f64];
+///     let sol = gauss_seidel(&a, &b, &vec![0f64; 2], 1e-12, 100)?;
+///     assert!((sol.x[0] - 1f64 / 11f64).abs() < 1e-12);
+///     assert!((sol.x[1] - 7f64 / 11f64).abs() < 1e-12);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn gauss_seidel(
+    a: &Matrix,
+    b: &[f64],
+    x0: &[f64],
+    tol: f64,
+    max_iter: usize,
+) -> Result<IterativeResult, IterativeError> {
+    sor(a, b, 1f64, x0, tol, max_iter)
+}
+
+/// Successive over-relaxation (SOR)
+///
+/// # Description
+/// `x_i <- (1 - omega) x_i + omega * (b_i - sum_{j != i} a_ij x_j) / a_ii` (in place).
+/// `0 < omega < 2` is required for convergence. For consistently ordered matrices (e.g. tridiagonal),
+/// the optimal value is `omega = 2 / (1 + sqrt(1 - rho^2))` where `rho` is the spectral radius
+/// of the Jacobi iteration matrix.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let a = ml_matrix("4 -1 0;-1 4 -1;0 -1 4");
+///     let b = vec![1f64, 2f64, 3f64];
+///     let sol = sor(&a, &b, 1.05, &vec![0f64; 3], 1e-12, 100)?;
+///     let x = a.solve(&b, SolveKind::LU);
+///     assert!(eq_vec(&sol.x, &x, 1e-10));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn sor(
+    a: &Matrix,
+    b: &[f64],
+    omega: f64,
+    x0: &[f64],
+    tol: f64,
+    max_iter: usize,
+) -> Result<IterativeResult, IterativeError> {
+    stationary(a, b, x0, tol, max_iter, |x| {
+        for i in 0..x.len() {
+            let gs = (b[i] - off_diag_dot(a, i, x)) / a[(i, i)];
+            x[i] = (1f64 - omega) * x[i] + omega * gs;
+        }
+    })
+}
+
+/// Iterate `sweep` from `x0` until the relative residual is less than `tol`
+fn stationary<F: FnMut(&mut Vec<f64>)>(
+    a: &Matrix,
+    b: &[f64],
+    x0: &[f64],
+    tol: f64,
+    max_iter: usize,
+    mut sweep: F,
+) -> Result<IterativeResult, IterativeError> {
+    let n = b.len();
+    assert!(a.row == n && a.col == n, "A should be n x n matrix (n = length of b)");
+    assert_eq!(x0.len(), n, "Length of x0 should be same as b");
+    assert!(
+        (0..n).all(|i| a[(i, i)] != 0f64),
+        "Diagonal of A should be nonzero"
+    );
+    let b_norm = b.iter().map(|bi| bi * bi).sum::<f64>().sqrt();
+    let b_norm = if b_norm == 0f64 { 1f64 } else { b_norm };
+
+    let mut x = x0.to_vec();
+    let mut history = vec![relative_residual(a, &x, b, b_norm)];
+    let mut iter = 0usize;
+    while history[iter] >= tol && iter < max_iter {
+        iter += 1;
+        sweep(&mut x);
+        history.push(relative_residual(a, &x, b, b_norm));
+    }
+
+    let residual = history[iter];
+    let result = IterativeResult {
+        x,
+        iter,
+        residual,
+        history,
+    };
+    if residual < tol {
+        Ok(result)
+    } else {
+        Err(IterativeError::NotConverged(result))
+    }
+}
+
+/// `sum_{j != i} a_ij x_j`
+fn off_diag_dot(a: &Matrix, i: usize, x: &[f64]) -> f64 {
+    x.iter()
+        .enumerate()
+        .filter(|(j, _)| *j != i)
+        .map(|(j, xj)| a[(i, j)] * xj)
+        .sum()
+}
+
+fn precondition(r: &[f64], jacobi: Option<&Vec<f64>>) -> Vec<f64> {
+    match jacobi {
+        None => r.to_vec(),
+        Some(d) => {
+            assert_eq!(d.len(), r.len(), "Length of preconditioner should be same as b");
+            r.iter().zip(d.iter()).map(|(ri, di)| ri / di).collect()
+        }
+    }
+}
+
+fn relative_residual<A: LinearOp<Vec<f64>, Vec<f64>>>(
+    a: &A,
+    x: &Vec<f64>,
+    b: &[f64],
+    b_norm: f64,
+) -> f64 {
+    let ax = a.apply(x);
+    let r = b
+        .iter()
+        .zip(ax.iter())
+        .map(|(bi, axi)| (bi - axi).powi(2))
+        .sum::<f64>();
+    r.sqrt() / b_norm
+}
@@ -1,11 +1,15 @@
 //! Differential equations & Numerical Analysis tools
 
+pub mod deriv;
 pub mod eigen;
+pub mod fft;
 pub mod integral;
 pub mod interp;
+pub mod iterative;
 pub mod newton;
 pub mod ode;
 pub mod optimize;
 pub mod root;
+pub mod signal;
 pub mod spline;
 pub mod utils;
\ No newline at end of file
@@ -7,6 +7,9 @@
 //! - `ODEProblem`: Trait for defining an ODE problem.
 //! - `ODEIntegrator`: Trait for ODE integrators.
 //! - `ODESolver`: Trait for ODE solvers.
+//! - `ODEState`: Final (or recorded) state of a solution, to continue integration from it.
+//! - `DDEProblem` & `DDESolver`: Delay differential equations with constant delays.
+//! - `ShootingSolver`: Boundary value problems with Dirichlet conditions (shooting method).
 //! - `ODEError`: Enum for ODE errors.
 //!   - `ReachedMaxStepIter`: Reached maximum number of steps per step. (internal error)
 //!   - `ConstraintViolation(f64, Vec<f64>, Vec<f64>)`: Constraint violation. (user-defined error)
@@ -15,17 +18,27 @@
 //! ## Available integrators
 //!
 //! - **Explicit**
+//!   - Midpoint method 2nd order (Midpoint)
+//!   - Heun's method 2nd order (Heun)
+//!   - Runge-Kutta 3rd order (RK3)
 //!   - Ralston's 3rd order (RALS3)
 //!   - Runge-Kutta 4th order (RK4)
 //!   - Ralston's 4th order (RALS4)
 //!   - Runge-Kutta 5th order (RK5)
+//! - **Custom** : `ExplicitRK` (explicit or embedded method with user-supplied Butcher tableau)
+//! - **Symplectic** (separable Hamiltonian systems, state `(q, p)`)
+//!   - Störmer-Verlet / leapfrog 2nd order (Verlet)
+//!   - Yoshida 4th order (Yoshida4)
 //! - **Embedded**
 //!   - Bogacki-Shampine 2/3rd order (BS23)
 //!   - Runge-Kutta-Fehlberg 4/5th order (RKF45)
 //!   - Dormand-Prince 4/5th order (DP45)
 //!   - Tsitouras 4/5th order (TSIT45)
 //! - **Implicit**
+//!   - Backward Euler (BackwardEuler)
 //!   - Gauss-Legendre 4th order (GL4)
+//! - **Linearly implicit (Rosenbrock)**
+//!   - Rosenbrock 2/3rd order (ROS23, adaptive step for stiff ODEs)
 //!
 //! ## Available solvers
 //!
@@ -88,6 +101,9 @@
 //! ```
 
 use anyhow::{Result, bail};
+use crate::numerical::utils::numerical_jacobian;
+use crate::structure::matrix::{LinearAlgebra, Matrix, SolveKind};
+use crate::util::non_macro::eye;
 
 /// Trait for defining an ODE problem.
 ///
@@ -121,6 +137,8 @@ pub trait ODEProblem {
 /// Trait for ODE integrators.
 ///
 /// Implement this trait to define your own ODE integrator.
+/// `step` advances `y` from `t` to `t + dt` (adaptive methods may take several internal steps)
+/// and returns the suggested `dt` for the next step.
 pub trait ODEIntegrator {
     fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64>;
 }
@@ -178,8 +196,65 @@ pub trait ODESolver {
     fn solve<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)>;
 }
 
+/// Callback for ODE solvers : `f(step, t, y)`
+pub type ODECallback = fn(usize, f64, &[f64]);
+
+/// State of an ODE solution
+///
+/// * `t` : Time
+/// * `y` : Solution at `t`
+/// * `dt` : Step size for the next step (adapted step for embedded methods)
+///
+/// Obtained from `BasicODESolver::solve_with_final` or `ODEState::from_record`,
+/// and used by `BasicODESolver::continue_from`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ODEState {
+    pub t: f64,
+    pub y: Vec<f64>,
+    pub dt: f64,
+}
+
+impl ODEState {
+    /// State at `idx`-th row of a recorded solution `(t_vec, y_vec)`
+    ///
+    /// `dt` is the spacing to the next row (to the previous row for the last one).
+    ///
+    /// # Panics
+    /// * `idx` is out of range
+    /// * `t_vec` and `y_vec` have different lengths
+    pub fn from_record(t_vec: &[f64], y_vec: &[Vec<f64>], idx: usize) -> Self {
+        assert_eq!(t_vec.len(), y_vec.len(), "t_vec and y_vec should have same length");
+        assert!(idx < t_vec.len(), "Index out of range");
+        let dt = if idx + 1 < t_vec.len() {
+            t_vec[idx + 1] - t_vec[idx]
+        } else if idx > 0 {
+            t_vec[idx] - t_vec[idx - 1]
+        } else {
+            f64::NAN
+        };
+        Self {
+            t: t_vec[idx],
+            y: y_vec[idx].clone(),
+            dt,
+        }
+    }
+}
+
 /// A basic ODE solver using a specified integrator.
 ///
+/// # Options
+///
+/// - `set_callback(every, f)`: Call `f(step, t, y)` every `every` steps (step `0` is the initial condition).
+///   It can be used to log, update a progress bar, or stream rows to a file.
+/// - `set_record(false)`: Do not record the trajectory. `solve` returns only the last `t` & `y`,
+///   so long integrations do not allocate the whole trajectory.
+///
+/// # Multi-phase integration
+///
+/// - `solve_with_final`: Same as `solve`, but also returns the final `ODEState`.
+/// - `continue_from(problem, state, t_end)`: Integrate from `state` instead of `problem.initial_conditions()`,
+///   keeping the integrator & options. The problem may differ from the previous phase.
+///
 /// # Example
 ///
 /// ```
@@ -195,9 +270,23 @@ pub trait ODESolver {
 ///     )?;
 ///     let y_vec: Vec<f64> = y_vec.into_iter().flatten().collect();
 ///
+///     // Callback only
+///     let mut solver = BasicODESolver::new(RK4);
+///     solver.set_callback(100, progress).set_record(false);
+///     let (t_last, y_last) = solver.solve(&Test, (0f64, 10f64), 0.01)?;
+///     assert_eq!(t_last.len(), 1);
+///     assert_eq!(y_last.len(), 1);
+///     assert!(t_last[0] >= 10f64);
+///
 ///     Ok(())
 /// }
 ///
+/// fn progress(step: usize, t: f64, y: &[f64]) {
+///     assert_eq!(step % 100, 0);
+///     assert!((t - 0.01 * step as f64).abs() < 1e-9);
+///     assert!(y[0].is_finite());
+/// }
+///
 /// struct Test;
 ///
 /// impl ODEProblem for Test {
@@ -213,30 +302,132 @@ pub trait ODESolver {
 /// ```
 pub struct BasicODESolver<I: ODEIntegrator> {
     integrator: I,
+    callback: Option<(usize, ODECallback)>,
+    record: bool,
 }
 
 impl<I: ODEIntegrator> BasicODESolver<I> {
     pub fn new(integrator: I) -> Self {
-        Self { integrator }
+        Self {
+            integrator,
+            callback: None,
+            record: true,
+        }
     }
-}
 
-impl<I: ODEIntegrator> ODESolver for BasicODESolver<I> {
-    fn solve<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
-        let mut t = t_span.0;
+    /// Call `f(step, t, y)` every `every` steps
+    pub fn set_callback(&mut self, every: usize, f: ODECallback) -> &mut Self {
+        assert!(every > 0, "Callback interval should be positive");
+        self.callback = Some((every, f));
+        self
+    }
+
+    /// Record the trajectory or not (default: `true`)
+    pub fn set_record(&mut self, record: bool) -> &mut Self {
+        self.record = record;
+        self
+    }
+
+    /// Solve, and return the final state as well
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let solver = BasicODESolver::new(RK4);
+    ///
+    ///     // Phase 1 : thrust (v' = 1)
+    ///     let (_, _, state) = solver.solve_with_final(&Thrust, (0f64, 1f64), 0.01)?;
+    ///
+    ///     // Phase 2 : drag (v' = -v) from the final state of phase 1
+    ///     let (t_vec, y_vec, _) = solver.continue_from(&Drag, &state, 2f64)?;
+    ///     assert_eq!(t_vec[0], state.t);
+    ///     assert_eq!(y_vec[0], state.y);
+    ///
+    ///     let n = t_vec.len() - 1;
+    ///     assert!((y_vec[n][0] - state.y[0] * (state.t - t_vec[n]).exp()).abs() < 1e-8);
+    ///     Ok(())
+    /// }
+    ///
+    /// struct Thrust;
+    ///
+    /// impl ODEProblem for Thrust {
+    ///     fn initial_conditions(&self) -> Vec<f64> { vec![0f64] }
+    ///     fn rhs(&self, _t: f64, _y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+    ///         dy[0] = 1f64;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// struct Drag;
+    ///
+    /// impl ODEProblem for Drag {
+    ///     fn initial_conditions(&self) -> Vec<f64> { unreachable!() }
+    ///     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+    ///         dy[0] = -y[0];
+    ///         Ok(())
+    ///     }
+    /// }
+    /// ```
+    pub fn solve_with_final<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>, ODEState)> {
+        self.run(problem, t_span.0, problem.initial_conditions(), t_span.1, dt)
+    }
+
+    /// Continue integration from `state` until `t_end`
+    ///
+    /// `problem.initial_conditions()` is not used.
+    /// The first row of the result is `state` itself.
+    ///
+    /// # Errors
+    /// `state.dt` is not positive (e.g. `ODEState::from_record` with a single row)
+    pub fn continue_from<P: ODEProblem>(&self, problem: &P, state: &ODEState, t_end: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>, ODEState)> {
+        if state.dt.is_nan() || state.dt <= 0f64 {
+            bail!("Step size of the state should be positive (dt = {})", state.dt);
+        }
+        self.run(problem, state.t, state.y.clone(), t_end, state.dt)
+    }
+
+    fn run<P: ODEProblem>(&self, problem: &P, t0: f64, y0: Vec<f64>, t_end: f64, dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>, ODEState)> {
+        let mut t = t0;
         let mut dt = dt;
-        let mut y = problem.initial_conditions();
-        let mut t_vec = vec![t];
-        let mut y_vec = vec![y.clone()];
+        let mut y = y0;
+        let mut t_vec = vec![];
+        let mut y_vec = vec![];
+        let mut step = 0usize;
+
+        loop {
+            if self.record {
+                t_vec.push(t);
+                y_vec.push(y.clone());
+            }
+            if let Some((every, f)) = self.callback {
+                if step.is_multiple_of(every) {
+                    f(step, t, &y);
+                }
+            }
+            if t >= t_end {
+                break;
+            }
 
-        while t < t_span.1 {
             let dt_step = self.integrator.step(problem, t, &mut y, dt)?;
             t += dt;
+            dt = dt_step;
+            step += 1;
+        }
+
+        if !self.record {
             t_vec.push(t);
             y_vec.push(y.clone());
-            dt = dt_step;
         }
 
+        Ok((t_vec, y_vec, ODEState { t, y, dt }))
+    }
+}
+
+impl<I: ODEIntegrator> ODESolver for BasicODESolver<I> {
+    fn solve<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
+        let (t_vec, y_vec, _) = self.solve_with_final(problem, t_span, dt)?;
         Ok((t_vec, y_vec))
     }
 }
@@ -286,63 +477,111 @@ pub trait ButcherTableau {
 
 impl<BU: ButcherTableau> ODEIntegrator for BU {
     fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64> {
-        let n = y.len();
-        let mut iter_count = 0usize;
-        let mut dt = dt;
-        let n_k = Self::C.len();
+        let control = if Self::BE.is_empty() {
+            None
+        } else {
+            Some(StepControl {
+                tol: self.tol(),
+                safety_factor: self.safety_factor(),
+                min_step_size: self.min_step_size(),
+                max_step_size: self.max_step_size(),
+                max_step_iter: self.max_step_iter(),
+            })
+        };
+        explicit_rk_step(problem, t, y, dt, Self::C, Self::A, Self::BU, Self::BE, control)
+    }
+}
 
-        loop {
-            let mut k_vec = vec![vec![0.0; n]; n_k];
-            let mut y_temp = y.to_vec();
-
-            for i in 0 .. n_k {
-                for i in 0 .. n {
-                    let mut s = 0.0;
-                    for j in 0 .. i {
-                        s += Self::A[i][j] * k_vec[j][i];
-                    }
-                    y_temp[i] = y[i] + dt * s;
+/// Step size control of embedded methods
+struct StepControl {
+    tol: f64,
+    safety_factor: f64,
+    min_step_size: f64,
+    max_step_size: f64,
+    max_step_iter: usize,
+}
+
+/// One step of explicit Runge-Kutta method (common to `ButcherTableau` & `ExplicitRK`)
+#[allow(clippy::too_many_arguments)]
+fn explicit_rk_step<P: ODEProblem, R: AsRef<[f64]>>(
+    problem: &P,
+    t: f64,
+    y: &mut [f64],
+    dt: f64,
+    c: &[f64],
+    a: &[R],
+    bu: &[f64],
+    be: &[f64],
+    control: Option<StepControl>,
+) -> Result<f64> {
+    let n = y.len();
+    let n_k = c.len();
+    let t_end = t + dt;
+    let mut t = t;
+    let mut h = dt;
+    let mut h_next = dt;
+    let mut iter_count = 0usize;
+
+    loop {
+        // Rejected steps are retried with smaller `h` until `t_end` is reached
+        let last = t + h >= t_end;
+        let h_step = if last { t_end - t } else { h };
+        let mut k_vec = vec![vec![0.0; n]; n_k];
+        let mut y_temp = y.to_vec();
+
+        // Stage i : k_i = f(t + c_i h, y + h * sum_j a_ij k_j)
+        for i in 0 .. n_k {
+            for m in 0 .. n {
+                let mut s = 0.0;
+                for (a_ij, k_j) in a[i].as_ref().iter().zip(k_vec.iter()) {
+                    s += a_ij * k_j[m];
                 }
-                problem.rhs(t + dt * Self::C[i], &y_temp, &mut k_vec[i])?;
+                y_temp[m] = y[m] + h_step * s;
             }
+            problem.rhs(t + h_step * c[i], &y_temp, &mut k_vec[i])?;
+        }
 
-            if !Self::BE.is_empty() {
+        // sum_j w_j k_j (m-th component)
+        let weighted_sum = |w: &[f64], m: usize| {
+            w.iter().zip(k_vec.iter()).map(|(w_j, k_j)| w_j * k_j[m]).sum::<f64>()
+        };
+
+        match control {
+            Some(ref control) => {
+                let b_diff = bu.iter().zip(be.iter()).map(|(u, e)| u - e).collect::<Vec<f64>>();
                 let mut error = 0f64;
-                for i in 0 .. n {
-                    let mut s = 0.0;
-                    for j in 0 .. n_k {
-                        s += (Self::BU[j] - Self::BE[j]) * k_vec[j][i];
-                    }
-                    error = error.max(dt * s.abs())
+                for m in 0 .. n {
+                    error = error.max(h_step * weighted_sum(&b_diff, m).abs())
                 }
 
-                let factor = (self.tol() * dt / error).powf(0.2);
-                let new_dt = self.safety_factor() * dt * factor;
-                let new_dt = new_dt.clamp(self.min_step_size(),self.max_step_size());
+                let factor = (control.tol * h_step / error).powf(0.2);
+                let new_h = control.safety_factor * h_step * factor;
+                let new_h = new_h.clamp(control.min_step_size, control.max_step_size);
 
-                if error < self.tol() {
-                    for i in 0 .. n {
-                        let mut s = 0.0;
-                        for j in 0 .. n_k {
-                            s += Self::BU[j] * k_vec[j][i];
-                        }
-                        y[i] += dt * s;
+                if error < control.tol {
+                    for (m, y_m) in y.iter_mut().enumerate() {
+                        *y_m += h_step * weighted_sum(bu, m);
+                    }
+                    // Keep the suggestion of the last full step
+                    if !last || h_step >= h {
+                        h_next = new_h;
                     }
-                    return Ok(new_dt);
+                    if last {
+                        return Ok(h_next);
+                    }
+                    t += h_step;
+                    h = new_h;
                 } else {
                     iter_count += 1;
-                    if iter_count >= self.max_step_iter() {
+                    if iter_count >= control.max_step_iter {
                         bail!(ODEError::ReachedMaxStepIter);
                     }
-                    dt = new_dt;
+                    h = new_h;
                 }
-            } else {
-                for i in 0 .. n {
-                    let mut s = 0.0;
-                    for j in 0 .. n_k {
-                        s += Self::BU[j] * k_vec[j][i];
-                    }
-                    y[i] += dt * s;
+            }
+            None => {
+                for (m, y_m) in y.iter_mut().enumerate() {
+                    *y_m += dt * weighted_sum(bu, m);
                 }
                 return Ok(dt);
             }
@@ -350,9 +589,218 @@ impl<BU: ButcherTableau> ODEIntegrator for BU {
     }
 }
 
+// ┌─────────────────────────────────────────────────────────┐
+//  Custom explicit Runge-Kutta
+// └─────────────────────────────────────────────────────────┘
+/// Explicit Runge-Kutta method with user-supplied Butcher tableau
+///
+/// # Description
+/// Unlike `ButcherTableau` (compile-time constants), the tableau is given at runtime.
+///
+/// - `ExplicitRK::new(a, b, c)`: Explicit method (fixed step size)
+/// - `ExplicitRK::embedded(a, b, b_err, c)`: Embedded method (adaptive step size with `b_err` for error estimation)
+/// - `ExplicitRK::from_tableau(&method)`: Tableau of built-in method (e.g. `RK4`, `DP45::default()`)
+///
+/// The tableau is validated : `a` should be strictly lower triangular,
+/// each row of `a` should sum to `c`, and `b` (`b_err`) should sum to `1` (within `1e-8`).
+///
+/// For embedded methods, step size control is given by public fields
+/// (`tol`, `safety_factor`, `min_step_size`, `max_step_size`, `max_step_iter`) - same as `RKF45`.
+///
+/// # Example
+///
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     // Strong stability preserving RK3 (Shu-Osher)
+///     let ssprk3 = ExplicitRK::new(
+///         vec![vec![], vec![1.0], vec![0.25, 0.25]],
+///         vec![1.0 / 6.0, 1.0 / 6.0, 2.0 / 3.0],
+///         vec![0.0, 1.0, 0.5],
+///     )?;
+///     let (t, y) = BasicODESolver::new(ssprk3).solve(&Decay, (0f64, 1f64), 0.01)?;
+///     let n = t.len() - 1;
+///     assert!((y[n][0] - (-t[n]).exp()).abs() < 1e-7);
+///
+///     // Inconsistent tableau
+///     assert!(ExplicitRK::new(vec![vec![], vec![0.5]], vec![0.5, 0.5], vec![0.0, 1.0]).is_err());
+///     Ok(())
+/// }
+///
+/// struct Decay;
+///
+/// impl ODEProblem for Decay {
+///     fn initial_conditions(&self) -> Vec<f64> {
+///         vec![1f64]
+///     }
+///
+///     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+///         dy[0] = -y[0];
+///         Ok(())
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExplicitRK {
+    a: Vec<Vec<f64>>,
+    b: Vec<f64>,
+    b_err: Vec<f64>,
+    c: Vec<f64>,
+    pub tol: f64,
+    pub safety_factor: f64,
+    pub min_step_size: f64,
+    pub max_step_size: f64,
+    pub max_step_iter: usize,
+}
+
+impl ExplicitRK {
+    /// Explicit method with tableau `(a, b, c)`
+    pub fn new(a: Vec<Vec<f64>>, b: Vec<f64>, c: Vec<f64>) -> Result<Self> {
+        Self::embedded(a, b, vec![], c)
+    }
+
+    /// Embedded method with tableau `(a, b, b_err, c)`
+    ///
+    /// `b` is used to update, and `b - b_err` to estimate the error.
+    /// (If `b_err` is empty, it is same as `new`)
+    pub fn embedded(a: Vec<Vec<f64>>, b: Vec<f64>, b_err: Vec<f64>, c: Vec<f64>) -> Result<Self> {
+        let method = Self::with_tableau(a, b, b_err, c);
+        method.validate()?;
+        Ok(method)
+    }
+
+    /// Tableau of built-in method (step size control is copied for embedded methods)
+    pub fn from_tableau<T: ButcherTableau>(method: &T) -> Self {
+        let mut rk = Self::with_tableau(
+            T::A.iter().map(|row| row.to_vec()).collect(),
+            T::BU.to_vec(),
+            T::BE.to_vec(),
+            T::C.to_vec(),
+        );
+        if !T::BE.is_empty() {
+            rk.tol = method.tol();
+            rk.safety_factor = method.safety_factor();
+            rk.min_step_size = method.min_step_size();
+            rk.max_step_size = method.max_step_size();
+            rk.max_step_iter = method.max_step_iter();
+        }
+        rk
+    }
+
+    /// Tableau without validation (step size control of `RKF45::default()`)
+    fn with_tableau(a: Vec<Vec<f64>>, b: Vec<f64>, b_err: Vec<f64>, c: Vec<f64>) -> Self {
+        let rk = RKF45::default();
+        Self {
+            a,
+            b,
+            b_err,
+            c,
+            tol: rk.tol,
+            safety_factor: rk.safety_factor,
+            min_step_size: rk.min_step_size,
+            max_step_size: rk.max_step_size,
+            max_step_iter: rk.max_step_iter,
+        }
+    }
+
+    /// Number of stages
+    pub fn stages(&self) -> usize {
+        self.c.len()
+    }
+
+    /// Check consistency of tableau
+    pub fn validate(&self) -> Result<()> {
+        const TOL: f64 = 1e-8;
+        let s = self.c.len();
+        if self.a.len() != s || self.b.len() != s {
+            bail!("Butcher tableau: a ({} rows), b ({}) and c ({}) should have the same length", self.a.len(), self.b.len(), s);
+        }
+        if !self.b_err.is_empty() && self.b_err.len() != s {
+            bail!("Butcher tableau: b_err ({}) should have the same length as c ({})", self.b_err.len(), s);
+        }
+        for (i, (row, c_i)) in self.a.iter().zip(self.c.iter()).enumerate() {
+            if row.len() > i {
+                bail!("Butcher tableau: a should be strictly lower triangular (row {} has {} entries)", i, row.len());
+            }
+            let sum = row.iter().sum::<f64>();
+            if (sum - c_i).abs() > TOL {
+                bail!("Butcher tableau: row {} of a sums to {}, but c[{}] = {}", i, sum, i, c_i);
+            }
+        }
+        for (name, b) in [("b", &self.b), ("b_err", &self.b_err)] {
+            let sum = b.iter().sum::<f64>();
+            if !b.is_empty() && (sum - 1f64).abs() > TOL {
+                bail!("Butcher tableau: {} sums to {}, but should be 1", name, sum);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ODEIntegrator for ExplicitRK {
+    fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64> {
+        let control = if self.b_err.is_empty() {
+            None
+        } else {
+            Some(StepControl {
+                tol: self.tol,
+                safety_factor: self.safety_factor,
+                min_step_size: self.min_step_size,
+                max_step_size: self.max_step_size,
+                max_step_iter: self.max_step_iter,
+            })
+        };
+        explicit_rk_step(problem, t, y, dt, &self.c, &self.a, &self.b, &self.b_err, control)
+    }
+}
+
 // ┌─────────────────────────────────────────────────────────┐
 //  Runge-Kutta
 // └─────────────────────────────────────────────────────────┘
+/// Explicit midpoint integrator
+///
+/// This integrator uses the 2nd order midpoint method (`k2` at `t + dt/2`).
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Midpoint;
+
+impl ButcherTableau for Midpoint {
+    const C: &'static [f64] = &[0.0, 0.5];
+    const A: &'static [&'static [f64]] = &[&[], &[0.5]];
+    const BU: &'static [f64] = &[0.0, 1.0];
+    const BE: &'static [f64] = &[];
+}
+
+/// Heun's integrator
+///
+/// This integrator uses the 2nd order Heun's method (explicit trapezoidal rule).
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Heun;
+
+impl ButcherTableau for Heun {
+    const C: &'static [f64] = &[0.0, 1.0];
+    const A: &'static [&'static [f64]] = &[&[], &[1.0]];
+    const BU: &'static [f64] = &[0.5, 0.5];
+    const BE: &'static [f64] = &[];
+}
+
+/// Runge-Kutta 3rd order integrator
+///
+/// This integrator uses the Kutta's 3rd order method.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RK3;
+
+impl ButcherTableau for RK3 {
+    const C: &'static [f64] = &[0.0, 0.5, 1.0];
+    const A: &'static [&'static [f64]] = &[&[], &[0.5], &[-1.0, 2.0]];
+    const BU: &'static [f64] = &[1.0 / 6.0, 2.0 / 3.0, 1.0 / 6.0];
+    const BE: &'static [f64] = &[];
+}
+
 /// Ralston's 3rd order integrator
 ///
 /// This integrator uses the Ralston's 3rd order method to numerically integrate the ODE system.
@@ -399,8 +847,8 @@ impl ButcherTableau for RALS4 {
     const A: &'static [&'static [f64]] = &[
         &[],
         &[0.4],
-        &[0.29697761, 0.158575964],
-        &[0.21810040, -3.050965616, 3.83286476],
+        &[0.29697761, 0.15875964],
+        &[0.21810040, -3.05096516, 3.83286476],
     ];
     const BU: &'static [f64] = &[0.17476028, -0.55148066, 1.20553560, 0.17118478];
     const BE: &'static [f64] = &[];
@@ -414,7 +862,7 @@ impl ButcherTableau for RALS4 {
 pub struct RK5;
 
 impl ButcherTableau for RK5 {
-    const C: &'static [f64] = &[0.0, 0.2, 0.3, 0.8, 8.0 / 9.0, 1.0, 1.0];
+    const C: &'static [f64] = &[0.0, 0.2, 0.3, 0.8, 8.0 / 9.0, 1.0];
     const A: &'static [&'static [f64]] = &[
         &[],
         &[0.2],
@@ -422,9 +870,8 @@ impl ButcherTableau for RK5 {
         &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
         &[19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0],
         &[9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0],
-        &[35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
     ];
-    const BU: &'static [f64] = &[5179.0 / 57600.0, 0.0, 7571.0 / 16695.0, 393.0 / 640.0, -92097.0 / 339200.0, 187.0 / 2100.0, 1.0 / 40.0];
+    const BU: &'static [f64] = &[35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0];
     const BE: &'static [f64] = &[];
 }
 
@@ -679,14 +1126,15 @@ impl ButcherTableau for TSIT45 {
         &[Self::BU[0], Self::BU[1], Self::BU[2], Self::BU[3], Self::BU[4], Self::BU[5]],
     ];
     const BU: &'static [f64] = &[0.09646076681806523, 0.01, 0.4798896504144996, 1.379008574103742, -3.290069515436081, 2.324710524099774, 0.0];
+    // Embedded weights : BU - (error coefficients of Tsitouras)
     const BE: &'static [f64] = &[
-        0.001780011052226,
-        0.000816434459657,
-        - 0.007880878010262,
-        0.144711007173263,
-        - 0.582357165452555,
-        0.458082105929187,
-        1.0 / 66.0,
+        Self::BU[0] + 0.001780011052226,
+        Self::BU[1] + 0.000816434459657,
+        Self::BU[2] - 0.007880878010262,
+        Self::BU[3] + 0.144711007173263,
+        Self::BU[4] - 0.582357165452555,
+        Self::BU[5] + 0.458082105929187,
+        Self::BU[6] - 1.0 / 66.0,
     ];
 
     fn tol(&self) -> f64 { self.tol }
@@ -696,17 +1144,222 @@ impl ButcherTableau for TSIT45 {
     fn max_step_iter(&self) -> usize { self.max_step_iter }
 }
 
+// ┌─────────────────────────────────────────────────────────┐
+//  Symplectic
+// └─────────────────────────────────────────────────────────┘
+/// Störmer-Verlet (leapfrog) integrator for separable Hamiltonian systems.
+///
+/// The state is split as `y = (q, p)` (first half : positions, second half : momenta),
+/// and `rhs` should give `dq = ∂H/∂p` (depending only on `p`) and `dp = -∂H/∂q` (depending only on `q`),
+/// i.e. `H(q, p) = T(p) + V(q)`.
+///
+/// One step is kick-drift-kick :
+/// `p_{1/2} = p + dt/2 dp(q)`, `q' = q + dt dq(p_{1/2})`, `p' = p_{1/2} + dt/2 dp(q')`.
+///
+/// It is 2nd order, time-reversible and symplectic, so the energy error stays bounded
+/// (no secular drift) over long integrations - unlike `RK4`.
+///
+/// # Example
+///
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let (_, y) = BasicODESolver::new(Verlet).solve(&Pendulum, (0f64, 1000f64), 0.1)?;
+///     let energy = |y: &Vec<f64>| 0.5 * y[1].powi(2) - y[0].cos();
+///     let e0 = energy(&y[0]);
+///     assert!(y.iter().all(|yi| (energy(yi) - e0).abs() < 1e-2));
+///     Ok(())
+/// }
+///
+/// // H = p^2 / 2 - cos q
+/// struct Pendulum;
+///
+/// impl ODEProblem for Pendulum {
+///     fn initial_conditions(&self) -> Vec<f64> {
+///         vec![1f64, 0f64]
+///     }
+///
+///     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+///         dy[0] = y[1];
+///         dy[1] = -y[0].sin();
+///         Ok(())
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Verlet;
+
+impl ODEIntegrator for Verlet {
+    fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64> {
+        check_phase_space(y)?;
+        verlet_step(problem, t, y, dt)?;
+        Ok(dt)
+    }
+}
+
+/// Yoshida's 4th order symplectic integrator.
+///
+/// Composition of three `Verlet` steps with step sizes `w_1 dt`, `w_0 dt`, `w_1 dt`
+/// (`w_1 = 1 / (2 - 2^{1/3})`, `w_0 = 1 - 2 w_1`).
+/// The requirements on `rhs` are same as `Verlet`.
+///
+/// # Reference
+///
+/// - H. Yoshida, _Construction of higher order symplectic integrators_, Phys. Lett. A, 150(5-7), 262-268, 1990.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Yoshida4;
+
+impl ODEIntegrator for Yoshida4 {
+    fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64> {
+        check_phase_space(y)?;
+        let w1 = 1f64 / (2f64 - 2f64.cbrt());
+        let w0 = 1f64 - 2f64 * w1;
+        let mut t = t;
+        for w in [w1, w0, w1] {
+            verlet_step(problem, t, y, w * dt)?;
+            t += w * dt;
+        }
+        Ok(dt)
+    }
+}
+
+fn check_phase_space(y: &[f64]) -> Result<()> {
+    if !y.len().is_multiple_of(2) {
+        bail!("Symplectic integrators require the state (q, p) of even length, but got {}", y.len());
+    }
+    Ok(())
+}
+
+/// Kick-drift-kick
+fn verlet_step<P: ODEProblem>(problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<()> {
+    let n = y.len() / 2;
+    let mut dy = vec![0f64; y.len()];
+
+    problem.rhs(t, y, &mut dy)?;
+    for i in 0 .. n {
+        y[n + i] += 0.5 * dt * dy[n + i];
+    }
+    problem.rhs(t + 0.5 * dt, y, &mut dy)?;
+    for i in 0 .. n {
+        y[i] += dt * dy[i];
+    }
+    problem.rhs(t + dt, y, &mut dy)?;
+    for i in 0 .. n {
+        y[n + i] += 0.5 * dt * dy[n + i];
+    }
+    Ok(())
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Backward Euler
+// └─────────────────────────────────────────────────────────┘
+/// Backward Euler integrator.
+///
+/// This integrator uses the implicit (backward) Euler method, `y_{n+1} = y_n + dt f(t_{n+1}, y_{n+1})`.
+/// It is 1st order, but L-stable, so it is suitable for stiff ODEs.
+/// The nonlinear equation is solved by Newton's method with the Jacobian of `rhs` obtained by `numerical_jacobian`.
+///
+/// # Member variables
+///
+/// - `tol`: The tolerance for the Newton iteration (maximum norm of the update).
+/// - `max_step_iter`: The maximum number of Newton iterations per step.
+///
+/// # Example
+///
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     // Stiff problem : y' = -1000 y (explicit Euler diverges for dt > 0.002)
+///     let solver = BasicODESolver::new(BackwardEuler::default());
+///     let (_, y_vec) = solver.solve(&Stiff, (0f64, 1f64), 0.1)?;
+///     assert!(y_vec.last().unwrap()[0].abs() < 1e-10);
+///
+///     Ok(())
+/// }
+///
+/// struct Stiff;
+///
+/// impl ODEProblem for Stiff {
+///     fn initial_conditions(&self) -> Vec<f64> {
+///         vec![1f64]
+///     }
+///
+///     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+///         dy[0] = -1000f64 * y[0];
+///         Ok(())
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BackwardEuler {
+    pub tol: f64,
+    pub max_step_iter: usize,
+}
+
+impl Default for BackwardEuler {
+    fn default() -> Self {
+        BackwardEuler {
+            tol: 1e-10,
+            max_step_iter: 100,
+        }
+    }
+}
+
+impl BackwardEuler {
+    pub fn new(tol: f64, max_step_iter: usize) -> Self {
+        BackwardEuler { tol, max_step_iter }
+    }
+}
+
+impl ODEIntegrator for BackwardEuler {
+    fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64> {
+        let n = y.len();
+        let t_next = t + dt;
+        let mut z = y.to_vec();
+        let mut fz = vec![0f64; n];
+
+        for _ in 0..self.max_step_iter {
+            // G(z) = z - y - dt f(t + dt, z)
+            problem.rhs(t_next, &z, &mut fz)?;
+            let g = (0..n).map(|i| z[i] - y[i] - dt * fz[i]).collect::<Vec<f64>>();
+            let j = eye(n) - rhs_jacobian(problem, t_next, &z)? * dt;
+            let dz = j.solve(&g, SolveKind::LU);
+
+            let mut max_diff = 0f64;
+            for i in 0..n {
+                z[i] -= dz[i];
+                max_diff = max_diff.max(dz[i].abs());
+            }
+
+            if max_diff < self.tol {
+                y.copy_from_slice(&z);
+                return Ok(dt);
+            }
+        }
+
+        bail!(ODEError::ReachedMaxStepIter)
+    }
+}
+
 // ┌─────────────────────────────────────────────────────────┐
 //  Gauss-Legendre 4th order
 // └─────────────────────────────────────────────────────────┘
 /// Enum for implicit solvers.
 ///
 /// This enum defines the available implicit solvers for the Gauss-Legendre 4th order integrator.
-/// Currently, only the fixed-point iteration method is implemented.
+///
+/// - `FixedPoint`: Fixed-point iteration. It converges only if `dt` is small compared to the stiffness.
+/// - `Newton`: Simplified Newton iteration with the Jacobian of `rhs` at the start of the step (`numerical_jacobian`).
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImplicitSolver {
     FixedPoint,
+    Newton,
     //Broyden,
     //TrustRegion(f64, f64),
 }
@@ -755,10 +1408,15 @@ impl ODEIntegrator for GL4 {
     fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64> {
         let n = y.len();
         let sqrt3 = 3.0_f64.sqrt();
-        let c = 0.5 * (3.0 - sqrt3) / 6.0;
-        let d = 0.5 * (3.0 + sqrt3) / 6.0;
+        // Butcher tableau
+        let c = [0.5 - sqrt3 / 6.0, 0.5 + sqrt3 / 6.0];
+        let a = [[0.25, 0.25 - sqrt3 / 6.0], [0.25 + sqrt3 / 6.0, 0.25]];
+
         let mut k1 = vec![0.0; n];
-        let mut k2 = vec![0.0; n];
+        problem.rhs(t, y, &mut k1)?;
+        let mut k2 = k1.clone();
+        let mut f1 = vec![0.0; n];
+        let mut f2 = vec![0.0; n];
         let mut y1 = vec![0.0; n];
         let mut y2 = vec![0.0; n];
 
@@ -767,23 +1425,72 @@ impl ODEIntegrator for GL4 {
                 // Fixed-point iteration
                 for _ in 0..self.max_step_iter {
                     for i in 0..n {
-                        y1[i] = y[i] + dt * (c * k1[i] + d * k2[i] - sqrt3 * (k2[i] - k1[i]) / 2.0);
-                        y2[i] = y[i] + dt * (c * k1[i] + d * k2[i] + sqrt3 * (k2[i] - k1[i]) / 2.0);
+                        y1[i] = y[i] + dt * (a[0][0] * k1[i] + a[0][1] * k2[i]);
+                        y2[i] = y[i] + dt * (a[1][0] * k1[i] + a[1][1] * k2[i]);
+                    }
+
+                    problem.rhs(t + c[0] * dt, &y1, &mut f1)?;
+                    problem.rhs(t + c[1] * dt, &y2, &mut f2)?;
+
+                    let mut max_diff = 0f64;
+                    for i in 0..n {
+                        max_diff = max_diff
+                            .max(dt * (f1[i] - k1[i]).abs())
+                            .max(dt * (f2[i] - k2[i]).abs());
+                    }
+                    k1.copy_from_slice(&f1);
+                    k2.copy_from_slice(&f2);
+
+                    if max_diff < self.tol {
+                        break;
+                    }
+                }
+            }
+            ImplicitSolver::Newton => {
+                // M = I - dt (A ⊗ J)
+                let jac = rhs_jacobian(problem, t, y)?;
+                let mut m = eye(2 * n);
+                for p in 0..2 {
+                    for q in 0..2 {
+                        for i in 0..n {
+                            for j in 0..n {
+                                m[(p * n + i, q * n + j)] -= dt * a[p][q] * jac[(i, j)];
+                            }
+                        }
+                    }
+                }
+                let m_inv = m.inv();
+
+                let mut converged = false;
+                for _ in 0..self.max_step_iter {
+                    for i in 0..n {
+                        y1[i] = y[i] + dt * (a[0][0] * k1[i] + a[0][1] * k2[i]);
+                        y2[i] = y[i] + dt * (a[1][0] * k1[i] + a[1][1] * k2[i]);
                     }
 
-                    problem.rhs(t + c * dt, &y1, &mut k1)?;
-                    problem.rhs(t + d * dt, &y2, &mut k2)?;
+                    problem.rhs(t + c[0] * dt, &y1, &mut f1)?;
+                    problem.rhs(t + c[1] * dt, &y2, &mut f2)?;
+
+                    // G(K) = K - F(K)
+                    let mut g = k1.iter().zip(f1.iter()).map(|(k, f)| k - f).collect::<Vec<f64>>();
+                    g.extend(k2.iter().zip(f2.iter()).map(|(k, f)| k - f));
+                    let dk = &m_inv * &g;
 
                     let mut max_diff = 0f64;
                     for i in 0..n {
-                        max_diff = max_diff.max((y1[i] - y[i] - dt * (c * k1[i] + d * k2[i] - sqrt3 * (k2[i] - k1[i]) / 2.0)).abs())
-                                            .max((y2[i] - y[i] - dt * (c * k1[i] + d * k2[i] + sqrt3 * (k2[i] - k1[i]) / 2.0)).abs());
+                        k1[i] -= dk[i];
+                        k2[i] -= dk[n + i];
+                        max_diff = max_diff.max(dt * dk[i].abs()).max(dt * dk[n + i].abs());
                     }
 
                     if max_diff < self.tol {
+                        converged = true;
                         break;
                     }
                 }
+                if !converged {
+                    bail!(ODEError::ReachedMaxStepIter);
+                }
             }
         }
 
@@ -794,3 +1501,552 @@ impl ODEIntegrator for GL4 {
         Ok(dt)
     }
 }
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Rosenbrock 2/3rd order
+// └─────────────────────────────────────────────────────────┘
+/// Rosenbrock 2/3rd order integrator (adaptive step).
+///
+/// This integrator uses the L-stable Rosenbrock (linearly implicit) method of Shampine & Reichelt (MATLAB `ode23s`).
+/// Each stage needs only a linear solve with `W = I - h d J` (`d = 1 / (2 + sqrt(2))`) instead of Newton iterations,
+/// and the Jacobian `J` of `rhs` (`numerical_jacobian`) is reused for rejected steps.
+///
+/// `step` always advances exactly `dt`: it takes as many internal adaptive steps as needed,
+/// and returns the internal step size as the suggestion for the next `dt`.
+///
+/// # Member variables
+///
+/// - `tol`: The tolerance for the estimated error (absolute for `|y| < 1`, relative otherwise).
+/// - `safety_factor`: The safety factor for the step size adjustment.
+/// - `min_step_size`: The minimum step size.
+/// - `max_step_size`: The maximum step size.
+/// - `max_step_iter`: The maximum number of rejected steps per step.
+///
+/// # Example
+///
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     // Robertson's chemical kinetics (stiff)
+///     let ros = ROS23::new(1e-8, 0.9, 1e-12, 1e2, 100);
+///     let mut y = Robertson.initial_conditions();
+///     ros.step(&Robertson, 0f64, &mut y, 40f64)?;
+///     assert!((y[0] + y[1] + y[2] - 1f64).abs() < 1e-8);
+///     assert!((y[0] - 0.715827).abs() < 1e-5);
+///
+///     Ok(())
+/// }
+///
+/// struct Robertson;
+///
+/// impl ODEProblem for Robertson {
+///     fn initial_conditions(&self) -> Vec<f64> {
+///         vec![1f64, 0f64, 0f64]
+///     }
+///
+///     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+///         dy[0] = -0.04 * y[0] + 1e4 * y[1] * y[2];
+///         dy[1] = 0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1].powi(2);
+///         dy[2] = 3e7 * y[1].powi(2);
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// # References
+///
+/// - L. F. Shampine and M. W. Reichelt, _The MATLAB ODE Suite_, SIAM J. Sci. Comput., 18(1), 1-22, 1997.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ROS23 {
+    pub tol: f64,
+    pub safety_factor: f64,
+    pub min_step_size: f64,
+    pub max_step_size: f64,
+    pub max_step_iter: usize,
+}
+
+impl Default for ROS23 {
+    fn default() -> Self {
+        Self {
+            tol: 1e-6,
+            safety_factor: 0.9,
+            min_step_size: 1e-10,
+            max_step_size: 1e-1,
+            max_step_iter: 100,
+        }
+    }
+}
+
+impl ROS23 {
+    pub fn new(tol: f64, safety_factor: f64, min_step_size: f64, max_step_size: f64, max_step_iter: usize) -> Self {
+        Self {
+            tol,
+            safety_factor,
+            min_step_size,
+            max_step_size,
+            max_step_iter,
+        }
+    }
+}
+
+impl ODEIntegrator for ROS23 {
+    fn step<P: ODEProblem>(&self, problem: &P, t: f64, y: &mut [f64], dt: f64) -> Result<f64> {
+        let n = y.len();
+        let d = 1f64 / (2f64 + 2f64.sqrt());
+        let e32 = 6f64 + 2f64.sqrt();
+        let t_end = t + dt;
+
+        let mut t = t;
+        let mut h = dt.min(self.max_step_size);
+        let mut h_next = h;
+        let mut iter_count = 0usize;
+        let mut f0 = vec![0f64; n];
+        let mut f1 = vec![0f64; n];
+        let mut f2 = vec![0f64; n];
+        let mut f_t = vec![0f64; n];
+
+        while t < t_end {
+            // Jacobian & time derivative at (t, y) : reused for rejected steps
+            problem.rhs(t, y, &mut f0)?;
+            let jac = rhs_jacobian(problem, t, y)?;
+            let delta = f64::EPSILON.sqrt() * t.abs().max(1f64);
+            problem.rhs(t + delta, y, &mut f_t)?;
+            let dfdt = f_t.iter().zip(f0.iter()).map(|(a, b)| (a - b) / delta).collect::<Vec<f64>>();
+
+            loop {
+                // Do not step over t_end
+                let last = t + h >= t_end;
+                let h_step = if last { t_end - t } else { h };
+
+                let w = eye(n) - jac.clone() * (h_step * d);
+                let w_inv = w.inv();
+
+                let r1 = (0..n).map(|i| f0[i] + h_step * d * dfdt[i]).collect::<Vec<f64>>();
+                let k1 = &w_inv * &r1;
+                let y1 = (0..n).map(|i| y[i] + 0.5 * h_step * k1[i]).collect::<Vec<f64>>();
+                problem.rhs(t + 0.5 * h_step, &y1, &mut f1)?;
+                let r2 = (0..n).map(|i| f1[i] - k1[i]).collect::<Vec<f64>>();
+                let k2 = (&w_inv * &r2).into_iter().zip(k1.iter()).map(|(a, b)| a + b).collect::<Vec<f64>>();
+                let y_new = (0..n).map(|i| y[i] + h_step * k2[i]).collect::<Vec<f64>>();
+                problem.rhs(t + h_step, &y_new, &mut f2)?;
+                let r3 = (0..n)
+                    .map(|i| f2[i] - e32 * (k2[i] - f1[i]) - 2f64 * (k1[i] - f0[i]) + h_step * d * dfdt[i])
+                    .collect::<Vec<f64>>();
+                let k3 = &w_inv * &r3;
+
+                let mut error = 0f64;
+                for i in 0..n {
+                    let e = h_step / 6f64 * (k1[i] - 2f64 * k2[i] + k3[i]);
+                    error = error.max(e.abs() / y[i].abs().max(y_new[i].abs()).max(1f64));
+                }
+
+                let factor = if error == 0f64 {
+                    5f64
+                } else {
+                    (self.safety_factor * (self.tol / error).powf(1f64 / 3f64)).clamp(0.2, 5f64)
+                };
+                let new_h = (h_step * factor).clamp(self.min_step_size, self.max_step_size);
+
+                if error < self.tol || h_step <= self.min_step_size {
+                    y.copy_from_slice(&y_new);
+                    t = if last { t_end } else { t + h_step };
+                    // Keep the suggestion of the last full step
+                    if !last || h_step >= h {
+                        h_next = new_h;
+                    }
+                    h = new_h;
+                    break;
+                } else {
+                    iter_count += 1;
+                    if iter_count >= self.max_step_iter {
+                        bail!(ODEError::ReachedMaxStepIter);
+                    }
+                    h = new_h;
+                }
+            }
+        }
+
+        Ok(h_next)
+    }
+}
+
+/// Jacobian of `rhs` with respect to `y` (central finite differences)
+fn rhs_jacobian<P: ODEProblem>(problem: &P, t: f64, y: &[f64]) -> Result<Matrix> {
+    let n = y.len();
+    let f = |x: &Vec<f64>| {
+        let mut dy = vec![0f64; n];
+        match problem.rhs(t, x, &mut dy) {
+            Ok(()) => dy,
+            Err(_) => vec![f64::NAN; n],
+        }
+    };
+    let j = numerical_jacobian(f, &y.to_vec());
+    if j.data.iter().any(|x| !x.is_finite()) {
+        bail!("Failed to evaluate the Jacobian of rhs at t = {}", t);
+    }
+    Ok(j)
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Delay differential equation
+// └─────────────────────────────────────────────────────────┘
+/// Trait for defining a delay differential equation (DDE) problem.
+///
+/// `dy/dt = f(t, y(t), y(t - τ_1), ..., y(t - τ_k))` with constant delays `τ_i > 0`
+/// (registered by `DDESolver::set_delays`).
+///
+/// - `history(t)`: `y(t)` for `t <= t0` (the initial condition is `history(t0)`)
+/// - `rhs(t, y, past, dy)`: `past.delayed(i)` is `y(t - τ_i)`
+pub trait DDEProblem {
+    fn history(&self, t: f64) -> Vec<f64>;
+    fn rhs(&self, t: f64, y: &[f64], past: &DelayContext, dy: &mut [f64]) -> Result<()>;
+}
+
+/// Delayed states `y(t - τ_i)` passed to `DDEProblem::rhs`
+#[derive(Debug, Clone)]
+pub struct DelayContext {
+    states: Vec<Vec<f64>>,
+}
+
+impl DelayContext {
+    /// `y(t - τ_i)`
+    pub fn delayed(&self, i: usize) -> &[f64] {
+        &self.states[i]
+    }
+
+    /// Number of delays
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
+
+/// A solver for delay differential equations using a specified (explicit) integrator.
+///
+/// # Description
+///
+/// Steps, states and derivatives are stored, and delayed states `y(t - τ)` are evaluated by
+/// cubic Hermite interpolation between stored steps (or `history` for `t - τ <= t0`).
+/// So, the step size need not divide the delays, but it should not exceed the smallest delay
+/// (then `t - τ` never falls into the current step).
+/// Steps proposed by adaptive integrators are clamped to the smallest delay.
+///
+/// # Example
+///
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let mut solver = DDESolver::new(RK4);
+///     solver.set_delays(vec![1f64]);
+///     let (t, y) = solver.solve(&Decay, (0f64, 2f64), 0.01)?;
+///
+///     // Method of steps : y = 1 - t on [0, 1], y = 1 - t + (t - 1)^2 / 2 on [1, 2]
+///     let n = t.len() - 1;
+///     let exact = 1f64 - t[n] + (t[n] - 1f64).powi(2) / 2f64;
+///     assert!((y[n][0] - exact).abs() < 1e-6);
+///     Ok(())
+/// }
+///
+/// // y'(t) = -y(t - 1), y(t) = 1 for t <= 0
+/// struct Decay;
+///
+/// impl DDEProblem for Decay {
+///     fn history(&self, _t: f64) -> Vec<f64> {
+///         vec![1f64]
+///     }
+///
+///     fn rhs(&self, _t: f64, _y: &[f64], past: &DelayContext, dy: &mut [f64]) -> anyhow::Result<()> {
+///         dy[0] = -past.delayed(0)[0];
+///         Ok(())
+///     }
+/// }
+/// ```
+pub struct DDESolver<I: ODEIntegrator> {
+    integrator: I,
+    delays: Vec<f64>,
+}
+
+impl<I: ODEIntegrator> DDESolver<I> {
+    pub fn new(integrator: I) -> Self {
+        Self {
+            integrator,
+            delays: vec![],
+        }
+    }
+
+    /// Register constant delays `τ_i` (`past.delayed(i)` in `rhs`)
+    pub fn set_delays(&mut self, delays: Vec<f64>) -> &mut Self {
+        assert!(delays.iter().all(|tau| *tau > 0f64), "Delays should be positive");
+        self.delays = delays;
+        self
+    }
+
+    pub fn solve<P: DDEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
+        let tau_min = self.delays.iter().fold(f64::INFINITY, |a, b| a.min(*b));
+        if dt > tau_min {
+            bail!("Step size ({}) should not exceed the smallest delay ({})", dt, tau_min);
+        }
+
+        let mut t = t_span.0;
+        let mut dt = dt;
+        let mut y = problem.history(t);
+        let mut history = DelayHistory {
+            t: vec![],
+            y: vec![],
+            dy: vec![],
+        };
+
+        loop {
+            let mut dy = vec![0f64; y.len()];
+            self.as_ode(problem, &history, t_span.0).rhs(t, &y, &mut dy)?;
+            history.t.push(t);
+            history.y.push(y.clone());
+            history.dy.push(dy);
+            if t >= t_span.1 {
+                break;
+            }
+
+            let dt_step = self.integrator.step(&self.as_ode(problem, &history, t_span.0), t, &mut y, dt)?;
+            t += dt;
+            // Adaptive integrators may propose a step longer than the smallest delay
+            dt = dt_step.min(tau_min);
+        }
+
+        Ok((history.t, history.y))
+    }
+
+    fn as_ode<'a, P: DDEProblem>(&'a self, problem: &'a P, history: &'a DelayHistory, t0: f64) -> DelayedProblem<'a, P> {
+        DelayedProblem {
+            problem,
+            delays: &self.delays,
+            history,
+            t0,
+        }
+    }
+}
+
+/// Stored steps of DDE solution
+struct DelayHistory {
+    t: Vec<f64>,
+    y: Vec<Vec<f64>>,
+    dy: Vec<Vec<f64>>,
+}
+
+impl DelayHistory {
+    /// `y(s)` by cubic Hermite interpolation (or `history` for `s <= t0`)
+    fn eval<P: DDEProblem>(&self, problem: &P, t0: f64, s: f64) -> Result<Vec<f64>> {
+        if s <= t0 || self.t.is_empty() {
+            return Ok(problem.history(s));
+        }
+        let n = self.t.len();
+        let t_last = self.t[n - 1];
+        if s > t_last + 1e-10 * (1f64 + t_last.abs()) {
+            bail!("Delayed time {} is beyond the computed solution (t = {}); step size should not exceed the smallest delay", s, t_last);
+        }
+        if n == 1 || s >= t_last {
+            return Ok(self.y[n - 1].clone());
+        }
+
+        let i = self.t.partition_point(|x| *x <= s).clamp(1, n - 1);
+        let (t_a, t_b) = (self.t[i - 1], self.t[i]);
+        let h = t_b - t_a;
+        let u = (s - t_a) / h;
+        let h00 = (1f64 + 2f64 * u) * (1f64 - u).powi(2);
+        let h10 = u * (1f64 - u).powi(2);
+        let h01 = u.powi(2) * (3f64 - 2f64 * u);
+        let h11 = u.powi(2) * (u - 1f64);
+        Ok((0 .. self.y[i].len())
+            .map(|m| {
+                h00 * self.y[i - 1][m] + h10 * h * self.dy[i - 1][m] + h01 * self.y[i][m] + h11 * h * self.dy[i][m]
+            })
+            .collect())
+    }
+}
+
+/// DDE as ODE for the current step
+struct DelayedProblem<'a, P: DDEProblem> {
+    problem: &'a P,
+    delays: &'a [f64],
+    history: &'a DelayHistory,
+    t0: f64,
+}
+
+impl<P: DDEProblem> ODEProblem for DelayedProblem<'_, P> {
+    fn initial_conditions(&self) -> Vec<f64> {
+        self.problem.history(self.t0)
+    }
+
+    fn rhs(&self, t: f64, y: &[f64], dy: &mut [f64]) -> Result<()> {
+        let states = self
+            .delays
+            .iter()
+            .map(|tau| self.history.eval(self.problem, self.t0, t - tau))
+            .collect::<Result<Vec<Vec<f64>>>>()?;
+        self.problem.rhs(t, y, &DelayContext { states }, dy)
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Boundary value problem (Shooting method)
+// └─────────────────────────────────────────────────────────┘
+/// Dirichlet boundary conditions : `(component, value)` pairs
+pub type BoundaryCondition = Vec<(usize, f64)>;
+
+/// Boundary value problem solver by shooting method.
+///
+/// # Description
+///
+/// Dirichlet conditions `y_i(t0) = a_i` (left) and `y_j(t1) = b_j` (right) are given by
+/// `set_boundary_condition(left, right)` as `(component, value)` pairs.
+/// The components not fixed at `t0` are unknown (their values in `initial_conditions` are the initial guess),
+/// and the number of unknowns should equal the number of right conditions.
+///
+/// The unknowns are found by Newton's method on the residual `y_j(t1) - b_j`
+/// (Jacobian by finite differences), integrating the IVP with fixed step `h = (t1 - t0) / ceil((t1 - t0) / dt)`
+/// so that the last step lands exactly on `t1`.
+///
+/// # Example
+///
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     // y'' = -y, y(0) = 0, y(π/2) = 1 => y = sin(t)
+///     let mut solver = ShootingSolver::new(RK4);
+///     solver.set_boundary_condition(vec![(0, 0f64)], vec![(0, 1f64)]);
+///     let (t, y) = solver.solve_bvp(&Oscillator, (0f64, std::f64::consts::FRAC_PI_2), 0.01)?;
+///
+///     assert!((y[0][1] - 1f64).abs() < 1e-8); // y'(0) = 1
+///     for (ti, yi) in t.iter().zip(y.iter()) {
+///         assert!((yi[0] - ti.sin()).abs() < 1e-8);
+///     }
+///     Ok(())
+/// }
+///
+/// struct Oscillator;
+///
+/// impl ODEProblem for Oscillator {
+///     fn initial_conditions(&self) -> Vec<f64> {
+///         vec![0f64, 0f64] // y'(0) = 0 : initial guess
+///     }
+///
+///     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+///         dy[0] = y[1];
+///         dy[1] = -y[0];
+///         Ok(())
+///     }
+/// }
+/// ```
+pub struct ShootingSolver<I: ODEIntegrator> {
+    integrator: I,
+    boundary: Option<(BoundaryCondition, BoundaryCondition)>,
+    tol: f64,
+    max_iter: usize,
+}
+
+impl<I: ODEIntegrator> ShootingSolver<I> {
+    pub fn new(integrator: I) -> Self {
+        Self {
+            integrator,
+            boundary: None,
+            tol: 1e-10,
+            max_iter: 50,
+        }
+    }
+
+    /// Dirichlet conditions `(component, value)` at `t0` (left) and `t1` (right)
+    pub fn set_boundary_condition(&mut self, left: BoundaryCondition, right: BoundaryCondition) -> &mut Self {
+        self.boundary = Some((left, right));
+        self
+    }
+
+    /// Tolerance for the residual at `t1` (default: `1e-10`)
+    pub fn set_tol(&mut self, tol: f64) -> &mut Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Maximum number of Newton iterations (default: `50`)
+    pub fn set_max_iter(&mut self, max_iter: usize) -> &mut Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    pub fn solve_bvp<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
+        let (left, right) = match self.boundary {
+            Some((ref left, ref right)) => (left, right),
+            None => bail!("Boundary conditions are not set (use set_boundary_condition)"),
+        };
+        let mut y0 = problem.initial_conditions();
+        let n = y0.len();
+        if left.iter().chain(right.iter()).any(|(i, _)| *i >= n) {
+            bail!("Boundary condition for component out of range (dimension = {})", n);
+        }
+        for (i, a) in left {
+            y0[*i] = *a;
+        }
+        let unknown = (0 .. n).filter(|i| left.iter().all(|(j, _)| j != i)).collect::<Vec<usize>>();
+        if unknown.len() != right.len() {
+            bail!("Number of unknowns at t0 ({}) should equal the number of right boundary conditions ({})", unknown.len(), right.len());
+        }
+
+        let shoot = |s: &[f64]| -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
+            let mut y = y0.clone();
+            for (i, s_i) in unknown.iter().zip(s.iter()) {
+                y[*i] = *s_i;
+            }
+            self.integrate(problem, y, t_span, dt)
+        };
+        let residual = |y_end: &[f64]| right.iter().map(|(j, b)| y_end[*j] - b).collect::<Vec<f64>>();
+
+        let mut s = unknown.iter().map(|i| y0[*i]).collect::<Vec<f64>>();
+        for _ in 0 .. self.max_iter {
+            let (t_vec, y_vec) = shoot(&s)?;
+            let r = residual(y_vec.last().unwrap());
+            if r.iter().all(|x| x.abs() < self.tol) {
+                return Ok((t_vec, y_vec));
+            }
+
+            let f = |x: &Vec<f64>| match shoot(x) {
+                Ok((_, y_vec)) => residual(y_vec.last().unwrap()),
+                Err(_) => vec![f64::NAN; x.len()],
+            };
+            let j = numerical_jacobian(f, &s);
+            if j.data.iter().any(|x| !x.is_finite()) {
+                bail!("Failed to evaluate the Jacobian of the shooting residual");
+            }
+            let ds = j.solve(&r, SolveKind::LU);
+            s.iter_mut().zip(ds.iter()).for_each(|(x, d)| *x -= d);
+        }
+        bail!("Shooting method did not converge in {} iterations", self.max_iter)
+    }
+
+    /// Integrate with the step sizes returned by the integrator (last step lands on `t1`)
+    fn integrate<P: ODEProblem>(&self, problem: &P, y0: Vec<f64>, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
+        if dt.is_nan() || dt <= 0f64 {
+            bail!("Step size should be positive (dt = {})", dt);
+        }
+        let (t0, t1) = t_span;
+        let mut t = t0;
+        let mut dt = dt;
+        let mut y = y0;
+        let mut t_vec = vec![t0];
+        let mut y_vec = vec![y.clone()];
+        while t < t1 {
+            // Take the rest of the span if it is (up to rounding) within one step
+            let last = t + dt * (1f64 + 1e-9) >= t1;
+            let h = if last { t1 - t } else { dt };
+            dt = self.integrator.step(problem, t, &mut y, h)?;
+            t = if last { t1 } else { t + h };
+            t_vec.push(t);
+            y_vec.push(y.clone());
+        }
+        Ok((t_vec, y_vec))
+    }
+}
@@ -20,6 +20,8 @@
 //!     method: OptMethod,
 //!     option: HashMap<OptOption, bool>,
 //!     hyperparams: HashMap<String, f64>,
+//!     jacobian: Option<fn(&Vec<f64>, &Vec<f64>) -> Matrix>,
+//!     weights: Option<Vec<f64>>,
 //! }
 //! ```
 //!
@@ -38,6 +40,8 @@
 //! * `set_lr` : Set learning rate (For `GradientDescent`)
 //! * `set_lambda_init` : Set initial value of lambda (For `LevenbergMarquardt`)
 //! * `set_lambda_max` : Set maximum value of lambda (For `LevenbergMarquardt`)
+//! * `set_jacobian` : Set explicit Jacobian of model (instead of automatic differentiation)
+//! * `set_weights` : Set weights of residuals (weighted least squares)
 //!
 //! ### Method (Generate result)
 //!
@@ -107,6 +111,24 @@
 //! ```
 //!
 //! ![LM test](https://raw.githubusercontent.com/Axect/Peroxide/master/example_data/lm_test.png)
+//!
+//! ## Global optimization
+//!
+//! For multi-modal objective `f: R^n -> R` in a box, there are derivative-free global methods.
+//! Both take a seed for reproducibility and return `GlobalOptResult` (best point, best value, number of evaluations).
+//!
+//! * `simulated_annealing(f, bounds, schedule, seed)` : Simulated annealing with `AnnealingSchedule`
+//! * `differential_evolution(f, bounds, pop_size, cr, f_weight, max_gen, seed)` : Differential evolution (DE/rand/1/bin)
+//!
+//! ```rust
+//! use peroxide::fuga::*;
+//!
+//! // Minimum at (1, 1)
+//! let rosenbrock = |x: &Vec<f64>| (1f64 - x[0]).powi(2) + 100f64 * (x[1] - x[0].powi(2)).powi(2);
+//! let bounds = vec![(-2f64, 2f64); 2];
+//! let result = differential_evolution(rosenbrock, &bounds, 30, 0.9, 0.8, 500, 42);
+//! assert!(result.value < 1e-10);
+//! ```
 
 pub use self::OptMethod::{GaussNewton, GradientDescent, LevenbergMarquardt};
 use self::OptOption::{InitParam, MaxIter};
@@ -114,6 +136,9 @@ use crate::numerical::utils::jacobian;
 use crate::structure::matrix::{LinearAlgebra, Matrix};
 use crate::structure::ad::{AD, ADVec};
 use crate::util::useful::max;
+use crate::statistics::rand::stdrng_from_seed;
+use rand::Rng;
+use rand_distr::StandardNormal;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy)]
@@ -129,6 +154,9 @@ pub enum OptOption {
     MaxIter,
 }
 
+/// Explicit Jacobian of model : `(domain, param) -> J`
+pub type JacobianFn = fn(&Vec<f64>, &Vec<f64>) -> Matrix;
+
 /// Optimizer for optimization (non-linear regression)
 ///
 /// # Methods
@@ -151,6 +179,8 @@ where
     method: OptMethod,
     option: HashMap<OptOption, bool>,
     hyperparams: HashMap<String, f64>,
+    jacobian: Option<JacobianFn>,
+    weights: Option<Vec<f64>>,
 }
 
 impl<F> Optimizer<F>
@@ -172,6 +202,8 @@ where
             method: LevenbergMarquardt,
             option: default_option,
             hyperparams: HashMap::new(),
+            jacobian: None,
+            weights: None,
         }
     }
 
@@ -234,22 +266,99 @@ where
         self
     }
 
+    /// Set explicit Jacobian of model
+    ///
+    /// # Description
+    /// `j(x, p)` should return `n x m` matrix `J_ik = d f(x_i; p) / d p_k`
+    /// (`n` : number of data, `m` : number of parameters).
+    /// It is used instead of automatic differentiation of `func`,
+    /// so `func` only needs to provide values (e.g. lookup table or external routine).
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let x = seq(0, 2, 0.1);
+    ///     let y = x.fmap(|t| 2f64 * (-0.5 * t).exp());
+    ///     let data = hstack!(x, y);
+    ///
+    ///     let mut opt = Optimizer::new(data, model);
+    ///     let p = opt
+    ///         .set_init_param(c!(1, -1))
+    ///         .set_max_iter(50)
+    ///         .set_jacobian(model_jacobian)
+    ///         .optimize();
+    ///     assert!(eq_vec(&p, &c!(2, -0.5), 1e-6));
+    /// }
+    ///
+    /// // Values only (no derivative information)
+    /// fn model(x: &Vec<f64>, p: Vec<AD>) -> Option<Vec<AD>> {
+    ///     let (a, b) = (p[0].x(), p[1].x());
+    ///     Some(x.iter().map(|t| AD0(a * (b * t).exp())).collect())
+    /// }
+    ///
+    /// fn model_jacobian(x: &Vec<f64>, p: &Vec<f64>) -> Matrix {
+    ///     let mut j = zeros(x.len(), 2);
+    ///     for (i, t) in x.iter().enumerate() {
+    ///         j[(i, 0)] = (p[1] * t).exp();
+    ///         j[(i, 1)] = p[0] * t * (p[1] * t).exp();
+    ///     }
+    ///     j
+    /// }
+    /// ```
+    pub fn set_jacobian(&mut self, j: JacobianFn) -> &mut Self {
+        self.jacobian = Some(j);
+        self
+    }
+
+    /// Set weights of residuals
+    ///
+    /// # Description
+    /// Residuals are scaled by `w_i` (usually `w_i = 1 / sigma_i`), so that
+    /// `sum_i (w_i (y_i - f(x_i; p)))^2` is minimized.
+    /// `get_error` also reports the weighted RMS of residuals.
+    pub fn set_weights(&mut self, w: Vec<f64>) -> &mut Self {
+        assert_eq!(w.len(), self.observed.len(), "Length of weights should be same as data");
+        self.weights = Some(w);
+        self
+    }
+
     /// Main function for optimization
     pub fn optimize(&mut self) -> Vec<f64> {
         // Receive initial data
         let (x_vec, y_vec) = (self.domain.clone(), self.observed.clone());
         let (p_init, max_iter) = (self.param.clone(), self.max_iter);
+        let w = self.weights.clone().unwrap_or_else(|| vec![1f64; y_vec.len()]);
         let safe_f = |p: &Vec<AD>| (self.func)(&x_vec, p.clone()).unwrap();
         let unsafe_f = |p: Vec<AD>| (self.func)(&x_vec, p);
+        let weighted = |v: Vec<f64>| -> Matrix {
+            v.iter().zip(w.iter()).map(|(vi, wi)| vi * wi).collect::<Vec<f64>>().into()
+        };
+        let jac = |p: &Vec<f64>| -> Matrix {
+            let mut j = match self.jacobian {
+                Some(jf) => jf(&x_vec, p),
+                None => jacobian(safe_f, p),
+            };
+            assert_eq!((j.row, j.col), (w.len(), p.len()), "Jacobian should be (number of data) x (number of parameters)");
+            for (i, wi) in w.iter().enumerate() {
+                for k in 0..j.col {
+                    j[(i, k)] *= wi;
+                }
+            }
+            j
+        };
 
         // Take various form of initial data
         let p_init_vec = p_init.to_f64_vec();
-        let y = y_vec.into();
+        let y = weighted(y_vec);
 
         // Declare mutable values
         let mut p: Matrix = p_init_vec.clone().into();
-        let mut j = jacobian(safe_f, &p_init_vec);
-        let mut y_hat: Matrix = safe_f(&p_init).to_f64_vec().into();
+        let mut j = jac(&p_init_vec);
+        let mut y_hat: Matrix = weighted(safe_f(&p_init).to_f64_vec());
         let mut jtj = &j.t() * &j;
         let mut valid_p = p.clone();
         let mut err_stack = 0usize;
@@ -265,8 +374,8 @@ where
                             p = p_cand;
                             valid_p = p.clone();
                             err_stack = 0;
-                            j = jacobian(safe_f, &p.data);
-                            y_hat = value.to_f64_vec().into();
+                            j = jac(&p.data);
+                            y_hat = weighted(value.to_f64_vec());
                         }
                         None => {
                             if i < max_iter - 1 && err_stack < 3 {
@@ -309,8 +418,8 @@ where
                     let p_temp = &p + &h;
                     match unsafe_f(p_temp.data.to_ad_vec()) {
                         Some(value) => {
-                            let j_temp = jacobian(safe_f, &p.data);
-                            let y_hat_temp: Matrix = value.to_f64_vec().into();
+                            let j_temp = jac(&p_temp.data);
+                            let y_hat_temp = weighted(value.to_f64_vec());
                             let chi2_temp = ((&y - &y_hat_temp).t() * (&y - &y_hat_temp))[(0, 0)];
                             let rho = (chi2 - chi2_temp)
                                 / (h.t()
@@ -350,3 +459,279 @@ where
         p.data
     }
 }
+
+// =============================================================================
+// Global optimization
+// =============================================================================
+/// Result of global optimization
+///
+/// * `x` : Best point
+/// * `value` : `f(x)`
+/// * `n_eval` : Number of function evaluations
+#[derive(Debug, Clone)]
+pub struct GlobalOptResult {
+    pub x: Vec<f64>,
+    pub value: f64,
+    pub n_eval: usize,
+}
+
+/// Cooling schedule of simulated annealing (`k` : iteration, `n` : total iterations)
+///
+/// * `Exponential(alpha)` : `T_k = T_0 alpha^k`
+/// * `Linear` : `T_k = T_0 (1 - k / n)`
+/// * `Logarithmic` : `T_k = T_0 / ln(k + e)`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Cooling {
+    Exponential(f64),
+    Linear,
+    Logarithmic,
+}
+
+/// Schedule of simulated annealing
+///
+/// * `t_init` : Initial temperature
+/// * `cooling` : Cooling schedule
+/// * `n_iter` : Number of iterations
+/// * `step` : Proposal scale relative to the width of bounds at `T = t_init`
+///   (scaled by `sqrt(T / t_init)` as the temperature decreases)
+#[derive(Debug, Copy, Clone)]
+pub struct AnnealingSchedule {
+    pub t_init: f64,
+    pub cooling: Cooling,
+    pub n_iter: usize,
+    pub step: f64,
+}
+
+impl Default for AnnealingSchedule {
+    fn default() -> Self {
+        AnnealingSchedule {
+            t_init: 10f64,
+            cooling: Cooling::Exponential(0.9999),
+            n_iter: 100_000,
+            step: 0.1,
+        }
+    }
+}
+
+impl AnnealingSchedule {
+    /// Temperature at `k`-th iteration
+    pub fn temperature(&self, k: usize) -> f64 {
+        match self.cooling {
+            Cooling::Exponential(alpha) => self.t_init * alpha.powi(k as i32),
+            Cooling::Linear => self.t_init * (1f64 - k as f64 / self.n_iter as f64),
+            Cooling::Logarithmic => self.t_init / (k as f64 + std::f64::consts::E).ln(),
+        }
+    }
+}
+
+/// Simulated annealing
+///
+/// # Description
+/// Start from a random point in `bounds`. At each iteration, move a random coordinate by `N(0, s^2)`
+/// (`s = step * width * sqrt(T / t_init)`, clamped to `bounds`), and accept it with
+/// Metropolis criterion `min(1, exp(-(f(x') - f(x)) / T))`.
+/// Finally, the best point visited is refined by compass search.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Double well : global minimum at x = -1
+/// let f = |x: &Vec<f64>| (x[0].powi(2) - 1f64).powi(2) + 0.3 * x[0];
+/// let schedule = AnnealingSchedule { n_iter: 20_000, ..Default::default() };
+/// let result = simulated_annealing(f, &[(-2f64, 2f64)], schedule, 42);
+/// assert!((result.x[0] + 1.036).abs() < 1e-2);
+/// assert!(result.n_eval > 20_000);
+/// ```
+pub fn simulated_annealing<F: Fn(&Vec<f64>) -> f64>(
+    f: F,
+    bounds: &[(f64, f64)],
+    schedule: AnnealingSchedule,
+    seed: u64,
+) -> GlobalOptResult {
+    check_bounds(bounds);
+    let mut rng = stdrng_from_seed(seed);
+
+    let mut x = bounds
+        .iter()
+        .map(|(a, b)| a + (b - a) * rng.gen::<f64>())
+        .collect::<Vec<f64>>();
+    let mut fx = f(&x);
+    let mut best = (x.clone(), fx);
+    let mut n_eval = 1usize;
+
+    for k in 0..schedule.n_iter {
+        let t = schedule.temperature(k);
+        if t <= 0f64 {
+            break;
+        }
+        let scale = schedule.step * (t / schedule.t_init).sqrt();
+        let j = rng.gen_range(0..x.len());
+        let (a, b) = bounds[j];
+        let z: f64 = rng.sample(StandardNormal);
+        let mut x_new = x.clone();
+        x_new[j] = (x[j] + z * scale * (b - a)).clamp(a, b);
+        let f_new = f(&x_new);
+        n_eval += 1;
+
+        if f_new <= fx || rng.gen::<f64>() < (-(f_new - fx) / t).exp() {
+            x = x_new;
+            fx = f_new;
+            if fx < best.1 {
+                best = (x.clone(), fx);
+            }
+        }
+    }
+
+    let (x, value, n) = compass_search(&f, best.0, best.1, bounds);
+    GlobalOptResult {
+        x,
+        value,
+        n_eval: n_eval + n,
+    }
+}
+
+/// Differential evolution (DE/rand/1/bin)
+///
+/// # Description
+/// * Population of `pop_size` (>= 4) points uniformly sampled in `bounds`
+/// * Mutation : `v = x_r1 + f_weight * (x_r2 - x_r3)` (distinct random `r1, r2, r3`)
+/// * Binomial crossover with probability `cr` (at least one coordinate from `v`)
+/// * Coordinates out of `bounds` are set to the midpoint of the parent and the violated bound
+/// * Greedy selection for `max_gen` generations
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let sphere = |x: &Vec<f64>| x.iter().map(|t| t * t).sum::<f64>();
+/// let result = differential_evolution(sphere, &vec![(-5f64, 5f64); 3], 20, 0.9, 0.5, 300, 1);
+/// assert!(result.value < 1e-12);
+/// assert_eq!(result.n_eval, 20 * 301);
+/// ```
+pub fn differential_evolution<F: Fn(&Vec<f64>) -> f64>(
+    f: F,
+    bounds: &[(f64, f64)],
+    pop_size: usize,
+    cr: f64,
+    f_weight: f64,
+    max_gen: usize,
+    seed: u64,
+) -> GlobalOptResult {
+    check_bounds(bounds);
+    assert!(pop_size >= 4, "Population size should be at least 4");
+    assert!((0f64..=1f64).contains(&cr), "Crossover probability should be in [0, 1]");
+    let mut rng = stdrng_from_seed(seed);
+    let dim = bounds.len();
+
+    let mut pop = (0..pop_size)
+        .map(|_| {
+            bounds
+                .iter()
+                .map(|(a, b)| a + (b - a) * rng.gen::<f64>())
+                .collect::<Vec<f64>>()
+        })
+        .collect::<Vec<Vec<f64>>>();
+    let mut values = pop.iter().map(&f).collect::<Vec<f64>>();
+    let mut n_eval = pop_size;
+
+    for _ in 0..max_gen {
+        for i in 0..pop_size {
+            let mut pick = || loop {
+                let r = rng.gen_range(0..pop_size);
+                if r != i {
+                    break r;
+                }
+            };
+            let r1 = pick();
+            let r2 = loop {
+                let r = pick();
+                if r != r1 {
+                    break r;
+                }
+            };
+            let r3 = loop {
+                let r = pick();
+                if r != r1 && r != r2 {
+                    break r;
+                }
+            };
+            let j_rand = rng.gen_range(0..dim);
+
+            let trial = (0..dim)
+                .map(|j| {
+                    if j == j_rand || rng.gen::<f64>() < cr {
+                        let v = pop[r1][j] + f_weight * (pop[r2][j] - pop[r3][j]);
+                        let (a, b) = bounds[j];
+                        if v < a {
+                            (a + pop[i][j]) / 2f64
+                        } else if v > b {
+                            (b + pop[i][j]) / 2f64
+                        } else {
+                            v
+                        }
+                    } else {
+                        pop[i][j]
+                    }
+                })
+                .collect::<Vec<f64>>();
+            let f_trial = f(&trial);
+            n_eval += 1;
+            if f_trial <= values[i] {
+                pop[i] = trial;
+                values[i] = f_trial;
+            }
+        }
+    }
+
+    let best = (0..pop_size)
+        .min_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap())
+        .unwrap();
+    GlobalOptResult {
+        x: pop[best].clone(),
+        value: values[best],
+        n_eval,
+    }
+}
+
+/// Compass search : try `x ± h e_j` for each coordinate and halve `h` if no improvement
+fn compass_search<F: Fn(&Vec<f64>) -> f64>(
+    f: &F,
+    x: Vec<f64>,
+    fx: f64,
+    bounds: &[(f64, f64)],
+) -> (Vec<f64>, f64, usize) {
+    let (mut x, mut fx) = (x, fx);
+    let mut n_eval = 0usize;
+    let mut h = 1e-2;
+    while h > 1e-12 {
+        let mut improved = false;
+        for j in 0..x.len() {
+            let (a, b) = bounds[j];
+            for sign in [1f64, -1f64] {
+                let mut x_new = x.clone();
+                x_new[j] = (x[j] + sign * h * (b - a)).clamp(a, b);
+                let f_new = f(&x_new);
+                n_eval += 1;
+                if f_new < fx {
+                    x = x_new;
+                    fx = f_new;
+                    improved = true;
+                    break;
+                }
+            }
+        }
+        if !improved {
+            h /= 2f64;
+        }
+    }
+    (x, fx, n_eval)
+}
+
+fn check_bounds(bounds: &[(f64, f64)]) {
+    assert!(!bounds.is_empty(), "Bounds should not be empty");
+    assert!(
+        bounds.iter().all(|(a, b)| a < b),
+        "Lower bound should be less than upper bound"
+    );
+}
@@ -0,0 +1,213 @@
+//! Convolution, cross-correlation & smoothing
+//!
+//! # Description
+//! Follows the conventions of `numpy.convolve` & `numpy.correlate`.
+//! For `n = x.len()` and `m = kernel.len()` :
+//!
+//! * `Full` : Full discrete convolution (length `n + m - 1`)
+//! * `Same` : Length `max(n, m)`, centered with respect to `Full` output.
+//!   It starts at index `(min(n, m) - 1) / 2` of `Full` output,
+//!   so the out-of-range samples at both edges are treated as zeros.
+//!   (For even kernel length, the result is shifted to the left by half a sample)
+//! * `Valid` : Only where signals overlap completely (length `max(n, m) - min(n, m) + 1`)
+//!
+//! Direct `O(nm)` summation is used for short kernels,
+//! and the FFT path (`numerical::fft`) for long signals.
+//!
+//! # Examples
+//!
+//! ```
+//! #[macro_use]
+//! extern crate peroxide;
+//! use peroxide::fuga::*;
+//!
+//! fn main() {
+//!     let x = c!(1, 2, 3);
+//!     let k = c!(0, 1, 0.5);
+//!
+//!     assert_eq!(convolve(&x, &k, ConvMode::Full), c!(0, 1, 2.5, 4, 1.5));
+//!     assert_eq!(convolve(&x, &k, ConvMode::Same), c!(1, 2.5, 4));
+//!     assert_eq!(convolve(&x, &k, ConvMode::Valid), c!(2.5));
+//!     assert_eq!(cross_correlate(&x, &k, ConvMode::Same), c!(2, 3.5, 3));
+//! }
+//! ```
+
+use crate::ml::reg::poly_reg;
+use crate::numerical::fft::{fft_complex, ifft};
+
+/// Output mode of convolution
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConvMode {
+    Full,
+    Same,
+    Valid,
+}
+
+/// Kernel length above which the FFT path is used
+const FFT_THRESHOLD: usize = 64;
+
+/// Discrete linear convolution
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = c!(1, 2, 3);
+///     let k = c!(1, 1);
+///     assert_eq!(convolve(&x, &k, ConvMode::Full), c!(1, 3, 5, 3));
+///     assert_eq!(convolve(&x, &k, ConvMode::Same), c!(1, 3, 5));
+///     assert_eq!(convolve(&x, &k, ConvMode::Valid), c!(3, 5));
+/// }
+/// ```
+pub fn convolve(x: &[f64], kernel: &[f64], mode: ConvMode) -> Vec<f64> {
+    let (n, m) = (x.len(), kernel.len());
+    assert!(n > 0 && m > 0, "Signals should not be empty");
+
+    let full = if n.min(m) > FFT_THRESHOLD {
+        convolve_fft(x, kernel)
+    } else {
+        convolve_direct(x, kernel)
+    };
+
+    let (long, short) = (n.max(m), n.min(m));
+    match mode {
+        ConvMode::Full => full,
+        ConvMode::Same => {
+            let start = (short - 1) / 2;
+            full[start..start + long].to_vec()
+        }
+        ConvMode::Valid => full[short - 1..long].to_vec(),
+    }
+}
+
+/// Discrete cross-correlation
+///
+/// # Description
+/// `z[k] = sum_i x[i + k] * y[i]`, computed as `convolve(x, reverse(y), mode)`.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = c!(1, 2, 3);
+///     let y = c!(0, 1, 0.5);
+///     assert_eq!(cross_correlate(&x, &y, ConvMode::Full), c!(0.5, 2, 3.5, 3, 0));
+///     assert_eq!(cross_correlate(&x, &y, ConvMode::Valid), c!(3.5));
+/// }
+/// ```
+pub fn cross_correlate(x: &[f64], y: &[f64], mode: ConvMode) -> Vec<f64> {
+    let y_rev = y.iter().rev().cloned().collect::<Vec<f64>>();
+    convolve(x, &y_rev, mode)
+}
+
+/// Moving average
+///
+/// # Description
+/// `convolve(x, [1/w; w], ConvMode::Same)` - edges are averaged with zeros.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = c!(3, 6, 9, 12);
+///     assert_eq!(moving_average(&x, 3), c!(3, 6, 9, 7));
+/// }
+/// ```
+pub fn moving_average(x: &[f64], window: usize) -> Vec<f64> {
+    assert!(window > 0, "Window should be positive");
+    let k = vec![1f64 / window as f64; window];
+    convolve(x, &k, ConvMode::Same)
+}
+
+/// Savitzky-Golay filter
+///
+/// # Description
+/// Fit a polynomial of degree `polyorder` to each window of length `window` (odd) by least square
+/// (`ml::reg::poly_reg`), and take its value at the center.
+///
+/// * Interior : Convolution with the Savitzky-Golay coefficients
+/// * Edges : Evaluate the polynomial fitted to the first (last) window (same as `mode='interp'` of `scipy.signal.savgol_filter`)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     // Polynomial of degree <= polyorder is preserved
+///     let x = seq(0, 9, 1).fmap(|t| t * t);
+///     let y = savgol(&x, 5, 2);
+///     for (a, b) in x.iter().zip(y.iter()) {
+///         assert!((a - b).abs() < 1e-8);
+///     }
+/// }
+/// ```
+pub fn savgol(x: &[f64], window: usize, polyorder: usize) -> Vec<f64> {
+    let n = x.len();
+    assert!(window % 2 == 1, "Window should be odd");
+    assert!(polyorder < window, "Polyorder should be less than window");
+    assert!(n >= window, "Signal should be longer than window");
+    let half = window / 2;
+
+    // Coefficients : value at center of the fit to each unit impulse
+    let node = (0..window)
+        .map(|i| i as f64 - half as f64)
+        .collect::<Vec<f64>>();
+    let coef = (0..window)
+        .map(|k| {
+            let mut e = vec![0f64; window];
+            e[k] = 1f64;
+            poly_reg(node.clone(), e, polyorder).eval(0f64)
+        })
+        .collect::<Vec<f64>>();
+
+    let mut y = cross_correlate(x, &coef, ConvMode::Valid);
+
+    // Edges
+    let local = (0..window).map(|i| i as f64).collect::<Vec<f64>>();
+    let head = poly_reg(local.clone(), x[..window].to_vec(), polyorder);
+    let tail = poly_reg(local, x[n - window..].to_vec(), polyorder);
+    let mut result = (0..half).map(|i| head.eval(i as f64)).collect::<Vec<f64>>();
+    result.append(&mut y);
+    result.extend((half + 1..window).map(|i| tail.eval(i as f64)));
+    result
+}
+
+fn convolve_direct(x: &[f64], k: &[f64]) -> Vec<f64> {
+    let mut z = vec![0f64; x.len() + k.len() - 1];
+    for (i, xi) in x.iter().enumerate() {
+        for (j, kj) in k.iter().enumerate() {
+            z[i + j] += xi * kj;
+        }
+    }
+    z
+}
+
+fn convolve_fft(x: &[f64], k: &[f64]) -> Vec<f64> {
+    let l = x.len() + k.len() - 1;
+    let len = l.next_power_of_two();
+    let pad = |v: &[f64]| {
+        let mut z = v.iter().map(|&t| (t, 0f64)).collect::<Vec<_>>();
+        z.resize(len, (0f64, 0f64));
+        fft_complex(&z)
+    };
+    let (xf, kf) = (pad(x), pad(k));
+    let prod = xf
+        .iter()
+        .zip(kf.iter())
+        .map(|(a, b)| (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0))
+        .collect::<Vec<_>>();
+    let mut z = ifft(&prod).into_iter().map(|t| t.0).collect::<Vec<f64>>();
+    z.truncate(l);
+    z
+}
@@ -42,8 +42,15 @@
 //!   * `fn open(degree: usize, knots: Vec<f64>, control_points: Vec<Vec<f64>>) -> Result<Self>` : Create an open B-Spline
 //!   * `fn clamped(degree: usize, knots: Vec<f64>, control_points: Vec<Vec<f64>>) -> Result<Self>`
 //!     : Create a clamped B-Spline
+//!   * `fn periodic(degree: usize, knots: Vec<f64>, control_points: Vec<Vec<f64>>) -> Result<Self>`
+//!     : Create a periodic (closed) B-Spline
+//!   * `fn fit(x: &[f64], y: &[f64], n_knots: usize, degree: usize) -> Result<Self>`
+//!     : Least square fitting of `y(x)` with a clamped B-Spline
 //!   * `fn cox_de_boor(t: f64, i: f64)` : Cox-de Boor recursion formula (Here, use iteration
 //!   instead of recursion)
+//!   * `fn de_boor(t: f64) -> Vec<f64>` : Evaluate the curve by De Boor's algorithm (any dimension)
+//!   * `fn deriv(t: f64) -> Vec<f64>` & `fn derivative() -> BSpline` : Derivative of the curve
+//!   * `fn insert_knot(t: f64) -> Result<()>` : Knot insertion (Boehm's algorithm)
 //!
 //! ## Usage (Cubic Spline Family)
 //!
@@ -1059,6 +1066,7 @@ impl CubicBSplineBases {
 /// - Let K = the number of knots, C = the number of control points
 /// - For open, K = C + degree + 1 (C = K - degree - 1)
 /// - For clamped, K + 2 * degree = C + degree + 1 (C = K + degree - 1)
+/// - For periodic, K = C + 1 (first `degree` control points are wrapped)
 ///
 /// # Example
 /// ```
@@ -1137,6 +1145,269 @@ impl BSpline {
         Ok(Self { degree, knots, control_points })
     }
 
+    /// Create new periodic (closed) B-Spline
+    ///
+    /// # Arguments
+    /// - `degree` - Degree of B-Spline
+    /// - `knots` - Knots of one period (length = K, period = `knots[K-1] - knots[0]`)
+    /// - `control_points` - Control points (length = C)
+    ///
+    /// # Description
+    /// The first `degree` control points are appended at the end, and the knots are extended periodically,
+    /// so the curve is closed with `C^(degree-1)` continuity at `knots[0] = knots[K-1]`.
+    ///
+    /// # Caution
+    /// - K = C + 1, C > degree
+    ///
+    /// # Example
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let square = vec![vec![0f64, 0f64], vec![1f64, 0f64], vec![1f64, 1f64], vec![0f64, 1f64]];
+    ///     let spline = BSpline::periodic(2, seq(0, 4, 1), square)?;
+    ///     let start = spline.de_boor(0f64);
+    ///     let end = spline.de_boor(4f64);
+    ///     assert!(eq_vec(&start, &end, 1e-12));
+    ///     assert!(eq_vec(&spline.deriv(0f64), &spline.deriv(4f64), 1e-12));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn periodic(degree: usize, knots: Vec<f64>, control_points: Vec<Vec<f64>>) -> Result<Self> {
+        let m = control_points.len();
+        if knots.len() != m + 1 {
+            bail!("For periodic, the number of knots ({}) should be equal to the number of control points ({}) + 1", knots.len(), m);
+        }
+        if m <= degree {
+            bail!("For periodic, the number of control points ({}) should be larger than degree ({})", m, degree);
+        }
+        let period = knots[m] - knots[0];
+
+        let mut ext_knots = knots[m - degree..m].iter().map(|t| t - period).collect::<Vec<f64>>();
+        ext_knots.extend_from_slice(&knots);
+        ext_knots.extend(knots[1..=degree].iter().map(|t| t + period));
+
+        let mut control_points = control_points;
+        control_points.extend_from_within(..degree);
+
+        Ok(Self { degree, knots: ext_knots, control_points })
+    }
+
+    /// Least square fitting with clamped B-Spline
+    ///
+    /// # Arguments
+    /// - `x`, `y` - Data
+    /// - `n_knots` - Number of (uniform) knots in `[min(x), max(x)]` (C = n_knots + degree - 1)
+    /// - `degree` - Degree of B-Spline
+    ///
+    /// # Description
+    /// The control points are `(Greville abscissa, coefficient)`, so the curve is the graph of the
+    /// fitted function : `eval(x) = (x, f(x))`. Coefficients are obtained by `lstsq`.
+    ///
+    /// # Example
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let x = linspace(0, 1, 20);
+    ///     let y = x.fmap(|t| t * t);
+    ///     let spline = BSpline::fit(&x, &y, 3, 2)?;
+    ///     let (u, v) = spline.eval(0.3);
+    ///     assert!((u - 0.3).abs() < 1e-12);
+    ///     assert!((v - 0.09).abs() < 1e-12);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fit(x: &[f64], y: &[f64], n_knots: usize, degree: usize) -> Result<Self> {
+        if x.len() != y.len() {
+            bail!("The length of x ({}) should be equal to the length of y ({})", x.len(), y.len());
+        }
+        if n_knots < 2 {
+            bail!("The number of knots ({}) should be at least 2", n_knots);
+        }
+        let n = n_knots + degree - 1;
+        if x.len() < n {
+            bail!("The number of data ({}) should be at least the number of control points ({})", x.len(), n);
+        }
+        let x_min = x.iter().cloned().fold(f64::INFINITY, f64::min);
+        let x_max = x.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        // Basis only needs knots
+        let mut spline = Self::clamped(degree, linspace(x_min, x_max, n_knots), vec![vec![0f64, 0f64]; n])?;
+        let a = Matrix::from_fn(x.len(), n, |i, j| spline.cox_de_boor(x[i], j), Row);
+        let coef = a.lstsq(&y.to_vec(), false).x;
+
+        for (j, c) in coef.into_iter().enumerate() {
+            let greville = if degree == 0 {
+                (spline.knots[j] + spline.knots[j + 1]) / 2f64
+            } else {
+                spline.knots[j + 1..=j + degree].iter().sum::<f64>() / degree as f64
+            };
+            spline.control_points[j] = vec![greville, c];
+        }
+        Ok(spline)
+    }
+
+    /// Index of knot span `[knots[k], knots[k+1])` containing `t` (`degree <= k < C`)
+    ///
+    /// # Description
+    /// `t` outside of the domain `[knots[degree], knots[C]]` uses the first or last non-empty span,
+    /// so the end of the domain is included in the last span.
+    fn find_span(&self, t: f64) -> usize {
+        let p = self.degree;
+        let n = self.control_points.len();
+        let mut last = p;
+        for k in p..n {
+            if self.knots[k] < self.knots[k + 1] {
+                if t < self.knots[k + 1] {
+                    return k;
+                }
+                last = k;
+            }
+        }
+        last
+    }
+
+    /// Evaluate B-Spline curve by De Boor's algorithm
+    ///
+    /// # Description
+    /// Works for control points of any dimension.
+    ///
+    /// # Example
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let control_points = vec![vec![0f64, 0f64, 0f64], vec![1f64, 2f64, 0f64], vec![2f64, 0f64, 1f64]];
+    ///     let spline = BSpline::clamped(2, vec![0f64, 1f64], control_points)?;
+    ///     assert_eq!(spline.de_boor(0f64), vec![0f64, 0f64, 0f64]);
+    ///     assert_eq!(spline.de_boor(0.5), vec![1f64, 1f64, 0.25]);
+    ///     assert_eq!(spline.de_boor(1f64), vec![2f64, 0f64, 1f64]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn de_boor(&self, t: f64) -> Vec<f64> {
+        let p = self.degree;
+        let k = self.find_span(t);
+        let mut d = self.control_points[k - p..=k].to_vec();
+
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let left = self.knots[j + k - p];
+                let right = self.knots[j + 1 + k - r];
+                let alpha = if right == left { 0f64 } else { (t - left) / (right - left) };
+                let (prev, curr) = d.split_at_mut(j);
+                for (c, q) in curr[0].iter_mut().zip(prev[j - 1].iter()) {
+                    *c = (1f64 - alpha) * q + alpha * *c;
+                }
+            }
+        }
+        d.swap_remove(p)
+    }
+
+    /// Derivative of B-Spline curve (B-Spline of degree `degree - 1`)
+    pub fn derivative(&self) -> Self {
+        let p = self.degree;
+        let n = self.control_points.len();
+        let dim = self.control_points.first().map_or(0, |c| c.len());
+        if p == 0 {
+            return Self {
+                degree: 0,
+                knots: self.knots.clone(),
+                control_points: vec![vec![0f64; dim]; n],
+            };
+        }
+
+        let control_points = (0..n - 1)
+            .map(|i| {
+                let dt = self.knots[i + p + 1] - self.knots[i + 1];
+                self.control_points[i + 1]
+                    .iter()
+                    .zip(self.control_points[i].iter())
+                    .map(|(b, a)| if dt == 0f64 { 0f64 } else { p as f64 * (b - a) / dt })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            degree: p - 1,
+            knots: self.knots[1..self.knots.len() - 1].to_vec(),
+            control_points,
+        }
+    }
+
+    /// Derivative of B-Spline curve at `t`
+    ///
+    /// # Example
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     // y = x^2 on [0, 1]
+    ///     let control_points = vec![vec![0f64, 0f64], vec![0.5, 0f64], vec![1f64, 1f64]];
+    ///     let spline = BSpline::clamped(2, vec![0f64, 1f64], control_points)?;
+    ///     assert_eq!(spline.deriv(0.5), vec![1f64, 1f64]);
+    ///     assert_eq!(spline.deriv(1f64), vec![1f64, 2f64]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn deriv(&self, t: f64) -> Vec<f64> {
+        self.derivative().de_boor(t)
+    }
+
+    /// Insert knot `t` (Boehm's algorithm)
+    ///
+    /// # Description
+    /// The curve does not change, and one control point is added.
+    /// `t` should be in the domain `[knots[degree], knots[C]]`.
+    ///
+    /// # Example
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let control_points = vec![vec![0f64, 0f64], vec![1f64, 2f64], vec![2f64, -1f64], vec![3f64, 0f64]];
+    ///     let mut spline = BSpline::clamped(3, vec![0f64, 1f64], control_points)?;
+    ///     let before = spline.de_boor(0.3);
+    ///     spline.insert_knot(0.5)?;
+    ///     assert_eq!(spline.control_points.len(), 5);
+    ///     assert!(eq_vec(&spline.de_boor(0.3), &before, 1e-12));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn insert_knot(&mut self, t: f64) -> Result<()> {
+        let p = self.degree;
+        let n = self.control_points.len();
+        if t < self.knots[p] || t > self.knots[n] {
+            bail!("Knot ({}) should be in the domain [{}, {}]", t, self.knots[p], self.knots[n]);
+        }
+        let k = self.find_span(t);
+
+        let mut control_points = Vec::with_capacity(n + 1);
+        control_points.extend_from_slice(&self.control_points[..=k - p]);
+        for i in k - p + 1..=k {
+            let denom = self.knots[i + p] - self.knots[i];
+            let alpha = if denom == 0f64 { 0f64 } else { (t - self.knots[i]) / denom };
+            control_points.push(
+                self.control_points[i - 1]
+                    .iter()
+                    .zip(self.control_points[i].iter())
+                    .map(|(a, b)| (1f64 - alpha) * a + alpha * b)
+                    .collect(),
+            );
+        }
+        control_points.extend_from_slice(&self.control_points[k..]);
+
+        self.knots.insert(k + 1, t);
+        self.control_points = control_points;
+        Ok(())
+    }
+
     /// Obtain basis function via Cox-de Boor algorithm
     #[allow(non_snake_case)]
     pub fn cox_de_boor(&self, t: f64, i: usize) -> f64 {
@@ -1176,18 +1447,8 @@ impl BSpline {
 }
 
 impl Spline<(f64, f64)> for BSpline {
-    #[allow(non_snake_case)]
     fn eval(&self, t: f64) -> (f64, f64) {
-        let n = self.control_points.len();
-
-        let mut x = 0f64;
-        let mut y = 0f64;
-        for i in 0 .. n {
-            let B = self.cox_de_boor(t, i);
-            x += B * self.control_points[i][0];
-            y += B * self.control_points[i][1];
-        }
-
-        (x, y)
+        let p = self.de_boor(t);
+        (p[0], p[1])
     }
 }
@@ -54,6 +54,67 @@ pub fn jacobian<F: Fn(&Vec<AD>) -> Vec<AD>>(f: F, x: &Vec<f64>) -> Matrix {
     J
 }
 
+/// Numerical Jacobian Matrix
+///
+/// # Description
+/// : Jacobian matrix of `f: R^n -> R^m` (`m x n` matrix) using central finite differences.
+/// Use this when `f` can not be written with `AD` (otherwise, `jacobian` is exact).
+///
+/// * Step size : `h_j = eps^(1/3) * max(|x_j|, 1)`
+/// * Truncation error : `O(h^2)` (about `1e-10` relative error for smooth `f`)
+///
+/// # Type
+/// `(F, &Vec<f64>) -> Matrix where F: Fn(&Vec<f64>) -> Vec<f64>`
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = c!(1, 2);
+///     let j = numerical_jacobian(f, &x);
+///     let j_exact = ml_matrix("3 1;1 4");
+///     assert!(eq_vec(&j.data, &j_exact.data, 1e-8));
+/// }
+///
+/// fn f(xs: &Vec<f64>) -> Vec<f64> {
+///     let x = xs[0];
+///     let y = xs[1];
+///
+///     vec![
+///        x * y + x,
+///        x + y.powi(2),
+///    ]
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn numerical_jacobian<F: Fn(&Vec<f64>) -> Vec<f64>>(f: F, x: &Vec<f64>) -> Matrix {
+    let l = x.len();
+    let l2 = f(x).len();
+    let step = f64::EPSILON.cbrt();
+
+    let mut J = zeros(l2, l);
+    let mut x_h = x.clone();
+
+    for i in 0 .. l {
+        let h = step * x[i].abs().max(1f64);
+        x_h[i] = x[i] + h;
+        let f_plus = f(&x_h);
+        x_h[i] = x[i] - h;
+        let f_minus = f(&x_h);
+        x_h[i] = x[i];
+        let slopes: Vec<f64> = f_plus
+            .iter()
+            .zip(f_minus.iter())
+            .map(|(p, m)| (p - m) / (2f64 * h))
+            .collect();
+        J.subs_col(i, &slopes);
+    }
+    J
+}
+
 ///// Hessian Matrix
 //#[allow(non_snake_case)]
 //pub fn hessian<F: Fn(&Vec<AD>) -> AD>(f: F, x: &Vec<f64>) -> Matrix {
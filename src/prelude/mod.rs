@@ -151,30 +151,38 @@ pub mod simpler;
 
 pub use crate::traits::{
     fp::{FPMatrix, FPVector},
-    general::Algorithm,
-    math::{InnerProduct, LinearOp, MatrixProduct, Vector, VectorProduct},
+    general::{Algorithm, Side},
+    math::{InnerProduct, LinearOp, MatrixProduct, SquareOp, Vector, VectorProduct},
     mutable::{MutFP, MutMatrix},
     num::Real,
     pointer::{MatrixPtr, Oxide, Redox, RedoxCommon},
-    sugar::{Scalable, ScalableMut, VecOps, ConvToMat},
+    stable::StableFn,
+    sugar::{ElementwiseOps, Scalable, ScalableMut, VecOps, ConvToMat},
+};
+
+#[cfg(feature = "parallel")]
+pub use crate::traits::{
+    fp::{ParallelFPMatrix, ParallelFPVector},
+    math::ParallelLinearOp,
 };
 
 pub use peroxide_num::{ExpLogOps, TrigOps, PowOps};
 
 pub use simpler::SimpleNorm;
 
-#[allow(unused_imports)]
+#[allow(unused_imports, deprecated)]
 pub use crate::structure::{
     ad::*,
     ad::AD::*,
     matrix::{
-        combine, diag, gemm, gemv, gen_householder, inv_l, inv_u, matrix, ml_matrix, py_matrix,
-        r_matrix, Col, Matrix, Row, Shape, PQLU, QR, WAZD,
+        combine, diag, diag_mat, gemm, gemv, gen_householder, inv_l, inv_u, matrix, ml_matrix, py_matrix,
+        r_matrix, tridiag_solve, Col, Factorized, LabeledMatrix, LstsqResult, Matrix, Row, Shape, PQLU, QR, WAZD,
     },
-    polynomial::{Polynomial,poly,Calculus,lagrange_polynomial,legendre_polynomial},
+    polynomial::{Polynomial,poly,Calculus,lagrange_polynomial,legendre_polynomial,ChebyshevSeries,LegendreSeries},
+    sparse::SPMatrix,
     vector::*,
     dataframe::{
-        DataFrame, DType, DTypeArray, DTypeValue, Series, Scalar, TypedScalar, TypedVector
+        DataFrame, DataFrameAttrs, DType, DTypeArray, DTypeValue, Series, Scalar, TypedScalar, TypedVector
     },
     //complex::C64,
 };
@@ -184,29 +192,62 @@ pub use crate::structure::dataframe::WithCSV;
 #[cfg(feature="nc")]
 pub use crate::structure::dataframe::WithNetCDF;
 
+#[cfg(feature="json")]
+pub use crate::structure::dataframe::WithJSON;
+
+#[cfg(feature="hdf5")]
+pub use crate::util::hdf5::WithHDF5;
+
+#[cfg(feature="parallel")]
+pub use crate::structure::matrix::par_matmul;
+
 pub use simpler::{solve, SimplerLinearAlgebra};
 
 #[allow(unused_imports)]
-pub use crate::util::{api::*, low_level::*, non_macro::*, print::*, useful::*, wrapper::*};
+pub use crate::util::{api::*, low_level::*, non_macro::*, npy::*, print::*, useful::*, wrapper::*};
 
 #[allow(unused_imports)]
-pub use crate::statistics::{dist::*, ops::*, rand::*, stat::*};
+pub use crate::statistics::{dist::*, mcmc::*, ops::*, rand::*, resample::*, stat::*, test::*};
 
 #[allow(unused_imports)]
 pub use crate::special::function::*;
 
 #[allow(unused_imports)]
 pub use crate::numerical::{
-    eigen::Eigen,
+    deriv::{
+        derivative, derivative_n, derivative_n_with_err, derivative_with_err, second_derivative,
+    },
+    eigen::{partial_eigen, Eigen, Which},
+    fft::*,
+    integral::{
+        cumtrapz, gauss_hermite_nodes, gauss_laguerre_nodes, gauss_legendre_nodes, monte_carlo_integrate,
+        simpson, trapz, SampledIntegralError,
+    },
     interp::*,
+    iterative::{
+        bicgstab, cg, gauss_seidel, gmres, jacobi_solve, sor, FnOp, IterativeError, IterativeResult,
+    },
     ode::*,
     optimize::*,
     root::*,
-    spline::{cubic_spline, CubicSpline, CubicHermiteSpline, Spline},
+    signal::*,
+    spline::{
+        cubic_spline, BSpline, CubicBSplineBases, CubicHermiteSpline, CubicSpline, PolynomialSpline,
+        Spline, SplineError, UnitCubicBasis,
+    },
     utils::*,
 };
 
-pub use simpler::{eigen, integrate, chebyshev_polynomial, cubic_hermite_spline};
+#[cfg(feature = "complex")]
+pub use crate::numerical::{
+    deriv::{complex_step_derivative, complex_step_derivative_with_step},
+    eigen::complex_eigenvalues,
+};
+
+#[allow(unused_imports)]
+pub use crate::ml::reg::*;
+
+pub use simpler::{eigen, integrate, integrate2d, integrate3d, chebyshev_polynomial, cubic_hermite_spline};
 
 #[allow(unused_imports)]
 pub use crate::statistics::stat::Metric::*;
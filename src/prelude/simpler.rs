@@ -27,6 +27,21 @@ pub fn integrate<F: Fn(f64) -> f64 + Copy>(f: F, (a, b): (f64, f64)) -> f64 {
     integral::integrate(f, (a, b), G7K15R(1e-4, 20))
 }
 
+/// Simple double integrate
+pub fn integrate2d<F: Fn(f64, f64) -> f64>(f: F, x: (f64, f64), y: (f64, f64)) -> f64 {
+    integral::integrate2d(f, x, y, G7K15R(1e-4, 20))
+}
+
+/// Simple triple integrate
+pub fn integrate3d<F: Fn(f64, f64, f64) -> f64>(
+    f: F,
+    x: (f64, f64),
+    y: (f64, f64),
+    z: (f64, f64),
+) -> f64 {
+    integral::integrate3d(f, x, y, z, G7K15R(1e-4, 20))
+}
+
 /// Simple Linear algebra
 pub trait SimplerLinearAlgebra {
     fn back_subs(&self, b: &Vec<f64>) -> Vec<f64>;
@@ -39,11 +54,13 @@ pub trait SimplerLinearAlgebra {
     fn cholesky(&self) -> Matrix;
     fn rref(&self) -> Matrix;
     fn det(&self) -> f64;
+    fn slogdet(&self) -> (f64, f64);
     fn block(&self) -> (Matrix, Matrix, Matrix, Matrix);
     fn inv(&self) -> Matrix;
     fn pseudo_inv(&self) -> Matrix;
     fn solve(&self, b: &Vec<f64>) -> Vec<f64>;
     fn solve_mat(&self, m: &Matrix) -> Matrix;
+    fn lstsq(&self, b: &Vec<f64>) -> matrix::LstsqResult;
     fn is_symmetric(&self) -> bool;
 }
 
@@ -69,8 +86,9 @@ impl SimpleNorm for Matrix {
         Normed::norm(self, Norm::F)
     }
 
+    /// Divide by Frobenius norm (zero matrix is returned unchanged)
     fn normalize(&self) -> Self {
-        unimplemented!()
+        Normed::normalize(self, Norm::F)
     }
 }
 
@@ -112,6 +130,10 @@ impl SimplerLinearAlgebra for Matrix {
         matrix::LinearAlgebra::det(self)
     }
 
+    fn slogdet(&self) -> (f64, f64) {
+        matrix::LinearAlgebra::slogdet(self)
+    }
+
     fn block(&self) -> (Matrix, Matrix, Matrix, Matrix) {
         matrix::LinearAlgebra::block(self)
     }
@@ -132,6 +154,10 @@ impl SimplerLinearAlgebra for Matrix {
         matrix::LinearAlgebra::solve_mat(self, m, matrix::SolveKind::LU)
     }
 
+    fn lstsq(&self, b: &Vec<f64>) -> matrix::LstsqResult {
+        matrix::LeastSquares::lstsq(self, b, false)
+    }
+
     fn is_symmetric(&self) -> bool {
         matrix::LinearAlgebra::is_symmetric(self)
     }
@@ -6,18 +6,24 @@
 //!     * Bernoulli
 //!     * Binomial
 //!     * Beta
+//!     * Categorical
+//!     * Chi-squared
 //!     * Dirichlet
+//!     * F
 //!     * Gamma
 //!     * Normal
 //!     * Student's t
 //!     * Uniform
 //!     * Weighted Uniform
 //! * There are two enums to represent probability distribution
-//!     * `OPDist<T>` : One parameter distribution (Bernoulli)
-//!     * `TPDist<T>` : Two parameter distribution (Uniform, Normal, Beta, Gamma)
+//!     * `OPDist<T>` : One parameter distribution (Bernoulli, Student's t, Chi-squared)
+//!     * `TPDist<T>` : Two parameter distribution (Uniform, Normal, Beta, Gamma, F)
 //!         * `T: PartialOrd + SampleUniform + Copy + Into<f64>`
+//!     * `Categorical` : Discrete distribution over `0, 1, ..., K-1` (`Categorical::new(probs)`)
+//!     * `Dirichlet` : Distribution over probability simplex (`Dirichlet::new(alpha)`, samples are rows of `Matrix`)
 //! * There are some traits for pdf
 //!     * `RNG` trait - extract sample & calculate pdf
+//!     * `DiscreteRNG` trait - extract integer samples (only for discrete distributions)
 //!     * `Statistics` trait - already shown above
 //!
 //! ### `RNG` trait
@@ -26,6 +32,7 @@
 //!     * `sample`: Extract samples
 //!     * `sample_with_rng`: Extract samples with specific rng
 //!     * `pdf` : Calculate pdf value at specific point
+//!     * `log_pdf`, `log_likelihood` : Logarithm of pdf & sum of it over data (no underflow)
 //!     ```no_run
 //!     use rand::{Rng, distributions::uniform::SampleUniform};
 //!     pub trait RNG {
@@ -129,7 +136,7 @@
 //! * Representative value
 //!     * Mean: $\frac{\alpha}{\alpha+\beta}$
 //!     * Var: $\frac{\alpha\beta}{(\alpha+\beta)^2(\alpha+\beta+1)}$
-//! * To generate beta random samples, Peroxide uses the `rand_distr::Beta` distribution from the `rand_distr` crate.
+//! * To generate beta random samples, Peroxide uses $X / (X + Y)$ for $X \sim \text{Gamma}(\alpha, 1)$, $Y \sim \text{Gamma}(\beta, 1)$.
 //!
 //!     ```rust
 //!     use peroxide::fuga::*;
@@ -152,13 +159,13 @@
 //! * Representative value
 //!     * Mean: $\frac{\alpha}{\beta}$
 //!     * Var: $\frac{\alpha}{\beta^2}$
-//! * To generate gamma random samples, Peroxide uses the `rand_distr::Gamma` distribution from the `rand_distr` crate.
+//! * To generate gamma random samples, Peroxide uses Marsaglia & Tsang's method ($\alpha < 1$ is boosted by $\text{Gamma}(\alpha + 1) U^{1/\alpha}$).
 //!
 //!     ```rust
 //!     use peroxide::fuga::*;
 //!
 //!     fn main() {
-//!         // Gamma(shape, scale)
+//!         // Gamma(shape, rate)
 //!         let a = Gamma(2.0, 1.0);
 //!         a.sample(100).print();
 //!         a.pdf(1.5).print();
@@ -198,7 +205,7 @@
 //! * Representative value
 //!     * Mean: 0 (for $\nu > 1$)
 //!     * Var: $\frac{\nu}{\nu-2}$ (for $\nu > 2$)
-//! * To generate Student's t random samples, Peroxide uses the `rand_distr::StudentT` distribution from the `rand_distr` crate.
+//! * To generate Student's t random samples, Peroxide uses $Z / \sqrt{V / \nu}$ for $Z \sim N(0, 1)$, $V \sim \chi^2(\nu) = 2\,\text{Gamma}(\nu/2, 1)$ (Gamma samples as above, in log scale).
 //!
 //!     ```rust
 //!     use peroxide::fuga::*;
@@ -213,6 +220,52 @@
 //!     }
 //!     ```
 //!
+//! ### Chi-squared Distribution
+//!
+//! * Definition
+//!   $$\chi^2(x | k) = \frac{1}{2^{k/2}\Gamma(k/2)} x^{k/2-1} e^{-x/2} \quad (x > 0)$$
+//!   where $k$ is the degrees of freedom.
+//! * Representative value
+//!     * Mean: $k$
+//!     * Var: $2k$
+//! * CDF is the regularized lower incomplete gamma function $P(k/2, x/2)$.
+//! * To generate chi-squared random samples, Peroxide uses $2\,\text{Gamma}(k/2, 1)$.
+//!
+//!     ```rust
+//!     use peroxide::fuga::*;
+//!
+//!     fn main() {
+//!         // ChiSquared(k)
+//!         let a = ChiSquared(3.0);
+//!         a.sample(100).print();
+//!         assert!((a.cdf(7.815) - 0.95).abs() < 1e-4); // 95% critical value
+//!         assert_eq!(a.mean(), 3.0);
+//!         assert_eq!(a.var(), 6.0);
+//!     }
+//!     ```
+//!
+//! ### F Distribution
+//!
+//! * Definition
+//!   $$F(x | d_1, d_2) = \frac{1}{x B(d_1/2, d_2/2)} \sqrt{\frac{(d_1 x)^{d_1} d_2^{d_2}}{(d_1 x + d_2)^{d_1 + d_2}}} \quad (x > 0)$$
+//!   where $d_1, d_2$ are the degrees of freedom and $B$ is the Beta function.
+//! * Representative value
+//!     * Mean: $\frac{d_2}{d_2 - 2}$ (for $d_2 > 2$)
+//!     * Var: $\frac{2 d_2^2 (d_1 + d_2 - 2)}{d_1 (d_2 - 2)^2 (d_2 - 4)}$ (for $d_2 > 4$)
+//! * CDF is the regularized incomplete beta function $I_{d_1 x / (d_1 x + d_2)}(d_1/2, d_2/2)$.
+//!
+//!     ```rust
+//!     use peroxide::fuga::*;
+//!
+//!     fn main() {
+//!         // FDist(d1, d2)
+//!         let a = FDist(5.0, 10.0);
+//!         a.sample(100).print();
+//!         assert!((a.cdf(3.326) - 0.95).abs() < 1e-4); // 95% critical value
+//!         assert_eq!(a.mean(), 1.25);
+//!     }
+//!     ```
+//!
 //! ### Weighted Uniform Distribution
 //!
 //! * Definition
@@ -238,9 +291,11 @@ use crate::traits::fp::FPVector;
 use crate::special::function::*;
 //use statistics::rand::ziggurat;
 use crate::statistics::{ops::C, stat::Statistics};
-use crate::util::non_macro::{linspace, seq};
+use crate::structure::matrix::{matrix, Matrix, Shape::Row};
+use crate::structure::vector::ksum_iter;
+use crate::util::non_macro::{linspace, seq, zeros};
 use crate::util::useful::{auto_zip, find_interval};
-use std::f64::consts::E;
+use std::f64::consts::{E, LN_2, PI};
 use self::WeightedUniformError::*;
 use anyhow::{Result, bail};
 
@@ -248,10 +303,13 @@ use anyhow::{Result, bail};
 ///
 /// # Distributions
 /// * `Bernoulli(prob)`: Bernoulli distribution
+/// * `StudentT(nu)`: Student's t distribution
+/// * `ChiSquared(k)`: Chi-squared distribution
 #[derive(Debug, Clone)]
 pub enum OPDist<T: PartialOrd + SampleUniform + Copy + Into<f64>> {
     Bernoulli(T),
     StudentT(T),
+    ChiSquared(T),
 }
 
 /// Two parameter distribution
@@ -259,6 +317,9 @@ pub enum OPDist<T: PartialOrd + SampleUniform + Copy + Into<f64>> {
 /// # Distributions
 /// * `Uniform(start, end)`: Uniform distribution
 /// * `Normal(mean, std)`: Normal distribution
+/// * `Beta(alpha, beta)`: Beta distribution
+/// * `Gamma(shape, rate)`: Gamma distribution
+/// * `FDist(d1, d2)`: F distribution
 #[derive(Debug, Clone)]
 pub enum TPDist<T: PartialOrd + SampleUniform + Copy + Into<f64>> {
     Uniform(T, T),
@@ -266,6 +327,7 @@ pub enum TPDist<T: PartialOrd + SampleUniform + Copy + Into<f64>> {
     Normal(T, T),
     Beta(T, T),
     Gamma(T, T),
+    FDist(T, T),
 }
 
 pub struct WeightedUniform<T: PartialOrd + SampleUniform + Copy + Into<f64>> {
@@ -457,6 +519,242 @@ impl WeightedUniform<f64> {
     }
 }
 
+/// Categorical distribution
+///
+/// # Description
+/// Discrete distribution over categories `0, 1, ..., K-1` with probabilities `probs`.
+/// Samples are category indices (as `f64`).
+#[derive(Debug, Clone)]
+pub struct Categorical {
+    probs: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum CategoricalError {
+    EmptyProbError,
+    NegativeProbError,
+    NotNormalizedError,
+    AllZeroWeightError,
+}
+
+impl std::fmt::Display for CategoricalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CategoricalError::EmptyProbError => write!(f, "probabilities are empty"),
+            CategoricalError::NegativeProbError => write!(f, "probabilities should be non-negative"),
+            CategoricalError::NotNormalizedError => write!(f, "sum of probabilities should be 1"),
+            CategoricalError::AllZeroWeightError => write!(f, "all weights are zero"),
+        }
+    }
+}
+
+impl Categorical {
+    /// Create a new categorical distribution
+    ///
+    /// # Description
+    /// `probs` should be non-negative and sum to 1 (within `1e-8`).
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let c = Categorical::new(vec![0.2, 0.5, 0.3])?;
+    ///     assert_eq!(c.pmf(1), 0.5);
+    ///     assert_eq!(c.pmf(3), 0f64);
+    ///     assert!(c.sample(10).iter().all(|&k| k == 0f64 || k == 1f64 || k == 2f64));
+    ///
+    ///     assert!(Categorical::new(vec![0.5, 0.6]).is_err());
+    ///     assert!(Categorical::new(vec![1.5, -0.5]).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(probs: Vec<f64>) -> Result<Self> {
+        if probs.is_empty() {
+            bail!(CategoricalError::EmptyProbError);
+        }
+        if probs.iter().any(|&p| p < 0f64 || p.is_nan()) {
+            bail!(CategoricalError::NegativeProbError);
+        }
+        if (probs.iter().sum::<f64>() - 1f64).abs() > 1e-8 {
+            bail!(CategoricalError::NotNormalizedError);
+        }
+        Ok(Categorical { probs })
+    }
+
+    /// Create a categorical distribution from (unnormalized) non-negative weights
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let c = Categorical::from_weights(vec![1f64, 3f64])?;
+    ///     assert_eq!(c.probs(), &vec![0.25, 0.75]);
+    ///     assert!(c.sample_int(5)?.iter().all(|&k| k == 0 || k == 1));
+    ///
+    ///     assert!(Categorical::from_weights(vec![0f64, 0f64]).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_weights(weights: Vec<f64>) -> Result<Self> {
+        if weights.iter().any(|&w| w < 0f64 || w.is_nan()) {
+            bail!(CategoricalError::NegativeProbError);
+        }
+        if weights.is_empty() {
+            bail!(CategoricalError::EmptyProbError);
+        }
+        let sum = weights.iter().sum::<f64>();
+        if sum == 0f64 {
+            bail!(CategoricalError::AllZeroWeightError);
+        }
+        Self::new(weights.iter().map(|w| w / sum).collect())
+    }
+
+    pub fn probs(&self) -> &Vec<f64> {
+        &self.probs
+    }
+
+    /// Number of categories
+    pub fn len(&self) -> usize {
+        self.probs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.probs.is_empty()
+    }
+
+    /// Probability mass function
+    pub fn pmf(&self, k: usize) -> f64 {
+        self.probs.get(k).copied().unwrap_or(0f64)
+    }
+}
+
+/// Dirichlet distribution
+///
+/// # Description
+/// Distribution over the probability simplex `{x | x_i >= 0, sum(x) = 1}` with concentration `alpha`.
+/// A sample is `(g_1, ..., g_K) / sum(g)` for `g_i ~ Gamma(alpha_i, 1)`.
+#[derive(Debug, Clone)]
+pub struct Dirichlet {
+    alpha: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DirichletError {
+    EmptyAlphaError,
+    NonPositiveAlphaError,
+}
+
+impl std::fmt::Display for DirichletError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DirichletError::EmptyAlphaError => write!(f, "alpha is empty"),
+            DirichletError::NonPositiveAlphaError => write!(f, "alpha should be positive"),
+        }
+    }
+}
+
+impl Dirichlet {
+    /// Create a new Dirichlet distribution
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let d = Dirichlet::new(vec![1f64, 2f64, 5f64])?;
+    ///     assert_eq!(d.mean(), vec![0.125, 0.25, 0.625]);
+    ///
+    ///     let x = d.sample(10);
+    ///     assert_eq!((x.row, x.col), (10, 3));
+    ///     for row in x.row_iter() {
+    ///         assert!((row.iter().sum::<f64>() - 1f64).abs() < 1e-12);
+    ///     }
+    ///
+    ///     assert!(Dirichlet::new(vec![1f64, 0f64]).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(alpha: Vec<f64>) -> Result<Self> {
+        if alpha.is_empty() {
+            bail!(DirichletError::EmptyAlphaError);
+        }
+        if alpha.iter().any(|&a| a <= 0f64 || a.is_nan()) {
+            bail!(DirichletError::NonPositiveAlphaError);
+        }
+        Ok(Dirichlet { alpha })
+    }
+
+    pub fn alpha(&self) -> &Vec<f64> {
+        &self.alpha
+    }
+
+    /// Dimension of simplex
+    pub fn len(&self) -> usize {
+        self.alpha.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.alpha.is_empty()
+    }
+
+    /// Extract samples (each row is a point of simplex)
+    pub fn sample(&self, n: usize) -> Matrix {
+        let mut rng = thread_rng();
+        self.sample_with_rng(&mut rng, n)
+    }
+
+    /// Extract samples with rng (each row is a point of simplex)
+    pub fn sample_with_rng<R: Rng>(&self, rng: &mut R, n: usize) -> Matrix {
+        let k = self.alpha.len();
+        let mut data = Vec::with_capacity(n * k);
+        for _ in 0..n {
+            // Normalize in log scale (small alpha)
+            let ln_g = self
+                .alpha
+                .iter()
+                .map(|&a| ln_gamma_sample(rng, a))
+                .collect::<Vec<f64>>();
+            let ln_max = ln_g.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let g = ln_g.iter().map(|t| (t - ln_max).exp()).collect::<Vec<f64>>();
+            let sum = g.iter().sum::<f64>();
+            data.extend(g.iter().map(|t| t / sum));
+        }
+        matrix(data, n, k, Row)
+    }
+
+    /// Probability density function
+    ///
+    /// # Description
+    /// `0` if `x` is not in the simplex (tolerance `1e-8` for the sum)
+    pub fn pdf(&self, x: &[f64]) -> f64 {
+        self.log_pdf(x).exp()
+    }
+
+    /// Logarithm of probability density function
+    ///
+    /// # Description
+    /// `-∞` if `x` is not in the simplex (tolerance `1e-8` for the sum)
+    pub fn log_pdf(&self, x: &[f64]) -> f64 {
+        assert_eq!(x.len(), self.alpha.len(), "Dimension mismatch");
+        if x.iter().any(|&t| t < 0f64) || (x.iter().sum::<f64>() - 1f64).abs() > 1e-8 {
+            return f64::NEG_INFINITY;
+        }
+        let a0 = self.alpha.iter().sum::<f64>();
+        ln_gamma(a0)
+            + ksum_iter(
+                self.alpha
+                    .iter()
+                    .zip(x.iter())
+                    .map(|(&a, &t)| xlogy(a - 1f64, t) - ln_gamma(a)),
+            )
+    }
+}
+
 /// Extract parameter
 pub trait ParametricDist {
     type Parameter;
@@ -470,6 +768,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> ParametricDist for OPDist
         match self {
             Bernoulli(mu) => (*mu).into(),
             StudentT(nu) => (*nu).into(),
+            ChiSquared(k) => (*k).into(),
         }
     }
 }
@@ -484,6 +783,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> ParametricDist for TPDist
             Normal(mu, sigma) => ((*mu).into(), (*sigma).into()),
             Beta(a, b) => ((*a).into(), (*b).into()),
             Gamma(a, b) => ((*a).into(), (*b).into()),
+            FDist(d1, d2) => ((*d1).into(), (*d2).into()),
         }
     }
 }
@@ -498,6 +798,22 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> ParametricDist for Weight
     }
 }
 
+impl ParametricDist for Categorical {
+    type Parameter = Vec<f64>;
+
+    fn params(&self) -> Self::Parameter {
+        self.probs.clone()
+    }
+}
+
+impl ParametricDist for Dirichlet {
+    type Parameter = Vec<f64>;
+
+    fn params(&self) -> Self::Parameter {
+        self.alpha.clone()
+    }
+}
+
 /// Random Number Generator trait
 ///
 /// # Methods
@@ -523,33 +839,119 @@ pub trait RNG {
     /// # Type
     /// `f64` -> `f64`
     fn cdf<S: PartialOrd + SampleUniform + Copy + Into<f64>>(&self, x: S) -> f64;
+
+    /// Logarithm of Probability Distribution Function
+    ///
+    /// # Description
+    /// Computed directly in log scale (no underflow of `pdf`), `-∞` outside of the support.
+    /// The default implementation is `pdf(x).ln()`.
+    fn log_pdf<S: PartialOrd + SampleUniform + Copy + Into<f64>>(&self, x: S) -> f64 {
+        self.pdf(x).ln()
+    }
+
+    /// Log likelihood of data
+    ///
+    /// # Description
+    /// `Σ log_pdf(x_i)` with compensated summation
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// // Product of 1000 pdfs underflows, but log likelihood does not
+    /// let x = vec![10f64; 1000];
+    /// let n = Normal(0f64, 1f64);
+    /// assert_eq!(x.iter().map(|&t| n.pdf(t)).product::<f64>(), 0f64);
+    /// let l = n.log_likelihood(&x);
+    /// assert!((l + 1000f64 * (50f64 + 0.5 * (2f64 * std::f64::consts::PI).ln())).abs() < 1e-9);
+    /// ```
+    fn log_likelihood(&self, data: &[f64]) -> f64 {
+        ksum_iter(data.iter().map(|&x| self.log_pdf(x)))
+    }
+}
+
+/// Integer sampling for discrete distributions
+///
+/// # Description
+/// Only distributions with an integer support implement this trait,
+/// so e.g. `Dirichlet::sample_int` does not compile.
+/// `OPDist` & `TPDist` are enums, so their variant is only known at runtime :
+/// continuous variants (`Normal`, `Beta`, ...) return `DiscreteRNGError::NotDiscreteError`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let k = Binomial(10, 0.5).sample_int(100)?;
+///     assert!(k.iter().all(|&t| (0..=10).contains(&t)));
+///     assert!(Normal(0f64, 1f64).sample_int(100).is_err());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use peroxide::fuga::*;
+///
+/// let d = Dirichlet::new(vec![1f64, 1f64]).unwrap();
+/// d.sample_int(1);
+/// ```
+pub trait DiscreteRNG: RNG {
+    /// Extract integer samples
+    fn sample_int(&self, n: usize) -> Result<Vec<i64>> {
+        let mut rng = thread_rng();
+        self.sample_int_with_rng(&mut rng, n)
+    }
+
+    /// Extract integer samples with rng
+    fn sample_int_with_rng<R: Rng + Clone>(&self, rng: &mut R, n: usize) -> Result<Vec<i64>>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DiscreteRNGError {
+    NotDiscreteError,
+}
+
+impl std::fmt::Display for DiscreteRNGError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscreteRNGError::NotDiscreteError => {
+                write!(f, "integer sampling is only for discrete distributions")
+            }
+        }
+    }
 }
 
 /// RNG for OPDist
 impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for OPDist<T> {
     fn sample_with_rng<R: Rng + Clone>(&self, rng: &mut R, n: usize) -> Vec<f64> {
         match self {
-            Bernoulli(prob) => {
-                assert!(
-                    (*prob).into() <= 1f64,
-                    "Probability should be smaller than 1"
-                );
-
-                let mut v = vec![0f64; n];
-
-                for i in 0..n {
-                    let uniform = rng.gen_range(0f64..=1f64);
-                    if uniform <= (*prob).into() {
-                        v[i] = 1f64;
-                    } else {
-                        v[i] = 0f64;
-                    }
-                }
-                v
-            }
+            Bernoulli(_) => self
+                .sample_int_with_rng(rng, n)
+                .unwrap()
+                .into_iter()
+                .map(|k| k as f64)
+                .collect(),
             StudentT(nu) => {
-                let stud = rand_distr::StudentT::<f64>::new((*nu).into()).unwrap();
-                stud.sample_iter(rng).take(n).collect()
+                let nu: f64 = (*nu).into();
+                assert!(nu > 0f64, "Degrees of freedom should be positive");
+
+                // Z / sqrt(V / nu) for Z ~ N(0, 1), V ~ ChiSquared(nu) = 2 Gamma(nu / 2, 1) (in log scale)
+                (0..n)
+                    .map(|_| {
+                        let z: f64 = rng.sample(rand_distr::StandardNormal);
+                        let ln_v = LN_2 + ln_gamma_sample(rng, nu / 2f64);
+                        z * (0.5 * (nu.ln() - ln_v)).exp()
+                    })
+                    .collect()
+            }
+            ChiSquared(k) => {
+                let k: f64 = (*k).into();
+                assert!(k > 0f64, "Degrees of freedom should be positive");
+                (0..n)
+                    .map(|_| 2f64 * ln_gamma_sample(rng, k / 2f64).exp())
+                    .collect()
             }
         }
     }
@@ -557,10 +959,13 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for OPDist<T> {
     fn pdf<S: PartialOrd + SampleUniform + Copy + Into<f64>>(&self, x: S) -> f64 {
         match self {
             Bernoulli(prob) => {
-                if x.into() == 1f64 {
+                let k: f64 = x.into();
+                if k == 1f64 {
                     (*prob).into()
-                } else {
+                } else if k == 0f64 {
                     1f64 - (*prob).into()
+                } else {
+                    0f64
                 }
             }
             StudentT(nu) => {
@@ -569,6 +974,15 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for OPDist<T> {
                 1f64 / (dof.sqrt() * beta(0.5f64, dof / 2f64))
                     * (1f64 + t.powi(2) / dof).powf(-(dof + 1f64) / 2f64)
             }
+            ChiSquared(k) => {
+                let half_k = (*k).into() / 2f64;
+                let x: f64 = x.into();
+                if x <= 0f64 {
+                    0f64
+                } else {
+                    ((half_k - 1f64) * x.ln() - x / 2f64 - half_k * 2f64.ln() - ln_gamma(half_k)).exp()
+                }
+            }
         }
     }
 
@@ -590,16 +1004,72 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for OPDist<T> {
                 let _odd_nu = (nu + 1f64) / 2f64;
                 let even_nu = nu / 2f64;
 
+                // Lower tail P(T < -|x|) = I_{nu / (nu + x^2)}(nu / 2, 1 / 2) / 2
+                let x2 = x.powi(2);
+                let tail = 0.5 * inc_beta_xy(even_nu, 0.5, nu / (x2 + nu), x2 / (x2 + nu));
                 if x > 0f64 {
-                    let x_t = nu / (x.powi(2) + nu);
-                    1f64 - 0.5 * inc_beta(even_nu, 0.5, x_t)
-                } else if x < 0f64 {
-                    self.cdf(-x) - 0.5
+                    1f64 - tail
                 } else {
-                    0.5
+                    tail
                 }
                 // 0.5f64 + x * gamma(odd_nu) * hyp2f1(0.5, odd_nu, 1.5, -x.powi(2) / (*nu).into()) / (PI * (*nu).into()).sqrt() * gamma(even_nu)
             }
+            ChiSquared(k) => {
+                let x: f64 = x.into();
+                if x <= 0f64 {
+                    0f64
+                } else {
+                    inc_gamma((*k).into() / 2f64, x / 2f64)
+                }
+            }
+        }
+    }
+
+    fn log_pdf<S: PartialOrd + SampleUniform + Copy + Into<f64>>(&self, x: S) -> f64 {
+        let x: f64 = x.into();
+        match self {
+            Bernoulli(prob) => {
+                let p: f64 = (*prob).into();
+                if x == 1f64 {
+                    p.ln()
+                } else if x == 0f64 {
+                    (-p).ln_1p()
+                } else {
+                    f64::NEG_INFINITY
+                }
+            }
+            StudentT(nu) => {
+                let nu: f64 = (*nu).into();
+                ln_gamma((nu + 1f64) / 2f64)
+                    - ln_gamma(nu / 2f64)
+                    - 0.5 * (nu * PI).ln()
+                    - (nu + 1f64) / 2f64 * (x * x / nu).ln_1p()
+            }
+            ChiSquared(k) => {
+                let half_k = (*k).into() / 2f64;
+                if x <= 0f64 {
+                    f64::NEG_INFINITY
+                } else {
+                    xlogy(half_k - 1f64, x) - x / 2f64 - half_k * LN_2 - ln_gamma(half_k)
+                }
+            }
+        }
+    }
+}
+
+/// Integer samples (only for `Bernoulli`)
+impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> DiscreteRNG for OPDist<T> {
+    fn sample_int_with_rng<R: Rng + Clone>(&self, rng: &mut R, n: usize) -> Result<Vec<i64>> {
+        match self {
+            Bernoulli(prob) => {
+                let p = (*prob).into();
+                assert!(
+                    (0f64..=1f64).contains(&p),
+                    "Probability should be in [0, 1]"
+                );
+                Ok((0..n).map(|_| (rng.gen::<f64>() < p) as i64).collect())
+            }
+            _ => bail!(DiscreteRNGError::NotDiscreteError),
         }
     }
 }
@@ -617,14 +1087,12 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for TPDist<T> {
                 v
             }
 
-            Binomial(num, mu) => {
-                let binom = rand_distr::Binomial::new(*num as u64, (*mu).into()).unwrap();
-                binom
-                    .sample_iter(rng)
-                    .take(n)
-                    .map(|t| t as f64)
-                    .collect()
-            }
+            Binomial(_, _) => self
+                .sample_int_with_rng(rng, n)
+                .unwrap()
+                .into_iter()
+                .map(|k| k as f64)
+                .collect(),
 
             Normal(m, s) => {
                 let normal = rand_distr::Normal::<f64>::new((*m).into(), (*s).into()).unwrap();
@@ -640,64 +1108,34 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for TPDist<T> {
             //                v
             //            }
             Beta(a, b) => {
-                let beta = rand_distr::Beta::<f64>::new((*a).into(), (*b).into()).unwrap();
-                beta.sample_iter(rng).take(n).collect()
+                let a_f64 = (*a).into();
+                let b_f64 = (*b).into();
+                assert!(a_f64 > 0f64 && b_f64 > 0f64, "Parameters should be positive");
+
+                // X / (X + Y) for X ~ Gamma(a, 1), Y ~ Gamma(b, 1) (in log scale)
+                (0..n)
+                    .map(|_| {
+                        let ln_x = ln_gamma_sample(rng, a_f64);
+                        let ln_y = ln_gamma_sample(rng, b_f64);
+                        1f64 / (1f64 + (ln_y - ln_x).exp())
+                    })
+                    .collect()
+            }
+            Gamma(shape, rate) => {
+                let shape_f64 = (*shape).into();
+                let rate_f64 = (*rate).into();
+                assert!(
+                    shape_f64 > 0f64 && rate_f64 > 0f64,
+                    "Parameters should be positive"
+                );
+                (0..n)
+                    .map(|_| ln_gamma_sample(rng, shape_f64).exp() / rate_f64)
+                    .collect()
+            }
+            FDist(d1, d2) => {
+                let f = rand_distr::FisherF::<f64>::new((*d1).into(), (*d2).into()).unwrap();
+                f.sample_iter(rng).take(n).collect()
             }
-            //            Beta(a, b) => {
-            //                let mut rng1 = thread_rng();
-            //                let mut rng2 = thread_rng();
-            //                let mut v = vec![0f64; n];
-            //
-            //                let a_f64 = (*a).into();
-            //                let b_f64 = (*b).into();
-            //
-            //                // For acceptance-rejection method
-            //                let c_x = (a_f64 - 1f64) / (a_f64 + b_f64 - 2f64);
-            //                let c = self.pdf(c_x); // Beta(mode(x) | a, b)
-            //
-            //                let mut iter_num = 0usize;
-            //
-            //                while iter_num < n {
-            //                    let u1 = rng1.gen_range(0f64, 1f64);
-            //                    let u2 = rng2.gen_range(0f64, 1f64);
-            //
-            //                    if u2 <= 1f64 / c * self.pdf(u1) {
-            //                        v[iter_num] = u1;
-            //                        iter_num += 1;
-            //                    }
-            //                }
-            //                v
-            //            }
-            Gamma(shape, scale) => {
-                let gamma =
-                    rand_distr::Gamma::<f64>::new((*shape).into(), (*scale).into()).unwrap();
-                gamma.sample_iter(rng).take(n).collect()
-            } //            Gamma(a, b) => {
-              //                let a_f64 = (*a).into();
-              //                let b_f64 = (*b).into();
-              //
-              //                // for Marsaglia & Tsang's Method
-              //                let d = a_f64 - 1f64 / 3f64;
-              //                let c = 1f64 / (9f64 * d).sqrt();
-              //
-              //                let mut rng1 = thread_rng();
-              //                let mut rng2 = thread_rng();
-              //
-              //                let mut v = vec![0f64; n];
-              //                let mut iter_num = 0usize;
-              //
-              //                while iter_num < n {
-              //                    let u = rng1.gen_range(0f64, 1f64);
-              //                    let z = ziggurat(&mut rng2, 1f64);
-              //                    let w = (1f64 + c * z).powi(3);
-              //
-              //                    if z >= -1f64 / c && u.ln() < 0.5 * z.powi(2) + d - d * w + d * w.ln() {
-              //                        v[iter_num] = d * w / b_f64;
-              //                        iter_num += 1;
-              //                    }
-              //                }
-              //                v
-              //            }
         }
     }
 
@@ -740,6 +1178,19 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for TPDist<T> {
                     * x.into().powf(a_f64 - 1f64)
                     * E.powf(-b_f64 * x.into())
             }
+            FDist(d1, d2) => {
+                let d1 = (*d1).into();
+                let d2 = (*d2).into();
+                let x: f64 = x.into();
+                if x <= 0f64 {
+                    0f64
+                } else {
+                    let ln_pdf = 0.5 * (d1 * (d1 * x).ln() + d2 * d2.ln() - (d1 + d2) * (d1 * x + d2).ln())
+                        - x.ln()
+                        - beta(d1 / 2f64, d2 / 2f64).ln();
+                    ln_pdf.exp()
+                }
+            }
         }
     }
 
@@ -770,7 +1221,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for TPDist<T> {
                 let a: f64 = (*a).into();
                 let b: f64 = (*b).into();
 
-                inc_beta(a, b, x)
+                inc_beta_xy(a, b, x, 1f64 - x)
             }
             Gamma(a, b) => {
                 let a: f64 = (*a).into();
@@ -778,6 +1229,163 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> RNG for TPDist<T> {
 
                 inc_gamma(a, b * x)
             }
+            FDist(d1, d2) => {
+                let d1: f64 = (*d1).into();
+                let d2: f64 = (*d2).into();
+                if x <= 0f64 {
+                    0f64
+                } else {
+                    let s = d1 * x + d2;
+                    inc_beta_xy(d1 / 2f64, d2 / 2f64, d1 * x / s, d2 / s)
+                }
+            }
+        }
+    }
+
+    /// Logarithm of pdf (`Binomial` : `-∞` for non-integer `x`)
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let g = Gamma(2f64, 3f64);
+    /// assert!((g.log_pdf(0.5) - g.pdf(0.5).ln()).abs() < 1e-10);
+    ///
+    /// // pdf underflows, but log_pdf does not
+    /// let n = Normal(0f64, 1f64);
+    /// assert_eq!(n.pdf(40f64), 0f64);
+    /// assert!((n.log_pdf(40f64) + 800f64 + 0.5 * (2f64 * std::f64::consts::PI).ln()).abs() < 1e-12);
+    /// assert_eq!(Beta(2f64, 2f64).log_pdf(1.5), f64::NEG_INFINITY);
+    /// ```
+    fn log_pdf<S: PartialOrd + SampleUniform + Copy + Into<f64>>(&self, x: S) -> f64 {
+        let x: f64 = x.into();
+        match self {
+            Uniform(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                if x >= a && x <= b {
+                    -(b - a).ln()
+                } else {
+                    f64::NEG_INFINITY
+                }
+            }
+            Binomial(n, mu) => {
+                let n = *n as f64;
+                let p: f64 = (*mu).into();
+                if x < 0f64 || x > n || x.fract() != 0f64 {
+                    return f64::NEG_INFINITY;
+                }
+                ln_gamma(n + 1f64) - ln_gamma(x + 1f64) - ln_gamma(n - x + 1f64)
+                    + xlogy(x, p)
+                    + xlog1py(n - x, -p)
+            }
+            Normal(m, s) => {
+                let m: f64 = (*m).into();
+                let s: f64 = (*s).into();
+                let z = (x - m) / s;
+                -0.5 * z * z - s.ln() - 0.5 * (2f64 * PI).ln()
+            }
+            Beta(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                if !(0f64..=1f64).contains(&x) {
+                    return f64::NEG_INFINITY;
+                }
+                xlogy(a - 1f64, x) + xlog1py(b - 1f64, -x) - ln_beta(a, b)
+            }
+            Gamma(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                if x < 0f64 {
+                    return f64::NEG_INFINITY;
+                }
+                a * b.ln() - ln_gamma(a) + xlogy(a - 1f64, x) - b * x
+            }
+            FDist(d1, d2) => {
+                let d1: f64 = (*d1).into();
+                let d2: f64 = (*d2).into();
+                if x <= 0f64 {
+                    return f64::NEG_INFINITY;
+                }
+                0.5 * (d1 * (d1 * x).ln() + d2 * d2.ln() - (d1 + d2) * (d1 * x + d2).ln())
+                    - x.ln()
+                    - ln_beta(d1 / 2f64, d2 / 2f64)
+            }
+        }
+    }
+}
+
+/// Integer samples
+///
+/// * `Binomial(n, p)`
+/// * `Uniform(a, b)` : Discrete uniform on integers in `[a, b]`
+impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> DiscreteRNG for TPDist<T> {
+    fn sample_int_with_rng<R: Rng + Clone>(&self, rng: &mut R, n: usize) -> Result<Vec<i64>> {
+        match self {
+            Binomial(num, mu) => {
+                let binom = rand_distr::Binomial::new(*num as u64, (*mu).into()).unwrap();
+                Ok(binom.sample_iter(rng).take(n).map(|t| t as i64).collect())
+            }
+            Uniform(start, end) => {
+                let a = (*start).into().ceil() as i64;
+                let b = (*end).into().floor() as i64;
+                assert!(a <= b, "No integer in the interval");
+                Ok((0..n).map(|_| rng.gen_range(a..=b)).collect())
+            }
+            _ => bail!(DiscreteRNGError::NotDiscreteError),
+        }
+    }
+}
+
+/// `x ln(y)` with `0 ln(y) = 0` (e.g. `0 ln(0) = 0`)
+fn xlogy(x: f64, y: f64) -> f64 {
+    if x == 0f64 {
+        0f64
+    } else {
+        x * y.ln()
+    }
+}
+
+/// `x ln(1 + y)` with `0 ln(1 + y) = 0`
+fn xlog1py(x: f64, y: f64) -> f64 {
+    if x == 0f64 {
+        0f64
+    } else {
+        x * y.ln_1p()
+    }
+}
+
+/// Logarithm of Beta function
+fn ln_beta(a: f64, b: f64) -> f64 {
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+}
+
+/// Logarithm of `Gamma(shape, 1)` random sample
+///
+/// # Description
+/// * `shape >= 1` : Marsaglia & Tsang's method
+/// * `shape < 1` : `Gamma(shape + 1) * U^(1 / shape)` for `U ~ Uniform(0, 1)`
+///
+/// Log scale avoids underflow of `U^(1 / shape)` for small `shape`.
+///
+/// * Reference : Marsaglia, G., & Tsang, W. W. (2000). *A simple method for generating gamma variables.* ACM TOMS, 26(3), 363-372.
+fn ln_gamma_sample<R: Rng>(rng: &mut R, shape: f64) -> f64 {
+    if shape < 1f64 {
+        let u = 1f64 - rng.gen::<f64>(); // (0, 1]
+        return ln_gamma_sample(rng, shape + 1f64) + u.ln() / shape;
+    }
+    let d = shape - 1f64 / 3f64;
+    let c = 1f64 / (9f64 * d).sqrt();
+    loop {
+        let z: f64 = rng.sample(rand_distr::StandardNormal);
+        let v = (1f64 + c * z).powi(3);
+        if v <= 0f64 {
+            continue;
+        }
+        let u = 1f64 - rng.gen::<f64>();
+        // Squeeze test first
+        if u < 1f64 - 0.0331 * z.powi(4) || u.ln() < 0.5 * z * z + d * (1f64 - v + v.ln()) {
+            return (d * v).ln();
         }
     }
 }
@@ -820,6 +1428,45 @@ impl RNG for WeightedUniform<f64> {
     }
 }
 
+/// RNG for Categorical
+///
+/// `pdf(x)` is `pmf(x)` for integer `x` (`0` otherwise)
+impl RNG for Categorical {
+    fn sample_with_rng<R: Rng + Clone>(&self, rng: &mut R, n: usize) -> Vec<f64> {
+        self.sample_int_with_rng(rng, n)
+            .unwrap()
+            .into_iter()
+            .map(|k| k as f64)
+            .collect()
+    }
+
+    fn pdf<S: PartialOrd + SampleUniform + Copy + Into<f64>>(&self, x: S) -> f64 {
+        let x: f64 = x.into();
+        if x < 0f64 || x.fract() != 0f64 {
+            0f64
+        } else {
+            self.pmf(x as usize)
+        }
+    }
+
+    fn cdf<S: PartialOrd + SampleUniform + Copy + Into<f64>>(&self, x: S) -> f64 {
+        let x: f64 = x.into();
+        if x < 0f64 {
+            return 0f64;
+        }
+        let k = (x.floor() as usize).min(self.probs.len() - 1);
+        self.probs[..=k].iter().sum::<f64>().min(1f64)
+    }
+}
+
+/// Alias method : `O(K)` setup & `O(1)` per draw
+impl DiscreteRNG for Categorical {
+    fn sample_int_with_rng<R: Rng + Clone>(&self, rng: &mut R, n: usize) -> Result<Vec<i64>> {
+        let w = WeightedAliasIndex::new(self.probs.clone()).unwrap();
+        Ok(w.sample_iter(rng).take(n).map(|k| k as i64).collect())
+    }
+}
+
 impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for OPDist<T> {
     type Array = Vec<f64>;
     type Value = f64;
@@ -828,6 +1475,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for OPDist<T>
         match self {
             Bernoulli(mu) => (*mu).into(),
             StudentT(_) => 0f64,
+            ChiSquared(k) => (*k).into(),
         }
     }
 
@@ -841,6 +1489,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for OPDist<T>
                 let nu_f64 = (*nu).into();
                 nu_f64 / (nu_f64 - 2f64)
             }
+            ChiSquared(k) => 2f64 * (*k).into(),
         }
     }
 
@@ -848,6 +1497,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for OPDist<T>
         match self {
             Bernoulli(_mu) => self.var().sqrt(),
             StudentT(_nu) => self.var().sqrt(),
+            ChiSquared(_k) => self.var().sqrt(),
         }
     }
 
@@ -871,6 +1521,10 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for TPDist<T>
             Normal(m, _s) => (*m).into(),
             Beta(a, b) => (*a).into() / ((*a).into() + (*b).into()),
             Gamma(a, b) => (*a).into() / (*b).into(),
+            FDist(_d1, d2) => {
+                let d2 = (*d2).into();
+                d2 / (d2 - 2f64)
+            }
         }
     }
 
@@ -885,6 +1539,11 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for TPDist<T>
                 a_f64 * b_f64 / ((a_f64 + b_f64).powi(2) * (a_f64 + b_f64 + 1f64))
             }
             Gamma(a, b) => (*a).into() / (*b).into().powi(2),
+            FDist(d1, d2) => {
+                let d1 = (*d1).into();
+                let d2 = (*d2).into();
+                2f64 * d2.powi(2) * (d1 + d2 - 2f64) / (d1 * (d2 - 2f64).powi(2) * (d2 - 4f64))
+            }
         }
     }
 
@@ -895,6 +1554,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for TPDist<T>
             Normal(_m, s) => (*s).into(),
             Beta(_a, _b) => self.var().sqrt(),
             Gamma(_a, _b) => self.var().sqrt(),
+            FDist(_d1, _d2) => self.var().sqrt(),
         }
     }
 
@@ -936,3 +1596,107 @@ impl Statistics for WeightedUniform<f64> {
         vec![1f64]
     }
 }
+
+/// Regularized incomplete Beta function `I_x(a, b)` with `y = 1 - x` given separately
+///
+/// Uses `I_x(a, b) = 1 - I_y(b, a)` for `x > (a + 1) / (a + b + 2)`,
+/// where the continued fraction of `inc_beta` loses accuracy.
+/// Passing `y` directly avoids the cancellation of `1 - x` for `x` close to 1.
+fn inc_beta_xy(a: f64, b: f64, x: f64, y: f64) -> f64 {
+    if x <= 0f64 {
+        0f64
+    } else if y <= 0f64 {
+        1f64
+    } else if x > (a + 1f64) / (a + b + 2f64) {
+        1f64 - inc_beta(b, a, y)
+    } else {
+        inc_beta(a, b, x)
+    }
+}
+
+impl Statistics for Categorical {
+    type Array = Vec<f64>;
+    type Value = f64;
+
+    fn mean(&self) -> Self::Value {
+        self.probs
+            .iter()
+            .enumerate()
+            .map(|(k, p)| k as f64 * p)
+            .sum()
+    }
+
+    fn var(&self) -> Self::Value {
+        let mean = self.mean();
+        self.probs
+            .iter()
+            .enumerate()
+            .map(|(k, p)| (k as f64 - mean).powi(2) * p)
+            .sum()
+    }
+
+    fn sd(&self) -> Self::Value {
+        self.var().sqrt()
+    }
+
+    fn cov(&self) -> Self::Array {
+        vec![self.var()]
+    }
+
+    fn cor(&self) -> Self::Array {
+        vec![1f64]
+    }
+}
+
+/// Statistics for Dirichlet
+///
+/// * `mean`, `var`, `sd` : For each component
+/// * `cov`, `cor` : `K x K` matrix
+impl Statistics for Dirichlet {
+    type Array = Matrix;
+    type Value = Vec<f64>;
+
+    fn mean(&self) -> Self::Value {
+        let a0 = self.alpha.iter().sum::<f64>();
+        self.alpha.iter().map(|a| a / a0).collect()
+    }
+
+    fn var(&self) -> Self::Value {
+        let a0 = self.alpha.iter().sum::<f64>();
+        self.mean()
+            .iter()
+            .map(|m| m * (1f64 - m) / (a0 + 1f64))
+            .collect()
+    }
+
+    fn sd(&self) -> Self::Value {
+        self.var().iter().map(|v| v.sqrt()).collect()
+    }
+
+    fn cov(&self) -> Self::Array {
+        let a0 = self.alpha.iter().sum::<f64>();
+        let m = self.mean();
+        let k = m.len();
+        let mut c = zeros(k, k);
+        for i in 0..k {
+            for j in 0..k {
+                let delta = if i == j { m[i] } else { 0f64 };
+                c[(i, j)] = (delta - m[i] * m[j]) / (a0 + 1f64);
+            }
+        }
+        c
+    }
+
+    fn cor(&self) -> Self::Array {
+        let sd = self.sd();
+        let c = self.cov();
+        let k = sd.len();
+        let mut r = zeros(k, k);
+        for i in 0..k {
+            for j in 0..k {
+                r[(i, j)] = c[(i, j)] / (sd[i] * sd[j]);
+            }
+        }
+        r
+    }
+}
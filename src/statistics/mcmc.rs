@@ -0,0 +1,307 @@
+//! Markov Chain Monte Carlo
+//!
+//! * `metropolis_hastings` : Random walk Metropolis-Hastings sampler
+//! * `adaptive_metropolis_hastings` : Same sampler, but tunes the proposal scale during burn-in
+//! * `autocorrelation`, `effective_sample_size` : Diagnostics for a chain
+//!
+//! # Examples
+//! ```
+//! use peroxide::fuga::*;
+//!
+//! // Standard normal target
+//! let log_target = |x: &Vec<f64>| -0.5 * x[0].powi(2);
+//! let res = adaptive_metropolis_hastings(log_target, vec![3f64], vec![1f64], 2000, 500, 1, Some(42));
+//!
+//! let chain = res.samples.col(0);
+//! assert!(chain.mean().abs() < 0.3);
+//! assert!((0.15..0.5).contains(&res.acceptance_rate));
+//! assert!(effective_sample_size(&chain) > 100f64);
+//! ```
+
+use crate::structure::matrix::{matrix, Matrix, Shape::Row};
+use rand::prelude::*;
+use rand_distr::StandardNormal;
+
+/// Acceptance rate targeted by `adaptive_metropolis_hastings`
+const TARGET_ACCEPTANCE: f64 = 0.3;
+
+/// Number of iterations between adaptations of the proposal scale
+const ADAPT_BATCH: usize = 50;
+
+/// Result of MCMC samplers
+///
+/// * `samples` : Samples after burn-in & thinning (each row is a sample)
+/// * `acceptance_rate` : Acceptance rate after burn-in
+/// * `proposal_sd` : Proposal standard deviations used after burn-in
+#[derive(Debug, Clone)]
+pub struct MCMCResult {
+    pub samples: Matrix,
+    pub acceptance_rate: f64,
+    pub proposal_sd: Vec<f64>,
+}
+
+/// Random walk Metropolis-Hastings sampler
+///
+/// # Description
+/// Propose `x' = x + proposal_sd * z` with `z ~ N(0, I)`,
+/// and accept it with probability `min(1, exp(log_target(x') - log_target(x)))`.
+///
+/// * `log_target` : Log density of target (up to constant, `NaN` is treated as `-∞`)
+/// * `x0` : Initial point
+/// * `proposal_sd` : Standard deviation of proposal for each component
+/// * `n_samples` : Number of samples to return
+/// * `burn_in` : Number of initial iterations to discard
+/// * `thin` : Keep every `thin`-th iteration after burn-in
+/// * `seed` : Seed of `StdRng` (`None` for a seed from `thread_rng`)
+///
+/// # Panics
+/// * `x0` and `proposal_sd` have different lengths
+/// * `thin` is zero
+/// * `log_target(x0)` is not finite
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let log_target = |x: &Vec<f64>| -0.5 * (x[0] - 1f64).powi(2);
+/// let res = metropolis_hastings(log_target, vec![0f64], vec![2.4], 2000, 200, 2, Some(42));
+///
+/// assert_eq!(res.samples.row, 2000);
+/// assert_eq!(res.samples.col, 1);
+/// assert!((res.samples.col(0).mean() - 1f64).abs() < 0.2);
+/// ```
+pub fn metropolis_hastings<F>(
+    log_target: F,
+    x0: Vec<f64>,
+    proposal_sd: Vec<f64>,
+    n_samples: usize,
+    burn_in: usize,
+    thin: usize,
+    seed: Option<u64>,
+) -> MCMCResult
+where
+    F: Fn(&Vec<f64>) -> f64,
+{
+    run_chain(
+        log_target,
+        x0,
+        proposal_sd,
+        n_samples,
+        burn_in,
+        thin,
+        seed,
+        false,
+    )
+}
+
+/// Adaptive random walk Metropolis-Hastings sampler
+///
+/// # Description
+/// Same as `metropolis_hastings`, but during burn-in, every 50 iterations,
+/// the proposal scale is updated by `log s += (rate - 0.3) / sqrt(k)` (`k` : number of updates),
+/// so that the acceptance rate approaches `0.3`.
+/// The scale is fixed after burn-in, hence the returned samples form a valid Markov chain.
+///
+/// The tuned proposal is returned as `proposal_sd`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Too small initial proposal
+/// let log_target = |x: &Vec<f64>| -0.5 * (x[0].powi(2) + x[1].powi(2));
+/// let res = adaptive_metropolis_hastings(log_target, vec![0f64; 2], vec![0.01; 2], 1000, 2000, 1, Some(42));
+///
+/// assert!(res.proposal_sd[0] > 0.5);
+/// assert!((0.15..0.5).contains(&res.acceptance_rate));
+/// ```
+pub fn adaptive_metropolis_hastings<F>(
+    log_target: F,
+    x0: Vec<f64>,
+    proposal_sd: Vec<f64>,
+    n_samples: usize,
+    burn_in: usize,
+    thin: usize,
+    seed: Option<u64>,
+) -> MCMCResult
+where
+    F: Fn(&Vec<f64>) -> f64,
+{
+    run_chain(
+        log_target,
+        x0,
+        proposal_sd,
+        n_samples,
+        burn_in,
+        thin,
+        seed,
+        true,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_chain<F>(
+    log_target: F,
+    x0: Vec<f64>,
+    proposal_sd: Vec<f64>,
+    n_samples: usize,
+    burn_in: usize,
+    thin: usize,
+    seed: Option<u64>,
+    adapt: bool,
+) -> MCMCResult
+where
+    F: Fn(&Vec<f64>) -> f64,
+{
+    let dim = x0.len();
+    assert_eq!(
+        dim,
+        proposal_sd.len(),
+        "x0 and proposal_sd should have same length"
+    );
+    assert!(thin > 0, "thin should be positive");
+
+    let mut rng = StdRng::seed_from_u64(seed.unwrap_or_else(|| thread_rng().gen()));
+    let log_density = |x: &Vec<f64>| {
+        let l = log_target(x);
+        if l.is_nan() {
+            f64::NEG_INFINITY
+        } else {
+            l
+        }
+    };
+
+    let mut x = x0;
+    let mut log_p = log_density(&x);
+    assert!(log_p.is_finite(), "log_target(x0) should be finite");
+
+    let mut sd = proposal_sd;
+    let mut log_scale = 0f64;
+    let mut n_adapt = 0usize;
+    let mut batch_accept = 0usize;
+    let mut accepted = 0usize;
+
+    let mut samples: Vec<f64> = Vec::with_capacity(n_samples * dim);
+    let mut proposal = vec![0f64; dim];
+    let total = burn_in + n_samples * thin;
+    for iter in 0..total {
+        let scale = log_scale.exp();
+        for (p, (&xi, &si)) in proposal.iter_mut().zip(x.iter().zip(sd.iter())) {
+            let z: f64 = rng.sample(StandardNormal);
+            *p = xi + scale * si * z;
+        }
+        let log_p_new = log_density(&proposal);
+        let accept = log_p_new - log_p >= rng.gen::<f64>().ln();
+        if accept {
+            x.copy_from_slice(&proposal);
+            log_p = log_p_new;
+        }
+
+        if iter < burn_in {
+            if adapt {
+                batch_accept += accept as usize;
+                if (iter + 1).is_multiple_of(ADAPT_BATCH) {
+                    n_adapt += 1;
+                    let rate = batch_accept as f64 / ADAPT_BATCH as f64;
+                    log_scale += (rate - TARGET_ACCEPTANCE) / (n_adapt as f64).sqrt();
+                    batch_accept = 0;
+                }
+            }
+        } else {
+            accepted += accept as usize;
+            if (iter - burn_in + 1).is_multiple_of(thin) {
+                samples.extend_from_slice(&x);
+            }
+        }
+    }
+
+    let scale = log_scale.exp();
+    sd.iter_mut().for_each(|s| *s *= scale);
+    let n_iter = n_samples * thin;
+    let acceptance_rate = if n_iter > 0 {
+        accepted as f64 / n_iter as f64
+    } else {
+        f64::NAN
+    };
+
+    MCMCResult {
+        samples: matrix(samples, n_samples, dim, Row),
+        acceptance_rate,
+        proposal_sd: sd,
+    }
+}
+
+/// Sample autocorrelation of a chain at `lag`
+///
+/// # Description
+/// `ρ(k) = Σ (x_t - x̄)(x_{t+k} - x̄) / Σ (x_t - x̄)²`
+///
+/// Returns `NaN` if the chain is constant, and `0` if `lag >= chain.len()`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = vec![1f64, -1f64, 1f64, -1f64];
+/// assert_eq!(autocorrelation(&x, 0), 1f64);
+/// assert_eq!(autocorrelation(&x, 1), -0.75);
+/// ```
+pub fn autocorrelation(chain: &[f64], lag: usize) -> f64 {
+    let n = chain.len();
+    let mean = chain.iter().sum::<f64>() / n as f64;
+    let denom = chain.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+    if lag >= n {
+        return 0f64;
+    }
+    let num = chain
+        .iter()
+        .zip(chain[lag..].iter())
+        .map(|(a, b)| (a - mean) * (b - mean))
+        .sum::<f64>();
+    num / denom
+}
+
+/// Effective sample size of a chain
+///
+/// # Description
+/// `ESS = n / (1 + 2 Σ ρ(k))`, where the sum is truncated by Geyer's initial positive sequence
+/// (pairs `ρ(2m) + ρ(2m+1)` are summed while positive).
+///
+/// Returns `n` for a constant chain.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Independent samples
+/// let x = Normal(0f64, 1f64).sample_with_rng(&mut stdrng_from_seed(42), 1000);
+/// let ess = effective_sample_size(&x);
+/// assert!(ess > 800f64);
+///
+/// // AR(1) with phi = 0.9 : ESS ~ n (1 - phi) / (1 + phi)
+/// let mut y = vec![0f64; 1000];
+/// for i in 1 .. y.len() {
+///     y[i] = 0.9 * y[i-1] + x[i];
+/// }
+/// assert!(effective_sample_size(&y) < 150f64);
+/// ```
+pub fn effective_sample_size(chain: &[f64]) -> f64 {
+    let n = chain.len();
+    if n < 2 || chain.iter().all(|&x| x == chain[0]) {
+        return n as f64;
+    }
+
+    let mut sum = 0f64;
+    let mut m = 0usize;
+    while 2 * m + 1 < n {
+        let pair = autocorrelation(chain, 2 * m) + autocorrelation(chain, 2 * m + 1);
+        if pair <= 0f64 {
+            break;
+        }
+        sum += pair;
+        m += 1;
+    }
+    // Σ_{k>=0} pairs includes ρ(0) = 1 : 1 + 2 Σ_{k>=1} ρ(k) = 2 sum - 1
+    let tau = (2f64 * sum - 1f64).max(1f64 / n as f64);
+    n as f64 / tau
+}
@@ -4,8 +4,14 @@
 //! * Popular distributions - `dist.rs`
 //! * Simple Random Number Generator - `rand.rs`
 //! * Basic probabilistic operations - `ops.rs`
+//! * Hypothesis tests - `test.rs`
+//! * Markov Chain Monte Carlo - `mcmc.rs`
+//! * Resampling methods - `resample.rs`
 
 pub mod dist;
+pub mod mcmc;
 pub mod ops;
 pub mod rand;
+pub mod resample;
 pub mod stat;
+pub mod test;
@@ -0,0 +1,321 @@
+//! Resampling methods
+//!
+//! * `bootstrap` : Nonparametric bootstrap of a statistic (standard error, percentile & BCa intervals)
+//! * `permutation_test` : Two-sample permutation test
+//!
+//! # Reproducibility
+//! With `seed = Some(s)`, a master `StdRng` seeded by `s` draws one seed per resample,
+//! and each resample uses its own `StdRng`.
+//! Hence results depend only on `s`, and are identical whether or not resamples run in parallel
+//! (`parallel` feature).
+//! With `seed = None`, the master seed is drawn from `thread_rng`.
+//!
+//! # Examples
+//! ```
+//! use peroxide::fuga::*;
+//!
+//! let x = Normal(0f64, 1f64).sample_with_rng(&mut stdrng_from_seed(42), 50);
+//! let y = Normal(1f64, 1f64).sample_with_rng(&mut stdrng_from_seed(43), 50);
+//!
+//! // Bootstrap of median
+//! let boot = bootstrap(&x, |v| v.median(), 1000, Some(42));
+//! let (lo, hi) = boot.bca_ci(0.05);
+//! assert!(lo < boot.estimate && boot.estimate < hi);
+//!
+//! // Permutation test for difference of means
+//! let p = permutation_test(&x, &y, |a, b| a.mean() - b.mean(), 1000, Some(42));
+//! assert!(p < 0.01);
+//! ```
+
+use crate::special::function::{inv_erfc, phi};
+use crate::statistics::stat::{OrderedStat, QType::Type2, Statistics};
+use rand::prelude::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Result of `bootstrap`
+///
+/// # Description
+/// * `estimate` : statistic of the original data
+/// * `replicates` : statistic of each resample
+/// * `se` : bootstrap standard error (standard deviation of `replicates`)
+/// * `bias` : `mean(replicates) - estimate`
+/// * `acceleration` : jackknife estimate of the BCa acceleration
+#[derive(Debug, Clone, PartialEq)]
+pub struct BootstrapResult {
+    pub estimate: f64,
+    pub replicates: Vec<f64>,
+    pub se: f64,
+    pub bias: f64,
+    pub acceleration: f64,
+}
+
+impl BootstrapResult {
+    /// Percentile confidence interval
+    ///
+    /// # Description
+    /// `alpha / 2` & `1 - alpha / 2` quantiles of `replicates`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    ///     let boot = bootstrap(&a, |v| v.mean(), 1000, Some(42));
+    ///     let (lo, hi) = boot.percentile_ci(0.05);
+    ///     assert!(1f64 <= lo && lo < 5.5 && 5.5 < hi && hi <= 10f64);
+    /// }
+    /// ```
+    pub fn percentile_ci(&self, alpha: f64) -> (f64, f64) {
+        percentile_interval(&self.replicates, alpha)
+    }
+
+    /// Bias-corrected and accelerated (BCa) confidence interval
+    ///
+    /// # Description
+    /// Percentile interval with adjusted levels (Efron, 1987)
+    ///
+    /// * `z0 = Φ⁻¹(#{θ* < θ} / B)` : bias correction
+    /// * `a` : acceleration
+    /// * `α₁ = Φ(z0 + (z0 + z_{α/2}) / (1 - a (z0 + z_{α/2})))` (`α₂` likewise with `z_{1-α/2}`)
+    ///
+    /// If every replicate lies on one side of `estimate`, the interval degenerates to
+    /// the range of `replicates`.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     // Skewed data
+    ///     let a = c!(0.1, 0.2, 0.2, 0.3, 0.5, 0.8, 1.3, 2.1, 3.4, 5.5);
+    ///     let boot = bootstrap(&a, |v| v.mean(), 2000, Some(42));
+    ///     let (lo, hi) = boot.bca_ci(0.05);
+    ///     assert!(lo < boot.estimate && boot.estimate < hi);
+    /// }
+    /// ```
+    pub fn bca_ci(&self, alpha: f64) -> (f64, f64) {
+        assert!(alpha > 0f64 && alpha < 1f64, "alpha should be in (0, 1)");
+        let b = self.replicates.len() as f64;
+        let below = self
+            .replicates
+            .iter()
+            .filter(|&&t| t < self.estimate)
+            .count() as f64;
+        let z0 = norm_ppf(below / b);
+        if !z0.is_finite() {
+            let q = self.replicates.quantiles(vec![0f64, 1f64], Type2);
+            return (q[0], q[1]);
+        }
+        let a = self.acceleration;
+
+        let adjust = |q: f64| {
+            let z = norm_ppf(q);
+            let level = phi(z0 + (z0 + z) / (1f64 - a * (z0 + z)));
+            if level.is_nan() {
+                q
+            } else {
+                level
+            }
+        };
+        let q = self.replicates.quantiles(
+            vec![adjust(alpha / 2f64), adjust(1f64 - alpha / 2f64)],
+            Type2,
+        );
+        (q[0], q[1])
+    }
+}
+
+/// Nonparametric bootstrap
+///
+/// # Description
+/// Resample `data` with replacement `n_resamples` times and evaluate `statistic` on each resample.
+/// The acceleration for `bca_ci` is computed by jackknife (`data.len()` more evaluations).
+///
+/// With `parallel` feature, resamples are evaluated in parallel (so `statistic` should be `Sync`).
+///
+/// # Panics
+/// * `data` is empty
+/// * `n_resamples` is zero
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = Normal(5f64, 2f64).sample_with_rng(&mut stdrng_from_seed(42), 100);
+/// let boot = bootstrap(&x, |v| v.mean(), 1000, Some(42));
+///
+/// assert_eq!(boot.replicates.len(), 1000);
+/// assert_eq!(boot.estimate, x.mean());
+/// assert!((boot.se - 0.2).abs() < 0.05);   // σ / √n
+///
+/// // Same seed, same result
+/// assert_eq!(boot, bootstrap(&x, |v| v.mean(), 1000, Some(42)));
+/// ```
+pub fn bootstrap<F>(
+    data: &[f64],
+    statistic: F,
+    n_resamples: usize,
+    seed: Option<u64>,
+) -> BootstrapResult
+where
+    F: Fn(&Vec<f64>) -> f64 + Sync,
+{
+    let n = data.len();
+    let replicates = bootstrap_replicates(data, &statistic, n_resamples, seed);
+
+    let estimate = statistic(&data.to_vec());
+    let se = if n_resamples > 1 {
+        replicates.sd()
+    } else {
+        0f64
+    };
+    let bias = replicates.mean() - estimate;
+
+    // Jackknife acceleration
+    let jack = (0..n)
+        .map(|i| {
+            let sub = data
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &x)| x)
+                .collect::<Vec<f64>>();
+            statistic(&sub)
+        })
+        .collect::<Vec<f64>>();
+    let jack_mean = jack.mean();
+    let (num, den) = jack.iter().fold((0f64, 0f64), |(num, den), &t| {
+        let d = jack_mean - t;
+        (num + d.powi(3), den + d.powi(2))
+    });
+    let acceleration = if den > 0f64 {
+        num / (6f64 * den.powf(1.5))
+    } else {
+        0f64
+    };
+
+    BootstrapResult {
+        estimate,
+        replicates,
+        se,
+        bias,
+        acceleration,
+    }
+}
+
+/// Statistic of each bootstrap resample (shared with `OrderedStat::bootstrap_ci`)
+pub(crate) fn bootstrap_replicates<F>(
+    data: &[f64],
+    statistic: F,
+    n_resamples: usize,
+    seed: Option<u64>,
+) -> Vec<f64>
+where
+    F: Fn(&Vec<f64>) -> f64 + Sync,
+{
+    let n = data.len();
+    assert!(n > 0, "Sample should not be empty");
+    assert!(n_resamples > 0, "Number of resamples should be positive");
+
+    let seeds = resample_seeds(n_resamples, seed);
+    map_seeds(&seeds, |s| {
+        let mut rng = StdRng::seed_from_u64(s);
+        let resample = (0..n)
+            .map(|_| data[rng.gen_range(0..n)])
+            .collect::<Vec<f64>>();
+        statistic(&resample)
+    })
+}
+
+/// `alpha / 2` & `1 - alpha / 2` quantiles of bootstrap replicates
+pub(crate) fn percentile_interval(replicates: &Vec<f64>, alpha: f64) -> (f64, f64) {
+    assert!(alpha > 0f64 && alpha < 1f64, "alpha should be in (0, 1)");
+    let q = replicates.quantiles(vec![alpha / 2f64, 1f64 - alpha / 2f64], Type2);
+    (q[0], q[1])
+}
+
+/// Two-sample permutation test
+///
+/// # Description
+/// `H0 : x and y come from the same distribution`
+///
+/// Pool `x` & `y`, shuffle and split them `n_permutations` times,
+/// and compare `|statistic(x*, y*)|` with the observed `|statistic(x, y)|` (two-sided).
+///
+/// * `p = (1 + #{|T*| >= |T|}) / (1 + n_permutations)`
+///
+/// With `parallel` feature, permutations are evaluated in parallel (so `statistic` should be `Sync`).
+///
+/// # Panics
+/// * `x` or `y` is empty
+/// * `n_permutations` is zero
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = vec![5.1, 4.9, 5.3, 5.0, 5.2, 4.8];
+/// let y = vec![5.9, 6.1, 5.7, 6.3, 5.8, 6.0];
+///
+/// let p = permutation_test(&x, &y, |a, b| a.mean() - b.mean(), 2000, Some(42));
+/// assert!(p < 0.01);
+///
+/// let p = permutation_test(&x, &x, |a, b| a.mean() - b.mean(), 2000, Some(42));
+/// assert_eq!(p, 1f64);
+/// ```
+pub fn permutation_test<F>(
+    x: &[f64],
+    y: &[f64],
+    statistic: F,
+    n_permutations: usize,
+    seed: Option<u64>,
+) -> f64
+where
+    F: Fn(&Vec<f64>, &Vec<f64>) -> f64 + Sync,
+{
+    let n_x = x.len();
+    assert!(n_x > 0 && !y.is_empty(), "Samples should not be empty");
+    assert!(n_permutations > 0, "Number of permutations should be positive");
+
+    let observed = statistic(&x.to_vec(), &y.to_vec()).abs();
+    let pooled = x.iter().chain(y.iter()).cloned().collect::<Vec<f64>>();
+
+    let seeds = resample_seeds(n_permutations, seed);
+    let permuted = map_seeds(&seeds, |s| {
+        let mut rng = StdRng::seed_from_u64(s);
+        let mut v = pooled.clone();
+        v.shuffle(&mut rng);
+        let y_perm = v.split_off(n_x);
+        statistic(&v, &y_perm).abs()
+    });
+
+    let extreme = permuted.iter().filter(|&&t| t >= observed).count();
+    (1 + extreme) as f64 / (1 + n_permutations) as f64
+}
+
+/// Seeds of each resample
+fn resample_seeds(n: usize, seed: Option<u64>) -> Vec<u64> {
+    let mut rng = StdRng::seed_from_u64(seed.unwrap_or_else(|| thread_rng().gen()));
+    (0..n).map(|_| rng.gen()).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn map_seeds<F: Fn(u64) -> f64 + Sync>(seeds: &[u64], f: F) -> Vec<f64> {
+    seeds.iter().map(|&s| f(s)).collect()
+}
+
+#[cfg(feature = "parallel")]
+fn map_seeds<F: Fn(u64) -> f64 + Sync>(seeds: &[u64], f: F) -> Vec<f64> {
+    seeds.par_iter().map(|&s| f(s)).collect()
+}
+
+/// Quantile function of standard normal distribution
+fn norm_ppf(p: f64) -> f64 {
+    -2f64.sqrt() * inv_erfc(2f64 * p)
+}
@@ -149,10 +149,16 @@
 use std::fmt;
 
 use self::QType::*;
-//use crate::structure::dataframe::*;
+use crate::structure::dataframe::{DType, DataFrame, Series, TypedVector};
 use crate::structure::matrix::*;
-use crate::traits::fp::FPVector;
+use crate::statistics::resample::{bootstrap_replicates, percentile_interval};
+use crate::structure::vector::{ksum, ksum_iter};
+#[cfg(feature = "parallel")]
+use crate::traits::fp::ParallelFPMatrix;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use order_stat::kth_by;
+use rand::prelude::*;
 
 /// Statistics Trait
 ///
@@ -186,11 +192,15 @@ impl Statistics for Vec<f64> {
     /// }
     /// ```
     fn mean(&self) -> f64 {
-        self.reduce(0f64, |x, y| x + y) / (self.len() as f64)
+        ksum(self) / (self.len() as f64)
     }
 
     /// Variance
     ///
+    /// # Description
+    /// Corrected two-pass algorithm with compensated sums :
+    /// `(Σ(x - x̄)² - (Σ(x - x̄))² / n) / (n - 1)`
+    ///
     /// # Examples
     /// ```
     /// #[macro_use]
@@ -203,17 +213,12 @@ impl Statistics for Vec<f64> {
     /// }
     /// ```
     fn var(&self) -> f64 {
-        let mut ss = 0f64;
-        let mut s = 0f64;
-        let mut l = 0f64;
-
-        for x in self.into_iter() {
-            ss += x.powf(2f64);
-            s += *x;
-            l += 1f64;
-        }
+        let l = self.len() as f64;
         assert_ne!(l, 1f64);
-        (ss / l - (s / l).powf(2f64)) * l / (l - 1f64)
+        let m = self.mean();
+        let ss = ksum_iter(self.iter().map(|x| (x - m).powi(2)));
+        let s = ksum_iter(self.iter().map(|x| x - m));
+        (ss - s * s / l) / (l - 1f64)
     }
 
     /// Standard Deviation
@@ -362,6 +367,75 @@ impl Statistics for Matrix {
     }
 }
 
+/// Parallel Statistics Trait (`parallel` feature)
+///
+/// Column statistics are computed on separate threads.
+/// Each column is computed sequentially, so the results are identical to `Statistics`.
+#[cfg(feature = "parallel")]
+pub trait ParallelStatistics {
+    type Array;
+    type Value;
+
+    fn par_mean(&self) -> Self::Value;
+    fn par_var(&self) -> Self::Value;
+    fn par_sd(&self) -> Self::Value;
+    fn par_cov(&self) -> Self::Array;
+    fn par_cor(&self) -> Self::Array;
+}
+
+#[cfg(feature = "parallel")]
+impl ParallelStatistics for Matrix {
+    type Array = Matrix;
+    type Value = Vec<f64>;
+
+    /// Parallel column mean
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     # #[cfg(feature = "parallel")] {
+    ///     let m = matrix(c!(1,3,3,1), 2, 2, Col);
+    ///     assert_eq!(m.par_mean(), c!(2,2));
+    ///     # }
+    /// }
+    /// ```
+    fn par_mean(&self) -> Vec<f64> {
+        self.par_col_reduce(|c| c.mean())
+    }
+
+    fn par_var(&self) -> Vec<f64> {
+        self.par_col_reduce(|c| c.var())
+    }
+
+    fn par_sd(&self) -> Vec<f64> {
+        self.par_col_reduce(|c| c.sd())
+    }
+
+    fn par_cov(&self) -> Self {
+        let c = self.col;
+        let cols = (0..c).map(|i| self.col(i)).collect::<Vec<_>>();
+        let v = (0..c * c)
+            .into_par_iter()
+            .map(|k| cov(&cols[k / c], &cols[k % c]))
+            .collect::<Vec<f64>>();
+        matrix(v, c, c, Row)
+    }
+
+    fn par_cor(&self) -> Self {
+        let c = self.col;
+        let cols = (0..c).map(|i| self.col(i)).collect::<Vec<_>>();
+        let v = (0..c * c)
+            .into_par_iter()
+            .map(|k| cor(&cols[k / c], &cols[k % c]))
+            .collect::<Vec<f64>>();
+        matrix(v, c, c, Row)
+    }
+}
+
 //impl Statistics for DataFrame {
 //    type Array = Matrix;
 //    type Value = Self;
@@ -414,20 +488,17 @@ impl Statistics for Matrix {
 ///     assert!(nearly_eq(cov(&v1, &v2), -1f64));
 /// }
 /// ```
-pub fn cov(v1: &Vec<f64>, v2: &Vec<f64>) -> f64 {
-    let mut ss = 0f64;
-    let mut sx = 0f64;
-    let mut sy = 0f64;
-    let mut l = 0f64;
-
-    for (x, y) in v1.into_iter().zip(v2) {
-        ss += x * y;
-        sx += *x;
-        sy += *y;
-        l += 1f64;
-    }
+pub fn cov(v1: &[f64], v2: &[f64]) -> f64 {
+    let n = v1.len().min(v2.len());
+    let l = n as f64;
     assert_ne!(l, 1f64);
-    (ss / l - (sx * sy) / (l.powf(2f64))) * l / (l - 1f64)
+    let mx = ksum(&v1[..n]) / l;
+    let my = ksum(&v2[..n]) / l;
+    let pairs = || v1.iter().zip(v2).map(|(x, y)| (x - mx, y - my));
+    let ss = ksum_iter(pairs().map(|(dx, dy)| dx * dy));
+    let sx = ksum_iter(pairs().map(|(dx, _)| dx));
+    let sy = ksum_iter(pairs().map(|(_, dy)| dy));
+    (ss - sx * sy / l) / (l - 1f64)
 }
 
 /// Pearson's correlation coefficient
@@ -480,6 +551,11 @@ pub fn lm(input: &Matrix, target: &Matrix) -> Matrix {
 ///
 /// * `median`
 /// * `quantile`
+/// * `histogram`
+/// * `ecdf`
+/// * `bootstrap_ci`
+///
+/// `histogram`, `ecdf` and `bootstrap_ci` are provided for every `AsRef<[f64]>` implementor.
 pub trait OrderedStat {
     type Array;
     type Value;
@@ -487,6 +563,201 @@ pub trait OrderedStat {
     fn median(&self) -> Self::Value;
     fn quantile(&self, q: f64, qtype: QType) -> Self::Value;
     fn quantiles(&self, q: Vec<f64>, qtype: QType) -> Self::Array;
+
+    /// Histogram with equally spaced bins
+    ///
+    /// # Description
+    /// * Edges : `bins + 1` equally spaced points from `min` to `max`
+    ///   (`min - 0.5` to `max + 0.5` if all elements are equal)
+    /// * Bins are half-open `[e_i, e_{i+1})`, except the last one which includes `max`
+    /// * `NaN` is ignored
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1, 2, 2, 3, 3, 3, 4, 4, 4, 4);
+    ///     let (edges, counts) = a.histogram(3);
+    ///     assert_eq!(edges, c!(1, 2, 3, 4));
+    ///     assert_eq!(counts, vec![1, 2, 7]);
+    /// }
+    /// ```
+    fn histogram(&self, bins: usize) -> (Vec<f64>, Vec<usize>)
+    where
+        Self: AsRef<[f64]>,
+    {
+        assert!(bins > 0, "Number of bins should be positive");
+        let (min, max) = self
+            .as_ref()
+            .iter()
+            .filter(|x| !x.is_nan())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| (lo.min(x), hi.max(x)));
+        let (min, max) = if min > max {
+            (0f64, 1f64)
+        } else if min == max {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        };
+        let width = (max - min) / bins as f64;
+        let mut edges = (0..bins)
+            .map(|i| min + i as f64 * width)
+            .collect::<Vec<f64>>();
+        edges.push(max);
+
+        let counts = self.histogram_with_edges(&edges);
+        (edges, counts)
+    }
+
+    /// Histogram with explicit edges
+    ///
+    /// # Description
+    /// * `edges` should be increasing (length = number of bins + 1)
+    /// * Bins are half-open `[e_i, e_{i+1})`, except the last one which includes `e_last`
+    /// * Elements outside `[e_0, e_last]` and `NaN` are ignored
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(-1, 0, 0.5, 1, 2.5, 10);
+    ///     assert_eq!(a.histogram_with_edges(&c!(0, 1, 3)), vec![2, 2]);
+    /// }
+    /// ```
+    fn histogram_with_edges(&self, edges: &[f64]) -> Vec<usize>
+    where
+        Self: AsRef<[f64]>,
+    {
+        assert!(edges.len() >= 2, "Edges should have at least 2 elements");
+        assert!(
+            edges.windows(2).all(|w| w[0] < w[1]),
+            "Edges should be strictly increasing"
+        );
+        let bins = edges.len() - 1;
+        let (first, last) = (edges[0], edges[bins]);
+        let mut counts = vec![0usize; bins];
+        for &x in self.as_ref().iter() {
+            if !(first..=last).contains(&x) {
+                continue;
+            }
+            let i = edges.partition_point(|&e| e <= x) - 1;
+            counts[i.min(bins - 1)] += 1;
+        }
+        counts
+    }
+
+    /// Empirical cumulative distribution function
+    ///
+    /// # Description
+    /// Returns sorted distinct values `x` and `F(x) = #{x_i <= x} / n` (`NaN` is ignored).
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(3, 1, 2, 2);
+    ///     let (x, p) = a.ecdf();
+    ///     assert_eq!(x, c!(1, 2, 3));
+    ///     assert_eq!(p, c!(0.25, 0.75, 1));
+    /// }
+    /// ```
+    fn ecdf(&self) -> (Vec<f64>, Vec<f64>)
+    where
+        Self: AsRef<[f64]>,
+    {
+        let mut v = self
+            .as_ref()
+            .iter()
+            .cloned()
+            .filter(|x| !x.is_nan())
+            .collect::<Vec<f64>>();
+        v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = v.len() as f64;
+
+        let mut x: Vec<f64> = Vec::with_capacity(v.len());
+        let mut p: Vec<f64> = Vec::with_capacity(v.len());
+        for (i, &t) in v.iter().enumerate() {
+            let prob = (i + 1) as f64 / n;
+            match x.last() {
+                Some(&last) if last == t => *p.last_mut().unwrap() = prob,
+                _ => {
+                    x.push(t);
+                    p.push(prob);
+                }
+            }
+        }
+        (x, p)
+    }
+
+    /// Bootstrap percentile confidence interval
+    ///
+    /// # Description
+    /// Resample `n_boot` times with replacement, and return `alpha / 2` & `1 - alpha / 2`
+    /// quantiles of `stat` over the resamples (uses `thread_rng`).
+    ///
+    /// Same as `bootstrap(self, stat, n_boot, None).percentile_ci(alpha)`,
+    /// without the jackknife pass for the BCa acceleration.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    ///     let (lo, hi) = a.bootstrap_ci(|v| v.mean(), 1000, 0.05);
+    ///     assert!(1f64 <= lo && lo <= hi && hi <= 10f64);
+    /// }
+    /// ```
+    fn bootstrap_ci(&self, stat: fn(&Vec<f64>) -> f64, n_boot: usize, alpha: f64) -> (f64, f64)
+    where
+        Self: AsRef<[f64]>,
+    {
+        let replicates = bootstrap_replicates(self.as_ref(), stat, n_boot, None);
+        percentile_interval(&replicates, alpha)
+    }
+
+    /// Bootstrap percentile confidence interval with specific rng
+    ///
+    /// # Description
+    /// The seed of `bootstrap` is drawn from `rng`.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    ///     let ci1 = a.bootstrap_ci_with_rng(|v| v.mean(), 100, 0.1, &mut stdrng_from_seed(42));
+    ///     let ci2 = a.bootstrap_ci_with_rng(|v| v.mean(), 100, 0.1, &mut stdrng_from_seed(42));
+    ///     assert_eq!(ci1, ci2);
+    /// }
+    /// ```
+    fn bootstrap_ci_with_rng<R: Rng>(
+        &self,
+        stat: fn(&Vec<f64>) -> f64,
+        n_boot: usize,
+        alpha: f64,
+        rng: &mut R,
+    ) -> (f64, f64)
+    where
+        Self: AsRef<[f64]>,
+    {
+        let replicates = bootstrap_replicates(self.as_ref(), stat, n_boot, Some(rng.gen()));
+        percentile_interval(&replicates, alpha)
+    }
 }
 
 /// R Quantile Type enums
@@ -567,6 +838,367 @@ pub fn quantile(v: &Vec<f64>, qtype: QType) -> Vec<f64> {
     v.quantiles(q_vec, qtype)
 }
 
+// =============================================================================
+// Outliers
+// =============================================================================
+/// Winsorization
+///
+/// # Description
+/// Clamp `x` to `[Q(lower_q), Q(upper_q)]` (quantiles use `QType::Type2`, same as `median`)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = c!(1, 2, 3, 4, 5, 6, 7, 8, 9, 100);
+///     assert_eq!(winsorize(&a, 0.15, 0.85), c!(2, 2, 3, 4, 5, 6, 7, 8, 9, 9));
+/// }
+/// ```
+pub fn winsorize(x: &Vec<f64>, lower_q: f64, upper_q: f64) -> Vec<f64> {
+    let (lo, hi) = quantile_bounds(x, lower_q, upper_q);
+    x.iter().map(|&t| t.clamp(lo, hi)).collect()
+}
+
+/// Trimming
+///
+/// # Description
+/// Remove elements of `x` outside of `[Q(lower_q), Q(upper_q)]` (order is preserved)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = c!(100, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+///     assert_eq!(trim(&a, 0.15, 0.85), c!(2, 3, 4, 5, 6, 7, 8, 9));
+/// }
+/// ```
+pub fn trim(x: &Vec<f64>, lower_q: f64, upper_q: f64) -> Vec<f64> {
+    let (lo, hi) = quantile_bounds(x, lower_q, upper_q);
+    x.iter().cloned().filter(|t| (lo..=hi).contains(t)).collect()
+}
+
+fn quantile_bounds(x: &Vec<f64>, lower_q: f64, upper_q: f64) -> (f64, f64) {
+    assert!(!x.is_empty(), "Sample should not be empty");
+    assert!(
+        (0f64..=1f64).contains(&lower_q) && (0f64..=1f64).contains(&upper_q) && lower_q <= upper_q,
+        "Quantiles should satisfy 0 <= lower_q <= upper_q <= 1"
+    );
+    let q = x.quantiles(vec![lower_q, upper_q], Type2);
+    (q[0], q[1])
+}
+
+/// Standard score
+///
+/// # Description
+/// `z_i = (x_i - mean) / sd` (sample standard deviation). For constant `x`, every score is `NaN`.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let z = zscore(&c!(1, 2, 3));
+///     assert!(eq_vec(&z, &c!(-1, 0, 1), 1e-12));
+/// }
+/// ```
+pub fn zscore(x: &Vec<f64>) -> Vec<f64> {
+    let (mean, sd) = (x.mean(), x.sd());
+    x.iter().map(|t| (t - mean) / sd).collect()
+}
+
+/// Median absolute deviation
+///
+/// # Description
+/// `MAD = median(|x_i - median(x)|)` (unscaled, multiply by `1.4826` to estimate `sd` of normal data)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = c!(1, 1, 2, 2, 4, 6, 9);
+///     assert_eq!(mad(&a), 1f64);
+/// }
+/// ```
+pub fn mad(x: &Vec<f64>) -> f64 {
+    let med = x.median();
+    x.iter().map(|t| (t - med).abs()).collect::<Vec<f64>>().median()
+}
+
+/// Outlier detection methods
+///
+/// * `IQR(k)` : `x < Q1 - k IQR` or `x > Q3 + k IQR` (Tukey's fences, `k = 1.5` is common)
+/// * `ZScore(k)` : `|zscore(x)| > k` (`k = 3` is common)
+/// * `MAD(k)` : `0.6745 |x - median| / MAD > k` (modified z-score of Iglewicz & Hoaglin, `k = 3.5` is common)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum OutlierMethod {
+    IQR(f64),
+    ZScore(f64),
+    MAD(f64),
+}
+
+/// Mask of outliers (`true` for outlier)
+///
+/// # Description
+/// Quartiles & medians use `QType::Type2` (same as `winsorize`, `trim` and `median`).
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = c!(1, 2, 3, 4, 5, 6, 7, 8, 9, 100);
+///     let mask = outlier_mask(&a, OutlierMethod::IQR(1.5));
+///     assert_eq!(mask.iter().filter(|&&m| m).count(), 1);
+///     assert!(mask[9]);
+/// }
+/// ```
+pub fn outlier_mask(x: &Vec<f64>, method: OutlierMethod) -> Vec<bool> {
+    match method {
+        OutlierMethod::IQR(k) => {
+            let q = x.quantiles(vec![0.25, 0.75], Type2);
+            let iqr = q[1] - q[0];
+            let (lo, hi) = (q[0] - k * iqr, q[1] + k * iqr);
+            x.iter().map(|t| !(lo..=hi).contains(t)).collect()
+        }
+        OutlierMethod::ZScore(k) => zscore(x).into_iter().map(|z| z.abs() > k).collect(),
+        OutlierMethod::MAD(k) => {
+            let med = x.median();
+            let mad = mad(x);
+            x.iter().map(|t| 0.6745 * (t - med).abs() / mad > k).collect()
+        }
+    }
+}
+
+// =============================================================================
+// Summary
+// =============================================================================
+/// Statistical summary (like `summary` of R or `describe` of pandas)
+///
+/// * `NaN` is excluded from all statistics (`n_nan` counts it)
+/// * Quartiles use `QType::Type2` (same as `median`)
+/// * For `count < 2`, `sd` is `NaN` (all statistics are `NaN` for `count = 0`)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = c!(4, 1, f64::NAN, 3, 2, 5);
+///     let s = a.summary();
+///     assert_eq!(s.count, 5);
+///     assert_eq!(s.n_nan, 1);
+///     assert_eq!((s.min, s.q1, s.median, s.q3, s.max), (1f64, 2f64, 3f64, 4f64, 5f64));
+///
+///     println!("{}", s);
+///     //  count   mean     sd    min     q1 median     q3    max  n_nan
+///     //      5      3 1.5811      1      2      3      4      5      1
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub mean: f64,
+    pub sd: f64,
+    pub min: f64,
+    pub q1: f64,
+    pub median: f64,
+    pub q3: f64,
+    pub max: f64,
+    pub n_nan: usize,
+}
+
+impl Summary {
+    /// Names of statistics (same order as `to_vec`)
+    pub const NAMES: [&'static str; 9] = [
+        "count", "mean", "sd", "min", "q1", "median", "q3", "max", "n_nan",
+    ];
+
+    /// Statistics as `Vec<f64>` (same order as `NAMES`)
+    pub fn to_vec(&self) -> Vec<f64> {
+        vec![
+            self.count as f64,
+            self.mean,
+            self.sd,
+            self.min,
+            self.q1,
+            self.median,
+            self.q3,
+            self.max,
+            self.n_nan as f64,
+        ]
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values = self
+            .to_vec()
+            .into_iter()
+            .map(|x| {
+                let st1 = format!("{:.4}", x);
+                let st2 = x.to_string();
+                if st1.len() < st2.len() {
+                    st1
+                } else {
+                    st2
+                }
+            })
+            .collect::<Vec<String>>();
+        let space = values
+            .iter()
+            .map(|v| v.len())
+            .chain(Self::NAMES.iter().map(|n| n.len()))
+            .max()
+            .unwrap()
+            + 1;
+        let header = Self::NAMES
+            .iter()
+            .map(|n| format!("{:>w$}", n, w = space))
+            .collect::<String>();
+        let body = values
+            .iter()
+            .map(|v| format!("{:>w$}", v, w = space))
+            .collect::<String>();
+        write!(f, "{}\n{}", header, body)
+    }
+}
+
+/// Statistical summary
+///
+/// * `Vec<f64>` : `Summary`
+/// * `Matrix` : `Vec<Summary>` (for each column)
+/// * `DataFrame` : `DataFrame` (for each numeric column, rows are statistics)
+pub trait Summarize {
+    type Output;
+    fn summary(&self) -> Self::Output;
+}
+
+impl Summarize for Vec<f64> {
+    type Output = Summary;
+
+    fn summary(&self) -> Summary {
+        let v = self
+            .iter()
+            .cloned()
+            .filter(|x| !x.is_nan())
+            .collect::<Vec<f64>>();
+        let count = v.len();
+        let n_nan = self.len() - count;
+        if count == 0 {
+            let nan = f64::NAN;
+            return Summary {
+                count,
+                mean: nan,
+                sd: nan,
+                min: nan,
+                q1: nan,
+                median: nan,
+                q3: nan,
+                max: nan,
+                n_nan,
+            };
+        }
+        let q = v.quantiles(vec![0f64, 0.25, 0.5, 0.75, 1f64], Type2);
+        Summary {
+            count,
+            mean: v.mean(),
+            sd: if count > 1 { v.sd() } else { f64::NAN },
+            min: q[0],
+            q1: q[1],
+            median: q[2],
+            q3: q[3],
+            max: q[4],
+            n_nan,
+        }
+    }
+}
+
+impl Summarize for Matrix {
+    type Output = Vec<Summary>;
+
+    /// Summary for each column
+    fn summary(&self) -> Vec<Summary> {
+        self.col_iter().map(|c| c.summary()).collect()
+    }
+}
+
+impl Summarize for DataFrame {
+    type Output = DataFrame;
+
+    /// Summary for each numeric column
+    ///
+    /// # Description
+    /// The first column `stat` contains names of statistics (`Summary::NAMES`).
+    /// Non-numeric columns (`Bool`, `Char`, `Str`) are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut df = DataFrame::new(vec![]);
+    /// df.push("x", Series::new(vec![1, 2, 3, 4]));
+    /// df.push("y", Series::new(vec![0.5, 1.5, f64::NAN, 2.5]));
+    /// df.push("z", Series::new(vec!['a', 'b', 'c', 'd']));
+    ///
+    /// let s = df.summary();
+    /// assert_eq!(s.header(), &vec!["stat", "x", "y"]);
+    /// let y: Vec<f64> = s["y"].to_vec();
+    /// assert_eq!(y[0], 3f64); // count
+    /// assert_eq!(y[8], 1f64); // n_nan
+    ///
+    /// let table = concat!(
+    ///     "        stat         x    y\n",
+    ///     " r[0]  count         4    3\n",
+    ///     " r[1]   mean       2.5  1.5\n",
+    ///     " r[2]     sd 1.2910e+0    1\n",
+    ///     " r[3]    min         1  0.5\n",
+    ///     " r[4]     q1       1.5  0.5\n",
+    ///     " r[5] median       2.5  1.5\n",
+    ///     " r[6]     q3       3.5  2.5\n",
+    ///     " r[7]    max         4  2.5\n",
+    ///     " r[8]  n_nan         0    1",
+    /// );
+    /// assert_eq!(s.print_with(4), table);
+    /// ```
+    fn summary(&self) -> DataFrame {
+        let mut df = DataFrame::new(vec![]);
+        df.push(
+            "stat",
+            Series::new(
+                Summary::NAMES
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+        );
+        for (name, series) in self.header().iter().zip(self.data.iter()) {
+            match series.dtype {
+                DType::Bool | DType::Char | DType::Str => continue,
+                _ => {
+                    let v: Vec<f64> = series.to_type(DType::F64).to_vec();
+                    df.push(name, Series::new(v.summary().to_vec()));
+                }
+            }
+        }
+        df
+    }
+}
+
 // =============================================================================
 // Confusion Matrix
 // =============================================================================
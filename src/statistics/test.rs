@@ -0,0 +1,109 @@
+//! Hypothesis tests
+//!
+//! * `t_test_one` : One-sample Student's t-test
+//! * `t_test_two` : Two-sample t-test (Student's or Welch's)
+//!
+//! Both return `(t-statistic, two-sided p-value)`.
+//!
+//! # Examples
+//! ```
+//! use peroxide::fuga::*;
+//!
+//! fn main() {
+//!     let mut rng = stdrng_from_seed(42);
+//!     let a = Normal(0f64, 1f64).sample_with_rng(&mut rng, 100);
+//!     let b = Normal(1f64, 1f64).sample_with_rng(&mut rng, 100);
+//!
+//!     // Same mean : large p-value
+//!     let (_, p) = t_test_one(&a, 0f64);
+//!     assert!(p > 0.01);
+//!
+//!     // Different mean : small p-value
+//!     let (t, p) = t_test_two(&a, &b, false);
+//!     assert!(t < 0f64);
+//!     assert!(p < 0.01);
+//! }
+//! ```
+
+use crate::statistics::dist::{StudentT, RNG};
+use crate::statistics::stat::Statistics;
+
+/// One-sample Student's t-test
+///
+/// # Description
+/// `H0 : mean(sample) = mu0`
+///
+/// * `t = (mean - mu0) / (s / sqrt(n))` with `n - 1` degrees of freedom
+///
+/// # Panics
+/// Fewer than 2 samples
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = vec![5.1, 4.9, 5.3, 5.0, 5.2, 4.8];
+///     let (t, p) = t_test_one(&x, 5f64);
+///     assert!((t - 0.6547).abs() < 1e-4);
+///     assert!((p - 0.5416).abs() < 1e-4);
+/// }
+/// ```
+pub fn t_test_one(sample: &[f64], mu0: f64) -> (f64, f64) {
+    let n = sample.len();
+    assert!(n >= 2, "t-test needs at least 2 samples");
+    let sample = sample.to_vec();
+    let (mean, var) = (sample.mean(), sample.var());
+    let t = (mean - mu0) / (var / n as f64).sqrt();
+    (t, two_sided_p(t, (n - 1) as f64))
+}
+
+/// Two-sample t-test
+///
+/// # Description
+/// `H0 : mean(a) = mean(b)`
+///
+/// * `equal_var = true` : Student's t-test with pooled variance (`n_a + n_b - 2` degrees of freedom)
+/// * `equal_var = false` : Welch's t-test (Welch-Satterthwaite degrees of freedom)
+///
+/// # Panics
+/// Fewer than 2 samples in `a` or `b`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = vec![0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+///     let b = vec![1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+///
+///     // R : t.test(a, b)
+///     let (t, p) = t_test_two(&a, &b, false);
+///     assert!((t + 1.8608).abs() < 1e-4);
+///     assert!((p - 0.07939).abs() < 1e-5);
+/// }
+/// ```
+pub fn t_test_two(a: &[f64], b: &[f64], equal_var: bool) -> (f64, f64) {
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    assert!(a.len() >= 2 && b.len() >= 2, "t-test needs at least 2 samples in each group");
+    let (a, b) = (a.to_vec(), b.to_vec());
+    let (mean_a, var_a) = (a.mean(), a.var());
+    let (mean_b, var_b) = (b.mean(), b.var());
+
+    let (se2, df) = if equal_var {
+        let df = n_a + n_b - 2f64;
+        let pooled = ((n_a - 1f64) * var_a + (n_b - 1f64) * var_b) / df;
+        (pooled * (1f64 / n_a + 1f64 / n_b), df)
+    } else {
+        let (s_a, s_b) = (var_a / n_a, var_b / n_b);
+        let df = (s_a + s_b).powi(2) / (s_a.powi(2) / (n_a - 1f64) + s_b.powi(2) / (n_b - 1f64));
+        (s_a + s_b, df)
+    };
+    let t = (mean_a - mean_b) / se2.sqrt();
+    (t, two_sided_p(t, df))
+}
+
+/// `P(|T| >= |t|)` for Student's t distribution with `df` degrees of freedom
+fn two_sided_p(t: f64, df: f64) -> f64 {
+    2f64 * StudentT(df).cdf(-t.abs())
+}
@@ -9,7 +9,7 @@
 //! ## Implemented Traits for `AD`
 //!
 //! * `#[derive(Debug, Copy, Clone, PartialEq)]`
-//! * `std::fmt::Display`
+//! * `std::fmt::Display`, `std::fmt::LowerExp`
 //! * `IntoIterator<Item = f64>`
 //! * `IntoIterator<Item = &f64>`
 //! * `IntoIterator<Item = &mut f64>`
@@ -124,20 +124,97 @@ pub enum AD {
     AD2(f64, f64, f64),
 }
 
+/// Compare by value (`x`) - derivative parts are ignored
 impl PartialOrd for AD {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.x().partial_cmp(&other.x())
     }
 }
 
+/// Display for AD
+///
+/// # Description
+/// * Precision applies to each component
+/// * Width (with fill & alignment, default is right) applies to the whole
+/// * Alternate flag (`{:#}`) omits the variant name
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = AD1(1.5, -0.2);
+/// assert_eq!(format!("{}", x), "AD1(1.5, -0.2)");
+/// assert_eq!(format!("{:.3}", x), "AD1(1.500, -0.200)");
+/// assert_eq!(format!("{:#.2}", AD2(1.0, 2.0, 3.0)), "(1.00, 2.00, 3.00)");
+/// assert_eq!(format!("{:20.2}", x), "    AD1(1.50, -0.20)");
+/// assert_eq!(format!("{:<16}|", x), "AD1(1.5, -0.2)  |");
+/// assert_eq!(format!("{:*^12.1}", AD0(2.0)), "**AD0(2.0)**");
+/// ```
 impl std::fmt::Display for AD {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = format!("{:?}", self);
-        write!(f, "{}", s)
+        self.fmt_components(f, |x, p| match p {
+            Some(p) => format!("{:.p$}", x, p = p),
+            None => format!("{}", x),
+        })
+    }
+}
+
+/// Scientific notation for AD
+///
+/// # Description
+/// Same flags as `Display`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = AD2(1234.5, 0.01, 0.0);
+/// assert_eq!(format!("{:e}", x), "AD2(1.2345e3, 1e-2, 0e0)");
+/// assert_eq!(format!("{:.2e}", x), "AD2(1.23e3, 1.00e-2, 0.00e0)");
+/// assert_eq!(format!("{:#.1e}", x), "(1.2e3, 1.0e-2, 0.0e0)");
+/// ```
+impl std::fmt::LowerExp for AD {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_components(f, |x, p| match p {
+            Some(p) => format!("{:.p$e}", x, p = p),
+            None => format!("{:e}", x),
+        })
     }
 }
 
 impl AD {
+    /// Format each component (with precision), then pad the whole (with width)
+    fn fmt_components<F>(&self, f: &mut std::fmt::Formatter<'_>, fmt_x: F) -> std::fmt::Result
+    where
+        F: Fn(f64, Option<usize>) -> String,
+    {
+        let body = self
+            .iter()
+            .map(|x| fmt_x(*x, f.precision()))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let s = if f.alternate() {
+            format!("({})", body)
+        } else {
+            format!("AD{}({})", self.order(), body)
+        };
+
+        let len = s.chars().count();
+        match f.width() {
+            Some(w) if w > len => {
+                let pad = w - len;
+                let (left, right) = match f.align() {
+                    Some(std::fmt::Alignment::Left) => (0, pad),
+                    Some(std::fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+                    _ => (pad, 0),
+                };
+                let fill = f.fill().to_string();
+                write!(f, "{}{}{}", fill.repeat(left), s, fill.repeat(right))
+            }
+            _ => f.write_str(&s),
+        }
+    }
+
     pub fn to_order(&self, n: usize) -> Self {
         if n == self.order() {
             return *self;
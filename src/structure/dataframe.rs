@@ -129,13 +129,24 @@
 //!         pub fn drop(&mut self, col_header: &str);
 //!         pub fn row(&self, i: usize) -> DataFrame;
 //!         pub fn spread(&self) -> String;
+//!         pub fn print_with(&self, precision: usize) -> String;
 //!         pub fn as_types(&mut self, dtypes: Vec<DType>);
+//!         pub fn set_attr(&mut self, col_header: &str, key: &str, value: &str);
+//!         pub fn get_attr(&self, col_header: &str, key: &str) -> Option<&str>;
+//!         pub fn set_global_attr(&mut self, key: &str, value: &str);
+//!         pub fn get_global_attr(&self, key: &str) -> Option<&str>;
+//!         pub fn clear_attrs(&mut self);
+//!         pub fn to_matrix(&self) -> LabeledMatrix;
+//!         pub fn from_matrix(m: &LabeledMatrix) -> Self;
 //!     }
 //!     ```
 //!
 //!     * `push(&mut self, name: &str, series: Series)`: push head & Series pair
 //!     * `drop(&mut self, col_header: &str)`: drop specific column by header
 //!     * `row(&self, i: usize) -> DataFrame` : Extract $i$-th row as new DataFrame
+//!     * `set_attr`, `set_global_attr` : Metadata of column or whole frame (e.g. `units`).
+//!       With `nc` feature, they are saved as netcdf variable (global) attributes.
+//!     * `to_matrix`, `from_matrix` : Convert to (from) `LabeledMatrix`. The header is kept as column labels.
 //!
 //! * `WithCSV` trait
 //!
@@ -254,19 +265,102 @@
 //!         Ok(())
 //!     }
 //!     ```
+//!
+//! * `WithJSON` trait
+//!
+//!     ```ignore
+//!     pub trait WithJSON: Sized {
+//!         fn to_json(&self) -> String;
+//!         fn from_json(s: &str) -> Result<Self, Box<dyn Error>>;
+//!     }
+//!     ```
+//!
+//!     * `json` feature should be required
+//!     * `DataFrame` is encoded as an object which maps header to array (order of columns is preserved).
+//!     * Numeric types are read as `F64`, and `Char` is read as `Str`. Thus, explicit type casting is required.
+//!     * `Matrix` also implements `WithJSON` (`row`, `col`, `shape` and `data` are encoded).
+//!
+//!     ```
+//!     #[macro_use]
+//!     extern crate peroxide;
+//!     use peroxide::fuga::*;
+//!
+//!     fn main() -> Result<(), Box<dyn Error>> {
+//!     #    #[cfg(feature = "json")]
+//!     #    {
+//!         let mut df = DataFrame::new(vec![]);
+//!         df.push("a", Series::new(vec!['x', 'y', 'z']));
+//!         df.push("b", Series::new(vec![0, 1, 2]));
+//!         df.push("c", Series::new(c!(0.1, 0.2, 0.3)));
+//!         let s = df.to_json(); // {"a":["x","y","z"],"b":[0,1,2],"c":[0.1,0.2,0.3]}
+//!
+//!         let mut dg = DataFrame::from_json(&s)?;
+//!         dg.as_types(vec![Char, I32, F64]);
+//!
+//!         assert_eq!(df, dg);
+//!     #    }
+//!
+//!         Ok(())
+//!     }
+//!     ```
+//!
+//! * `WithHDF5` trait
+//!
+//!     ```ignore
+//!     pub trait WithHDF5: Sized {
+//!         fn write_hdf5(&self, file_path: &str, name: &str, gzip: Option<u8>) -> Result<(), Box<dyn Error>>;
+//!         fn read_hdf5(file_path: &str, name: &str) -> Result<Self, Box<dyn Error>>;
+//!     }
+//!     ```
+//!
+//!     * `hdf5` feature should be required
+//!     * `libhdf5` dependency should be required
+//!     * Each column is saved as a 1D dataset of the group `name`.
+//!     The order of columns is kept in the `columns` attribute of the group.
+//!     * Column & global attributes are saved as string attributes of datasets and group.
+//!     * `USIZE`, `ISIZE` are saved as `U64`, `I64` and `Char` is saved as `Str`.
+//!     Thus, explicit type casting is required for these types.
+//!     * `gzip` : deflate level (`0 ~ 9`) of chunked datasets (`None` : contiguous, uncompressed)
+//!     * `Matrix` also implements `WithHDF5` (see [`util::hdf5`](../../util/hdf5/index.html))
+//!
+//!     ```
+//!     #[macro_use]
+//!     extern crate peroxide;
+//!     use peroxide::fuga::*;
+//!
+//!     fn main() -> Result<(), Box<dyn Error>> {
+//!     #    #[cfg(feature = "hdf5")]
+//!     #    {
+//!         let mut df = DataFrame::new(vec![]);
+//!         df.push("a", Series::new(vec!['x', 'y', 'z']));
+//!         df.push("b", Series::new(vec![0, 1, 2]));
+//!         df.push("c", Series::new(c!(0.1, 0.2, 0.3)));
+//!         df.write_hdf5("example_data/doc_hdf5.h5", "df", Some(4))?;
+//!
+//!         let mut dg = DataFrame::read_hdf5("example_data/doc_hdf5.h5", "df")?;
+//!         dg["a"].as_type(Char); // Char is only read/written as String type
+//!
+//!         assert_eq!(df, dg);
+//!     #    }
+//!
+//!         Ok(())
+//!     }
+//!     ```
 
 #[cfg(feature="csv")]
 use std::collections::HashMap;
 use std::fmt;
 use std::ops::{Index, IndexMut};
 use std::cmp::{max, min};
-#[cfg(any(feature="csv", feature="nc", feature="parquet"))]
+#[cfg(any(feature="csv", feature="nc", feature="parquet", feature="json", feature="hdf5"))]
 use std::error::Error;
 use crate::util::{
     useful::tab,
     print::LowerExpWithPlus,
 };
 use crate::traits::math::Vector;
+use crate::structure::matrix::{matrix, LabeledMatrix, Shape::Col};
+use crate::statistics::stat::{outlier_mask, OutlierMethod};
 use DType::{
     USIZE,U8,U16,U32,U64,
     ISIZE,I8,I16,I32,I64,
@@ -277,10 +371,18 @@ use DType::{
 use csv::{ReaderBuilder, WriterBuilder};
 #[cfg(feature="nc")]
 use netcdf::{
+    AttrValue,
     types::VariableType,
     variable::{VariableMut, Variable},
     Numeric,
 };
+#[cfg(feature="hdf5")]
+use hdf5::{
+    types::{FloatSize, IntSize, TypeDescriptor, VarLenUnicode},
+    Dataset, H5Type, Location,
+};
+#[cfg(feature="hdf5")]
+use crate::util::hdf5::{h5_dataset, h5_open, h5_open_rw, h5_write, WithHDF5};
 #[cfg(feature="parquet")]
 use arrow2::{
     array::{
@@ -405,10 +507,25 @@ pub enum DTypeValue {
 ///     assert_eq!(df, dg);
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default)]
 pub struct DataFrame {
     pub data: Vec<Series>,
     pub ics: Vec<String>,
+    /// Metadata (not compared by `PartialEq`)
+    pub attrs: DataFrameAttrs,
+}
+
+/// Attributes of `DataFrame` (e.g. `units`, `long_name`)
+///
+/// # Description
+/// Use `set_attr`, `get_attr`, `set_global_attr`, `get_global_attr` of `DataFrame` to access.
+/// For struct literal of `DataFrame`, use `attrs: Default::default()`.
+#[derive(Debug, Clone, Default)]
+pub struct DataFrameAttrs {
+    /// Attributes of each column
+    columns: Vec<Vec<(String, String)>>,
+    /// Attributes of the whole frame
+    global: Vec<(String, String)>,
 }
 
 /// Generic Series
@@ -669,11 +786,11 @@ macro_rules! dtype_match {
 }
 
 macro_rules! set_space {
-    ($elem:expr) => {{
+    ($elem:expr; $p:expr) => {{
         match $elem.dtype {
             F32 => {
                 let elem: f32 = $elem.unwrap();
-                let st1 = elem.fmt_lower_exp(2);
+                let st1 = elem.fmt_lower_exp($p);
                 let st2 = elem.to_string();
 
                 if st1.len() < st2.len() {
@@ -684,7 +801,7 @@ macro_rules! set_space {
             }
             F64 => {
                 let elem: f64 = $elem.unwrap();
-                let st1 = elem.fmt_lower_exp(2);
+                let st1 = elem.fmt_lower_exp($p);
                 let st2 = elem.to_string();
 
                 if st1.len() < st2.len() {
@@ -697,20 +814,20 @@ macro_rules! set_space {
         }
     }};
 
-    ($elem:expr, $space:expr) => {{
+    ($elem:expr, $space:expr; $p:expr) => {{
         match $elem.dtype {
             F32 => {
                 let elem: f32 = $elem.unwrap();
                 $space = max(
                     $space,
-                    min(elem.fmt_lower_exp(2).len(), elem.to_string().len())
+                    min(elem.fmt_lower_exp($p).len(), elem.to_string().len())
                 );
             }
             F64 => {
                 let elem: f64 = $elem.unwrap();
                 $space = max(
                     $space,
-                    min(elem.fmt_lower_exp(2).len(), elem.to_string().len())
+                    min(elem.fmt_lower_exp($p).len(), elem.to_string().len())
                 );
             }
             _ => {
@@ -926,6 +1043,37 @@ fn nc_read_value<T: Numeric + Default + Clone>(val: &Variable, v: Vec<T>) -> Res
     Ok(Series::new(v.clone()))
 }
 
+#[cfg(feature= "hdf5")]
+fn h5type_to_dtype(td: &TypeDescriptor) -> Option<DType> {
+    match td {
+        TypeDescriptor::Integer(IntSize::U1) => Some(I8),
+        TypeDescriptor::Integer(IntSize::U2) => Some(I16),
+        TypeDescriptor::Integer(IntSize::U4) => Some(I32),
+        TypeDescriptor::Integer(IntSize::U8) => Some(I64),
+        TypeDescriptor::Unsigned(IntSize::U1) => Some(U8),
+        TypeDescriptor::Unsigned(IntSize::U2) => Some(U16),
+        TypeDescriptor::Unsigned(IntSize::U4) => Some(U32),
+        TypeDescriptor::Unsigned(IntSize::U8) => Some(U64),
+        TypeDescriptor::Float(FloatSize::U4) => Some(F32),
+        TypeDescriptor::Float(FloatSize::U8) => Some(F64),
+        TypeDescriptor::Boolean => Some(Bool),
+        TypeDescriptor::VarLenUnicode | TypeDescriptor::VarLenAscii => Some(Str),
+        _ => None,
+    }
+}
+
+#[cfg(feature= "hdf5")]
+fn h5_read_value<T: H5Type>(ds: &Dataset, _v: Vec<T>) -> Result<Series, hdf5::Error> where Series: TypedVector<T> {
+    Ok(Series::new(ds.read_raw::<T>()?))
+}
+
+#[cfg(feature= "hdf5")]
+fn h5_to_varlen(v: &[String]) -> Result<Vec<VarLenUnicode>, Box<dyn Error>> {
+    v.iter()
+        .map(|s| s.parse::<VarLenUnicode>().map_err(|e| format!("Can't write {:?} to HDF5 ({})", s, e).into()))
+        .collect()
+}
+
 #[cfg(feature="parquet")]
 fn dtype_to_arrow(dt: DType) -> DataType {
     match dt {
@@ -1371,14 +1519,26 @@ impl fmt::Display for Scalar {
 // Implementation for DataFrame
 // =============================================================================
 
+/// Attributes are metadata, so they are ignored in comparison
+impl PartialEq for DataFrame {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.ics == other.ics
+    }
+}
+
 impl DataFrame {
     /// Declare new DataFrame with `Vec<Series>`
     pub fn new(v: Vec<Series>) -> Self {
         let ics = (0usize .. v.len()).map(|x| x.to_string()).collect();
+        let attrs = DataFrameAttrs {
+            columns: vec![vec![]; v.len()],
+            global: vec![],
+        };
 
         Self {
             data: v,
             ics,
+            attrs,
         }
     }
 
@@ -1403,6 +1563,7 @@ impl DataFrame {
         }
         self.ics.push(name.to_string());
         self.data.push(series);
+        self.attrs.columns.resize(self.data.len(), vec![]);
     }
 
     /// Extract specific row as DataFrame
@@ -1413,10 +1574,30 @@ impl DataFrame {
             let new_series = s.to_series();
             df.push(&self.ics[j], new_series);
         }
+        df.attrs = self.attrs.clone();
         df
     }
 
     pub fn spread(&self) -> String {
+        self.print_with(2)
+    }
+
+    /// Spread DataFrame to formatted String with specific precision
+    ///
+    /// # Description
+    /// Floating point elements are printed in scientific notation with `precision` decimal places
+    /// (or as is, if it is shorter). `spread` (and `Display`) uses `precision = 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut df = DataFrame::new(vec![]);
+    /// df.push("x", Series::new(vec![1f64 / 3f64, 2f64]));
+    /// assert!(df.spread().contains("3.33e-1"));
+    /// assert!(df.print_with(6).contains("3.333333e-1"));
+    /// ```
+    pub fn print_with(&self, precision: usize) -> String {
         let r: usize = self.data.iter().fold(0, |max_len, column| max(max_len, column.len()));
         let h = self.header();
         
@@ -1432,12 +1613,12 @@ impl DataFrame {
                 let mut space = 0usize;
                 for j in 0 .. v.len().min(5) {
                     let elem = v.at(j);
-                    set_space!(elem, space);
+                    set_space!(elem, space; precision);
                 }
                 if v.len() >= r-5 {
                     for j in v.len()-5 .. v.len() {
                         let elem = v.at(j);
-                        set_space!(elem, space);
+                        set_space!(elem, space; precision);
                     }
                 }
                 space = max(space + 1, 5);
@@ -1457,7 +1638,7 @@ impl DataFrame {
                     let space = space_vec[j];
                     if i < v.len() {
                         let elem = v.at(i);
-                        let st = set_space!(elem);
+                        let st = set_space!(elem; precision);
                         result.push_str(&tab(&st, space));
                     }  else { 
                         result.push_str(&tab("", space));      
@@ -1478,7 +1659,7 @@ impl DataFrame {
                     let space = space_vec[j];
                     if i < v.len() {
                         let elem = v.at(i);
-                        let st = set_space!(elem);
+                        let st = set_space!(elem; precision);
                         result.push_str(&tab(&st, space));
                     } else {
                         result.push_str(&tab("", space));
@@ -1500,7 +1681,7 @@ impl DataFrame {
             let mut space = 0usize;
             for j in 0 .. v.len() {
                 let elem = v.at(j);
-                set_space!(elem, space)
+                set_space!(elem, space; precision)
             }
             space = max(space + 1, 5);
             let k = &h[i];
@@ -1519,7 +1700,7 @@ impl DataFrame {
                 let space = space_vec[j];
                 if i < v.len() {
                     let elem = v.at(i);
-                    let st = set_space!(elem);
+                    let st = set_space!(elem; precision);
                     result.push_str(&tab(&st, space));
                 } else {
                     result.push_str(&tab("", space));
@@ -1590,10 +1771,203 @@ impl DataFrame {
             Some(index) => {
                 self.data.remove(index);
                 self.ics.remove(index);
+                if index < self.attrs.columns.len() {
+                    self.attrs.columns.remove(index);
+                }
             }
             None => panic!("Can't drop header '{}'", col_header),
         }
     }
+
+    /// Set attribute of specific column (e.g. `units`, `long_name`)
+    ///
+    /// # Description
+    /// Attributes are kept in insertion order. Setting an existing key overwrites its value.
+    /// With `nc` feature, they are written & read as netcdf variable attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut df = DataFrame::new(vec![]);
+    ///     df.push("t", Series::new(vec![0.0, 0.1, 0.2]));
+    ///     df.set_attr("t", "units", "s");
+    ///     df.set_attr("t", "long_name", "time");
+    ///
+    ///     assert_eq!(df.get_attr("t", "units"), Some("s"));
+    ///     assert_eq!(df.get_attr("t", "standard_name"), None);
+    /// }
+    /// ```
+    pub fn set_attr(&mut self, col_header: &str, key: &str, value: &str) {
+        let index = match self.ics.iter().position(|h| h == col_header) {
+            Some(index) => index,
+            None => panic!("Can't find header '{}'", col_header),
+        };
+        self.attrs.columns.resize(self.data.len(), vec![]);
+        set_pair(&mut self.attrs.columns[index], key, value);
+    }
+
+    /// Get attribute of specific column
+    pub fn get_attr(&self, col_header: &str, key: &str) -> Option<&str> {
+        let index = self.ics.iter().position(|h| h == col_header)?;
+        get_pair(self.attrs.columns.get(index)?, key)
+    }
+
+    /// Set attribute of the whole frame (e.g. `title`, `source`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut df = DataFrame::new(vec![]);
+    ///     df.set_global_attr("title", "Simulation");
+    ///     assert_eq!(df.get_global_attr("title"), Some("Simulation"));
+    /// }
+    /// ```
+    pub fn set_global_attr(&mut self, key: &str, value: &str) {
+        set_pair(&mut self.attrs.global, key, value);
+    }
+
+    /// Get attribute of the whole frame
+    pub fn get_global_attr(&self, key: &str) -> Option<&str> {
+        get_pair(&self.attrs.global, key)
+    }
+
+    /// Remove all attributes (of columns & the whole frame)
+    pub fn clear_attrs(&mut self) {
+        self.attrs = DataFrameAttrs {
+            columns: vec![vec![]; self.data.len()],
+            global: vec![],
+        };
+    }
+
+    /// Convert to `LabeledMatrix` (each column is cast to `F64`)
+    ///
+    /// # Description
+    /// The result is a `Col` matrix whose column labels are the header of `DataFrame`.
+    /// Hence `DataFrame::from_matrix(&df.to_matrix())` recovers the header.
+    /// Use `into_matrix` (or deref) for the plain `Matrix`.
+    ///
+    /// # Panics
+    /// * Columns have different lengths
+    /// * Some column can't be cast to `F64`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut df = DataFrame::new(vec![]);
+    ///     df.push("x", Series::new(c!(1, 2, 3)));
+    ///     df.push("n", Series::new(vec![4i32, 5, 6]));
+    ///
+    ///     let m = df.to_matrix();
+    ///     assert_eq!(m.matrix(), &ml_matrix("1 4;2 5;3 6"));
+    ///     assert_eq!(m.col_labels(), Some(&vec!["x".to_string(), "n".to_string()]));
+    ///
+    ///     let dg = DataFrame::from_matrix(&(m.matrix() * 2f64).into());
+    ///     assert_eq!(dg.header(), &vec!["0".to_string(), "1".to_string()]);
+    ///     assert_eq!(DataFrame::from_matrix(&m).header(), df.header());
+    /// }
+    /// ```
+    pub fn to_matrix(&self) -> LabeledMatrix {
+        let nrow = self.data.first().map_or(0, |s| s.len());
+        assert!(
+            self.data.iter().all(|s| s.len() == nrow),
+            "Columns should have same length to convert DataFrame to Matrix"
+        );
+        let data = self
+            .data
+            .iter()
+            .flat_map(|s| -> Vec<f64> { s.to_type(F64).to_vec() })
+            .collect::<Vec<f64>>();
+        let mut m = LabeledMatrix::new(matrix(data, nrow, self.data.len(), Col));
+        m.set_col_labels(self.ics.clone());
+        m
+    }
+
+    /// Construct `DataFrame` from columns of `LabeledMatrix` (`F64`)
+    ///
+    /// # Description
+    /// Column labels of `m` become the header (`0, 1, ...` if `m` has no column labels).
+    /// Row labels are dropped. A plain `Matrix` converts with `m.into()`.
+    pub fn from_matrix(m: &LabeledMatrix) -> Self {
+        let mut df = DataFrame::new((0..m.col).map(|j| Series::new(m.col(j))).collect());
+        if let Some(labels) = m.col_labels() {
+            df.ics = labels.clone();
+        }
+        df
+    }
+
+    /// Remove rows whose value of `col_header` is an outlier
+    ///
+    /// # Description
+    /// * Outliers are found by `outlier_mask` of column `col_header` (cast to `F64`).
+    /// * Other columns keep elements whose row is not an outlier
+    ///   (rows beyond the length of `col_header` are kept).
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut df = DataFrame::new(vec![]);
+    ///     df.push("x", Series::new(c!(1, 2, 3, 4, 5, 6, 7, 8, 9, 100)));
+    ///     df.push("id", Series::new((0 .. 10).collect::<Vec<i32>>()));
+    ///
+    ///     let dg = df.filter_outliers("x", OutlierMethod::IQR(1.5));
+    ///     assert_eq!(dg["x"], Series::new(c!(1, 2, 3, 4, 5, 6, 7, 8, 9)));
+    ///     assert_eq!(dg["id"], Series::new((0 .. 9).collect::<Vec<i32>>()));
+    /// }
+    /// ```
+    pub fn filter_outliers(&self, col_header: &str, method: OutlierMethod) -> DataFrame {
+        let x: Vec<f64> = self[col_header].to_type(F64).to_vec();
+        let mask = outlier_mask(&x, method);
+        let keep = |i: usize| !mask.get(i).cloned().unwrap_or(false);
+
+        let mut df = self.clone();
+        for series in df.data.iter_mut() {
+            *series = dtype_match!(
+                series.dtype,
+                series.to_vec(),
+                |v| Series::new(select_rows(v, &keep));
+                Vec
+            );
+        }
+        df
+    }
+}
+
+fn select_rows<T>(v: Vec<T>, keep: &dyn Fn(usize) -> bool) -> Vec<T> {
+    v.into_iter()
+        .enumerate()
+        .filter(|&(i, _)| keep(i))
+        .map(|(_, t)| t)
+        .collect()
+}
+
+fn set_pair(pairs: &mut Vec<(String, String)>, key: &str, value: &str) {
+    match pairs.iter_mut().find(|(k, _)| k == key) {
+        Some(pair) => pair.1 = value.to_string(),
+        None => pairs.push((key.to_string(), value.to_string())),
+    }
+}
+
+fn get_pair<'a>(pairs: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    pairs
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
 }
 
 impl Index<&str> for DataFrame {
@@ -1712,6 +2086,10 @@ impl WithNetCDF for DataFrame {
     fn write_nc(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
         let mut f = netcdf::create(file_path)?;
 
+        for (key, value) in self.attrs.global.iter() {
+            f.add_attribute(key, value.as_str())?;
+        }
+
         for (i, h) in self.header().iter().enumerate() {
             let dim_name = format!("{}th col", i);
             let v = &self[h.as_str()];
@@ -1756,6 +2134,13 @@ impl WithNetCDF for DataFrame {
                 }
                 _ => unreachable!()
             }
+            if let Some(attrs) = self.attrs.columns.get(i) {
+                if let Some(mut var) = f.variable_mut(h) {
+                    for (key, value) in attrs.iter() {
+                        var.add_attribute(key, value.as_str())?;
+                    }
+                }
+            }
         }
 
         Ok(())
@@ -1765,6 +2150,7 @@ impl WithNetCDF for DataFrame {
     fn read_nc(file_path: &str) -> Result<Self, Box<dyn Error>> {
         let f = netcdf::open(file_path)?;
         let mut df = DataFrame::new(vec![]);
+        df.attrs.global = nc_read_attrs(f.attributes())?;
         for v in f.variables() {
             let h = v.name();
             if v.vartype().is_string() {
@@ -1778,7 +2164,8 @@ impl WithNetCDF for DataFrame {
                 let series = dtype_match!(N; dtype, vec![], |vec| nc_read_value(&v, vec); Vec)?;
                 df.push(&h, series);
             }
-            
+            let n = df.attrs.columns.len();
+            df.attrs.columns[n - 1] = nc_read_attrs(v.attributes())?;
         }
         Ok(df)
     }
@@ -1811,6 +2198,7 @@ impl WithNetCDF for DataFrame {
     fn read_nc_by_header(file_path: &str, header: Vec<&str>) -> Result<Self, Box<dyn Error>> {
         let f = netcdf::open(file_path)?;
         let mut df = DataFrame::new(vec![]);
+        df.attrs.global = nc_read_attrs(f.attributes())?;
         for h in header {
             let v = match f.variable(h) {
                 Some(val) => val,
@@ -1827,11 +2215,134 @@ impl WithNetCDF for DataFrame {
                 let series = dtype_match!(N; dtype, vec![], |vec| nc_read_value(&v, vec); Vec)?;
                 df.push(&h, series);
             }
+            let n = df.attrs.columns.len();
+            df.attrs.columns[n - 1] = nc_read_attrs(v.attributes())?;
+        }
+        Ok(df)
+    }
+}
+
+/// Read string attributes (other types are ignored)
+#[cfg(feature= "nc")]
+fn nc_read_attrs<'a, I: Iterator<Item = netcdf::Attribute<'a>>>(attrs: I) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let mut result = vec![];
+    for attr in attrs {
+        if let AttrValue::Str(value) = attr.value()? {
+            result.push((attr.name().to_string(), value));
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(feature= "hdf5")]
+impl WithHDF5 for DataFrame {
+    /// Write to `name` group of HDF5 file (one dataset per column)
+    fn write_hdf5(&self, file_path: &str, name: &str, gzip: Option<u8>) -> Result<(), Box<dyn Error>> {
+        let f = h5_open_rw(file_path, name)?;
+        let g = f.create_group(name)?;
+        let columns = h5_to_varlen(&self.ics)?;
+        g.new_attr_builder().with_data(&columns[..]).create("columns")?;
+        h5_write_attrs(&g, &self.attrs.global)?;
+
+        for (i, h) in self.ics.iter().enumerate() {
+            let v = &self.data[i];
+            let shape = [v.len()];
+            let ds = match v.dtype {
+                dtype if dtype.is_numeric() => {
+                    dtype_match!(N; dtype, v.to_vec(), |v: Vec<_>| h5_write(&g, h, &shape, &v, gzip); Vec)?
+                }
+                USIZE => {
+                    let v = v.to_type(U64);
+                    let v_slice: &[u64] = v.as_slice();
+                    h5_write(&g, h, &shape, v_slice, gzip)?
+                }
+                ISIZE => {
+                    let v = v.to_type(I64);
+                    let v_slice: &[i64] = v.as_slice();
+                    h5_write(&g, h, &shape, v_slice, gzip)?
+                }
+                Bool => {
+                    let v_slice: &[bool] = v.as_slice();
+                    h5_write(&g, h, &shape, v_slice, gzip)?
+                }
+                Char => {
+                    let v = v.to_type(Str);
+                    h5_write(&g, h, &shape, &h5_to_varlen(v.as_slice())?, gzip)?
+                }
+                Str => h5_write(&g, h, &shape, &h5_to_varlen(v.as_slice())?, gzip)?,
+                _ => unreachable!(),
+            };
+            if let Some(attrs) = self.attrs.columns.get(i) {
+                h5_write_attrs(&ds, attrs)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read `name` group of HDF5 file to DataFrame
+    fn read_hdf5(file_path: &str, name: &str) -> Result<Self, Box<dyn Error>> {
+        let f = h5_open(file_path)?;
+        let g = f
+            .group(name)
+            .map_err(|e| format!("{}: can't open group '{}' ({})", file_path, name, e))?;
+        let columns = g
+            .attr("columns")
+            .and_then(|a| a.read_raw::<VarLenUnicode>())
+            .map_err(|e| format!("{}: group '{}' has no 'columns' attribute ({})", file_path, name, e))?;
+
+        let mut df = DataFrame::new(vec![]);
+        df.attrs.global = h5_read_attrs(&g)?
+            .into_iter()
+            .filter(|(k, _)| k != "columns")
+            .collect();
+        for h in columns.iter().map(|h| h.as_str()) {
+            let path = format!("{}/{}", name, h);
+            let ds = h5_dataset(&g, file_path, h)?;
+            if ds.ndim() != 1 {
+                return Err(format!("{}: dataset '{}' has rank {} (expected 1)", file_path, path, ds.ndim()).into());
+            }
+            let td = ds.dtype()?.to_descriptor()?;
+            let series = match h5type_to_dtype(&td) {
+                Some(Str) => {
+                    let v = ds.read_raw::<VarLenUnicode>()?;
+                    Series::new(v.iter().map(|s| s.to_string()).collect::<Vec<String>>())
+                }
+                Some(Bool) => Series::new(ds.read_raw::<bool>()?),
+                Some(dtype) => dtype_match!(N; dtype, vec![], |vec| h5_read_value(&ds, vec); Vec)?,
+                None => return Err(format!("{}: dataset '{}' has unsupported dtype {}", file_path, path, td).into()),
+            };
+            df.push(h, series);
+            let n = df.attrs.columns.len();
+            df.attrs.columns[n - 1] = h5_read_attrs(&ds)?;
         }
         Ok(df)
     }
 }
 
+/// Write string attributes
+#[cfg(feature= "hdf5")]
+fn h5_write_attrs(loc: &Location, attrs: &[(String, String)]) -> Result<(), Box<dyn Error>> {
+    for (key, value) in attrs.iter() {
+        let value = h5_to_varlen(std::slice::from_ref(value))?;
+        loc.new_attr::<VarLenUnicode>().create(key.as_str())?.write_scalar(&value[0])?;
+    }
+    Ok(())
+}
+
+/// Read scalar string attributes (other types are ignored)
+#[cfg(feature= "hdf5")]
+fn h5_read_attrs(loc: &Location) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let mut result = vec![];
+    for key in loc.attr_names()? {
+        let attr = loc.attr(&key)?;
+        if attr.is_scalar() && attr.dtype()?.to_descriptor()? == TypeDescriptor::VarLenUnicode {
+            result.push((key, attr.read_scalar::<VarLenUnicode>()?.to_string()));
+        }
+    }
+    Ok(result)
+}
+
 /// To handle parquet format
 #[cfg(feature="parquet")]
 pub trait WithParquet {
@@ -1939,3 +2450,74 @@ impl WithParquet for DataFrame {
     //     todo!()
     // }
 }
+
+/// To handle with JSON format
+#[cfg(feature="json")]
+pub trait WithJSON: Sized {
+    fn to_json(&self) -> String;
+    fn from_json(s: &str) -> Result<Self, Box<dyn Error>>;
+}
+
+#[cfg(feature="json")]
+impl WithJSON for DataFrame {
+    /// Encode DataFrame to JSON (header -> array)
+    fn to_json(&self) -> String {
+        let mut obj = json::JsonValue::new_object();
+        for (h, v) in self.header().iter().zip(self.data.iter()) {
+            let arr: json::JsonValue = match v.dtype {
+                Bool => v.as_slice().iter().map(|&x: &bool| x.into()).collect::<Vec<json::JsonValue>>().into(),
+                Str | Char => v.to_type(Str).as_slice().iter().map(|x: &String| x.as_str().into()).collect::<Vec<json::JsonValue>>().into(),
+                dtype if dtype.is_integer() => v.to_type(I64).as_slice().iter().map(|&x: &i64| x.into()).collect::<Vec<json::JsonValue>>().into(),
+                _ => v.to_type(F64).as_slice().iter().map(|&x: &f64| x.into()).collect::<Vec<json::JsonValue>>().into(),
+            };
+            obj[h.as_str()] = arr;
+        }
+        obj.dump()
+    }
+
+    /// Decode DataFrame from JSON (header -> array)
+    fn from_json(s: &str) -> Result<Self, Box<dyn Error>> {
+        let obj = json::parse(s)?;
+        if !obj.is_object() {
+            return Err("JSON for DataFrame should be an object (header -> array)".into());
+        }
+        let mut df = DataFrame::new(vec![]);
+        for (h, arr) in obj.entries() {
+            if !arr.is_array() {
+                return Err(format!("Column '{}' should be an array", h).into());
+            }
+            let series = match arr.members().next() {
+                None => Series::new(Vec::<f64>::new()),
+                Some(x) if x.is_boolean() => Series::new(
+                    arr.members()
+                        .map(|x| x.as_bool().ok_or_else(|| format!("Column '{}' has non-boolean value", h)))
+                        .collect::<Result<Vec<bool>, String>>()?,
+                ),
+                Some(x) if x.is_string() => Series::new(
+                    arr.members()
+                        .map(|x| x.as_str().map(|t| t.to_string()).ok_or_else(|| format!("Column '{}' has non-string value", h)))
+                        .collect::<Result<Vec<String>, String>>()?,
+                ),
+                Some(_) => Series::new(
+                    arr.members()
+                        .map(|x| json_to_f64(x).ok_or_else(|| format!("Column '{}' has non-numeric value", h)))
+                        .collect::<Result<Vec<f64>, String>>()?,
+                ),
+            };
+            df.push(h, series);
+        }
+        Ok(df)
+    }
+}
+
+/// Exact conversion of JSON number to `f64`
+///
+/// `JsonValue::as_f64` can lose the last bit, so parse the shortest representation instead.
+#[cfg(feature="json")]
+pub(crate) fn json_to_f64(x: &json::JsonValue) -> Option<f64> {
+    if x.is_number() {
+        x.dump().parse::<f64>().ok()
+    } else {
+        None
+    }
+}
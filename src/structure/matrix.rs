@@ -105,6 +105,7 @@
 //! * `row(&self, index: usize) -> Vec<f64>` : Extract specific row as `Vec<f64>`
 //! * `col(&self, index: usize) -> Vec<f64>` : Extract specific column as `Vec<f64>`
 //! * `diag(&self) -> Vec<f64>`: Extract diagonal components as `Vec<f64>`
+//! * `trace(&self) -> f64`: Sum of diagonal components
 //! * `swap(&self, usize, usize, Shape)`: Swap two rows or columns (unsafe function)
 //! * `subs_col(&mut self, usize, Vec<f64>)`: Substitute column with `Vec<f64>`
 //! * `subs_row(&mut self, usize, Vec<f64>)`: Substitute row with `Vec<f64>`
@@ -614,28 +615,40 @@ use lapack::{dgecon, dgeqrf, dgetrf, dgetri, dgetrs, dorgqr, dgesvd, dpotrf};
 use serde::{Deserialize, Serialize};
 
 pub use self::Shape::{Col, Row};
-use crate::numerical::eigen::{eigen, EigenMethod};
 use crate::traits::{
-    general::Algorithm,
     fp::{FPMatrix, FPVector},
-    math::{InnerProduct, LinearOp, MatrixProduct, Norm, Normed, Vector},
+    math::{InnerProduct, LinearOp, MatrixProduct, Norm, Normed, SquareOp, Vector},
     mutable::MutMatrix,
 };
 use crate::util::{
-    low_level::{swap_vec_ptr, copy_vec_ptr},
+    low_level::swap_vec_ptr,
     non_macro::{cbind, eye, rbind, zeros},
     useful::{nearly_eq, tab},
 };
 use crate::structure::dataframe::{Series, TypedVector};
+use crate::structure::vector::ksum;
+#[cfg(feature = "json")]
+use crate::structure::dataframe::{json_to_f64, WithJSON};
 use std::cmp::{max, min};
 pub use std::error::Error;
 use std::fmt;
 use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
 use crate::traits::sugar::ScalableMut;
 use peroxide_num::{ExpLogOps, PowOps, TrigOps, Numeric};
+#[cfg(feature = "parallel")]
+use crate::traits::{fp::ParallelFPMatrix, math::ParallelLinearOp};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 pub type Perms = Vec<(usize, usize)>;
 
+/// Number of leading (trailing) rows & columns to print for large matrix
+const PRINT_EDGE_ITEMS: usize = 10;
+
+/// Below this number of rows, `par_matmul` uses the serial kernel
+#[cfg(feature = "parallel")]
+const PAR_MATMUL_MIN_ROWS: usize = 64;
+
 /// To select matrices' binding.
 ///
 /// Row - Row binding
@@ -820,12 +833,39 @@ impl PartialEq for Matrix {
 impl Matrix {
     /// Raw pointer for `self.data`
     pub fn ptr(&self) -> *const f64 {
-        &self.data[0] as *const f64
+        self.data.as_ptr()
     }
 
     /// Raw mutable pointer for `self.data`
     pub fn mut_ptr(&mut self) -> *mut f64 {
-        &mut self.data[0] as *mut f64
+        self.data.as_mut_ptr()
+    }
+
+    /// Matrix from function of indices
+    ///
+    /// # Description
+    /// `a[(i, j)] = f(i, j)` for `i < r`, `j < c`
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = Matrix::from_fn(2, 3, |i, j| (i * 3 + j) as f64, Col);
+    /// assert_eq!(a, ml_matrix("0 1 2;3 4 5"));
+    /// assert_eq!(a.shape, Col);
+    /// ```
+    pub fn from_fn<F: Fn(usize, usize) -> f64>(r: usize, c: usize, f: F, shape: Shape) -> Matrix {
+        let data = match shape {
+            Row => (0..r)
+                .flat_map(|i| (0..c).map(move |j| (i, j)))
+                .map(|(i, j)| f(i, j))
+                .collect(),
+            Col => (0..c)
+                .flat_map(|j| (0..r).map(move |i| (i, j)))
+                .map(|(i, j)| f(i, j))
+                .collect(),
+        };
+        matrix(data, r, c, shape)
     }
 
     /// Slice of `self.data`
@@ -875,6 +915,13 @@ impl Matrix {
         let r = self.row;
         let c = self.col;
         assert_eq!(r * c, self.data.len());
+        if self.data.is_empty() {
+            let shape = match self.shape {
+                Row => Col,
+                Col => Row,
+            };
+            return matrix(Vec::<f64>::new(), r, c, shape);
+        }
         let l = r * c - 1;
         let mut data: Vec<f64> = self.data.clone();
         let ref_data = &self.data;
@@ -916,6 +963,13 @@ impl Matrix {
         let r = self.row;
         let c = self.col;
         assert_eq!(r * c, self.data.len());
+        if self.data.is_empty() {
+            self.shape = match self.shape {
+                Row => Col,
+                Col => Row,
+            };
+            return;
+        }
         let l = r * c - 1;
         let ref_data = self.data.clone();
 
@@ -953,71 +1007,105 @@ impl Matrix {
     /// // r[1]     2    4
     /// ```
     pub fn spread(&self) -> String {
+        self.print_with(4)
+    }
+
+    /// Spread data to 2D formatted String with specific precision
+    ///
+    /// # Description
+    /// Each element is rounded at `precision` decimal places
+    /// (or printed as is, if it is shorter). `spread` (and `Display`) uses `precision = 4`.
+    ///
+    /// For large matrix (more than 100 rows or columns, or more than 20 of both),
+    /// only the first & last 10 rows (columns) are printed with `...` in between.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = matrix(vec![1f64 / 3f64, 2f64], 1, 2, Row);
+    /// assert!(a.spread().contains("0.3333 "));
+    /// assert!(a.print_with(8).contains("0.33333333 "));
+    /// ```
+    pub fn print_with(&self, precision: usize) -> String {
+        self.print_with_labels(precision, None, None)
+    }
+
+    /// `print_with` with optional row & column labels in place of `r[i]` & `c[j]`
+    fn print_with_labels(
+        &self,
+        precision: usize,
+        row_labels: Option<&[String]>,
+        col_labels: Option<&[String]>,
+    ) -> String {
         assert_eq!(self.row * self.col, self.data.len());
         let r = self.row;
         let c = self.col;
-        let mut key_row = 20usize;
-        let mut key_col = 20usize;
-
-        if r > 100 || c > 100 || (r > 20 && c > 20) {
-            let part = if r <= 10 {
-                key_row = r;
-                key_col = 100;
-                self.take_col(100)
-            } else if c <= 10 {
-                key_row = 100;
-                key_col = c;
-                self.take_row(100)
+
+        // Large matrix : Only first & last `PRINT_EDGE_ITEMS` rows (cols) are printed (like NumPy)
+        let truncate = r > 100 || c > 100 || (r > 20 && c > 20);
+        let indices = |n: usize| -> Vec<Option<usize>> {
+            if truncate && n > 2 * PRINT_EDGE_ITEMS {
+                (0..PRINT_EDGE_ITEMS)
+                    .map(Some)
+                    .chain(std::iter::once(None))
+                    .chain((n - PRINT_EDGE_ITEMS..n).map(Some))
+                    .collect()
             } else {
-                self.take_row(20).take_col(20)
-            };
-            return format!(
-                "Result is too large to print - {}x{}\nonly print {}x{} parts:\n{}",
-                self.row.to_string(),
-                self.col.to_string(),
-                key_row.to_string(),
-                key_col.to_string(),
-                part.spread()
-            );
-        }
+                (0..n).map(Some).collect()
+            }
+        };
+        let rows = indices(r);
+        let cols = indices(c);
+
+        // Choose shorter one of rounded (at `precision` position) & normal string
+        let fmt = |x: f64| {
+            let st1 = format!("{:.p$}", x, p = precision);
+            let st2 = x.to_string();
+            if st1.len() < st2.len() {
+                st1
+            } else {
+                st2
+            }
+        };
+        let label = |prefix: &str, labels: Option<&[String]>, idx: Option<usize>| match (labels, idx) {
+            (Some(l), Some(i)) => l[i].clone(),
+            (None, Some(i)) => format!("{}[{}]", prefix, i),
+            (_, None) => "...".to_string(),
+        };
+        let row_label = |i: Option<usize>| label("r", row_labels, i);
+        let col_label = |j: Option<usize>| label("c", col_labels, j);
 
-        // Find maximum length of data
-        let sample = self.data.clone();
-        let mut space: usize = sample
-            .into_iter()
-            .map(
-                |x| min(format!("{:.4}", x).len(), x.to_string().len()), // Choose minimum of approx vs normal
-            )
-            .fold(0, |x, y| max(x, y))
+        // Find maximum length of printed data
+        let mut space: usize = rows
+            .iter()
+            .flatten()
+            .flat_map(|&i| cols.iter().flatten().map(move |&j| (i, j)))
+            .map(|(i, j)| fmt(self[(i, j)]).len())
+            .fold(0, max)
             + 1;
-
-        if space < 5 {
-            space = 5;
-        }
+        space = max(space, 5);
+        space = max(space, cols.iter().map(|&j| col_label(j).len() + 1).fold(0, max));
+        let label_space = max(5, rows.iter().map(|&i| row_label(i).len()).fold(0, max));
 
         let mut result = String::new();
 
-        result.push_str(&tab("", 5));
-        for i in 0..c {
-            result.push_str(&tab(&format!("c[{}]", i), space)); // Header
+        result.push_str(&tab("", label_space));
+        for &j in cols.iter() {
+            result.push_str(&tab(&col_label(j), space)); // Header
         }
         result.push('\n');
 
-        for i in 0..r {
-            result.push_str(&tab(&format!("r[{}]", i), 5));
-            for j in 0..c {
-                let st1 = format!("{:.4}", self[(i, j)]); // Round at fourth position
-                let st2 = self[(i, j)].to_string(); // Normal string
-                let mut st = st2.clone();
-
-                // Select more small thing
-                if st1.len() < st2.len() {
-                    st = st1;
-                }
-
+        for (k, &i) in rows.iter().enumerate() {
+            result.push_str(&tab(&row_label(i), label_space));
+            for &j in cols.iter() {
+                let st = match (i, j) {
+                    (Some(i), Some(j)) => fmt(self[(i, j)]),
+                    _ => "...".to_string(),
+                };
                 result.push_str(&tab(&st, space));
             }
-            if i == (r - 1) {
+            if k == rows.len() - 1 {
                 break;
             }
             result.push('\n');
@@ -1070,6 +1158,109 @@ impl Matrix {
         container
     }
 
+    /// Iterator over rows
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;3 4");
+    ///     let s = a.row_iter().map(|r| r.sum()).collect::<Vec<f64>>();
+    ///     assert_eq!(s, c!(3,7));
+    /// }
+    /// ```
+    pub fn row_iter(&self) -> impl Iterator<Item = Vec<f64>> + '_ {
+        (0..self.row).map(move |i| self.row(i))
+    }
+
+    /// Iterator over columns
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;3 4");
+    ///     let s = a.col_iter().map(|c| c.sum()).collect::<Vec<f64>>();
+    ///     assert_eq!(s, c!(4,6));
+    /// }
+    /// ```
+    pub fn col_iter(&self) -> impl Iterator<Item = Vec<f64>> + '_ {
+        (0..self.col).map(move |j| self.col(j))
+    }
+
+    /// Iterator over `(i, j, value)` in row-major order
+    ///
+    /// # Description
+    /// The order does not depend on the internal `Shape`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = matrix(vec![1, 2, 3, 4], 2, 2, Col);
+    /// let v = a.iter_indexed().collect::<Vec<_>>();
+    /// assert_eq!(v, vec![(0, 0, 1f64), (0, 1, 3f64), (1, 0, 2f64), (1, 1, 4f64)]);
+    /// ```
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        let c = self.col;
+        (0..self.row * c).map(move |k| (k / c, k % c, self[(k / c, k % c)]))
+    }
+
+    /// Map with index `(i, j)`
+    ///
+    /// # Description
+    /// Same as `fmap`, but `f` takes `(i, j, value)`. The result has the same shape as `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = zeros(2, 3);
+    /// let b = a.fmap_with_index(|i, j, x| x + (10 * i + j) as f64);
+    /// assert_eq!(b, ml_matrix("0 1 2;10 11 12"));
+    /// ```
+    pub fn fmap_with_index<F>(&self, f: F) -> Matrix
+    where
+        F: Fn(usize, usize, f64) -> f64,
+    {
+        let (r, c) = (self.row, self.col);
+        let result = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(k, &x)| match self.shape {
+                Row => f(k / c, k % c, x),
+                Col => f(k % r, k / r, x),
+            })
+            .collect::<Vec<f64>>();
+        matrix(result, r, c, self.shape)
+    }
+
+    /// Apply `f` to each `(i, j, value)` in row-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// let mut trace = 0f64;
+    /// a.for_each_indexed(|i, j, x| if i == j { trace += x });
+    /// assert_eq!(trace, 5f64);
+    /// ```
+    pub fn for_each_indexed<F>(&self, f: F)
+    where
+        F: FnMut(usize, usize, f64),
+    {
+        let mut f = f;
+        self.iter_indexed().for_each(|(i, j, x)| f(i, j, x));
+    }
+
     /// Extract diagonal components
     ///
     /// # Examples
@@ -1095,6 +1286,25 @@ impl Matrix {
         container
     }
 
+    /// Trace (sum of diagonal components)
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// assert_eq!(a.trace(), 5f64);
+    /// ```
+    pub fn trace(&self) -> f64 {
+        assert!(self.is_square(), "Should be square matrix");
+        self.data.iter().step_by(self.row + 1).sum()
+    }
+
+    /// Check whether the matrix is square
+    pub fn is_square(&self) -> bool {
+        self.row == self.col
+    }
+
     /// Transpose
     ///
     /// # Examples
@@ -1438,6 +1648,36 @@ impl Matrix {
         }
     }
 
+    /// Substitute block matrix at specific position
+    ///
+    /// # Description
+    /// Write `block` into `self` so that `block[(0,0)]` lands on `self[(row0, col0)]`.
+    /// The extent is determined by the shape of `block`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut a = ml_matrix("1 2 3;4 5 6;7 8 9");
+    ///     let b = ml_matrix("1 2;3 4");
+    ///     a.set_submat(1, 1, &b);
+    ///     assert_eq!(a, ml_matrix("1 2 3;4 1 2;7 3 4"));
+    /// }
+    /// ```
+    pub fn set_submat(&mut self, row0: usize, col0: usize, block: &Matrix) {
+        assert!(
+            row0 + block.row <= self.row && col0 + block.col <= self.col,
+            "Block does not fit in matrix"
+        );
+        for i in 0..block.row {
+            for j in 0..block.col {
+                self[(row0 + i, col0 + j)] = block[(i, j)];
+            }
+        }
+    }
+
     /// Matrix from series
     ///
     /// # Example
@@ -1457,6 +1697,572 @@ impl Matrix {
         let v: Vec<f64> = series.to_vec();
         matrix(v, row, col, shape)
     }
+
+    /// Check whether the matrix is upper triangular (square & zeros below the diagonal)
+    pub fn is_upper_triangular(&self) -> bool {
+        self.row == self.col && (1..self.row).all(|i| (0..i).all(|j| self[(i, j)] == 0f64))
+    }
+
+    /// Check whether the matrix is lower triangular (square & zeros above the diagonal)
+    pub fn is_lower_triangular(&self) -> bool {
+        self.row == self.col
+            && (0..self.row).all(|i| (i + 1..self.col).all(|j| self[(i, j)] == 0f64))
+    }
+
+    /// Solve `Ux = b` for upper triangular `U`
+    ///
+    /// # Description
+    /// Backward substitution without LU decomposition.
+    /// Useful for repeated solves with a fixed factor (e.g. `R` of `QR`).
+    ///
+    /// # Panics
+    /// * Not upper triangular
+    /// * Zero on the diagonal
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let u = ml_matrix("2 1 -1;0 3 2;0 0 4");
+    ///     let x = u.solve_upper(&c!(3, 13, 8));
+    ///     assert_eq!(x, c!(1, 3, 2));
+    /// }
+    /// ```
+    pub fn solve_upper(&self, b: &Vec<f64>) -> Vec<f64> {
+        assert!(self.is_upper_triangular(), "Matrix is not upper triangular");
+        self.check_triangular_solve(b);
+        self.back_subs(b)
+    }
+
+    /// Solve `Lx = b` for lower triangular `L`
+    ///
+    /// # Description
+    /// Forward substitution without LU decomposition.
+    ///
+    /// # Panics
+    /// * Not lower triangular
+    /// * Zero on the diagonal
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let l = ml_matrix("2 0 0;1 3 0;-1 2 4");
+    ///     let x = l.solve_lower(&c!(2, 10, 13));
+    ///     assert_eq!(x, c!(1, 3, 2));
+    /// }
+    /// ```
+    pub fn solve_lower(&self, b: &Vec<f64>) -> Vec<f64> {
+        assert!(self.is_lower_triangular(), "Matrix is not lower triangular");
+        self.check_triangular_solve(b);
+        self.forward_subs(b)
+    }
+
+    /// Check symmetry with absolute tolerance
+    ///
+    /// # Description
+    /// `LinearAlgebra::is_symmetric` uses `nearly_eq`; this lets callers set the tolerance explicitly.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;2.001 1");
+    ///     assert!(a.is_symmetric_with_tol(1e-2));
+    ///     assert!(!a.is_symmetric_with_tol(1e-4));
+    /// }
+    /// ```
+    pub fn is_symmetric_with_tol(&self, tol: f64) -> bool {
+        self.row == self.col
+            && (0..self.row)
+                .all(|i| (i + 1..self.col).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tol))
+    }
+
+    /// Check positive definiteness
+    ///
+    /// # Description
+    /// Symmetric (up to `1e-12` relative to the largest entry) and Cholesky factorization succeeds
+    /// (every pivot is positive). It does not require the `O3` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("4 2;2 3");
+    ///     let b = ml_matrix("1 2;2 1"); // eigenvalues : 3, -1
+    ///     assert!(a.is_positive_definite());
+    ///     assert!(!b.is_positive_definite());
+    /// }
+    /// ```
+    pub fn is_positive_definite(&self) -> bool {
+        let scale = self.data.iter().fold(0f64, |acc, x| acc.max(x.abs()));
+        if !self.is_symmetric_with_tol(1e-12 * scale) {
+            return false;
+        }
+        let n = self.row;
+        let mut l = vec![0f64; n * n];
+        for j in 0..n {
+            let d = self[(j, j)] - (0..j).map(|k| l[j * n + k].powi(2)).sum::<f64>();
+            if d <= 0f64 || d.is_nan() {
+                return false;
+            }
+            let d = d.sqrt();
+            l[j * n + j] = d;
+            for i in j + 1..n {
+                let s = (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum::<f64>();
+                l[i * n + j] = (self[(i, j)] - s) / d;
+            }
+        }
+        true
+    }
+
+    /// Check whether every off-diagonal entry is within absolute tolerance
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 1e-10;0 2");
+    ///     assert!(a.is_diagonal(1e-8));
+    ///     assert!(!a.is_diagonal(0f64));
+    /// }
+    /// ```
+    pub fn is_diagonal(&self, tol: f64) -> bool {
+        self.is_square()
+            && (0..self.row).all(|i| {
+                (0..self.col).all(|j| i == j || self[(i, j)].abs() <= tol)
+            })
+    }
+
+    /// Numerical rank
+    ///
+    /// # Description
+    /// Number of pivots of QR with column pivoting with `|r_kk| > max(m, n) * eps * |r_00|`
+    /// (same criterion as `lstsq`).
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2 3;2 4 6");
+    ///     assert_eq!(a.rank(), 1);
+    ///     assert_eq!(eye(3).rank(), 3);
+    /// }
+    /// ```
+    pub fn rank(&self) -> usize {
+        let tol = max(self.row, self.col) as f64 * f64::EPSILON;
+        min_norm_lstsq(self, &vec![0f64; self.row], tol, false).1
+    }
+
+    /// Matrix power `A^n` (exponentiation by squaring)
+    ///
+    /// # Description
+    /// * `n = 0` : Identity matrix
+    /// * `n < 0` : `(A^{-1})^{-n}`
+    ///
+    /// Note that `powi` (from `PowOps`) is the element-wise power.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     // Fibonacci numbers
+    ///     let a = ml_matrix("1 1;1 0");
+    ///     assert_eq!(a.matrix_power(10), ml_matrix("89 55;55 34"));
+    ///     assert_eq!(a.matrix_power(0), eye(2));
+    /// }
+    /// ```
+    pub fn matrix_power(&self, n: i32) -> Matrix {
+        assert!(self.is_square(), "Matrix power is only defined for square matrix");
+        if n < 0 {
+            return self.inv().matrix_power_unsigned(n.unsigned_abs());
+        }
+        self.matrix_power_unsigned(n as u32)
+    }
+
+    fn matrix_power_unsigned(&self, n: u32) -> Matrix {
+        let mut result = eye(self.row);
+        let mut base = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = &result * &base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Orthonormal basis of the column space (Modified Gram-Schmidt)
+    ///
+    /// # Description
+    /// Columns are orthogonalized in order; a column whose remaining norm is below
+    /// `1e-12` times its original norm is regarded as linearly dependent and dropped.
+    /// So, for `m x n` matrix of rank `r`, the result is `m x r` matrix (`Col` shape).
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 1;1 0;0 1");
+    ///     let q = a.gram_schmidt();
+    ///     let qtq = &q.t() * &q;
+    ///     assert!(eq_vec(&qtq.data, &eye(2).data, 1e-12));
+    /// }
+    /// ```
+    pub fn gram_schmidt(&self) -> Matrix {
+        let m = self.row;
+        let mut basis: Vec<Vec<f64>> = Vec::with_capacity(self.col.min(m));
+        for j in 0..self.col {
+            let mut v = self.col(j);
+            let norm0 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm0 == 0f64 {
+                continue;
+            }
+            for q in basis.iter() {
+                let r = q.iter().zip(v.iter()).map(|(a, b)| a * b).sum::<f64>();
+                v.iter_mut().zip(q.iter()).for_each(|(x, y)| *x -= r * y);
+            }
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm <= 1e-12 * norm0 {
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x /= norm);
+            basis.push(v);
+        }
+        let r = basis.len();
+        matrix(basis.concat(), m, r, Col)
+    }
+
+    /// Estimate spectral norm (largest singular value) by power iteration on `AᵀA`
+    ///
+    /// # Description
+    /// Iterate `v <- AᵀAv / |AᵀAv|` starting from the row of `A` with the largest norm,
+    /// and stop when the relative change of `σ = |Av|` is below `tol` (or after `max_iter`).
+    /// This is what `norm(Norm::L2)` uses (`tol = 1e-14`, `max_iter = 1000`), so it doesn't need full SVD.
+    ///
+    /// # Accuracy
+    /// * `σ` converges from below, and its error shrinks like `(σ₂/σ₁)^(2k)` after `k` iterations.
+    /// * If `σ₁ ≈ σ₂` the convergence is slow and the result may underestimate `σ₁` by about `tol`-order relative error
+    ///   (when `max_iter` is reached, the error can be larger).
+    /// * If the starting vector is orthogonal to the top right singular vector (rare), it converges to a smaller singular value.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     // singular values : 3, 1
+    ///     let a = ml_matrix("2 1;1 2");
+    ///     assert!((a.opnorm_est(1e-14, 1000) - 3f64).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn opnorm_est(&self, tol: f64, max_iter: usize) -> f64 {
+        let mut v = (0..self.row)
+            .map(|i| self.row(i))
+            .max_by(|a, b| a.norm(Norm::L2).partial_cmp(&b.norm(Norm::L2)).unwrap())
+            .unwrap_or_default();
+        let n0 = v.norm(Norm::L2);
+        if n0 == 0f64 {
+            return 0f64;
+        }
+        v.iter_mut().for_each(|x| *x /= n0);
+
+        let at = self.t();
+        let mut sigma = 0f64;
+        for _ in 0..max_iter {
+            let av = self * &v;
+            let sigma_new = av.norm(Norm::L2);
+            let w = &at * &av;
+            let w_norm = w.norm(Norm::L2);
+            if w_norm == 0f64 {
+                return sigma_new;
+            }
+            v = w.fmap(|x| x / w_norm);
+            if (sigma_new - sigma).abs() <= tol * sigma_new {
+                return sigma_new;
+            }
+            sigma = sigma_new;
+        }
+        sigma
+    }
+
+    /// Estimate operator p-norm (`1 < p < ∞`) by Boyd's power method
+    ///
+    /// # Description
+    /// With `q = p / (p - 1)` and `dual_r(v) = sign(v) |v|^(r-1) / |v|_r^(r-1)`, iterate
+    /// `x <- dual_q(Aᵀ dual_p(Ax))` from the uniform vector until `|z|_q <= zᵀx` (local maximum).
+    ///
+    /// # Accuracy
+    /// * The result is a **lower bound** of `|A|_p` : the iteration may stop at a local maximum.
+    /// * It is exact for e.g. diagonal or rank one matrices.
+    /// * For `p = 1, 2, ∞`, use `norm(Norm::L1)`, `norm(Norm::L2)`, `norm(Norm::LInf)` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("3 0;0 -5");
+    ///     assert!((a.opnorm_estimate(3f64, 1e-14, 1000) - 5f64).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn opnorm_estimate(&self, p: f64, tol: f64, max_iter: usize) -> f64 {
+        assert!(
+            p > 1f64 && p.is_finite(),
+            "opnorm_estimate is only for 1 < p < ∞, the given value was p={}",
+            p
+        );
+        let q = p / (p - 1f64);
+        let dual = |v: &Vec<f64>, r: f64| -> Vec<f64> {
+            let n = v.norm(Norm::Lp(r));
+            v.fmap(|x| x.signum() * (x.abs() / n).powf(r - 1f64))
+        };
+        let at = self.t();
+        let mut x = vec![(self.col as f64).powf(-1f64 / p); self.col];
+        let mut est = 0f64;
+        for _ in 0..max_iter {
+            let y = self * &x;
+            let y_norm = y.norm(Norm::Lp(p));
+            if y_norm == 0f64 {
+                break;
+            }
+            est = est.max(y_norm);
+            let z = &at * &dual(&y, p);
+            let z_norm = z.norm(Norm::Lp(q));
+            if z_norm <= z.dot(&x) * (1f64 + tol) {
+                break;
+            }
+            x = dual(&z, q);
+        }
+        est
+    }
+
+    fn check_triangular_solve(&self, b: &[f64]) {
+        assert_eq!(self.row, b.len(), "Length of b should be equal to row");
+        assert!(
+            self.diag().iter().all(|&d| d != 0f64),
+            "Triangular matrix is singular"
+        );
+    }
+}
+
+#[cfg(feature = "json")]
+impl WithJSON for Matrix {
+    /// Encode Matrix to JSON
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let a = matrix(c!(1,2,3,4,5,6), 2, 3, Col);
+    ///     let s = a.to_json();
+    ///     assert_eq!(s, r#"{"row":2,"col":3,"shape":"Col","data":[1,2,3,4,5,6]}"#);
+    ///
+    ///     let b = Matrix::from_json(&s)?;
+    ///     assert_eq!(b.shape, Col);
+    ///     assert_eq!(a, b);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn to_json(&self) -> String {
+        let mut obj = json::JsonValue::new_object();
+        obj["row"] = self.row.into();
+        obj["col"] = self.col.into();
+        obj["shape"] = match self.shape {
+            Row => "Row",
+            Col => "Col",
+        }
+        .into();
+        obj["data"] = self.data.clone().into();
+        obj.dump()
+    }
+
+    /// Decode Matrix from JSON
+    fn from_json(s: &str) -> Result<Self, Box<dyn Error>> {
+        let obj = json::parse(s)?;
+        let row = obj["row"].as_usize().ok_or("'row' should be an unsigned integer")?;
+        let col = obj["col"].as_usize().ok_or("'col' should be an unsigned integer")?;
+        let shape = match obj["shape"].as_str() {
+            Some("Row") => Row,
+            Some("Col") => Col,
+            _ => return Err("'shape' should be \"Row\" or \"Col\"".into()),
+        };
+        let data = obj["data"]
+            .members()
+            .map(|x| json_to_f64(x).ok_or("'data' should be an array of numbers"))
+            .collect::<Result<Vec<f64>, _>>()?;
+        let len = row
+            .checked_mul(col)
+            .ok_or_else(|| format!("row * col overflows ({} * {})", row, col))?;
+        if data.len() != len {
+            return Err(format!("Length of 'data' ({}) should be row * col ({})", data.len(), len).into());
+        }
+        Ok(matrix(data, row, col, shape))
+    }
+}
+
+// =============================================================================
+// Labeled Matrix
+// =============================================================================
+/// `Matrix` with optional row & column labels (like R's `dimnames`)
+///
+/// # Description
+/// Labels replace `r[i]` & `c[j]` in `spread` (and `Display`).
+/// `transpose` swaps them, `submat` slices them,
+/// and `DataFrame::to_matrix` & `DataFrame::from_matrix` keep the header as column labels.
+///
+/// It dereferences to `Matrix`, so computations (arithmetic, decompositions, ...)
+/// return plain unlabeled `Matrix`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let mut a = LabeledMatrix::new(ml_matrix("1 2;3 4"));
+/// a.set_row_labels(vec!["x".to_string(), "y".to_string()]);
+/// a.set_col_labels(vec!["mean".to_string(), "sd".to_string()]);
+/// assert!(a.spread().contains("mean"));
+/// assert_eq!(a.det(), -2f64);
+///
+/// let b = a.t();
+/// assert_eq!(b.col_labels(), a.row_labels());
+/// assert_eq!(b.row_labels(), a.col_labels());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabeledMatrix {
+    matrix: Matrix,
+    row_labels: Option<Vec<String>>,
+    col_labels: Option<Vec<String>>,
+}
+
+impl LabeledMatrix {
+    /// Wrap `Matrix` without labels
+    pub fn new(matrix: Matrix) -> Self {
+        Self {
+            matrix,
+            row_labels: None,
+            col_labels: None,
+        }
+    }
+
+    /// Set row labels
+    pub fn set_row_labels(&mut self, labels: Vec<String>) -> &mut Self {
+        assert_eq!(labels.len(), self.matrix.row, "Number of row labels should be equal to row");
+        self.row_labels = Some(labels);
+        self
+    }
+
+    /// Set column labels
+    pub fn set_col_labels(&mut self, labels: Vec<String>) -> &mut Self {
+        assert_eq!(labels.len(), self.matrix.col, "Number of column labels should be equal to col");
+        self.col_labels = Some(labels);
+        self
+    }
+
+    /// Row labels (`None` if not set)
+    pub fn row_labels(&self) -> Option<&Vec<String>> {
+        self.row_labels.as_ref()
+    }
+
+    /// Column labels (`None` if not set)
+    pub fn col_labels(&self) -> Option<&Vec<String>> {
+        self.col_labels.as_ref()
+    }
+
+    /// Underlying matrix
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    /// Drop labels & return the underlying matrix
+    pub fn into_matrix(self) -> Matrix {
+        self.matrix
+    }
+
+    /// Transpose (row & column labels are swapped)
+    pub fn transpose(&self) -> Self {
+        Self {
+            matrix: self.matrix.transpose(),
+            row_labels: self.col_labels.clone(),
+            col_labels: self.row_labels.clone(),
+        }
+    }
+
+    /// R-like transpose function
+    pub fn t(&self) -> Self {
+        self.transpose()
+    }
+
+    /// Submatrix with sliced labels (see `Matrix::submat`)
+    pub fn submat(&self, start: (usize, usize), end: (usize, usize)) -> Self {
+        Self {
+            matrix: self.matrix.submat(start, end),
+            row_labels: self.row_labels.as_ref().map(|l| l[start.0..=end.0].to_vec()),
+            col_labels: self.col_labels.as_ref().map(|l| l[start.1..=end.1].to_vec()),
+        }
+    }
+
+    /// Spread to 2D formatted String with labels
+    pub fn spread(&self) -> String {
+        self.print_with(4)
+    }
+
+    /// `Matrix::print_with` with labels
+    pub fn print_with(&self, precision: usize) -> String {
+        self.matrix.print_with_labels(
+            precision,
+            self.row_labels.as_deref(),
+            self.col_labels.as_deref(),
+        )
+    }
+}
+
+impl From<Matrix> for LabeledMatrix {
+    fn from(matrix: Matrix) -> Self {
+        Self::new(matrix)
+    }
+}
+
+impl std::ops::Deref for LabeledMatrix {
+    type Target = Matrix;
+
+    fn deref(&self) -> &Self::Target {
+        &self.matrix
+    }
+}
+
+impl fmt::Display for LabeledMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.spread())
+    }
 }
 
 // =============================================================================
@@ -1485,12 +2291,8 @@ impl Vector for Matrix {
                 matrix(y, self.row, self.col, self.shape)
             }
             _ => {
-                let mut result = matrix(self.data.clone(), self.row, self.col, self.shape);
-                for i in 0..self.row {
-                    for j in 0..self.col {
-                        result[(i, j)] += other[(i, j)];
-                    }
-                }
+                let mut result = self.clone();
+                zip_mut_with(&mut result, other, |x, y| *x += y);
                 result
             }
         }
@@ -1515,12 +2317,8 @@ impl Vector for Matrix {
                 matrix(y, self.row, self.col, self.shape)
             }
             _ => {
-                let mut result = matrix(self.data.clone(), self.row, self.col, self.shape);
-                for i in 0..self.row {
-                    for j in 0..self.col {
-                        result[(i, j)] -= other[(i, j)];
-                    }
-                }
+                let mut result = self.clone();
+                zip_mut_with(&mut result, other, |x, y| *x -= y);
                 result
             }
         }
@@ -1564,55 +2362,45 @@ impl Normed for Matrix {
                 for j in 0..self.col {
                     let mut s_row = 0f64;
                     for i in 0..self.row {
-                        s_row += self[(i, j)].powi(p as i32);
+                        s_row += self[(i, j)].abs().powf(p);
                     }
                     s += s_row.powf(q / p);
                 }
                 s.powf(1f64 / q)
             }
-            Norm::L1 => {
-                let mut m = f64::MIN;
-                match self.shape {
-                    Row => self.change_shape().norm(Norm::L1),
-                    Col => {
-                        for c in 0..self.col {
-                            let s = self.col(c).iter().sum();
-                            if s > m {
-                                m = s;
-                            }
-                        }
-                        m
-                    }
-                }
-            }
-            Norm::LInf => {
-                let mut m = f64::MIN;
-                match self.shape {
-                    Col => self.change_shape().norm(Norm::LInf),
-                    Row => {
-                        for r in 0..self.row {
-                            let s = self.row(r).iter().sum();
-                            if s > m {
-                                m = s;
-                            }
-                        }
-                        m
-                    }
+            Norm::L1 => (0..self.col)
+                .map(|j| self.col(j).iter().map(|x| x.abs()).sum::<f64>())
+                .fold(0f64, f64::max),
+            Norm::LInf => (0..self.row)
+                .map(|i| self.row(i).iter().map(|x| x.abs()).sum::<f64>())
+                .fold(0f64, f64::max),
+            Norm::L2 => self.opnorm_est(1e-14, 1000),
+            Norm::Lp(p) => {
+                if p == 1f64 {
+                    self.norm(Norm::L1)
+                } else if p == 2f64 {
+                    self.norm(Norm::L2)
+                } else if p == f64::INFINITY {
+                    self.norm(Norm::LInf)
+                } else {
+                    panic!(
+                        "Operator lp norm is only computed for p = 1, 2, ∞ (use opnorm_estimate for a lower bound), the given value was p={}",
+                        p
+                    )
                 }
             }
-            Norm::L2 => {
-                let a = &self.t() * self;
-                let eig = eigen(&a, EigenMethod::Jacobi);
-                eig.eigenvalue.norm(Norm::LInf)
-            }
-            Norm::Lp(_) => unimplemented!(),
         }
     }
-    fn normalize(&self, _kind: Norm) -> Self
+    fn normalize(&self, kind: Norm) -> Self
     where
         Self: Sized,
     {
-        unimplemented!()
+        let denom = self.norm(kind);
+        if denom == 0f64 {
+            // Zero matrix can not be normalized
+            return self.clone();
+        }
+        self.fmap(|x| x / denom)
     }
 }
 
@@ -1651,6 +2439,14 @@ impl LinearOp<Vec<f64>, Vec<f64>> for Matrix {
                 }
                 y
             }
+            #[cfg(feature = "simd")]
+            () if self.shape == Row => {
+                assert_eq!(self.col, other.len());
+                self.data
+                    .chunks(self.col)
+                    .map(|r| crate::util::simd::dot(r, other))
+                    .collect()
+            }
             _ => {
                 assert_eq!(self.col, other.len());
                 let mut c = vec![0f64; self.row];
@@ -1661,6 +2457,48 @@ impl LinearOp<Vec<f64>, Vec<f64>> for Matrix {
     }
 }
 
+/// Square matrix as operator for iterative solvers
+impl SquareOp for Matrix {
+    fn size(&self) -> usize {
+        assert_eq!(self.row, self.col, "Should be square matrix");
+        self.row
+    }
+}
+
+/// Matrix as Linear operator for Vector (`parallel` feature)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     # #[cfg(feature = "parallel")] {
+///     let a = ml_matrix("1 2 3;4 5 6");
+///     let b = c!(1, 2, 3);
+///     assert_eq!(a.par_apply(&b), c!(14, 32));
+///     # }
+/// }
+/// ```
+#[cfg(feature = "parallel")]
+impl ParallelLinearOp<Vec<f64>, Vec<f64>> for Matrix {
+    fn par_apply(&self, other: &Vec<f64>) -> Vec<f64> {
+        assert_eq!(self.col, other.len());
+        match self.shape {
+            Row => self
+                .data
+                .par_chunks(self.col)
+                .map(|r| r.iter().zip(other).map(|(x, y)| x * y).sum())
+                .collect(),
+            Col => (0..self.row)
+                .into_par_iter()
+                .map(|i| (0..self.col).map(|j| self[(i, j)] * other[j]).sum())
+                .collect(),
+        }
+    }
+}
+
 impl MatrixProduct for Matrix {
     fn kronecker(&self, other: &Self) -> Self {
         let r1 = self.row;
@@ -1688,15 +2526,8 @@ impl MatrixProduct for Matrix {
         assert_eq!(self.row, other.row);
         assert_eq!(self.col, other.col);
 
-        let r = self.row;
-        let c = self.col;
-
-        let mut m = matrix(vec![0f64; r * c], r, c, self.shape);
-        for i in 0..r {
-            for j in 0..c {
-                m[(i, j)] = self[(i, j)] * other[(i, j)]
-            }
-        }
+        let mut m = self.clone();
+        zip_mut_with(&mut m, other, |x, y| *x *= y);
         m
     }
 }
@@ -1745,7 +2576,7 @@ impl Into<Matrix> for &Vec<f64> {
 impl Add<Matrix> for Matrix {
     type Output = Self;
 
-    fn add(self, other: Self) -> Self {
+    fn add(mut self, other: Self) -> Self {
         assert_eq!(&self.row, &other.row);
         assert_eq!(&self.col, &other.col);
 
@@ -1755,28 +2586,61 @@ impl Add<Matrix> for Matrix {
                 if self.shape != other.shape {
                     return self.add(other.change_shape());
                 }
-                let x = &self.data;
-                let mut y = other.data.clone();
+                let x = &other.data;
                 let n_i32 = x.len() as i32;
                 let a_f64 = 1f64;
                 unsafe {
-                    daxpy(n_i32, a_f64, x, 1, &mut y, 1);
+                    daxpy(n_i32, a_f64, x, 1, &mut self.data, 1);
                 }
-                matrix(y, self.row, self.col, self.shape)
+                self
             }
             _ => {
-                let mut result = matrix(self.data.clone(), self.row, self.col, self.shape);
-                for i in 0..self.row {
-                    for j in 0..self.col {
-                        result[(i, j)] += other[(i, j)];
-                    }
-                }
-                result
+                zip_mut_with(&mut self, &other, |x, y| *x += y);
+                self
             }
         }
     }
 }
 
+/// Element-wise addition of Matrix & &Matrix (reuses the buffer of `self`)
+impl Add<&Matrix> for Matrix {
+    type Output = Self;
+
+    fn add(mut self, other: &Matrix) -> Self {
+        zip_mut_with(&mut self, other, |x, y| *x += y);
+        self
+    }
+}
+
+/// Element-wise addition of &Matrix & Matrix (reuses the buffer of `other` if shapes are equal)
+impl Add<Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, mut other: Matrix) -> Matrix {
+        if self.shape != other.shape {
+            return self.add_vec(&other);
+        }
+        zip_mut_with(&mut other, self, |y, x| *y += x);
+        other
+    }
+}
+
+/// Element-wise addition of &Matrix & &Matrix
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = ml_matrix("1 2;3 4");
+///     let b = matrix(c!(1, 2, 3, 4), 2, 2, Col);
+///     assert_eq!(&a + &b, ml_matrix("2 5;5 8"));
+///     assert_eq!(&a + &b, a.clone() + b.clone());
+///     assert_eq!(a.clone() + &b, &a + b);
+/// }
+/// ```
 impl<'a, 'b> Add<&'b Matrix> for &'a Matrix {
     type Output = Matrix;
 
@@ -1815,7 +2679,11 @@ where
                 }
                 matrix(y, self.row, self.col, self.shape)
             }
-            _ => self.fmap(|x| x + other.into()),
+            _ => {
+                let (mut m, other) = (self, other.into());
+                m.data.iter_mut().for_each(|x| *x += other);
+                m
+            }
         }
     }
 }
@@ -1961,12 +2829,11 @@ impl Neg for Matrix {
                 }
                 matrix(y, self.row, self.col, self.shape)
             }
-            _ => matrix(
-                self.data.into_iter().map(|x: f64| -x).collect::<Vec<f64>>(),
-                self.row,
-                self.col,
-                self.shape,
-            ),
+            _ => {
+                let mut m = self;
+                m.data.iter_mut().for_each(|x| *x = -*x);
+                m
+            }
         }
     }
 }
@@ -1987,16 +2854,7 @@ impl<'a> Neg for &'a Matrix {
                 }
                 matrix(y, self.row, self.col, self.shape)
             }
-            _ => matrix(
-                self.data
-                    .clone()
-                    .into_iter()
-                    .map(|x: f64| -x)
-                    .collect::<Vec<f64>>(),
-                self.row,
-                self.col,
-                self.shape,
-            ),
+            _ => self.fmap(|x| -x),
         }
     }
 }
@@ -2018,7 +2876,7 @@ impl<'a> Neg for &'a Matrix {
 impl Sub<Matrix> for Matrix {
     type Output = Self;
 
-    fn sub(self, other: Self) -> Self {
+    fn sub(mut self, other: Self) -> Self {
         assert_eq!(&self.row, &other.row);
         assert_eq!(&self.col, &other.col);
         match () {
@@ -2028,27 +2886,44 @@ impl Sub<Matrix> for Matrix {
                     return self.sub(other.change_shape());
                 }
                 let x = &other.data;
-                let mut y = self.data.clone();
                 let n_i32 = x.len() as i32;
                 let a_f64 = -1f64;
                 unsafe {
-                    daxpy(n_i32, a_f64, x, 1, &mut y, 1);
+                    daxpy(n_i32, a_f64, x, 1, &mut self.data, 1);
                 }
-                matrix(y, self.row, self.col, self.shape)
+                self
             }
             _ => {
-                let mut result = matrix(self.data.clone(), self.row, self.col, self.shape);
-                for i in 0..self.row {
-                    for j in 0..self.col {
-                        result[(i, j)] -= other[(i, j)];
-                    }
-                }
-                result
+                zip_mut_with(&mut self, &other, |x, y| *x -= y);
+                self
             }
         }
     }
 }
 
+/// Subtraction between Matrix & &Matrix (reuses the buffer of `self`)
+impl Sub<&Matrix> for Matrix {
+    type Output = Self;
+
+    fn sub(mut self, other: &Matrix) -> Self {
+        zip_mut_with(&mut self, other, |x, y| *x -= y);
+        self
+    }
+}
+
+/// Subtraction between &Matrix & Matrix (reuses the buffer of `other` if shapes are equal)
+impl Sub<Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, mut other: Matrix) -> Matrix {
+        if self.shape != other.shape {
+            return self.sub_vec(&other);
+        }
+        zip_mut_with(&mut other, self, |y, x| *y = x - *y);
+        other
+    }
+}
+
 impl<'a, 'b> Sub<&'b Matrix> for &'a Matrix {
     type Output = Matrix;
 
@@ -2076,7 +2951,11 @@ where
                 }
                 matrix(y, self.row, self.col, self.shape)
             }
-            _ => self.fmap(|x| x - other.into()),
+            _ => {
+                let (mut m, other) = (self, other.into());
+                m.data.iter_mut().for_each(|x| *x -= other);
+                m
+            }
         }
     }
 }
@@ -2187,7 +3066,11 @@ impl Mul<f64> for Matrix {
                 }
                 matrix(y, self.row, self.col, self.shape)
             }
-            _ => self.fmap(|x| x * other),
+            _ => {
+                let mut m = self;
+                m.data.iter_mut().for_each(|x| *x *= other);
+                m
+            }
         }
     }
 }
@@ -2216,29 +3099,47 @@ impl Mul<usize> for Matrix {
     }
 }
 
-// impl<'a> Mul<i64> for &'a Matrix {
-//     type Output = Matrix;
-//
-//     fn mul(self, other: i64) -> Self::Output {
-//         self.mul(other as f64)
-//     }
-// }
-//
-// impl<'a> Mul<i32> for &'a Matrix {
-//     type Output = Matrix;
-//
-//     fn mul(self, other: i32) -> Self::Output {
-//         self.mul(other as f64)
-//     }
-// }
-//
-// impl<'a> Mul<usize> for &'a Matrix {
-//     type Output = Matrix;
-//
-//     fn mul(self, other: usize) -> Self::Output {
-//         self.mul(other as f64)
-//     }
-// }
+/// Element-wise multiplication between &Matrix vs f64
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = ml_matrix("1 2;3 4");
+/// assert_eq!(&a * 2f64, ml_matrix("2 4;6 8"));
+/// assert_eq!(&a * 2f64, a.clone() * 2f64);
+/// ```
+impl Mul<f64> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: f64) -> Self::Output {
+        self.mul_scalar(other)
+    }
+}
+
+impl Mul<i64> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: i64) -> Self::Output {
+        self.mul_scalar(other as f64)
+    }
+}
+
+impl Mul<i32> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: i32) -> Self::Output {
+        self.mul_scalar(other as f64)
+    }
+}
+
+impl Mul<usize> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: usize) -> Self::Output {
+        self.mul_scalar(other as f64)
+    }
+}
 
 impl Mul<Matrix> for f64 {
     type Output = Matrix;
@@ -2417,7 +3318,11 @@ impl Div<f64> for Matrix {
                 }
                 matrix(y, self.row, self.col, self.shape)
             }
-            _ => self.fmap(|x| x / other),
+            _ => {
+                let mut m = self;
+                m.data.iter_mut().for_each(|x| *x /= other);
+                m
+            }
         }
     }
 }
@@ -2556,77 +3461,172 @@ impl IndexMut<(usize, usize)> for Matrix {
     }
 }
 
+/// Iterator over elements of `&Matrix` in row-major order
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = matrix(vec![1, 2, 3, 4], 2, 2, Col);
+/// let v = a.into_iter().cloned().collect::<Vec<f64>>();
+/// assert_eq!(v, vec![1f64, 3f64, 2f64, 4f64]);
+///
+/// let mut s = 0f64;
+/// for x in &a {
+///     s += x;
+/// }
+/// assert_eq!(s, 10f64);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MatrixIter<'a> {
+    mat: &'a Matrix,
+    idx: usize,
+}
+
+impl<'a> Iterator for MatrixIter<'a> {
+    type Item = &'a f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.mat.col;
+        if self.idx >= self.mat.row * c {
+            return None;
+        }
+        let k = self.idx;
+        self.idx += 1;
+        Some(&self.mat[(k / c, k % c)])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let l = self.mat.row * self.mat.col - self.idx;
+        (l, Some(l))
+    }
+}
+
+impl<'a> ExactSizeIterator for MatrixIter<'a> {}
+
+impl<'a> IntoIterator for &'a Matrix {
+    type Item = &'a f64;
+    type IntoIter = MatrixIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MatrixIter { mat: self, idx: 0 }
+    }
+}
+
 // =============================================================================
 // Functional Programming Tools (Hand-written)
 // =============================================================================
 
-impl FPMatrix for Matrix {
-    fn take_row(&self, n: usize) -> Self {
-        if n >= self.row {
-            return self.clone();
-        }
-        match self.shape {
-            Row => {
-                let new_data = self
-                    .data
-                    .clone()
-                    .into_iter()
-                    .take(n * self.col)
-                    .collect::<Vec<f64>>();
-                matrix(new_data, n, self.col, Row)
+/// Rows `start..end` of `m` with the same shape
+fn row_range(m: &Matrix, start: usize, end: usize) -> Matrix {
+    let (r, c) = (end - start, m.col);
+    let data = match m.shape {
+        Row => m.data[start * c..end * c].to_vec(),
+        Col => {
+            let mut data = Vec::with_capacity(r * c);
+            for chunk in m.data.chunks_exact(m.row.max(1)) {
+                data.extend_from_slice(&chunk[start..end]);
             }
-            Col => {
-                let mut temp_data: Vec<f64> = Vec::new();
-                for i in 0..n {
-                    temp_data.extend(self.row(i));
-                }
-                matrix(temp_data, n, self.col, Row)
+            data
+        }
+    };
+    matrix(data, r, c, m.shape)
+}
+
+/// Columns `start..end` of `m` with the same shape
+fn col_range(m: &Matrix, start: usize, end: usize) -> Matrix {
+    let (r, c) = (m.row, end - start);
+    let data = match m.shape {
+        Col => m.data[start * r..end * r].to_vec(),
+        Row => {
+            let mut data = Vec::with_capacity(r * c);
+            for chunk in m.data.chunks_exact(m.col.max(1)) {
+                data.extend_from_slice(&chunk[start..end]);
             }
+            data
         }
+    };
+    matrix(data, r, c, m.shape)
+}
+
+impl FPMatrix for Matrix {
+    /// Take the first `n` rows
+    ///
+    /// # Description
+    /// `n` larger than `self.row` is clamped (whole matrix), and `n = 0` gives `0 x col` matrix.
+    /// Only the taken rows are copied with the same shape as `self`:
+    /// a single slice of `data` for `Row` shape, `col` slices for `Col` shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;3 4;5 6");
+    ///     assert_eq!(a.take_row(2), ml_matrix("1 2;3 4"));
+    ///     assert_eq!(a.take_row(5), a);
+    ///     assert_eq!(a.take_row(0).row, 0);
+    /// }
+    /// ```
+    fn take_row(&self, n: usize) -> Self {
+        row_range(self, 0, n.min(self.row))
     }
 
+    /// Take the first `n` columns
+    ///
+    /// # Description
+    /// Same rules as `take_row` (slice of `data` for `Col` shape, `row` slices for `Row` shape).
     fn take_col(&self, n: usize) -> Self {
-        if n >= self.col {
-            return self.clone();
-        }
-        match self.shape {
-            Col => {
-                let new_data = self
-                    .data
-                    .clone()
-                    .into_iter()
-                    .take(n * self.row)
-                    .collect::<Vec<f64>>();
-                matrix(new_data, self.row, n, Col)
-            }
-            Row => {
-                let mut temp_data: Vec<f64> = Vec::new();
-                for i in 0..n {
-                    temp_data.extend(self.col(i));
-                }
-                matrix(temp_data, self.row, n, Col)
-            }
-        }
+        col_range(self, 0, n.min(self.col))
     }
 
+    /// Skip the first `n` rows
+    ///
+    /// # Description
+    /// `n` larger than `self.row` is clamped (`0 x col` matrix).
+    /// Copies the remaining rows like `take_row`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;3 4;5 6");
+    ///     assert_eq!(a.skip_row(1), ml_matrix("3 4;5 6"));
+    ///     assert_eq!(a.skip_row(3).row, 0);
+    /// }
+    /// ```
     fn skip_row(&self, n: usize) -> Self {
-        assert!(n < self.row, "Skip range is larger than row of matrix");
-
-        let mut temp_data: Vec<f64> = Vec::new();
-        for i in n..self.row {
-            temp_data.extend(self.row(i));
-        }
-        matrix(temp_data, self.row - n, self.col, Row)
+        row_range(self, n.min(self.row), self.row)
     }
 
+    /// Skip the first `n` columns
+    ///
+    /// # Description
+    /// Same rules as `skip_row`.
     fn skip_col(&self, n: usize) -> Self {
-        assert!(n < self.col, "Skip range is larger than col of matrix");
+        col_range(self, n.min(self.col), self.col)
+    }
 
-        let mut temp_data: Vec<f64> = Vec::new();
-        for i in n..self.col {
-            temp_data.extend(self.col(i));
-        }
-        matrix(temp_data, self.row, self.col - n, Col)
+    /// Split rows at `at` : `(take_row(at), skip_row(at))`
+    ///
+    /// # Description
+    /// Each part is allocated once with its exact size. `at` larger than `self.row` is clamped.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;3 4;5 6");
+    ///     let (top, bottom) = a.split_row(1);
+    ///     assert_eq!(top, ml_matrix("1 2"));
+    ///     assert_eq!(bottom, ml_matrix("3 4;5 6"));
+    /// }
+    /// ```
+    fn split_row(&self, at: usize) -> (Self, Self) {
+        let at = at.min(self.row);
+        (row_range(self, 0, at), row_range(self, at, self.row))
     }
 
     fn fmap<F>(&self, f: F) -> Matrix
@@ -2727,19 +3727,39 @@ impl FPMatrix for Matrix {
         self.data.iter().fold(init.into(), |x, y| f(x, *y))
     }
 
+    /// Compensated sum of all elements (see `ksum`)
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1e16 1;1 -1e16");
+    /// assert_eq!(a.reduce(0, |x, y| x + y), 0f64);
+    /// assert_eq!(a.ksum(), 2f64);
+    /// ```
+    fn ksum(&self) -> f64 {
+        ksum(&self.data)
+    }
+
     fn zip_with<F>(&self, f: F, other: &Matrix) -> Self
     where
         F: Fn(f64, f64) -> f64,
     {
-        assert_eq!(self.data.len(), other.data.len());
-        let mut a = other.clone();
+        assert!(
+            self.row == other.row && self.col == other.col,
+            "zip_with: dimension mismatch ({}x{} vs {}x{})",
+            self.row,
+            self.col,
+            other.row,
+            other.col
+        );
         if self.shape != other.shape {
-            a = a.change_shape();
+            return self.zip_with(f, &other.change_shape());
         }
         let result = self
             .data
             .iter()
-            .zip(a.data.iter())
+            .zip(other.data.iter())
             .map(|(x, y)| f(*x, *y))
             .collect::<Vec<f64>>();
         matrix(result, self.row, self.col, self.shape)
@@ -2768,6 +3788,88 @@ impl FPMatrix for Matrix {
     }
 }
 
+#[cfg(feature = "parallel")]
+impl ParallelFPMatrix for Matrix {
+    /// Parallel fmap for Matrix
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     # #[cfg(feature = "parallel")] {
+    ///     let a = ml_matrix("1 2;3 4");
+    ///     assert_eq!(a.par_fmap(|x| x + 1f64), ml_matrix("2 3;4 5"));
+    ///     # }
+    /// }
+    /// ```
+    fn par_fmap<F>(&self, f: F) -> Matrix
+    where
+        F: Fn(f64) -> f64 + Sync + Send,
+    {
+        let result = self.data.par_iter().map(|x| f(*x)).collect::<Vec<f64>>();
+        matrix(result, self.row, self.col, self.shape)
+    }
+
+    fn par_reduce<F, T>(&self, init: T, f: F) -> f64
+    where
+        F: Fn(f64, f64) -> f64 + Sync + Send,
+        T: Into<f64> + Copy + Sync + Send,
+    {
+        self.data
+            .par_iter()
+            .fold(|| init.into(), |x, y| f(x, *y))
+            .reduce(|| init.into(), &f)
+    }
+
+    fn par_zip_with<F>(&self, f: F, other: &Matrix) -> Matrix
+    where
+        F: Fn(f64, f64) -> f64 + Sync + Send,
+    {
+        assert!(
+            self.row == other.row && self.col == other.col,
+            "par_zip_with: dimension mismatch ({}x{} vs {}x{})",
+            self.row,
+            self.col,
+            other.row,
+            other.col
+        );
+        let mut a = other.clone();
+        if self.shape != other.shape {
+            a = a.change_shape();
+        }
+        let result = self
+            .data
+            .par_iter()
+            .zip(a.data.par_iter())
+            .map(|(x, y)| f(*x, *y))
+            .collect::<Vec<f64>>();
+        matrix(result, self.row, self.col, self.shape)
+    }
+
+    fn par_col_reduce<F>(&self, f: F) -> Vec<f64>
+    where
+        F: Fn(Vec<f64>) -> f64 + Sync + Send,
+    {
+        (0..self.col)
+            .into_par_iter()
+            .map(|i| f(self.col(i)))
+            .collect()
+    }
+
+    fn par_row_reduce<F>(&self, f: F) -> Vec<f64>
+    where
+        F: Fn(Vec<f64>) -> f64 + Sync + Send,
+    {
+        (0..self.row)
+            .into_par_iter()
+            .map(|i| f(self.row(i)))
+            .collect()
+    }
+}
+
 // =============================================================================
 // Linear Algebra
 // =============================================================================
@@ -2784,6 +3886,17 @@ pub trait LinearAlgebra {
     fn cholesky(&self, uplo: UPLO) -> Matrix;
     fn rref(&self) -> Matrix;
     fn det(&self) -> f64;
+    /// Sign and natural log of the absolute value of determinant
+    ///
+    /// The default implementation uses `det`, so it may overflow or underflow.
+    fn slogdet(&self) -> (f64, f64) {
+        let d = self.det();
+        if d == 0f64 {
+            (0f64, f64::NEG_INFINITY)
+        } else {
+            (d.signum(), d.abs().ln())
+        }
+    }
     fn block(&self) -> (Matrix, Matrix, Matrix, Matrix);
     fn inv(&self) -> Matrix;
     fn pseudo_inv(&self) -> Matrix;
@@ -2792,6 +3905,12 @@ pub trait LinearAlgebra {
     fn is_symmetric(&self) -> bool;
 }
 
+/// Least square solution of `Ax = b`
+pub trait LeastSquares {
+    fn lstsq(&self, b: &Vec<f64>, variance: bool) -> LstsqResult;
+}
+
+#[deprecated(note = "`diag(n)` builds the identity matrix; use `eye(n)` (or `diag_mat(&v)` for a diagonal matrix)")]
 pub fn diag(n: usize) -> Matrix {
     let mut v: Vec<f64> = vec![0f64; n * n];
     for i in 0..n {
@@ -2801,6 +3920,28 @@ pub fn diag(n: usize) -> Matrix {
     matrix(v, n, n, Row)
 }
 
+/// Diagonal matrix from vector
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = diag_mat(&c!(1, 2));
+///     assert_eq!(a, ml_matrix("1 0;0 2"));
+/// }
+/// ```
+pub fn diag_mat(v: &Vec<f64>) -> Matrix {
+    let n = v.len();
+    let mut m = matrix(vec![0f64; n * n], n, n, Row);
+    for (i, x) in v.iter().enumerate() {
+        m.data[i * (n + 1)] = *x;
+    }
+    m
+}
+
 /// Data structure for Complete Pivoting LU decomposition
 ///
 /// # Usage
@@ -2834,25 +3975,111 @@ impl PQLU {
         )
     }
 
-    pub fn det(&self) -> f64 {
-        // sgn of perms
-        let mut sgn_p = 1f64;
-        let mut sgn_q = 1f64;
-        for (i, &j) in self.p.iter().enumerate() {
+    /// Whether the matrix is (numerically) singular
+    ///
+    /// # Description
+    /// A pivot `u_ii` is regarded as zero if `|u_ii| <= n * eps * max_j (|L||U|)_ij`,
+    /// i.e. it is below the rounding error of the entries which produced it.
+    /// Since the tolerance is taken row by row, badly scaled but regular matrices
+    /// (e.g. `diag(1e8, 1e-8, 1)`) are not singular.
+    /// Only `solve`, `inv` use this test. `det` is always the product of pivots.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     assert!(ml_matrix("1 2 3;4 5 6;7 8 9").lu().is_singular());
+    ///     assert!(!ml_matrix("1 1;1 1.0000000001").lu().is_singular());
+    ///     assert!(!diag_mat(&vec![1e8, 1e-8, 1f64]).lu().is_singular());
+    /// }
+    /// ```
+    pub fn is_singular(&self) -> bool {
+        let n = self.u.row;
+        let eps = n as f64 * f64::EPSILON;
+        (0 .. n).any(|i| {
+            let scale = (0 .. n).fold(0f64, |acc, j| {
+                let s = (0 ..= i.min(j)).fold(0f64, |s, k| {
+                    s + self.l[(i, k)].abs() * self.u[(k, j)].abs()
+                });
+                acc.max(s)
+            });
+            self.u[(i, i)].abs() <= eps * scale
+        })
+    }
+
+    fn sgn(&self) -> f64 {
+        let mut sgn = 1f64;
+        for (i, &j) in self.p.iter().enumerate().chain(self.q.iter().enumerate()) {
             if i != j {
-                sgn_p *= -1f64;
+                sgn = -sgn;
             }
         }
-        for (i, &j) in self.q.iter().enumerate() {
-            if i != j {
-                sgn_q *= -1f64;
+        sgn
+    }
+
+    /// Determinant (signed product of pivots)
+    pub fn det(&self) -> f64 {
+        self.u.diag().reduce(1f64, |x, y| x * y) * self.sgn()
+    }
+
+    /// Sign and natural log of the absolute value of determinant
+    ///
+    /// # Description
+    /// Sum `ln|u_ii|` instead of multiplying to avoid overflow & underflow.
+    /// If a pivot is zero, returns `(0, -inf)`.
+    pub fn slogdet(&self) -> (f64, f64) {
+        let mut sgn = self.sgn();
+        let mut logdet = 0f64;
+        for d in self.u.diag() {
+            if d == 0f64 {
+                return (0f64, f64::NEG_INFINITY);
             }
+            sgn *= d.signum();
+            logdet += d.abs().ln();
         }
+        (sgn, logdet)
+    }
 
-        self.u.diag().reduce(1f64, |x, y| x * y) * sgn_p * sgn_q
+    /// Solve `A x = b` with the factors
+    ///
+    /// # Description
+    /// Apply row permutations `p` to `b`, forward substitution with unit lower `L`,
+    /// back substitution with `U`, then apply column permutations `q`.
+    /// Reuse a `PQLU` to solve for many right-hand sides with one factorization.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;3 4");
+    ///     let lu = a.lu();
+    ///     assert_eq!(lu.solve(&c!(5, 11)), c!(1, 2));
+    ///     assert_eq!(lu.solve(&c!(3, 7)), c!(1, 1));
+    /// }
+    /// ```
+    pub fn solve(&self, b: &Vec<f64>) -> Vec<f64> {
+        assert!(!self.is_singular(), "Try solve for Singular matrix");
+        assert_eq!(b.len(), self.l.row, "Length of b should be equal to the number of rows");
+        let mut v = b.clone();
+        for (i, &j) in self.p.iter().enumerate() {
+            v.swap(i, j);
+        }
+        let z = self.l.forward_subs(&v);
+        let mut y = self.u.back_subs(&z);
+        for (i, &j) in self.q.iter().enumerate().rev() {
+            y.swap(i, j);
+        }
+        y
     }
 
     pub fn inv(&self) -> Matrix {
+        assert!(!self.is_singular(), "Singular matrix");
         let (p, q, l, u) = self.extract();
         let mut m = inv_u(u) * inv_l(l);
         // Q = Q1 Q2 Q3 ..
@@ -2871,6 +4098,74 @@ impl PQLU {
     }
 }
 
+/// LU factorization cached for repeated solves
+///
+/// # Description
+/// `Factorized::new(&a)` factorizes `a` once (`a.lu()`, complete pivoting),
+/// then `solve`, `solve_mat`, `det` & `inv` reuse the factors.
+/// It is much cheaper than `a.solve(&b, LU)` for each right-hand side,
+/// and more accurate than multiplying by `a.inv()`.
+///
+/// # Panics
+/// `new` panics if `a` is not square or (numerically) singular.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = ml_matrix("4 1 0;1 4 1;0 1 4");
+///     let f = Factorized::new(&a);
+///     for b in [c!(5, 6, 5), c!(1, 0, 0)] {
+///         let x = f.solve(&b);
+///         assert!(eq_vec(&(&a * &x), &b, 1e-12));
+///     }
+///     assert!(nearly_eq(f.det(), a.det()));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Factorized {
+    lu: PQLU,
+}
+
+impl Factorized {
+    pub fn new(a: &Matrix) -> Self {
+        let lu = a.lu();
+        assert!(!lu.is_singular(), "Try factorize Singular matrix");
+        Factorized { lu }
+    }
+
+    /// LU factors
+    pub fn lu(&self) -> &PQLU {
+        &self.lu
+    }
+
+    /// Solve `A x = b`
+    pub fn solve(&self, b: &Vec<f64>) -> Vec<f64> {
+        self.lu.solve(b)
+    }
+
+    /// Solve `A X = B` column by column
+    pub fn solve_mat(&self, m: &Matrix) -> Matrix {
+        let n = self.lu.u.col;
+        let mut x = matrix(vec![0f64; n * m.col], n, m.col, Col);
+        for (i, x_col) in x.data.chunks_mut(n).enumerate() {
+            x_col.copy_from_slice(&self.lu.solve(&m.col(i)));
+        }
+        x
+    }
+
+    pub fn det(&self) -> f64 {
+        self.lu.det()
+    }
+
+    pub fn inv(&self) -> Matrix {
+        self.lu.inv()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WAZD {
     pub w: Matrix,
@@ -2900,6 +4195,21 @@ impl QR {
     }
 }
 
+/// Result of least square (`lstsq`)
+///
+/// * `x` : Solution (minimum norm solution for rank deficient or underdetermined system)
+/// * `residual` : `|b - Ax|` (L2 norm)
+/// * `rank` : Effective rank of `A`
+/// * `variance` : Variance of each coefficient `s^2 (A^T A)^+_ii` with `s^2 = residual^2 / (m - rank)`
+///   (`None` if it is not requested or `m <= rank`)
+#[derive(Debug, Clone)]
+pub struct LstsqResult {
+    pub x: Vec<f64>,
+    pub residual: f64,
+    pub rank: usize,
+    pub variance: Option<Vec<f64>>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SVD {
     pub s: Vec<f64>,
@@ -3174,8 +4484,12 @@ impl LinearAlgebra for Matrix {
                 let mut q = eye(m);
                 let sub = if m == n { 1 } else { 0 };
                 for i in 0..n - sub {
+                    let x = r.col(i).skip(i);
+                    if x.norm(Norm::L2) == 0f64 {
+                        continue;
+                    }
                     let mut H = eye(m);
-                    let hh = gen_householder(&self.col(i).skip(i));
+                    let hh = gen_householder(&x);
                     for j in i..m {
                         for k in i..m {
                             H[(j, k)] = hh[(j - i, k - i)];
@@ -3328,6 +4642,9 @@ impl LinearAlgebra for Matrix {
 
     /// Determinant
     ///
+    /// # Description
+    /// Closed form for `n <= 4`, otherwise via LU decomposition.
+    ///
     /// # Examples
     /// ```
     /// #[macro_use]
@@ -3341,6 +4658,9 @@ impl LinearAlgebra for Matrix {
     /// ```
     fn det(&self) -> f64 {
         assert_eq!(self.row, self.col);
+        if let Some(d) = small_det(self) {
+            return d;
+        }
         match () {
             #[cfg(feature = "O3")]
             () => {
@@ -3372,6 +4692,63 @@ impl LinearAlgebra for Matrix {
         }
     }
 
+    /// Sign and natural log of the absolute value of determinant
+    ///
+    /// # Description
+    /// `det = sign * exp(logdet)`, but it does not overflow or underflow for large matrices.
+    /// For singular matrix, returns `(0, -inf)`.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = ml_matrix("1 2;3 4");
+    ///     let (sgn, logdet) = a.slogdet();
+    ///     assert_eq!(sgn, -1f64);
+    ///     assert!((logdet - 2f64.ln()).abs() < 1e-12);
+    ///
+    ///     // det overflows, but slogdet does not
+    ///     let b = eye(400) * 10f64;
+    ///     assert_eq!(b.det(), f64::INFINITY);
+    ///     assert!((b.slogdet().1 - 400f64 * 10f64.ln()).abs() < 1e-9);
+    /// }
+    /// ```
+    fn slogdet(&self) -> (f64, f64) {
+        assert_eq!(self.row, self.col);
+        match () {
+            #[cfg(feature = "O3")]
+            () => {
+                let opt_dgrf = lapack_dgetrf(self);
+                match opt_dgrf {
+                    None => (f64::NAN, f64::NAN),
+                    Some(dgrf) => match dgrf.status {
+                        LAPACK_STATUS::Singular => (0f64, f64::NEG_INFINITY),
+                        LAPACK_STATUS::NonSingular => {
+                            let mat = &dgrf.fact_mat;
+                            let ipiv = &dgrf.ipiv;
+                            let mut sgn = 1f64;
+                            let mut logdet = 0f64;
+                            for i in 0..mat.col {
+                                sgn *= mat[(i, i)].signum();
+                                logdet += mat[(i, i)].abs().ln();
+                            }
+                            for (i, &p) in ipiv.iter().enumerate() {
+                                if p - 1 != i as i32 {
+                                    sgn = -sgn;
+                                }
+                            }
+                            (sgn, logdet)
+                        }
+                    },
+                }
+            }
+            _ => self.lu().slogdet(),
+        }
+    }
+
     /// Block Partition
     ///
     /// # Examples
@@ -3433,6 +4810,9 @@ impl LinearAlgebra for Matrix {
 
     /// Inverse of Matrix
     ///
+    /// # Description
+    /// Closed form (adjugate over determinant) for `n <= 4`, otherwise via LU decomposition.
+    ///
     /// # Caution
     ///
     /// `inv` function returns `Option<Matrix>`
@@ -3457,6 +4837,10 @@ impl LinearAlgebra for Matrix {
     /// }
     /// ```
     fn inv(&self) -> Self {
+        assert_eq!(self.row, self.col);
+        if let Some(m) = small_inv(self) {
+            return m;
+        }
         match () {
             #[cfg(feature = "O3")]
             () => {
@@ -3546,16 +4930,7 @@ impl LinearAlgebra for Matrix {
                 }
             }
             #[cfg(not(feature = "O3"))]
-            SolveKind::LU => {
-                let lu = self.lu();
-                let (p, q, l, u) = lu.extract();
-                let mut v = b.clone();
-                v.swap_with_perm(&p.into_iter().enumerate().collect());
-                let z = l.forward_subs(&v);
-                let mut y = u.back_subs(&z);
-                y.swap_with_perm(&q.into_iter().enumerate().rev().collect());
-                y
-            }
+            SolveKind::LU => self.lu().solve(b),
             SolveKind::WAZ => {
                 let wazd = match self.waz(Form::Identity) {
                     None => panic!("Can't solve by WAZ with Singular matrix!"),
@@ -3582,27 +4957,7 @@ impl LinearAlgebra for Matrix {
                 }
             }
             #[cfg(not(feature = "O3"))]
-            SolveKind::LU => {
-                let lu = self.lu();
-                let (p, q, l, u) = lu.extract();
-                let mut x = matrix(vec![0f64; self.col * m.col], self.col, m.col, Col);
-                for i in 0..m.col {
-                    let mut v = m.col(i).clone();
-                    for (r, &s) in p.iter().enumerate() {
-                        v.swap(r, s);
-                    }
-                    let z = l.forward_subs(&v);
-                    let mut y = u.back_subs(&z);
-                    for (r, &s) in q.iter().enumerate() {
-                        y.swap(r, s);
-                    }
-                    unsafe {
-                        let mut c = x.col_mut(i);
-                        copy_vec_ptr(&mut c, &y);
-                    }
-                }
-                x
-            }
+            SolveKind::LU => Factorized::new(self).solve_mat(m),
             SolveKind::WAZ => {
                 let wazd = match self.waz(Form::Identity) {
                     None => panic!("Try solve for Singular matrix"),
@@ -3631,11 +4986,151 @@ impl LinearAlgebra for Matrix {
     }
 }
 
+impl LeastSquares for Matrix {
+    /// Least square solution of `A x = b`
+    ///
+    /// # Description
+    /// Minimize `|b - Ax|` for `m x n` matrix `A`.
+    ///
+    /// * Full column rank (`m >= n`) : Householder QR (`A = QR`, `x = R^{-1} Q^T b`)
+    /// * Rank deficient or underdetermined (`m < n`) : Minimum norm solution (same as pseudo-inverse)
+    ///   via complete orthogonal decomposition (QR with column pivoting, then QR of `R^T`)
+    ///
+    /// The effective rank is the number of pivots with `|r_kk| > max(m, n) * eps * |r_00|`.
+    /// If `variance` is true, the variance of each coefficient is also returned.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     // Fit y = a + b x
+    ///     let a = ml_matrix("1 0;1 1;1 2;1 3");
+    ///     let b = c!(1, 3, 5, 8);
+    ///     let sol = a.lstsq(&b, true);
+    ///     assert!(eq_vec(&sol.x, &c!(0.8, 2.3), 1e-12));
+    ///     assert_eq!(sol.rank, 2);
+    ///     assert!((sol.residual - 0.3f64.sqrt()).abs() < 1e-12);
+    ///
+    ///     // Rank deficient : minimum norm solution
+    ///     let c = ml_matrix("1 1;1 1;1 1");
+    ///     let sol = c.lstsq(&c!(2, 2, 2), false);
+    ///     assert!(eq_vec(&sol.x, &c!(1, 1), 1e-12));
+    ///     assert_eq!(sol.rank, 1);
+    /// }
+    /// ```
+    fn lstsq(&self, b: &Vec<f64>, variance: bool) -> LstsqResult {
+        let (m, n) = (self.row, self.col);
+        assert_eq!(b.len(), m, "Length of b should be same as number of rows");
+        let tol = max(m, n) as f64 * f64::EPSILON;
+
+        let (x, rank, w) = if m >= n {
+            let qr = self.qr();
+            let (q, r) = (qr.q, qr.r);
+            let r_max = (0..n).fold(0f64, |acc, i| acc.max(r[(i, i)].abs()));
+            if r_max > 0f64 && (0..n).all(|i| r[(i, i)].abs() > tol * r_max) {
+                let c = (0..n)
+                    .map(|j| (0..m).map(|i| q[(i, j)] * b[i]).sum())
+                    .collect::<Vec<f64>>();
+                let mut r1 = matrix(vec![0f64; n * n], n, n, Row);
+                for i in 0..n {
+                    for j in i..n {
+                        r1[(i, j)] = r[(i, j)];
+                    }
+                }
+                let x = r1.back_subs(&c);
+                // (A^T A)^{-1} = R^{-1} R^{-T}
+                let w = if variance { Some(inv_u(r1)) } else { None };
+                (x, n, w)
+            } else {
+                min_norm_lstsq(self, b, tol, variance)
+            }
+        } else {
+            min_norm_lstsq(self, b, tol, variance)
+        };
+
+        let ax = self.apply(&x);
+        let residual = b
+            .iter()
+            .zip(ax.iter())
+            .map(|(bi, axi)| (bi - axi).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let variance = match w {
+            Some(w) if m > rank => {
+                let s2 = residual * residual / (m - rank) as f64;
+                Some((0..n).map(|i| s2 * w.row(i).dot(&w.row(i))).collect())
+            }
+            _ => None,
+        };
+
+        LstsqResult {
+            x,
+            residual,
+            rank,
+            variance,
+        }
+    }
+}
+
 #[allow(non_snake_case)]
 pub fn solve(A: &Matrix, b: &Matrix, sk: SolveKind) -> Matrix {
     A.solve_mat(b, sk)
 }
 
+/// Solve tridiagonal system (Thomas algorithm)
+///
+/// # Description
+/// Solve `Ax = b` in `O(n)` where `A` is tridiagonal :
+///
+/// * `lower` : Sub-diagonal `A[i+1][i]` (length `n-1`)
+/// * `diag` : Diagonal `A[i][i]` (length `n`)
+/// * `upper` : Super-diagonal `A[i][i+1]` (length `n-1`)
+///
+/// No pivoting is performed, so `A` should be (e.g. diagonally dominant) stable for Gaussian elimination.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     // [2 -1 0; -1 2 -1; 0 -1 2] x = [1, 0, 1]
+///     let x = tridiag_solve(&c!(-1, -1), &c!(2, 2, 2), &c!(-1, -1), &c!(1, 0, 1));
+///     assert!(eq_vec(&x, &c!(1, 1, 1), 1e-12));
+/// }
+/// ```
+pub fn tridiag_solve(lower: &[f64], diag: &[f64], upper: &[f64], b: &[f64]) -> Vec<f64> {
+    let n = diag.len();
+    assert!(n > 0, "Empty system");
+    assert!(
+        lower.len() == n - 1 && upper.len() == n - 1 && b.len() == n,
+        "Length of lower & upper should be n-1 and b should be n"
+    );
+
+    // Forward sweep
+    let mut c = vec![0f64; n];
+    let mut d = vec![0f64; n];
+    let mut denom = diag[0];
+    assert!(denom != 0f64, "Zero pivot in tridiagonal solve");
+    d[0] = b[0] / denom;
+    for i in 1..n {
+        c[i - 1] = upper[i - 1] / denom;
+        denom = diag[i] - lower[i - 1] * c[i - 1];
+        assert!(denom != 0f64, "Zero pivot in tridiagonal solve");
+        d[i] = (b[i] - lower[i - 1] * d[i - 1]) / denom;
+    }
+
+    // Back substitution
+    for i in (0..n - 1).rev() {
+        d[i] -= c[i] * d[i + 1];
+    }
+    d
+}
+
 impl MutMatrix for Matrix {
     unsafe fn col_mut(&mut self, idx: usize) -> Vec<*mut f64> {
         assert!(idx < self.col, "Index out of range");
@@ -3961,6 +5456,142 @@ pub fn inv_u(u: Matrix) -> Matrix {
     }
 }
 
+/// Closed-form determinant for `n <= 4` (`None` for larger matrices)
+///
+/// Also returns `None` when `|det| <= n ε max|a_ij|^n` : a tiny determinant may come from
+/// a singular matrix or from a badly scaled one (e.g. `diag(1e-8, 1e-8, 1)`).
+/// Callers then fall back to `PQLU`, whose product of pivots does not suffer from cancellation.
+fn small_det(m: &Matrix) -> Option<f64> {
+    let a = |i: usize, j: usize| m[(i, j)];
+    let d = match m.row {
+        1 => a(0, 0),
+        2 => a(0, 0) * a(1, 1) - a(0, 1) * a(1, 0),
+        3 => {
+            a(0, 0) * (a(1, 1) * a(2, 2) - a(1, 2) * a(2, 1))
+                - a(0, 1) * (a(1, 0) * a(2, 2) - a(1, 2) * a(2, 0))
+                + a(0, 2) * (a(1, 0) * a(2, 1) - a(1, 1) * a(2, 0))
+        }
+        4 => {
+            let (s, c) = minors4(m);
+            s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+        }
+        _ => return None,
+    };
+    let n = m.row;
+    let scale = m.data.iter().fold(0f64, |acc, x| acc.max(x.abs()));
+    if d.abs() <= n as f64 * f64::EPSILON * scale.powi(n as i32) {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+/// Closed-form inverse (adjugate over determinant) for `n <= 4`
+///
+/// `None` for larger matrices or whenever `small_det` defers to `PQLU`.
+fn small_inv(m: &Matrix) -> Option<Matrix> {
+    let det = small_det(m)?;
+    let a = |i: usize, j: usize| m[(i, j)];
+    let adj = match m.row {
+        1 => vec![1f64],
+        2 => vec![a(1, 1), -a(0, 1), -a(1, 0), a(0, 0)],
+        3 => vec![
+            a(1, 1) * a(2, 2) - a(1, 2) * a(2, 1),
+            a(0, 2) * a(2, 1) - a(0, 1) * a(2, 2),
+            a(0, 1) * a(1, 2) - a(0, 2) * a(1, 1),
+            a(1, 2) * a(2, 0) - a(1, 0) * a(2, 2),
+            a(0, 0) * a(2, 2) - a(0, 2) * a(2, 0),
+            a(0, 2) * a(1, 0) - a(0, 0) * a(1, 2),
+            a(1, 0) * a(2, 1) - a(1, 1) * a(2, 0),
+            a(0, 1) * a(2, 0) - a(0, 0) * a(2, 1),
+            a(0, 0) * a(1, 1) - a(0, 1) * a(1, 0),
+        ],
+        4 => {
+            let (s, c) = minors4(m);
+            vec![
+                a(1, 1) * c[5] - a(1, 2) * c[4] + a(1, 3) * c[3],
+                -a(0, 1) * c[5] + a(0, 2) * c[4] - a(0, 3) * c[3],
+                a(3, 1) * s[5] - a(3, 2) * s[4] + a(3, 3) * s[3],
+                -a(2, 1) * s[5] + a(2, 2) * s[4] - a(2, 3) * s[3],
+                -a(1, 0) * c[5] + a(1, 2) * c[2] - a(1, 3) * c[1],
+                a(0, 0) * c[5] - a(0, 2) * c[2] + a(0, 3) * c[1],
+                -a(3, 0) * s[5] + a(3, 2) * s[2] - a(3, 3) * s[1],
+                a(2, 0) * s[5] - a(2, 2) * s[2] + a(2, 3) * s[1],
+                a(1, 0) * c[4] - a(1, 1) * c[2] + a(1, 3) * c[0],
+                -a(0, 0) * c[4] + a(0, 1) * c[2] - a(0, 3) * c[0],
+                a(3, 0) * s[4] - a(3, 1) * s[2] + a(3, 3) * s[0],
+                -a(2, 0) * s[4] + a(2, 1) * s[2] - a(2, 3) * s[0],
+                -a(1, 0) * c[3] + a(1, 1) * c[1] - a(1, 2) * c[0],
+                a(0, 0) * c[3] - a(0, 1) * c[1] + a(0, 2) * c[0],
+                -a(3, 0) * s[3] + a(3, 1) * s[1] - a(3, 2) * s[0],
+                a(2, 0) * s[3] - a(2, 1) * s[1] + a(2, 2) * s[0],
+            ]
+        }
+        _ => unreachable!(),
+    };
+    Some(matrix(
+        adj.into_iter().map(|x| x / det).collect(),
+        m.row,
+        m.col,
+        Row,
+    ))
+}
+
+/// 2x2 minors of rows `(0,1)` (`s`) & rows `(2,3)` (`c`) of a 4x4 matrix
+///
+/// Both use column pairs `(0,1), (0,2), (0,3), (1,2), (1,3), (2,3)`.
+/// `c[5-k]` uses the complementary columns of `s[k]`, hence `det = Σ ± s[k] c[5-k]`
+/// (Laplace expansion along the first two rows).
+fn minors4(m: &Matrix) -> ([f64; 6], [f64; 6]) {
+    let a = |i: usize, j: usize| m[(i, j)];
+    let minor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| a(r0, c0) * a(r1, c1) - a(r1, c0) * a(r0, c1);
+    let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+    let mut s = [0f64; 6];
+    let mut c = [0f64; 6];
+    for (k, &(j0, j1)) in pairs.iter().enumerate() {
+        s[k] = minor(0, 1, j0, j1);
+        c[k] = minor(2, 3, j0, j1);
+    }
+    (s, c)
+}
+
+/// Apply `f(&mut a_ij, b_ij)` to every element of `a` in place
+///
+/// # Description
+/// If shapes are equal, the buffers are zipped directly.
+/// Otherwise, `b` is read with transposed strides (no `change_shape` copy).
+fn zip_mut_with<F: Fn(&mut f64, f64)>(a: &mut Matrix, b: &Matrix, f: F) {
+    assert_eq!(a.row, b.row);
+    assert_eq!(a.col, b.col);
+    if a.shape == b.shape {
+        a.data.iter_mut().zip(b.data.iter()).for_each(|(x, &y)| f(x, y));
+        return;
+    }
+    if a.data.is_empty() {
+        return;
+    }
+    let (r, c) = (a.row, a.col);
+    match a.shape {
+        // a : Row, b : Col
+        Row => {
+            for (i, a_row) in a.data.chunks_mut(c).enumerate() {
+                for (j, x) in a_row.iter_mut().enumerate() {
+                    f(x, b.data[i + j * r]);
+                }
+            }
+        }
+        // a : Col, b : Row
+        Col => {
+            for (j, a_col) in a.data.chunks_mut(r).enumerate() {
+                for (i, x) in a_col.iter_mut().enumerate() {
+                    f(x, b.data[i * c + j]);
+                }
+            }
+        }
+    }
+}
+
 /// Matrix multiply back-ends
 fn matmul(a: &Matrix, b: &Matrix) -> Matrix {
     assert_eq!(a.col, b.row);
@@ -3969,6 +5600,82 @@ fn matmul(a: &Matrix, b: &Matrix) -> Matrix {
     c
 }
 
+/// Parallel matrix multiplication (`parallel` feature)
+///
+/// # Description
+/// Rows of the output are split into contiguous blocks (one per thread),
+/// and each block is computed by the same cache-blocked kernel as `*` (`matrixmultiply`).
+/// Hence the result is the same as `&a * &b` up to rounding, and the speed-up grows with the size.
+/// For small matrices (`a.row < 64`), it falls back to the serial kernel.
+///
+/// Inputs can be any shape; the result is always `Row` shaped.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     # #[cfg(feature = "parallel")] {
+///     let a = ml_matrix("1 2 3;4 5 6");
+///     let b = ml_matrix("1 2;3 4;5 6");
+///     assert_eq!(par_matmul(&a, &b), ml_matrix("22 28;49 64"));
+///     # }
+/// }
+/// ```
+#[cfg(feature = "parallel")]
+pub fn par_matmul(a: &Matrix, b: &Matrix) -> Matrix {
+    assert_eq!(a.col, b.row);
+    let (m, k, n) = (a.row, a.col, b.col);
+    let mut c = matrix(vec![0f64; m * n], m, n, Row);
+    if m == 0 || n == 0 {
+        return c;
+    }
+    if m < PAR_MATMUL_MIN_ROWS {
+        gemm(1f64, a, b, 0f64, &mut c);
+        return c;
+    }
+
+    let (rsa, csa) = match a.shape {
+        Row => (a.col as isize, 1isize),
+        Col => (1isize, a.row as isize),
+    };
+    let (rsb, csb) = match b.shape {
+        Row => (b.col as isize, 1isize),
+        Col => (1isize, b.row as isize),
+    };
+    let block = m.div_ceil(rayon::current_num_threads()).max(PAR_MATMUL_MIN_ROWS / 2);
+
+    c.data
+        .par_chunks_mut(block * n)
+        .enumerate()
+        .for_each(|(idx, c_block)| {
+            let r0 = idx * block;
+            let rows = c_block.len() / n;
+            // Safety: rows r0..r0+rows of `a` are in bounds, and blocks of `c` are disjoint
+            unsafe {
+                matrixmultiply::dgemm(
+                    rows,
+                    k,
+                    n,
+                    1f64,
+                    a.data.as_ptr().offset(r0 as isize * rsa),
+                    rsa,
+                    csa,
+                    b.data.as_ptr(),
+                    rsb,
+                    csb,
+                    0f64,
+                    c_block.as_mut_ptr(),
+                    n as isize,
+                    1isize,
+                )
+            }
+        });
+    c
+}
+
 /// GEMM wrapper for Matrixmultiply
 ///
 /// # Examples
@@ -4121,47 +5828,6 @@ pub fn gevm(alpha: f64, a: &Vec<f64>, b: &Matrix, beta: f64, c: &mut Vec<f64>) {
     }
 }
 
-//fn matmul(a: &Matrix, b: &Matrix) -> Matrix {
-//    match (a.row, a.col) {
-//        (p, q) if p <= 100 && q <= 100 => {
-//            let r_self = a.row;
-//            let c_self = a.col;
-//            let new_other = b;
-//            let r_other = new_other.row;
-//            let c_other = new_other.col;
-//
-//            assert_eq!(c_self, r_other);
-//
-//            let r_new = r_self;
-//            let c_new = c_other;
-//
-//            let mut result = matrix(vec![0f64; r_new * c_new], r_new, c_new, a.shape);
-//
-//            for i in 0..r_new {
-//                for j in 0..c_new {
-//                    let mut s = 0f64;
-//                    for k in 0..c_self {
-//                        s += a[(i, k)] * new_other[(k, j)];
-//                    }
-//                    result[(i, j)] = s;
-//                }
-//            }
-//            result
-//        }
-//        _ => {
-//            let (a1, a2, a3, a4) = a.block();
-//            let (b1, b2, b3, b4) = b.block();
-//
-//            let m1 = matmul(&a1, &b1) + matmul(&a2, &b3);
-//            let m2 = matmul(&a1, &b2) + matmul(&a2, &b4);
-//            let m3 = matmul(&a3, &b1) + matmul(&a4, &b3);
-//            let m4 = matmul(&a3, &b2) + matmul(&a4, &b4);
-//
-//            combine(m1, m2, m3, m4)
-//        }
-//    }
-//}
-
 // =============================================================================
 // BLAS & LAPACK Area
 // =============================================================================
@@ -4710,6 +6376,106 @@ pub fn gen_householder(a: &Vec<f64>) -> Matrix {
     H
 }
 
+/// Minimum norm least square solution via complete orthogonal decomposition
+///
+/// # Description
+/// 1. Householder QR with column pivoting : `A P = Q [R11 R12; 0 0]` (`R11` : `rank x rank`)
+/// 2. QR of `[R11 R12]^T = Z T`, so that `A P = Q1 T^T Z^T`
+/// 3. `x = P Z T^{-T} Q1^T b`
+///
+/// Returns `(x, rank, W)` where `W = P Z T^{-T}` (`(A^T A)^+ = W W^T`) if `variance` is true.
+fn min_norm_lstsq(a: &Matrix, b: &[f64], tol: f64, variance: bool) -> (Vec<f64>, usize, Option<Matrix>) {
+    let (m, n) = (a.row, a.col);
+    let mut r = a.clone();
+    let mut c = b.to_vec();
+    let mut perm = (0..n).collect::<Vec<usize>>();
+    let mut rank = 0usize;
+    let mut r_00 = 0f64;
+
+    for k in 0..min(m, n) {
+        // Pivot : column with largest remaining norm
+        let col_norm = |r: &Matrix, j: usize| (k..m).map(|i| r[(i, j)].powi(2)).sum::<f64>();
+        let p = (k..n)
+            .max_by(|&i, &j| col_norm(&r, i).partial_cmp(&col_norm(&r, j)).unwrap())
+            .unwrap();
+        if p != k {
+            perm.swap(k, p);
+            for i in 0..m {
+                let tmp = r[(i, k)];
+                r[(i, k)] = r[(i, p)];
+                r[(i, p)] = tmp;
+            }
+        }
+        let norm = col_norm(&r, k).sqrt();
+        if k == 0 {
+            r_00 = norm;
+        }
+        if norm == 0f64 || norm <= tol * r_00 {
+            break;
+        }
+        rank += 1;
+
+        // Householder reflection : H = I - 2 v v^T / (v^T v)
+        let alpha = if r[(k, k)] > 0f64 { -norm } else { norm };
+        let mut v = (k..m).map(|i| r[(i, k)]).collect::<Vec<f64>>();
+        v[0] -= alpha;
+        let vv = v.dot(&v);
+        for j in k..n {
+            let s = 2f64 * (k..m).map(|i| v[i - k] * r[(i, j)]).sum::<f64>() / vv;
+            for i in k..m {
+                r[(i, j)] -= s * v[i - k];
+            }
+        }
+        let s = 2f64 * (k..m).map(|i| v[i - k] * c[i]).sum::<f64>() / vv;
+        for i in k..m {
+            c[i] -= s * v[i - k];
+        }
+    }
+
+    if rank == 0 {
+        let w = if variance { Some(zeros(n, 1)) } else { None };
+        return (vec![0f64; n], 0, w);
+    }
+
+    // [R11 R12]^T = Z T
+    let mut rt = matrix(vec![0f64; n * rank], n, rank, Row);
+    for i in 0..rank {
+        for j in i..n {
+            rt[(j, i)] = r[(i, j)];
+        }
+    }
+    let qr = rt.qr();
+    let mut t = matrix(vec![0f64; rank * rank], rank, rank, Row);
+    for i in 0..rank {
+        for j in i..rank {
+            t[(i, j)] = qr.r[(i, j)];
+        }
+    }
+    let z1 = qr.q.submat((0, 0), (n - 1, rank - 1));
+
+    // y = Z T^{-T} c1
+    let u = t.t().forward_subs(&c[..rank].to_vec());
+    let y = &z1 * &u;
+    let mut x = vec![0f64; n];
+    for (j, &pj) in perm.iter().enumerate() {
+        x[pj] = y[j];
+    }
+
+    let w = if variance {
+        let zt = &z1 * &inv_u(t).t();
+        let mut w = matrix(vec![0f64; n * rank], n, rank, Row);
+        for (j, &pj) in perm.iter().enumerate() {
+            for k in 0..rank {
+                w[(pj, k)] = zt[(j, k)];
+            }
+        }
+        Some(w)
+    } else {
+        None
+    };
+    (x, rank, w)
+}
+
 /// LU via Gaussian Elimination with Partial Pivoting
 #[allow(dead_code)]
 fn gepp(m: &mut Matrix) -> Vec<usize> {
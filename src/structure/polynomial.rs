@@ -249,6 +249,149 @@ impl Polynomial {
         let remainder = self.coef[self.coef.len() - 1] - d * coef[coef.len() - 1];
         (Self::new(coef), remainder)
     }
+
+    /// Degree of polynomial (after removing leading zeros)
+    pub fn degree(&self) -> usize {
+        self.normalize().coef.len() - 1
+    }
+
+    /// Remove leading zero coefficients
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = poly(c!(0, 0, 1, 2));
+    ///     assert_eq!(a.normalize().coef, c!(1, 2));
+    ///     assert_eq!(poly(c!(0, 0)).normalize().coef, c!(0));
+    /// }
+    /// ```
+    pub fn normalize(&self) -> Self {
+        match self.coef.iter().position(|&x| x != 0f64) {
+            Some(i) => Self::new(self.coef[i..].to_vec()),
+            None => Self::new(vec![0f64]),
+        }
+    }
+
+    /// Division with remainder
+    ///
+    /// # Description
+    /// Returns `(q, r)` such that `self = q * other + r` with `deg(r) < deg(other)`.
+    /// Leading zeros of both polynomials are removed before division.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = poly(c!(1, 0, 0, -1)); // x^3 - 1
+    ///     let b = poly(c!(1, -1));       // x - 1
+    ///     let (q, r) = a.div_rem(&b);
+    ///     assert_eq!(q.coef, c!(1, 1, 1));
+    ///     assert_eq!(r.coef, c!(0));
+    /// }
+    /// ```
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        let a = self.normalize();
+        let b = other.normalize();
+        assert!(
+            b.coef.len() > 1 || b.coef[0] != 0f64,
+            "Division by zero polynomial"
+        );
+        if a.coef.len() < b.coef.len() {
+            return (Self::new(vec![0f64]), a);
+        }
+        let (q, r) = a / b;
+        if r.coef.is_empty() {
+            (q, Self::new(vec![0f64]))
+        } else {
+            (q, r.normalize())
+        }
+    }
+
+    /// Greatest common divisor (monic)
+    ///
+    /// # Description
+    /// Euclidean algorithm. Remainders whose coefficients are all smaller than
+    /// `1e-10` times the largest coefficient of the dividend are treated as zero.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = poly(c!(1, -3, 2)); // (x - 1)(x - 2)
+    ///     let b = poly(c!(1, 2, -3)); // (x - 1)(x + 3)
+    ///     let g = a.gcd(&b);
+    ///     assert!(g.coef.iter().zip(c!(1, -1)).all(|(x, y)| (x - y).abs() < 1e-10));
+    /// }
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.normalize();
+        let mut b = other.normalize();
+        if a.coef.len() < b.coef.len() {
+            std::mem::swap(&mut a, &mut b);
+        }
+        loop {
+            let scale = a.coef.iter().fold(0f64, |m, x| m.max(x.abs()));
+            if b.coef.iter().all(|x| x.abs() <= 1e-10 * scale) {
+                break;
+            }
+            let (_, r) = a.div_rem(&b);
+            a = b;
+            b = r;
+        }
+        let lead = a.coef[0];
+        if lead == 0f64 {
+            a
+        } else {
+            a / lead
+        }
+    }
+
+    /// Complex roots
+    ///
+    /// # Description
+    /// Aberth-Ehrlich method - all roots are refined simultaneously.
+    /// Each root is returned as `(re, im)` and sorted by real part.
+    /// Roots at zero (trailing zero coefficients) are extracted exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = poly(c!(1, 0, 1)); // x^2 + 1
+    ///     let r = a.roots();
+    ///     assert!(r[0].0.abs() < 1e-12 && (r[0].1.abs() - 1f64).abs() < 1e-12);
+    ///     assert!((r[0].1 + r[1].1).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn roots(&self) -> Vec<(f64, f64)> {
+        let p = self.normalize();
+        let mut n = p.coef.len() - 1;
+        let mut roots = Vec::new();
+        // Roots at zero
+        while n > 0 && p.coef[n] == 0f64 {
+            roots.push((0f64, 0f64));
+            n -= 1;
+        }
+        if n > 0 {
+            let a = p.coef[..=n].iter().map(|x| x / p.coef[0]).collect::<Vec<f64>>();
+            roots.extend(aberth(&a));
+        }
+        roots.sort_by(|x, y| x.0.total_cmp(&y.0).then(x.1.total_cmp(&y.1)));
+        roots
+    }
 }
 
 /// Convenient to declare polynomial
@@ -256,6 +399,65 @@ pub fn poly(coef: Vec<f64>) -> Polynomial {
     Polynomial::new(coef)
 }
 
+/// Aberth-Ehrlich iteration for monic polynomial (highest degree first)
+fn aberth(a: &[f64]) -> Vec<(f64, f64)> {
+    let n = a.len() - 1;
+    let cmul = |x: (f64, f64), y: (f64, f64)| (x.0 * y.0 - x.1 * y.1, x.0 * y.1 + x.1 * y.0);
+    let cdiv = |x: (f64, f64), y: (f64, f64)| {
+        let d = y.0 * y.0 + y.1 * y.1;
+        ((x.0 * y.0 + x.1 * y.1) / d, (x.1 * y.0 - x.0 * y.1) / d)
+    };
+    // p(z) & p'(z) by Horner's method
+    let eval = |z: (f64, f64)| {
+        let mut p = (a[0], 0f64);
+        let mut dp = (0f64, 0f64);
+        for c in a.iter().skip(1) {
+            dp = cmul(dp, z);
+            dp = (dp.0 + p.0, dp.1 + p.1);
+            p = cmul(p, z);
+            p = (p.0 + c, p.1);
+        }
+        (p, dp)
+    };
+
+    // Initial guesses on a circle (Cauchy bound)
+    let center = -a[1] / n as f64;
+    let radius = 1f64 + a.iter().skip(1).fold(0f64, |m, x| m.max(x.abs()));
+    let mut z = (0..n)
+        .map(|k| {
+            let theta = 2f64 * std::f64::consts::PI * k as f64 / n as f64 + 0.4;
+            (center + radius * theta.cos(), radius * theta.sin())
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..500 {
+        let mut max_step = 0f64;
+        for k in 0..n {
+            let (p, dp) = eval(z[k]);
+            if p.0 == 0f64 && p.1 == 0f64 {
+                continue;
+            }
+            let ratio = cdiv(p, dp);
+            let mut s = (0f64, 0f64);
+            for j in 0..n {
+                if j != k {
+                    let inv = cdiv((1f64, 0f64), (z[k].0 - z[j].0, z[k].1 - z[j].1));
+                    s = (s.0 + inv.0, s.1 + inv.1);
+                }
+            }
+            let denom = cmul(ratio, s);
+            let w = cdiv(ratio, (1f64 - denom.0, -denom.1));
+            z[k] = (z[k].0 - w.0, z[k].1 - w.1);
+            let step = w.0.hypot(w.1) / (1f64 + z[k].0.hypot(z[k].1));
+            max_step = max_step.max(step);
+        }
+        if max_step < 1e-15 {
+            break;
+        }
+    }
+    z
+}
+
 // =============================================================================
 // std::ops for Polynomial
 // =============================================================================
@@ -495,6 +697,9 @@ pub trait Calculus {
 impl Calculus for Polynomial {
     fn derivative(&self) -> Self {
         let l = self.coef.len() - 1;
+        if l == 0 {
+            return Self::new(vec![0f64]);
+        }
         let mut result = vec![0f64; l];
 
         for i in 0..l {
@@ -617,3 +822,305 @@ pub fn chebyshev_polynomial(n: usize, kind: SpecialKind) -> Polynomial {
         }
     }
 }
+
+// =============================================================================
+// Orthogonal Polynomial Series
+// =============================================================================
+/// Chebyshev series on `[a, b]`
+///
+/// # Description
+/// $$ f(x) \approx \sum_{k=0}^{n-1} c_k T_k(y), \quad y = \frac{2x - a - b}{b - a} $$
+///
+/// Evaluation uses Clenshaw's recurrence, so it stays accurate at high degree
+/// where the expansion to monomial form (`Polynomial`) breaks down.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let cs = ChebyshevSeries::fit(|x: f64| x.exp(), (-1f64, 1f64), 15);
+///     assert!((cs.eval(0.5) - 0.5f64.exp()).abs() < 1e-14);
+///
+///     // Derivative & integral operate on the coefficients directly
+///     let d = cs.derivative();
+///     assert!((d.eval(0.5) - 0.5f64.exp()).abs() < 1e-12);
+///     assert!((cs.integrate((0, 1)) - (1f64.exp() - 1f64)).abs() < 1e-14);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChebyshevSeries {
+    pub coef: Vec<f64>,
+    pub domain: (f64, f64),
+}
+
+impl ChebyshevSeries {
+    /// Create Chebyshev series from coefficients
+    pub fn new(coef: Vec<f64>, domain: (f64, f64)) -> Self {
+        assert!(!coef.is_empty(), "Coefficients should not be empty");
+        assert!(domain.0 < domain.1, "Domain should be (a, b) with a < b");
+        Self { coef, domain }
+    }
+
+    /// Fit `n` coefficients by interpolation at Chebyshev nodes (discrete cosine transform)
+    pub fn fit<F: Fn(f64) -> f64>(f: F, domain: (f64, f64), n: usize) -> Self {
+        assert!(n > 0, "Number of coefficients should be positive");
+        let (a, b) = domain;
+        let (bma, bpa) = (0.5 * (b - a), 0.5 * (b + a));
+        let theta = (0..n)
+            .map(|k| std::f64::consts::PI * (k as f64 + 0.5) / n as f64)
+            .collect::<Vec<f64>>();
+        let fx = theta
+            .iter()
+            .map(|t| f(t.cos() * bma + bpa))
+            .collect::<Vec<f64>>();
+        let mut coef = (0..n)
+            .map(|j| {
+                2f64 / n as f64
+                    * fx
+                        .iter()
+                        .zip(theta.iter())
+                        .map(|(y, t)| y * (j as f64 * t).cos())
+                        .sum::<f64>()
+            })
+            .collect::<Vec<f64>>();
+        coef[0] /= 2f64;
+        Self::new(coef, domain)
+    }
+
+    /// Evaluate by Clenshaw's recurrence
+    pub fn eval<T: Into<f64>>(&self, x: T) -> f64 {
+        let (a, b) = self.domain;
+        let y = (2f64 * x.into() - a - b) / (b - a);
+        let (mut b1, mut b2) = (0f64, 0f64);
+        for c in self.coef.iter().skip(1).rev() {
+            let t = c + 2f64 * y * b1 - b2;
+            b2 = b1;
+            b1 = t;
+        }
+        self.coef[0] + y * b1 - b2
+    }
+
+    pub fn eval_vec(&self, v: &[f64]) -> Vec<f64> {
+        v.iter().map(|&x| self.eval(x)).collect()
+    }
+
+    /// Remove trailing coefficients whose magnitude is not larger than `tol`
+    pub fn trim(&self, tol: f64) -> Self {
+        let l = self
+            .coef
+            .iter()
+            .rposition(|x| x.abs() > tol)
+            .map_or(1, |i| i + 1);
+        Self::new(self.coef[..l].to_vec(), self.domain)
+    }
+
+    /// Expand to monomial form
+    pub fn to_polynomial(&self) -> Polynomial {
+        let (a, b) = self.domain;
+        let y = poly(vec![2f64 / (b - a), -(a + b) / (b - a)]);
+        let (mut b1, mut b2) = (poly(vec![0f64]), poly(vec![0f64]));
+        for &c in self.coef.iter().skip(1).rev() {
+            let t = 2f64 * y.clone() * b1.clone() - b2 + c;
+            b2 = b1;
+            b1 = t;
+        }
+        (y * b1 - b2 + self.coef[0]).normalize()
+    }
+}
+
+impl Calculus for ChebyshevSeries {
+    fn derivative(&self) -> Self {
+        let n = self.coef.len();
+        if n == 1 {
+            return Self::new(vec![0f64], self.domain);
+        }
+        // d_{k-1} = d_{k+1} + 2k c_k
+        let mut d = vec![0f64; n + 1];
+        for k in (1..n).rev() {
+            d[k - 1] = d[k + 1] + 2f64 * k as f64 * self.coef[k];
+        }
+        d[0] /= 2f64;
+        d.truncate(n - 1);
+        let scale = 2f64 / (self.domain.1 - self.domain.0);
+        Self::new(d.into_iter().map(|x| x * scale).collect(), self.domain)
+    }
+
+    /// Antiderivative which vanishes at the left end of the domain
+    fn integral(&self) -> Self {
+        let n = self.coef.len();
+        let c = |k: usize| match k {
+            0 => 2f64 * self.coef[0],
+            k if k < n => self.coef[k],
+            _ => 0f64,
+        };
+        let scale = 0.5 * (self.domain.1 - self.domain.0);
+        let mut coef = vec![0f64; n + 1];
+        for (k, ck) in coef.iter_mut().enumerate().skip(1) {
+            *ck = scale * (c(k - 1) - c(k + 1)) / (2f64 * k as f64);
+        }
+        let mut result = Self::new(coef, self.domain);
+        result.coef[0] = -result.eval(self.domain.0);
+        result
+    }
+
+    fn integrate<T: Into<f64> + Copy>(&self, interval: (T, T)) -> f64 {
+        let integral = self.integral();
+        integral.eval(interval.1) - integral.eval(interval.0)
+    }
+}
+
+/// Legendre series on `[a, b]`
+///
+/// # Description
+/// $$ f(x) \approx \sum_{k=0}^{n-1} c_k P_k(y), \quad y = \frac{2x - a - b}{b - a} $$
+///
+/// Coefficients are obtained by Gauss-Legendre quadrature and evaluation uses Clenshaw's recurrence.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let ls = LegendreSeries::fit(|x: f64| x.sin(), (0f64, 2f64), 20);
+///     assert!((ls.eval(1.3) - 1.3f64.sin()).abs() < 1e-14);
+///     assert!((ls.integrate((0, 2)) - (1f64 - 2f64.cos())).abs() < 1e-14);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LegendreSeries {
+    pub coef: Vec<f64>,
+    pub domain: (f64, f64),
+}
+
+impl LegendreSeries {
+    /// Create Legendre series from coefficients
+    pub fn new(coef: Vec<f64>, domain: (f64, f64)) -> Self {
+        assert!(!coef.is_empty(), "Coefficients should not be empty");
+        assert!(domain.0 < domain.1, "Domain should be (a, b) with a < b");
+        Self { coef, domain }
+    }
+
+    /// Fit `n` coefficients by `n`-point Gauss-Legendre quadrature
+    pub fn fit<F: Fn(f64) -> f64>(f: F, domain: (f64, f64), n: usize) -> Self {
+        assert!(n > 0, "Number of coefficients should be positive");
+        let (a, b) = domain;
+        let (bma, bpa) = (0.5 * (b - a), 0.5 * (b + a));
+        let (nodes, weights) = crate::numerical::integral::gauss_legendre_nodes(n);
+        let mut coef = vec![0f64; n];
+        for (y, w) in nodes.iter().zip(weights.iter()) {
+            let fw = f(y * bma + bpa) * w;
+            // P_k(y) by three-term recurrence
+            let (mut p0, mut p1) = (1f64, *y);
+            for (k, c) in coef.iter_mut().enumerate() {
+                let pk = if k == 0 { p0 } else { p1 };
+                *c += fw * pk;
+                if k > 0 {
+                    let kf = k as f64;
+                    let p2 = ((2f64 * kf + 1f64) * y * p1 - kf * p0) / (kf + 1f64);
+                    p0 = p1;
+                    p1 = p2;
+                }
+            }
+        }
+        for (k, c) in coef.iter_mut().enumerate() {
+            *c *= (2f64 * k as f64 + 1f64) / 2f64;
+        }
+        Self::new(coef, domain)
+    }
+
+    /// Evaluate by Clenshaw's recurrence
+    pub fn eval<T: Into<f64>>(&self, x: T) -> f64 {
+        let (a, b) = self.domain;
+        let y = (2f64 * x.into() - a - b) / (b - a);
+        // P_{k+1} = alpha_k P_k + beta_k P_{k-1}
+        let (mut b1, mut b2) = (0f64, 0f64);
+        for (k, c) in self.coef.iter().enumerate().rev() {
+            let kf = k as f64;
+            let alpha = (2f64 * kf + 1f64) * y / (kf + 1f64);
+            let beta = -(kf + 1f64) / (kf + 2f64);
+            let t = c + alpha * b1 + beta * b2;
+            b2 = b1;
+            b1 = t;
+        }
+        b1
+    }
+
+    pub fn eval_vec(&self, v: &[f64]) -> Vec<f64> {
+        v.iter().map(|&x| self.eval(x)).collect()
+    }
+
+    /// Remove trailing coefficients whose magnitude is not larger than `tol`
+    pub fn trim(&self, tol: f64) -> Self {
+        let l = self
+            .coef
+            .iter()
+            .rposition(|x| x.abs() > tol)
+            .map_or(1, |i| i + 1);
+        Self::new(self.coef[..l].to_vec(), self.domain)
+    }
+
+    /// Expand to monomial form
+    pub fn to_polynomial(&self) -> Polynomial {
+        let (a, b) = self.domain;
+        let y = poly(vec![2f64 / (b - a), -(a + b) / (b - a)]);
+        let (mut b1, mut b2) = (poly(vec![0f64]), poly(vec![0f64]));
+        for (k, &c) in self.coef.iter().enumerate().rev() {
+            let kf = k as f64;
+            let alpha = (2f64 * kf + 1f64) / (kf + 1f64);
+            let beta = -(kf + 1f64) / (kf + 2f64);
+            let t = alpha * y.clone() * b1.clone() + beta * b2 + c;
+            b2 = b1;
+            b1 = t;
+        }
+        b1.normalize()
+    }
+}
+
+impl Calculus for LegendreSeries {
+    /// Use `P'_{k+1} - P'_{k-1} = (2k + 1) P_k`
+    fn derivative(&self) -> Self {
+        let n = self.coef.len();
+        if n == 1 {
+            return Self::new(vec![0f64], self.domain);
+        }
+        let scale = 2f64 / (self.domain.1 - self.domain.0);
+        let coef = (0..n - 1)
+            .map(|k| {
+                let s = (k + 1..n).step_by(2).map(|j| self.coef[j]).sum::<f64>();
+                scale * (2f64 * k as f64 + 1f64) * s
+            })
+            .collect();
+        Self::new(coef, self.domain)
+    }
+
+    /// Antiderivative which vanishes at the left end of the domain
+    ///
+    /// Use `\int P_k = (P_{k+1} - P_{k-1}) / (2k + 1)`
+    fn integral(&self) -> Self {
+        let n = self.coef.len();
+        let scale = 0.5 * (self.domain.1 - self.domain.0);
+        let mut coef = vec![0f64; n + 1];
+        for (k, c) in self.coef.iter().enumerate() {
+            let s = scale * c / (2f64 * k as f64 + 1f64);
+            coef[k + 1] += s;
+            if k > 0 {
+                coef[k - 1] -= s;
+            }
+        }
+        let mut result = Self::new(coef, self.domain);
+        result.coef[0] -= result.eval(self.domain.0);
+        result
+    }
+
+    fn integrate<T: Into<f64> + Copy>(&self, interval: (T, T)) -> f64 {
+        let integral = self.integral();
+        integral.eval(interval.1) - integral.eval(interval.0)
+    }
+}
@@ -1,9 +1,24 @@
 //! Sparse matrix (CCS format)
 //!
+//! # Description
+//! `SPMatrix` stores a matrix in compressed column storage (CCS).
+//! It can be constructed from triplets (COO) or from dense `Matrix`,
+//! and can be used with the iterative solvers in `numerical::iterative`.
+//!
+//! # Examples
+//! ```
+//! use peroxide::fuga::*;
+//!
+//! // [[2, 0], [1, 3]] (duplicated entries are summed)
+//! let a = SPMatrix::from_triplets(&[0, 1, 1, 1], &[0, 0, 1, 1], &[2f64, 1f64, 1f64, 2f64], (2, 2));
+//! assert_eq!(a.to_dense(), ml_matrix("2 0;1 3"));
+//! assert_eq!(a.matvec(&vec![1f64, 1f64]), vec![2f64, 4f64]);
+//! ```
+//!
 //! * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
 
 use crate::structure::matrix::{Form, LinearAlgebra, Matrix, SolveKind, PQLU, QR, WAZD, SVD};
-use crate::traits::math::LinearOp;
+use crate::traits::math::{LinearOp, SquareOp};
 //use crate::traits::math::{InnerProduct, LinearOp, Norm, Normed, Vector};
 use crate::util::non_macro::zeros;
 use std::ops::Mul;
@@ -27,11 +42,69 @@ impl SPMatrix {
             col,
             nnz,
             col_ptr: vec![0usize; col + 1],
-            row_ics: vec![0usize, nnz],
+            row_ics: vec![0usize; nnz],
             data: vec![0f64; nnz],
         }
     }
 
+    /// Construct from triplets (COO format)
+    ///
+    /// # Description
+    /// `(rows[k], cols[k], vals[k])` is the `k`-th entry. Duplicated entries are summed.
+    /// Row indices in each column are sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = SPMatrix::from_triplets(&[1, 0, 1], &[1, 0, 1], &[1f64, 2f64, 3f64], (2, 3));
+    /// assert_eq!(a.nnz, 2);
+    /// assert_eq!(a.to_dense(), ml_matrix("2 0 0;0 4 0"));
+    /// ```
+    pub fn from_triplets(rows: &[usize], cols: &[usize], vals: &[f64], shape: (usize, usize)) -> Self {
+        let (row, col) = shape;
+        assert!(
+            rows.len() == cols.len() && cols.len() == vals.len(),
+            "Triplets should have same length"
+        );
+        assert!(
+            rows.iter().all(|&i| i < row) && cols.iter().all(|&j| j < col),
+            "Index out of range"
+        );
+
+        // Sort by (col, row)
+        let mut idx = (0..vals.len()).collect::<Vec<usize>>();
+        idx.sort_by_key(|&k| (cols[k], rows[k]));
+
+        let mut col_ptr = vec![0usize; col + 1];
+        let mut row_ics: Vec<usize> = Vec::with_capacity(vals.len());
+        let mut data: Vec<f64> = Vec::with_capacity(vals.len());
+        let mut prev: Option<(usize, usize)> = None;
+        for &k in idx.iter() {
+            let (i, j) = (rows[k], cols[k]);
+            if prev == Some((i, j)) {
+                *data.last_mut().unwrap() += vals[k];
+            } else {
+                row_ics.push(i);
+                data.push(vals[k]);
+                col_ptr[j + 1] += 1;
+                prev = Some((i, j));
+            }
+        }
+        for j in 0..col {
+            col_ptr[j + 1] += col_ptr[j];
+        }
+
+        SPMatrix {
+            row,
+            col,
+            nnz: data.len(),
+            col_ptr,
+            row_ics,
+            data,
+        }
+    }
+
     pub fn from_dense(m: &Matrix) -> Self {
         let mut data: Vec<f64> = Vec::new();
         let mut row_ics: Vec<usize> = Vec::new();
@@ -118,10 +191,30 @@ impl SPMatrix {
     pub fn t(&self) -> Self {
         self.transpose()
     }
+
+    /// Matrix-vector multiplication
+    pub fn matvec(&self, x: &Vec<f64>) -> Vec<f64> {
+        self.apply(x)
+    }
+
+    /// Extract diagonal components
+    pub fn diag(&self) -> Vec<f64> {
+        let n = self.row.min(self.col);
+        let mut d = vec![0f64; n];
+        for (j, dj) in d.iter_mut().enumerate() {
+            for i in self.col_ptr[j]..self.col_ptr[j + 1] {
+                if self.row_ics[i] == j {
+                    *dj += self.data[i];
+                }
+            }
+        }
+        d
+    }
 }
 
 impl LinearOp<Vec<f64>, Vec<f64>> for SPMatrix {
     fn apply(&self, rhs: &Vec<f64>) -> Vec<f64> {
+        assert_eq!(self.col, rhs.len(), "Dimension mismatch");
         let mut y = vec![0f64; self.row];
         let col_ptr = self.col_ptr();
         let row_ics = self.row_ics();
@@ -135,6 +228,13 @@ impl LinearOp<Vec<f64>, Vec<f64>> for SPMatrix {
     }
 }
 
+impl SquareOp for SPMatrix {
+    fn size(&self) -> usize {
+        assert_eq!(self.row, self.col, "Should be square matrix");
+        self.row
+    }
+}
+
 /// Linear algebra for sparse matrix
 ///
 /// **Caution** : In every ops in this trait, there is converting process to dense matrix
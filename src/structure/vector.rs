@@ -276,6 +276,12 @@ use crate::traits::{
     pointer::{Oxide, Redox, RedoxCommon},
 };
 use std::cmp::min;
+#[cfg(feature = "parallel")]
+use crate::traits::fp::ParallelFPVector;
+#[cfg(feature = "simd")]
+use crate::util::simd;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 impl FPVector for Vec<f64> {
     type Scalar = f64;
@@ -400,8 +406,24 @@ impl FPVector for Vec<f64> {
         v
     }
 
+    /// Sum of elements (compensated, see `ksum`)
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = vec![1e16, 1f64, -1e16];
+    /// assert_eq!(a.sum(), 1f64);
+    /// ```
     fn sum(&self) -> f64 {
-        self.iter().sum()
+        #[cfg(feature = "simd")]
+        {
+            simd::sum(self)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            ksum(self)
+        }
     }
 
     fn prod(&self) -> f64 {
@@ -409,6 +431,73 @@ impl FPVector for Vec<f64> {
     }
 }
 
+#[cfg(feature = "parallel")]
+impl ParallelFPVector for Vec<f64> {
+    type Scalar = f64;
+
+    /// Parallel fmap for `Vec<f64>`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     # #[cfg(feature = "parallel")] {
+    ///     let a = c!(1,2,3,4,5);
+    ///     assert_eq!(a.par_fmap(|x| x*2f64), seq!(2,10,2));
+    ///     # }
+    /// }
+    /// ```
+    fn par_fmap<F>(&self, f: F) -> Vec<f64>
+    where
+        F: Fn(f64) -> f64 + Sync + Send,
+    {
+        let mut v = self.clone();
+        v.par_iter_mut().for_each(|x| *x = f(*x));
+        v
+    }
+
+    /// Parallel reduce for `Vec<f64>`
+    ///
+    /// # Caution
+    /// `init` should be an identity element of `f`.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     # #[cfg(feature = "parallel")] {
+    ///     let a = seq!(1,100,1);
+    ///     assert_eq!(a.par_reduce(0, |x,y| x + y), 5050f64);
+    ///     # }
+    /// }
+    /// ```
+    fn par_reduce<F, T>(&self, init: T, f: F) -> f64
+    where
+        F: Fn(f64, f64) -> f64 + Sync + Send,
+        T: Into<f64> + Copy + Sync + Send,
+    {
+        self.par_iter()
+            .fold(|| init.into(), |x, &y| f(x, y))
+            .reduce(|| init.into(), &f)
+    }
+
+    fn par_zip_with<F>(&self, f: F, other: &Vec<f64>) -> Vec<f64>
+    where
+        F: Fn(f64, f64) -> f64 + Sync + Send,
+    {
+        self.par_iter()
+            .zip(other)
+            .map(|(x, y)| f(*x, *y))
+            .collect::<Vec<f64>>()
+    }
+}
+
 /// Explicit version of `map`
 pub fn map<F, T>(f: F, xs: &[T]) -> Vec<T>
 where
@@ -436,6 +525,48 @@ where
     s
 }
 
+/// Compensated summation (Kahan-Babuška)
+///
+/// # Description
+/// Neumaier's variant of Kahan summation : the rounding error of each addition is
+/// accumulated separately and added back at the end.
+/// The error is `O(ε) Σ|x_i|` regardless of the length, while a naive sum gives `O(nε) Σ|x_i|`.
+///
+/// If the sum overflows (or any element is infinite), the naive sum is returned.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let mut x = vec![1e10];
+/// x.extend(vec![1e-3; 100000]);
+/// assert_eq!(ksum(&x), 1e10 + 100f64);
+/// assert_ne!(x.iter().sum::<f64>(), 1e10 + 100f64);
+/// ```
+pub fn ksum(xs: &[f64]) -> f64 {
+    ksum_iter(xs.iter().copied())
+}
+
+/// Compensated summation of an iterator
+pub(crate) fn ksum_iter<I: IntoIterator<Item = f64>>(xs: I) -> f64 {
+    let mut s = 0f64;
+    let mut c = 0f64;
+    for x in xs {
+        let t = s + x;
+        if s.abs() >= x.abs() {
+            c += (s - t) + x;
+        } else {
+            c += (x - t) + s;
+        }
+        s = t;
+    }
+    if s.is_finite() {
+        s + c
+    } else {
+        s
+    }
+}
+
 /// Explicit version of `zip_with`
 pub fn zip_with<F, T>(f: F, xs: &[T], ys: &[T]) -> Vec<T>
 where
@@ -450,6 +581,36 @@ where
     result
 }
 
+/// `y <- a * x + y`
+///
+/// # Description
+/// Only the first `min(x.len(), y.len())` elements are updated (like `zip_with`).
+/// With the `simd` feature, this uses the chunked kernel of `util::simd`.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let x = c!(1, 2, 3);
+///     let mut y = c!(1, 1, 1);
+///     axpy(2f64, &x, &mut y);
+///     assert_eq!(y, c!(3, 5, 7));
+/// }
+/// ```
+pub fn axpy(a: f64, x: &[f64], y: &mut [f64]) {
+    #[cfg(feature = "simd")]
+    {
+        simd::axpy(a, x, y)
+    }
+    #[cfg(not(feature = "simd"))]
+    {
+        y.iter_mut().zip(x).for_each(|(u, v)| *u += a * v)
+    }
+}
+
 impl MutFP for Vec<f64> {
     type Scalar = f64;
 
@@ -638,11 +799,25 @@ impl Vector for Vec<f64> {
     type Scalar = f64;
 
     fn add_vec(&self, rhs: &Self) -> Self {
-        self.zip_with(|x, y| x + y, rhs)
+        #[cfg(feature = "simd")]
+        {
+            simd::add(self, rhs)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            self.zip_with(|x, y| x + y, rhs)
+        }
     }
 
     fn sub_vec(&self, rhs: &Self) -> Self {
-        self.zip_with(|x, y| x - y, rhs)
+        #[cfg(feature = "simd")]
+        {
+            simd::sub(self, rhs)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            self.zip_with(|x, y| x - y, rhs)
+        }
     }
 
     fn mul_scalar(&self, rhs: Self::Scalar) -> Self {
@@ -666,7 +841,11 @@ impl Normed for Vec<f64> {
                     }
                     res
                 }
-                #[cfg(not(feature = "O3"))]
+                #[cfg(all(feature = "simd", not(feature = "O3")))]
+                {
+                    simd::norm_l2(self)
+                }
+                #[cfg(not(any(feature = "O3", feature = "simd")))]
                 {
                     self.iter().map(|x| x.powi(2)).sum::<f64>().sqrt()
                 }
@@ -677,10 +856,13 @@ impl Normed for Vec<f64> {
                     "lp norm is only defined for p>=1, the given value was p={}",
                     p
                 );
-                self.iter().map(|x| x.powf(p)).sum::<f64>().powf(1f64 / p)
+                if p.is_infinite() {
+                    return self.norm(Norm::LInf);
+                }
+                self.iter().map(|x| x.abs().powf(p)).sum::<f64>().powf(1f64 / p)
             }
             Norm::LInf => self.iter().fold(0f64, |x, y| x.max(y.abs())),
-            Norm::F => unimplemented!(),
+            Norm::F => self.norm(Norm::L2),
             Norm::Lpq(_, _) => unimplemented!(),
         }
     }
@@ -704,7 +886,11 @@ impl InnerProduct for Vec<f64> {
             }
             res
         }
-        #[cfg(not(feature = "O3"))]
+        #[cfg(all(feature = "simd", not(feature = "O3")))]
+        {
+            simd::dot(self, rhs)
+        }
+        #[cfg(not(any(feature = "O3", feature = "simd")))]
         {
             self.iter()
                 .zip(rhs.iter())
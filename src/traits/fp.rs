@@ -1,4 +1,5 @@
 use crate::structure::matrix::Matrix;
+use crate::structure::vector::ksum;
 
 /// Functional Programming tools for Vector
 pub trait FPVector {
@@ -29,6 +30,10 @@ pub trait FPMatrix {
     fn take_col(&self, n: usize) -> Matrix;
     fn skip_row(&self, n: usize) -> Matrix;
     fn skip_col(&self, n: usize) -> Matrix;
+    /// Split rows at `at` : `(take_row(at), skip_row(at))`
+    fn split_row(&self, at: usize) -> (Matrix, Matrix) {
+        (self.take_row(at), self.skip_row(at))
+    }
     fn fmap<F>(&self, f: F) -> Matrix
     where
         F: Fn(f64) -> f64;
@@ -48,6 +53,12 @@ pub trait FPMatrix {
     where
         F: Fn(f64, f64) -> f64,
         T: Into<f64>;
+    /// Compensated sum of all elements
+    ///
+    /// The default sums each column with `ksum`, then the column sums.
+    fn ksum(&self) -> f64 {
+        ksum(&self.col_reduce(|c| ksum(&c)))
+    }
     fn zip_with<F>(&self, f: F, other: &Matrix) -> Matrix
     where
         F: Fn(f64, f64) -> f64;
@@ -58,3 +69,49 @@ pub trait FPMatrix {
     where
         F: Fn(Vec<f64>) -> f64;
 }
+
+/// Parallel Functional Programming tools for Vector (`parallel` feature)
+///
+/// # Caution
+/// `par_fmap` & `par_zip_with` give the same results as their sequential versions.
+/// `par_reduce` splits the data into chunks, so `f` should be associative and
+/// `init` should be an identity element of `f`. (e.g. `(0, +)`, `(1, *)`)
+#[cfg(feature = "parallel")]
+pub trait ParallelFPVector {
+    type Scalar;
+
+    fn par_fmap<F>(&self, f: F) -> Self
+    where
+        F: Fn(Self::Scalar) -> Self::Scalar + Sync + Send;
+    fn par_reduce<F, T>(&self, init: T, f: F) -> Self::Scalar
+    where
+        F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar + Sync + Send,
+        T: Into<Self::Scalar> + Copy + Sync + Send;
+    fn par_zip_with<F>(&self, f: F, other: &Self) -> Self
+    where
+        F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar + Sync + Send;
+}
+
+/// Parallel Functional Programming for Matrix (`parallel` feature)
+///
+/// # Caution
+/// Same rules as [`ParallelFPVector`] are applied for `par_reduce`.
+#[cfg(feature = "parallel")]
+pub trait ParallelFPMatrix {
+    fn par_fmap<F>(&self, f: F) -> Matrix
+    where
+        F: Fn(f64) -> f64 + Sync + Send;
+    fn par_reduce<F, T>(&self, init: T, f: F) -> f64
+    where
+        F: Fn(f64, f64) -> f64 + Sync + Send,
+        T: Into<f64> + Copy + Sync + Send;
+    fn par_zip_with<F>(&self, f: F, other: &Matrix) -> Matrix
+    where
+        F: Fn(f64, f64) -> f64 + Sync + Send;
+    fn par_col_reduce<F>(&self, f: F) -> Vec<f64>
+    where
+        F: Fn(Vec<f64>) -> f64 + Sync + Send;
+    fn par_row_reduce<F>(&self, f: F) -> Vec<f64>
+    where
+        F: Fn(Vec<f64>) -> f64 + Sync + Send;
+}
@@ -1,4 +1,6 @@
 /// Some algorithms for Vector
+///
+/// Methods with default bodies are provided for every `AsRef<[f64]>` implementor.
 pub trait Algorithm {
     fn rank(&self) -> Vec<usize>;
     fn sign(&self) -> f64;
@@ -7,4 +9,278 @@ pub trait Algorithm {
     fn max(&self) -> f64;
     fn min(&self) -> f64;
     fn swap_with_perm(&mut self, p: &Vec<(usize, usize)>);
+
+    /// Cumulative sum
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1, 2, 3, 4);
+    ///     assert_eq!(v.cumsum(), c!(1, 3, 6, 10));
+    /// }
+    /// ```
+    fn cumsum(&self) -> Vec<f64>
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        v.iter()
+            .scan(0f64, |acc, &x| {
+                *acc += x;
+                Some(*acc)
+            })
+            .collect()
+    }
+
+    /// Cumulative product
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1, 2, 3, 4);
+    ///     assert_eq!(v.cumprod(), c!(1, 2, 6, 24));
+    /// }
+    /// ```
+    fn cumprod(&self) -> Vec<f64>
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        v.iter()
+            .scan(1f64, |acc, &x| {
+                *acc *= x;
+                Some(*acc)
+            })
+            .collect()
+    }
+
+    /// Difference of adjacent elements (length `n-1`)
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1, 4, 9, 16);
+    ///     assert_eq!(v.diff(), c!(3, 5, 7));
+    /// }
+    /// ```
+    fn diff(&self) -> Vec<f64>
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        v.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    /// Numerical gradient of uniformly sampled data (length `n`)
+    ///
+    /// # Description
+    /// Central differences `(x[i+1] - x[i-1]) / 2dx` in the interior,
+    /// and one-sided differences at both ends (same as `numpy.gradient` with `edge_order=1`).
+    ///
+    /// # Panics
+    /// Fewer than 2 elements
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1, 4, 9, 16);
+    ///     assert_eq!(v.gradient(1f64), c!(3, 4, 6, 7));
+    ///     assert_eq!(v.gradient(0.5), c!(6, 8, 12, 14));
+    /// }
+    /// ```
+    fn gradient(&self, dx: f64) -> Vec<f64>
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        let n = v.len();
+        assert!(n >= 2, "gradient needs at least 2 elements");
+        let mut g = Vec::with_capacity(n);
+        g.push((v[1] - v[0]) / dx);
+        g.extend(v.windows(3).map(|w| (w[2] - w[0]) / (2f64 * dx)));
+        g.push((v[n - 1] - v[n - 2]) / dx);
+        g
+    }
+
+    /// Indices that would sort the vector (ascending, stable)
+    ///
+    /// # Description
+    /// Ordered by `f64::total_cmp`, so `NaN` goes last instead of panicking.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(3, 1, 2, 1);
+    ///     assert_eq!(v.argsort(), vec![1, 3, 2, 0]);
+    ///
+    ///     let w = c!(2, f64::NAN, 1);
+    ///     assert_eq!(w.argsort(), vec![2, 0, 1]);
+    /// }
+    /// ```
+    fn argsort(&self) -> Vec<usize>
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        let mut idx = (0..v.len()).collect::<Vec<usize>>();
+        idx.sort_by(|&i, &j| v[i].total_cmp(&v[j]));
+        idx
+    }
+
+    /// Insertion index of `value` into sorted vector
+    ///
+    /// # Description
+    /// Binary search for the index where `value` should be inserted to keep the order
+    /// (same as `numpy.searchsorted`).
+    ///
+    /// * `Left` : first index `i` with `self[i] >= value`
+    /// * `Right` : first index `i` with `self[i] > value`
+    ///
+    /// The vector should be sorted in ascending order (checked only in debug build).
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1, 2, 3, 3, 5);
+    ///     assert_eq!(v.searchsorted(0.5, Side::Left), 0);
+    ///     assert_eq!(v.searchsorted(3f64, Side::Left), 2);
+    ///     assert_eq!(v.searchsorted(3f64, Side::Right), 4);
+    ///     assert_eq!(v.searchsorted(4f64, Side::Left), 4);
+    ///     assert_eq!(v.searchsorted(6f64, Side::Right), 5);
+    /// }
+    /// ```
+    fn searchsorted(&self, value: f64, side: Side) -> usize
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        debug_assert!(
+            v.windows(2).all(|w| w[0] <= w[1]),
+            "Vector should be sorted in ascending order"
+        );
+        match side {
+            Side::Left => v.partition_point(|&t| t < value),
+            Side::Right => v.partition_point(|&t| t <= value),
+        }
+    }
+
+    /// Clamp each element into `[lo, hi]`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(-2, 0.5, 3);
+    ///     assert_eq!(v.clamp_vec(0f64, 1f64), c!(0, 0.5, 1));
+    /// }
+    /// ```
+    fn clamp_vec(&self, lo: f64, hi: f64) -> Vec<f64>
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        assert!(lo <= hi, "lo should be less than or equal to hi");
+        v.iter().map(|x| x.clamp(lo, hi)).collect()
+    }
+
+    /// Softmax : `exp(x_i) / sum_j exp(x_j)`
+    ///
+    /// # Description
+    /// The maximum is subtracted before exponentiating, so large inputs do not overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1000, 1000, 1000, 1000);
+    ///     assert_eq!(v.softmax(), c!(0.25, 0.25, 0.25, 0.25));
+    ///
+    ///     let w = c!(-3, 0.5, 2, 800).softmax();
+    ///     assert!((w.sum() - 1f64).abs() < 1e-15);
+    ///     assert!(w.iter().all(|x| x.is_finite()));
+    ///     assert_eq!(w[3], 1f64);
+    /// }
+    /// ```
+    fn softmax(&self) -> Vec<f64>
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        let m = v.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let e = v.iter().map(|x| (x - m).exp()).collect::<Vec<f64>>();
+        let sum = e.iter().sum::<f64>();
+        e.into_iter().map(|x| x / sum).collect()
+    }
+
+    /// Log-sum-exp : `ln(sum_i exp(x_i))`
+    ///
+    /// # Description
+    /// The maximum is subtracted before exponentiating (`m + ln(sum_i exp(x_i - m))`).
+    /// Returns `-inf` for empty vector.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1000, 1000);
+    ///     assert_eq!(v.logsumexp(), 1000f64 + 2f64.ln());
+    ///     assert_eq!(c!(-1000, -1000).logsumexp(), -1000f64 + 2f64.ln());
+    ///     assert_eq!(Vec::<f64>::new().logsumexp(), f64::NEG_INFINITY);
+    /// }
+    /// ```
+    fn logsumexp(&self) -> f64
+    where
+        Self: AsRef<[f64]>,
+    {
+        let v = self.as_ref();
+        let m = v.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        if m.is_infinite() {
+            // All -inf (or empty) : -inf, any +inf : +inf
+            return m;
+        }
+        m + v.iter().map(|x| (x - m).exp()).sum::<f64>().ln()
+    }
+}
+
+/// Side of insertion index for `searchsorted`
+///
+/// * `Left` : first index `i` with `v[i] >= value`
+/// * `Right` : first index `i` with `v[i] > value`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
 }
@@ -52,6 +52,21 @@ pub trait LinearOp<T: Vector, S: Vector> {
     fn apply(&self, rhs: &T) -> S;
 }
 
+/// Square linear operator on `Vec<f64>` with known dimension
+///
+/// # Description
+/// Used by iterative solvers (`numerical::iterative`).
+/// `Matrix`, `SPMatrix` and `FnOp` (matrix-free operator) implement this trait.
+pub trait SquareOp: LinearOp<Vec<f64>, Vec<f64>> {
+    fn size(&self) -> usize;
+}
+
+/// Parallel linear operation for Vector (`parallel` feature)
+#[cfg(feature = "parallel")]
+pub trait ParallelLinearOp<T: Vector, S: Vector> {
+    fn par_apply(&self, rhs: &T) -> S;
+}
+
 /// Vector Products
 pub trait VectorProduct: Vector {
     fn cross(&self, other: &Self) -> Self;
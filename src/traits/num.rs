@@ -4,6 +4,25 @@
 //!
 //! * `Real` is a trait for binding `f64`, `AD`
 //! * `Real` requires `PowOps, TrigOps, ExpLogOps` & `std::Ops<Self>` & `std::Ops<f64>`
+//! * `Real` provides `max`, `min`, `abs`, `signum`
+//!     * For `AD`, comparison is by value (derivative parts are ignored)
+//!     * `max` & `min` return one of the inputs, so derivatives follow the selected branch
+//!     * `abs` & `signum` have zero derivatives at `x = 0`
+//!
+//!     ```rust
+//!     extern crate peroxide;
+//!     use peroxide::fuga::*;
+//!
+//!     // Generic clamp for f64 & AD
+//!     fn clamp<T: Real>(x: T, lo: f64, hi: f64) -> T {
+//!         x.max(T::from_f64(lo)).min(T::from_f64(hi))
+//!     }
+//!
+//!     assert_eq!(clamp(2f64, 0f64, 1f64), 1f64);
+//!     assert_eq!(clamp(AD1(0.5, 1f64), 0f64, 1f64), AD1(0.5, 1f64));
+//!     assert_eq!(clamp(AD1(2f64, 1f64), 0f64, 1f64), AD1(1f64, 0f64));
+//!     assert_eq!(AD1(-3f64, 1f64).abs(), AD1(3f64, -1f64));
+//!     ```
 //!
 //!     ```rust
 //!     extern crate peroxide;
@@ -48,6 +67,33 @@ pub trait Real:
     fn to_f64(&self) -> f64;
     fn from_f64(f: f64) -> Self;
     fn to_ad(&self) -> AD;
+
+    /// Select by `to_f64` (`self` for a tie, NaN loses like `f64::max`)
+    fn max(self, other: Self) -> Self {
+        if self.to_f64() >= other.to_f64() || other.to_f64().is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Same convention as `max`
+    fn min(self, other: Self) -> Self {
+        if self.to_f64() <= other.to_f64() || other.to_f64().is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// `self * signum(self)` (keeps the type of `self`)
+    fn abs(self) -> Self {
+        self * self.to_f64().signum()
+    }
+
+    fn signum(self) -> Self {
+        Self::from_f64(self.to_f64().signum())
+    }
 }
 
 impl Real for f64 {
@@ -62,6 +108,22 @@ impl Real for f64 {
     fn to_ad(&self) -> AD {
         AD::from(*self)
     }
+
+    fn max(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        f64::min(self, other)
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn signum(self) -> Self {
+        f64::signum(self)
+    }
 }
 
 impl Real for AD {
@@ -76,4 +138,46 @@ impl Real for AD {
     fn to_ad(&self) -> AD {
         *self
     }
+
+    /// Select by value (derivatives follow the selected one, `self` for a tie)
+    ///
+    /// The result has the higher order of the two
+    fn max(self, other: Self) -> Self {
+        let order = self.order().max(other.order());
+        if self.x() >= other.x() || other.x().is_nan() {
+            self.to_order(order)
+        } else {
+            other.to_order(order)
+        }
+    }
+
+    /// Same convention as `max`
+    fn min(self, other: Self) -> Self {
+        let order = self.order().max(other.order());
+        if self.x() <= other.x() || other.x().is_nan() {
+            self.to_order(order)
+        } else {
+            other.to_order(order)
+        }
+    }
+
+    /// `sign(x) * (x, dx, ddx)` - derivatives are zero at `x = 0` (subgradient `0`)
+    fn abs(self) -> Self {
+        if self.x() > 0f64 {
+            self
+        } else if self.x() < 0f64 {
+            -self
+        } else {
+            let mut z = self.empty();
+            z.set_x(self.x().abs());
+            z
+        }
+    }
+
+    /// `f64::signum` of value - derivatives are zero (also at `x = 0`)
+    fn signum(self) -> Self {
+        let mut z = self.empty();
+        z.set_x(self.x().signum());
+        z
+    }
 }
@@ -43,6 +43,29 @@
 //! ```
 //!
 //! `ox()` and `red()` come from oxidation and reduction.
+//!
+//! Operators are also implemented for `&Redox<T>` and for a scalar on the left,
+//! so that operands can be reused without clone.
+//! Vector products (`dot`, `outer`, `cross`) are available through `Deref`,
+//! and `*` between two `Redox` is the element-wise (Hadamard) product.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate peroxide;
+//! use peroxide::fuga::*;
+//!
+//! fn main() {
+//!     let a = c!(1, 2, 3).ox();
+//!     let b = c!(4, 5, 6).ox();
+//!     assert_eq!((&a + 2f64 * &b).red(), c!(9, 12, 15));
+//!     assert_eq!((1f64 - &a / 2f64).red(), c!(0.5, 0, -0.5));
+//!     assert_eq!((&a * &b).red(), c!(4, 10, 18));
+//!
+//!     assert_eq!(a.dot(&b), 32f64);
+//!     assert_eq!(a.cross(&b), c!(-3, 6, -3));
+//!     assert_eq!(a.outer(&b), ml_matrix("4 5 6;8 10 12;12 15 18"));
+//! }
+//! ```
 use crate::structure::matrix::{Matrix, Shape};
 use crate::structure::sparse::SPMatrix;
 use crate::structure::ad::AD;
@@ -213,6 +236,93 @@ where
     }
 }
 
+// =============================================================================
+// Borrowed & Scalar-left Arithmetic
+// =============================================================================
+/// `&Redox op &Redox`, mixed owned & borrowed operands, `&Redox op f64` & `f64 op Redox`
+///
+/// Every operation allocates exactly one new vector, so `&a + 2f64 * &b` needs no clone of `a` or `b`.
+macro_rules! impl_redox_borrowed_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T: Vector + FPVector> $trait<&Redox<T>> for &Redox<T>
+        where
+            <T as FPVector>::Scalar: $trait<Output = <T as FPVector>::Scalar>,
+        {
+            type Output = Redox<T>;
+
+            fn $method(self, rhs: &Redox<T>) -> Self::Output {
+                Redox {
+                    data: Box::new(self.zip_with(|x, y| x $op y, &rhs.data)),
+                }
+            }
+        }
+
+        impl<T: Vector + FPVector> $trait<Redox<T>> for &Redox<T>
+        where
+            <T as FPVector>::Scalar: $trait<Output = <T as FPVector>::Scalar>,
+        {
+            type Output = Redox<T>;
+
+            fn $method(self, rhs: Redox<T>) -> Self::Output {
+                self $op &rhs
+            }
+        }
+
+        impl<T: Vector + FPVector> $trait<&Redox<T>> for Redox<T>
+        where
+            <T as FPVector>::Scalar: $trait<Output = <T as FPVector>::Scalar>,
+        {
+            type Output = Redox<T>;
+
+            fn $method(self, rhs: &Redox<T>) -> Self::Output {
+                &self $op rhs
+            }
+        }
+
+        impl<T: Vector + FPVector> $trait<f64> for &Redox<T>
+        where
+            <T as FPVector>::Scalar: $trait<f64, Output = <T as FPVector>::Scalar>,
+        {
+            type Output = Redox<T>;
+
+            fn $method(self, rhs: f64) -> Self::Output {
+                Redox {
+                    data: Box::new(self.fmap(|x| x $op rhs)),
+                }
+            }
+        }
+
+        impl<T: Vector + FPVector> $trait<&Redox<T>> for f64
+        where
+            f64: $trait<<T as FPVector>::Scalar, Output = <T as FPVector>::Scalar>,
+        {
+            type Output = Redox<T>;
+
+            fn $method(self, rhs: &Redox<T>) -> Self::Output {
+                Redox {
+                    data: Box::new(rhs.fmap(|x| self $op x)),
+                }
+            }
+        }
+
+        impl<T: Vector + FPVector> $trait<Redox<T>> for f64
+        where
+            f64: $trait<<T as FPVector>::Scalar, Output = <T as FPVector>::Scalar>,
+        {
+            type Output = Redox<T>;
+
+            fn $method(self, rhs: Redox<T>) -> Self::Output {
+                <f64 as $trait<&Redox<T>>>::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+impl_redox_borrowed_op!(Add, add, +);
+impl_redox_borrowed_op!(Sub, sub, -);
+impl_redox_borrowed_op!(Mul, mul, *);
+impl_redox_borrowed_op!(Div, div, /);
+
 impl Mul<Redox<Vec<f64>>> for Matrix {
     type Output = Redox<Vec<f64>>;
 
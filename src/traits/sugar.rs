@@ -1,5 +1,6 @@
 use crate::structure::matrix::{Matrix, Shape, matrix};
-use crate::traits::fp::FPVector;
+use crate::traits::fp::{FPMatrix, FPVector};
+use crate::traits::general::Algorithm;
 use crate::util::non_macro::zeros_shape;
 use std::ops::{Add, Sub, Mul, Div};
 
@@ -38,6 +39,55 @@ where Self::Scalar: Copy + Clone
     }
 }
 
+/// Element-wise sign, clamp & rounding (built on `fmap`)
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let v = c!(-1.5, 0.25, 2.5);
+///     assert_eq!(v.abs(), c!(1.5, 0.25, 2.5));
+///     assert_eq!(v.signum(), c!(-1, 1, 1));
+///     assert_eq!(v.round(), c!(-2, 0, 3));
+///     assert_eq!(v.floor(), c!(-2, 0, 2));
+///     assert_eq!(v.ceil(), c!(-1, 1, 3));
+///
+///     let m = ml_matrix("-1.5 0.25;2.5 -0.75");
+///     assert_eq!(m.abs(), ml_matrix("1.5 0.25;2.5 0.75"));
+///     assert_eq!(m.floor(), ml_matrix("-2 0;2 -1"));
+/// }
+/// ```
+pub trait ElementwiseOps: Sized {
+    /// Absolute value
+    fn abs(&self) -> Self;
+    /// Sign (same as `f64::signum` : `1` for `+0`, `-1` for `-0`)
+    fn signum(&self) -> Self;
+    /// Clamp each element into `[lo, hi]`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(-3, 0.5, 1, 7);
+    ///     assert_eq!(v.clamp(0f64, 1f64), c!(0, 0.5, 1, 1));
+    ///
+    ///     let m = ml_matrix("-3 0.5;1 7");
+    ///     assert_eq!(m.clamp(0f64, 1f64), ml_matrix("0 0.5;1 1"));
+    /// }
+    /// ```
+    fn clamp(&self, lo: f64, hi: f64) -> Self;
+    /// Round half away from zero
+    fn round(&self) -> Self;
+    fn floor(&self) -> Self;
+    fn ceil(&self) -> Self;
+}
+
 pub trait Scalable {
     type Vec;
     fn reshape(&self, size: (usize, usize), shape: Shape) -> Matrix;
@@ -61,7 +111,20 @@ pub trait ConvToMat {
 // Implementations
 // =============================================================================
 
-impl VecOps for Vec<f64> {}
+impl VecOps for Vec<f64> {
+    #[cfg(feature = "simd")]
+    fn add_v(&self, v: &Self) -> Self {
+        crate::util::simd::add(self, v)
+    }
+    #[cfg(feature = "simd")]
+    fn sub_v(&self, v: &Self) -> Self {
+        crate::util::simd::sub(self, v)
+    }
+    #[cfg(feature = "simd")]
+    fn mul_v(&self, v: &Self) -> Self {
+        crate::util::simd::mul(self, v)
+    }
+}
 //    /// Vector + Vector
 //    ///
 //    /// ```
@@ -276,21 +339,36 @@ impl Scalable for Matrix {
 
     /// Resize matrix
     ///
+    /// # Description
+    /// `data` is reinterpreted as `r x c` matrix with `shape` - nothing is recomputed.
+    /// (Unlike `change_shape`, which reorders `data` to keep the same matrix)
+    ///
+    /// * `a.reshape((r, c), a.shape)` : Same as `numpy.reshape` (`order='C'` for `Row`, `order='F'` for `Col`)
+    ///
     /// ```
     /// #[macro_use]
     /// extern crate peroxide;
     /// use peroxide::fuga::*;
     ///
     /// fn main() {
-    ///     let a = ml_matrix("1 2 3;4 5 6"); // ml_matrix has shape `Col`
+    ///     let a = ml_matrix("1 2 3;4 5 6"); // ml_matrix has shape `Row`
     ///     let b1 = a.reshape((3, 2), Row);
     ///     let b2 = a.reshape((3, 2), Col);
     ///     assert_eq!(b1, ml_matrix("1 2;3 4;5 6"));
     ///     assert_eq!(b2, ml_matrix("1 4;2 5;3 6"));
+    ///     assert_eq!(b1.reshape((2, 3), b1.shape), a);
     /// }
     /// ```
     fn reshape(&self, (r, c): (usize, usize), shape: Shape) -> Matrix {
-        assert_eq!(self.row * self.col, r * c);
+        assert_eq!(
+            self.row * self.col,
+            r * c,
+            "Cannot reshape {}x{} matrix into {}x{}",
+            self.row,
+            self.col,
+            r,
+            c
+        );
         let mut m = zeros_shape(r, c, shape);
         m.data = self.data[..].to_vec();
         m
@@ -454,3 +532,39 @@ impl ConvToMat for Vec<f64> {
         matrix(self.clone(), 1, self.len(), Shape::Row)
     }
 }
+
+macro_rules! impl_elementwise_ops {
+    ($t:ty, |$s:ident, $lo:ident, $hi:ident| $clamp:expr) => {
+        impl ElementwiseOps for $t {
+            fn abs(&self) -> Self {
+                self.fmap(|x| x.abs())
+            }
+
+            fn signum(&self) -> Self {
+                self.fmap(|x| x.signum())
+            }
+
+            fn clamp(&self, $lo: f64, $hi: f64) -> Self {
+                let $s = self;
+                $clamp
+            }
+
+            fn round(&self) -> Self {
+                self.fmap(|x| x.round())
+            }
+
+            fn floor(&self) -> Self {
+                self.fmap(|x| x.floor())
+            }
+
+            fn ceil(&self) -> Self {
+                self.fmap(|x| x.ceil())
+            }
+        }
+    };
+}
+
+impl_elementwise_ops!(Vec<f64>, |v, lo, hi| v.clamp_vec(lo, hi));
+impl_elementwise_ops!(Matrix, |m, lo, hi| {
+    matrix(m.data.clamp_vec(lo, hi), m.row, m.col, m.shape)
+});
@@ -0,0 +1,123 @@
+//! HDF5 format (`hdf5` feature)
+//!
+//! # Description
+//! Read & write `Matrix` as a 2D `f64` dataset of a HDF5 file.
+//! (`DataFrame` also implements `WithHDF5` : see [`dataframe`](../../structure/dataframe/index.html))
+//!
+//! * The data on disk is always row-major (HDF5 convention), whatever the `Shape` of `Matrix`.
+//!   `read_hdf5` returns `Row` matrix.
+//! * 1D datasets are read as `1 x n` matrix.
+//! * The file is created if it does not exist. Existing dataset with the same name is replaced.
+//! * `gzip` : deflate level (`0 ~ 9`). With `Some(level)`, the dataset is written in chunks of about 1 MiB.
+//! * Missing dataset, wrong dtype or wrong rank are returned as `Err` with the file path and dataset name.
+//!
+//! # Examples
+//! ```
+//! #[macro_use]
+//! extern crate peroxide;
+//! use peroxide::fuga::*;
+//!
+//! fn main() -> Result<(), Box<dyn Error>> {
+//!     # #[cfg(feature = "hdf5")] {
+//!     let path = std::env::temp_dir().join("doc_hdf5.h5");
+//!     let path = path.to_str().unwrap();
+//!
+//!     let a = ml_matrix("1 2 3;4 5 6");
+//!     a.write_hdf5(path, "a", None)?;
+//!     a.t().write_hdf5(path, "at", Some(4))?;
+//!
+//!     // In Python : `h5py.File(path)["a"][()]`
+//!     let b = Matrix::read_hdf5(path, "a")?;
+//!     assert_eq!(a, b);
+//!     assert_eq!(Matrix::read_hdf5(path, "at")?, a.t());
+//!     # }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::structure::matrix::{matrix, Matrix, Shape::*};
+use hdf5::types::{FloatSize, TypeDescriptor};
+use hdf5::{Dataset, File, Group, H5Type};
+use std::borrow::Cow;
+use std::error::Error;
+
+/// Minimum chunk size (KiB) of compressed datasets
+const CHUNK_KB: usize = 1024;
+
+/// Read & write HDF5 file
+pub trait WithHDF5: Sized {
+    fn write_hdf5(&self, file_path: &str, name: &str, gzip: Option<u8>) -> Result<(), Box<dyn Error>>;
+    fn read_hdf5(file_path: &str, name: &str) -> Result<Self, Box<dyn Error>>;
+}
+
+impl WithHDF5 for Matrix {
+    /// Write to `name` dataset of HDF5 file
+    fn write_hdf5(&self, file_path: &str, name: &str, gzip: Option<u8>) -> Result<(), Box<dyn Error>> {
+        let f = h5_open_rw(file_path, name)?;
+        let data: Cow<[f64]> = match self.shape {
+            Row => Cow::Borrowed(&self.data),
+            Col => Cow::Owned(self.change_shape().data),
+        };
+        h5_write(&f, name, &[self.row, self.col], &data, gzip)
+            .map_err(|e| format!("{}: can't write dataset '{}' ({})", file_path, name, e))?;
+        Ok(())
+    }
+
+    /// Read from `name` dataset of HDF5 file
+    fn read_hdf5(file_path: &str, name: &str) -> Result<Self, Box<dyn Error>> {
+        let f = h5_open(file_path)?;
+        let ds = h5_dataset(&f, file_path, name)?;
+        let dtype = ds.dtype()?.to_descriptor()?;
+        if dtype != TypeDescriptor::Float(FloatSize::U8) {
+            return Err(format!("{}: dataset '{}' has dtype {} (expected float64)", file_path, name, dtype).into());
+        }
+        let shape = ds.shape();
+        let (row, col) = match shape.len() {
+            1 => (1, shape[0]),
+            2 => (shape[0], shape[1]),
+            n => return Err(format!("{}: dataset '{}' has rank {} (expected 1 or 2)", file_path, name, n).into()),
+        };
+        let data = ds.read_raw::<f64>()?;
+        Ok(matrix(data, row, col, Row))
+    }
+}
+
+/// Open HDF5 file as read-only
+pub(crate) fn h5_open(file_path: &str) -> Result<File, Box<dyn Error>> {
+    File::open(file_path).map_err(|e| format!("{}: can't open HDF5 file ({})", file_path, e).into())
+}
+
+/// Open (or create) HDF5 file to write `name`, removing the old `name`
+pub(crate) fn h5_open_rw(file_path: &str, name: &str) -> Result<File, Box<dyn Error>> {
+    let f = File::append(file_path).map_err(|e| format!("{}: can't open HDF5 file ({})", file_path, e))?;
+    if f.link_exists(name) {
+        f.unlink(name)?;
+    }
+    Ok(f)
+}
+
+/// Open dataset with context
+pub(crate) fn h5_dataset(group: &Group, file_path: &str, name: &str) -> Result<Dataset, Box<dyn Error>> {
+    group
+        .dataset(name)
+        .map_err(|e| format!("{}: can't open dataset '{}' ({})", file_path, name, e).into())
+}
+
+/// Create dataset of `shape` (row-major) and write `data`
+pub(crate) fn h5_write<T: H5Type>(
+    group: &Group,
+    name: &str,
+    shape: &[usize],
+    data: &[T],
+    gzip: Option<u8>,
+) -> hdf5::Result<Dataset> {
+    let mut builder = group.new_dataset::<T>().shape(shape.to_vec());
+    // Chunks can't have zero length
+    if let (Some(level), false) = (gzip, data.is_empty()) {
+        builder = builder.chunk_min_kb(CHUNK_KB).deflate(level);
+    }
+    let ds = builder.create(name)?;
+    ds.write_raw(data)?;
+    Ok(ds)
+}
@@ -1,13 +1,18 @@
 //! Utility - plot, print, pickle and etc.
 
 pub mod api;
+#[cfg(feature = "hdf5")]
+pub mod hdf5;
 pub mod non_macro;
+pub mod npy;
 
 #[cfg(feature = "plot")]
 pub mod plot;
 
 pub mod low_level;
 pub mod print;
+#[cfg(feature = "simd")]
+pub mod simd;
 pub mod useful;
 pub mod wrapper;
 pub mod writer;
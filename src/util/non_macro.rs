@@ -17,12 +17,28 @@
 //! - linspace_with_precision
 //! - rand
 //! - rand_with_rng
+//! - randn, randn_with_rng
+//! - rand_with, rand_with_dist_rng
+//! - rand_symm, rand_symm_with_rng
+//! - rand_spd, rand_spd_with_rng
+//! - rand_orth, rand_orth_with_rng
+//! - hilbert
+//! - vandermonde
+//! - toeplitz
 //!
 //! # Numpy like non-macro functions
 //!
 //! - logspace
+//! - geomspace
 //! - column_stack
 //! - row_stack
+//! - hstack
+//! - vstack
+//! - meshgrid
+//!
+//! # Kernel matrix
+//!
+//! - gram
 //!
 //! # Haskell like non-macro functions
 //!
@@ -31,9 +47,10 @@
 
 extern crate rand;
 use self::rand::prelude::*;
+use crate::statistics::dist::{TPDist::Normal, RNG};
 use crate::structure::{
     matrix::Shape::{Col, Row},
-    matrix::{matrix, Matrix, Shape},
+    matrix::{matrix, LinearAlgebra, Matrix, Shape},
 };
 use crate::traits::float::FloatWithPrecision;
 use anyhow::{Result, bail};
@@ -41,12 +58,14 @@ use anyhow::{Result, bail};
 #[derive(Debug, Copy, Clone)]
 pub enum ConcatenateError {
     DifferentLength,
+    Empty,
 }
 
 impl std::fmt::Display for ConcatenateError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
             ConcatenateError::DifferentLength => write!(f, "To concatenate, vectors or matrices must have the same length"),
+            ConcatenateError::Empty => write!(f, "Nothing to concatenate"),
         }
     }
 }
@@ -65,6 +84,10 @@ impl std::fmt::Display for ConcatenateError {
 /// 
 /// let b = seq(1, 1, 1);
 /// assert_eq!(b, vec![1f64]);
+///
+/// // Descending
+/// let c = seq(5, 1, -2);
+/// assert_eq!(c, vec![5f64, 3f64, 1f64]);
 /// ```
 pub fn seq<S, T, U>(start: S, end: T, step: U) -> Vec<f64>
 where
@@ -76,10 +99,7 @@ where
     let e = end.into();
     let step = step.into();
 
-    assert!(e >= s);
-
-    let factor: f64 = (e - s) / step;
-    let l: usize = factor.floor() as usize + 1;
+    let l = seq_length(s, e, step);
     let mut v: Vec<f64> = vec![0f64; l];
 
     for (i, v) in v.iter_mut().enumerate() {
@@ -110,10 +130,7 @@ where
     let e = end.into();
     let step = step.into();
 
-    assert!(e >= s);
-
-    let factor: f64 = (e - s) / step;
-    let l: usize = factor.floor() as usize + 1;
+    let l = seq_length(s, e, step);
     let mut v: Vec<f64> = vec![0f64; l];
 
     for (i, v) in v.iter_mut().enumerate() {
@@ -122,6 +139,16 @@ where
     v
 }
 
+/// Length of `seq` (`step` should head from `start` to `end`)
+fn seq_length(s: f64, e: f64, step: f64) -> usize {
+    assert!(step != 0f64, "Step should not be zero");
+    assert!(
+        (e - s) * step >= 0f64,
+        "Step should have the same sign as end - start"
+    );
+    ((e - s) / step).floor() as usize + 1
+}
+
 /// R like cbind - concatenate two matrix by column direction
 ///
 /// # Examples
@@ -248,6 +275,11 @@ pub fn eye_shape(n: usize, shape: Shape) -> Matrix {
 }
 
 /// MATLAB like linspace
+///
+/// # Description
+/// The first and the last elements are exactly `start` and `end`.
+/// Interior points are `start + step * i` (no accumulation error).
+/// For `length = 1`, it returns `[start]`.
 /// 
 /// # Examples
 /// ```
@@ -256,17 +288,21 @@ pub fn eye_shape(n: usize, shape: Shape) -> Matrix {
 /// let a = linspace(1, 10, 10);
 /// assert_eq!(a, seq(1,10,1));
 /// assert_eq!(a.len(), 10);
+///
+/// assert_eq!(linspace(0, 1, 3), vec![0f64, 0.5, 1f64]);
+/// assert_eq!(linspace(1, 0, 3), vec![1f64, 0.5, 0f64]);
 /// ```
 pub fn linspace<S, T>(start: S, end: T, length: usize) -> Vec<f64>
 where
     S: Into<f64> + Copy,
     T: Into<f64> + Copy,
 {
-    let step: f64 = if length > 1 {
-        (end.into() - start.into()) / (length as f64 - 1f64)
-    } else {
-        0f64
-    };
+    match length {
+        0 => return vec![],
+        1 => return vec![start.into()],
+        _ => (),
+    }
+    let step: f64 = (end.into() - start.into()) / (length as f64 - 1f64);
 
     let mut v = vec![0f64; length];
     v[0] = start.into();
@@ -342,6 +378,198 @@ pub fn rand_with_rng<R: Rng>(r: usize, c: usize, rng: &mut R) -> Matrix {
     m
 }
 
+/// Gaussian random matrix
+///
+/// # Description
+///
+/// Each element is sampled from `Normal(mean, sd)`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = randn(100, 100, 1f64, 2f64);
+/// assert!((a.data.mean() - 1f64).abs() < 0.1);
+/// assert!((a.data.sd() - 2f64).abs() < 0.1);
+/// ```
+pub fn randn(r: usize, c: usize, mean: f64, sd: f64) -> Matrix {
+    randn_with_rng(r, c, mean, sd, &mut thread_rng())
+}
+
+/// Gaussian random matrix with specific rng
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = randn_with_rng(2, 2, 0f64, 1f64, &mut stdrng_from_seed(42));
+/// let b = randn_with_rng(2, 2, 0f64, 1f64, &mut stdrng_from_seed(42));
+/// assert_eq!(a, b);
+/// ```
+pub fn randn_with_rng<R: Rng + Clone>(r: usize, c: usize, mean: f64, sd: f64, rng: &mut R) -> Matrix {
+    rand_with_dist_rng(r, c, &Normal(mean, sd), rng)
+}
+
+/// Random matrix from distribution
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = rand_with(3, 4, &Uniform(-1f64, 1f64));
+/// assert_eq!((a.row, a.col), (3, 4));
+/// assert!(a.data.iter().all(|x| x.abs() <= 1f64));
+/// ```
+pub fn rand_with<D: RNG>(r: usize, c: usize, dist: &D) -> Matrix {
+    rand_with_dist_rng(r, c, dist, &mut thread_rng())
+}
+
+/// Random matrix from distribution with specific rng
+pub fn rand_with_dist_rng<D: RNG, R: Rng + Clone>(r: usize, c: usize, dist: &D, rng: &mut R) -> Matrix {
+    matrix(dist.sample_with_rng(rng, r * c), r, c, Row)
+}
+
+/// Random symmetric matrix
+///
+/// # Description
+///
+/// `(B + B^T) / 2` where `B = randn(n, n, 0, 1)`
+pub fn rand_symm(n: usize) -> Matrix {
+    rand_symm_with_rng(n, &mut thread_rng())
+}
+
+/// Random symmetric matrix with specific rng
+pub fn rand_symm_with_rng<R: Rng + Clone>(n: usize, rng: &mut R) -> Matrix {
+    let b = randn_with_rng(n, n, 0f64, 1f64, rng);
+    (&b + &b.transpose()) / 2f64
+}
+
+/// Random symmetric positive definite matrix
+///
+/// # Description
+///
+/// `B B^T + I` where `B = randn(n, n, 0, 1)` (All eigenvalues are larger than or equal to `1`)
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = rand_spd(5);
+/// assert!(a.is_symmetric());
+/// let eig = eigen(&a, Jacobi);
+/// assert!(eig.eigenvalue.iter().all(|&l| l > 0f64));
+/// ```
+pub fn rand_spd(n: usize) -> Matrix {
+    rand_spd_with_rng(n, &mut thread_rng())
+}
+
+/// Random symmetric positive definite matrix with specific rng
+pub fn rand_spd_with_rng<R: Rng + Clone>(n: usize, rng: &mut R) -> Matrix {
+    let b = randn_with_rng(n, n, 0f64, 1f64, rng);
+    let mut a = &b * &b.transpose() + eye(n);
+    // Remove rounding asymmetry
+    for i in 0..n {
+        for j in 0..i {
+            a[(i, j)] = a[(j, i)];
+        }
+    }
+    a
+}
+
+/// Random orthogonal matrix
+///
+/// # Description
+///
+/// `Q` of QR decomposition of `randn(n, n, 0, 1)`.
+/// Signs of columns are fixed to make `diag(R)` positive, so `Q` is uniformly distributed (Haar measure).
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let q = rand_orth(4);
+/// let qtq = &q.t() * &q;
+/// assert!(eq_vec(&qtq.data, &eye(4).data, 1e-12));
+/// ```
+pub fn rand_orth(n: usize) -> Matrix {
+    rand_orth_with_rng(n, &mut thread_rng())
+}
+
+/// Random orthogonal matrix with specific rng
+pub fn rand_orth_with_rng<R: Rng + Clone>(n: usize, rng: &mut R) -> Matrix {
+    let b = randn_with_rng(n, n, 0f64, 1f64, rng);
+    let qr = b.qr();
+    let mut q = qr.q;
+    for j in 0..n {
+        if qr.r[(j, j)] < 0f64 {
+            for i in 0..n {
+                q[(i, j)] = -q[(i, j)];
+            }
+        }
+    }
+    q
+}
+
+/// Hilbert matrix
+///
+/// # Description
+///
+/// `H[i][j] = 1 / (i + j + 1)` (Famous ill-conditioned test matrix)
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let h = hilbert(3);
+/// assert_eq!(h, ml_matrix("1 0.5 0.3333333333333333;0.5 0.3333333333333333 0.25;0.3333333333333333 0.25 0.2"));
+/// ```
+pub fn hilbert(n: usize) -> Matrix {
+    Matrix::from_fn(n, n, |i, j| 1f64 / (i + j + 1) as f64, Row)
+}
+
+/// Vandermonde matrix
+///
+/// # Description
+///
+/// `V[i][j] = x[i]^(degree - j)` (highest degree first, like coefficients of `Polynomial`)
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let v = vandermonde(&[1f64, 2f64, 3f64], 2);
+/// assert_eq!(v, ml_matrix("1 1 1;4 2 1;9 3 1"));
+/// ```
+pub fn vandermonde(x: &[f64], degree: usize) -> Matrix {
+    let mut v = zeros(x.len(), degree + 1);
+    for (i, &t) in x.iter().enumerate() {
+        let mut x_pow = 1f64;
+        for j in (0..=degree).rev() {
+            v[(i, j)] = x_pow;
+            x_pow *= t;
+        }
+    }
+    v
+}
+
+/// Toeplitz matrix
+///
+/// # Description
+///
+/// MATLAB like `toeplitz(c, r)` : `c` is the first column, `r` is the first row.
+/// `T[i][j] = c[i - j]` for `i >= j` and `r[j - i]` otherwise (`r[0]` is ignored).
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let t = toeplitz(&[1f64, 2f64, 3f64], &[1f64, 4f64]);
+/// assert_eq!(t, ml_matrix("1 4;2 1;3 2"));
+/// ```
+pub fn toeplitz(c: &[f64], r: &[f64]) -> Matrix {
+    Matrix::from_fn(c.len(), r.len(), |i, j| if i >= j { c[i - j] } else { r[j - i] }, Row)
+}
+
 // ┌─────────────────────────────────────────────────────────┐
 //  Numpy like non-macro functions
 // └─────────────────────────────────────────────────────────┘
@@ -357,6 +585,9 @@ pub fn rand_with_rng<R: Rng>(r: usize, c: usize, rng: &mut R) -> Matrix {
 ///
 /// let single = logspace(0f64, 0f64, 1, 10);
 /// assert_eq!(single, vec![1f64]);
+///
+/// let desc = logspace(2, 0, 3, 10);
+/// assert_eq!(desc, vec![100f64, 10f64, 1f64]);
 /// ```
 pub fn logspace<S, T, U>(start: S, end: T, length: usize, base: U) -> Vec<f64>
 where
@@ -364,22 +595,53 @@ where
     T: Into<f64> + Copy,
     U: Into<f64> + Copy,
 {
-    let s: f64 = start.into();
-    let e: f64 = end.into();
     let b: f64 = base.into();
+    linspace(start, end, length)
+        .into_iter()
+        .map(|x| b.powf(x))
+        .collect()
+}
 
-    assert!(e >= s);
-
-    let step: f64 = if length > 1 { 
-        (e - s) / (length as f64 - 1f64)
-    } else {
-        0f64
-    };
-
-    let mut v: Vec<f64> = vec![0f64; length];
-
-    for (i, v) in v.iter_mut().enumerate() {
-        *v = b.powf(s + step * (i as f64));
+/// Numpy like geomspace
+///
+/// # Description
+/// Geometric progression from `start` to `end` (endpoints are exact).
+///
+/// # Panics
+/// * `start` or `end` is zero
+/// * `start` and `end` have different signs
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = geomspace(1, 1000, 4);
+/// assert!(eq_vec(&a, &vec![1f64, 10f64, 100f64, 1000f64], 1e-12));
+///
+/// let b = geomspace(-8, -1, 4);
+/// assert!(eq_vec(&b, &vec![-8f64, -4f64, -2f64, -1f64], 1e-12));
+/// ```
+pub fn geomspace<S, T>(start: S, end: T, length: usize) -> Vec<f64>
+where
+    S: Into<f64> + Copy,
+    T: Into<f64> + Copy,
+{
+    let s: f64 = start.into();
+    let e: f64 = end.into();
+    assert!(s != 0f64 && e != 0f64, "Geometric sequence cannot include zero");
+    assert!(
+        s.signum() == e.signum(),
+        "Start and end of geometric sequence should have the same sign"
+    );
+
+    let sgn = s.signum();
+    let mut v = logspace(s.abs().log10(), e.abs().log10(), length, 10f64);
+    v.iter_mut().for_each(|x| *x *= sgn);
+    if length > 0 {
+        v[0] = s;
+    }
+    if length > 1 {
+        v[length - 1] = e;
     }
     v
 }
@@ -404,6 +666,134 @@ pub fn row_stack(v: &[Vec<f64>]) -> Result<Matrix> {
     Ok(matrix(data, v.len(), col, Row))
 }
 
+/// Numpy like hstack - concatenate matrices by column direction
+///
+/// # Description
+/// Runtime version of `cbind` for any number of matrices (all should have the same number of rows).
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let blocks = vec![eye(2), zeros(2, 1), ml_matrix("1;2")];
+///     assert_eq!(hstack(&blocks)?, ml_matrix("1 0 0 1;0 1 0 2"));
+///     assert!(hstack(&[eye(2), eye(3)]).is_err());
+///     assert!(hstack(&[]).is_err());
+///     Ok(())
+/// }
+/// ```
+pub fn hstack(mats: &[Matrix]) -> Result<Matrix> {
+    let row = match mats.first() {
+        Some(m) => m.row,
+        None => bail!(ConcatenateError::Empty),
+    };
+    if mats.iter().any(|m| m.row != row) {
+        bail!(ConcatenateError::DifferentLength);
+    }
+    let col = mats.iter().map(|m| m.col).sum();
+    let mut data = Vec::with_capacity(row * col);
+    for m in mats {
+        match m.shape {
+            Col => data.extend_from_slice(&m.data),
+            Row => data.extend(m.change_shape().data),
+        }
+    }
+    Ok(matrix(data, row, col, Col))
+}
+
+/// Numpy like vstack - concatenate matrices by row direction
+///
+/// # Description
+/// Runtime version of `rbind` for any number of matrices (all should have the same number of columns).
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let blocks = vec![eye(2), zeros(1, 2), ml_matrix("1 2")];
+///     assert_eq!(vstack(&blocks)?, ml_matrix("1 0;0 1;0 0;1 2"));
+///     assert!(vstack(&[eye(2), eye(3)]).is_err());
+///     Ok(())
+/// }
+/// ```
+pub fn vstack(mats: &[Matrix]) -> Result<Matrix> {
+    let col = match mats.first() {
+        Some(m) => m.col,
+        None => bail!(ConcatenateError::Empty),
+    };
+    if mats.iter().any(|m| m.col != col) {
+        bail!(ConcatenateError::DifferentLength);
+    }
+    let row = mats.iter().map(|m| m.row).sum();
+    let mut data = Vec::with_capacity(row * col);
+    for m in mats {
+        match m.shape {
+            Row => data.extend_from_slice(&m.data),
+            Col => data.extend(m.change_shape().data),
+        }
+    }
+    Ok(matrix(data, row, col, Row))
+}
+
+/// Numpy like meshgrid
+///
+/// # Description
+///
+/// Cartesian (`xy`) indexing : `X[i][j] = x[j]`, `Y[i][j] = y[i]` (`y.len() x x.len()` matrices)
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let (xx, yy) = meshgrid(&[1f64, 2f64, 3f64], &[4f64, 5f64]);
+/// assert_eq!(xx, ml_matrix("1 2 3;1 2 3"));
+/// assert_eq!(yy, ml_matrix("4 4 4;5 5 5"));
+/// ```
+pub fn meshgrid(x: &[f64], y: &[f64]) -> (Matrix, Matrix) {
+    let xx = Matrix::from_fn(y.len(), x.len(), |_, j| x[j], Row);
+    let yy = Matrix::from_fn(y.len(), x.len(), |i, _| y[i], Row);
+    (xx, yy)
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Kernel matrix
+// └─────────────────────────────────────────────────────────┘
+/// Gram matrix
+///
+/// # Description
+///
+/// `K[i][j] = kernel(data.row(i), data.row(j))` (Each row of `data` is a data point).
+/// `kernel` should be symmetric - only the upper triangle is evaluated.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = ml_matrix("1 0;0 1;1 1");
+/// let k = gram(&x, |a, b| a.dot(b));
+/// assert_eq!(k, &x * &x.t());
+///
+/// // Gaussian kernel
+/// let k = gram(&x, |a, b| (-a.sub_vec(b).norm(Norm::L2).powi(2) / 2f64).exp());
+/// assert_eq!(k[(0, 0)], 1f64);
+/// assert!((k[(0, 1)] - (-1f64).exp()).abs() < 1e-15);
+/// ```
+pub fn gram<F: Fn(&Vec<f64>, &Vec<f64>) -> f64>(data: &Matrix, kernel: F) -> Matrix {
+    let n = data.row;
+    let rows = (0..n).map(|i| data.row(i)).collect::<Vec<Vec<f64>>>();
+    let mut k = zeros(n, n);
+    for i in 0..n {
+        for j in i..n {
+            let v = kernel(&rows[i], &rows[j]);
+            k[(i, j)] = v;
+            k[(j, i)] = v;
+        }
+    }
+    k
+}
+
 // ┌─────────────────────────────────────────────────────────┐
 //  Haskell like non-macro functions
 // └─────────────────────────────────────────────────────────┘
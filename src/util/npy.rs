@@ -0,0 +1,172 @@
+//! NumPy `.npy` format
+//!
+//! # Description
+//! Read & write `Matrix` as a NumPy `.npy` file (format version 1.0).
+//!
+//! * dtype : `<f8` (little-endian `f64`)
+//! * `fortran_order` follows `Shape` (`Row` : `False`, `Col` : `True`), so the data buffer is written as-is.
+//! * 1D arrays are read as `1 x n` matrix (like `np.atleast_2d`).
+//!
+//! # Examples
+//! ```
+//! #[macro_use]
+//! extern crate peroxide;
+//! use peroxide::fuga::*;
+//!
+//! fn main() -> Result<(), Box<dyn Error>> {
+//!     let path = std::env::temp_dir().join("test.npy");
+//!     let path = path.to_str().unwrap();
+//!
+//!     let a = ml_matrix("1 2 3;4 5 6");
+//!     a.write_npy(path)?;
+//!
+//!     // In Python : `np.load(path)`
+//!     let b = Matrix::read_npy(path)?;
+//!     assert_eq!(a, b);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::structure::matrix::{matrix, Matrix, Shape::*};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Read & write NumPy `.npy` file
+pub trait WithNpy: Sized {
+    fn write_npy(&self, file_path: &str) -> Result<(), Box<dyn Error>>;
+    fn read_npy(file_path: &str) -> Result<Self, Box<dyn Error>>;
+}
+
+impl WithNpy for Matrix {
+    /// Write to `.npy` file
+    fn write_npy(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        let mut w = BufWriter::new(File::create(file_path)?);
+        w.write_all(&npy_header(self))?;
+        for x in self.data.iter() {
+            w.write_all(&x.to_le_bytes())?;
+        }
+        w.flush()?;
+        Ok(())
+    }
+
+    /// Read from `.npy` file
+    fn read_npy(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        let file = File::open(file_path)?;
+        let file_len = file.metadata()?.len();
+        let mut r = BufReader::new(file);
+
+        let mut preamble = [0u8; 8];
+        r.read_exact(&mut preamble)?;
+        if &preamble[..6] != MAGIC {
+            return Err("Not a npy file".into());
+        }
+        let (header_len, len_size) = match preamble[6] {
+            1 => {
+                let mut buf = [0u8; 2];
+                r.read_exact(&mut buf)?;
+                (u16::from_le_bytes(buf) as usize, 2)
+            }
+            2 | 3 => {
+                let mut buf = [0u8; 4];
+                r.read_exact(&mut buf)?;
+                (u32::from_le_bytes(buf) as usize, 4)
+            }
+            v => return Err(format!("Unsupported npy version: {}", v).into()),
+        };
+        let mut header = vec![0u8; header_len];
+        r.read_exact(&mut header)?;
+        let header = String::from_utf8_lossy(&header);
+
+        let descr = header_value(&header, "descr").ok_or("No 'descr' in npy header")?;
+        let descr = descr
+            .split(',')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .trim_matches(|c| c == '\'' || c == '"');
+        if descr != "<f8" && descr != "f8" {
+            return Err(format!("Unsupported dtype: {} (only '<f8' is supported)", descr).into());
+        }
+        let fortran_order = header_value(&header, "fortran_order")
+            .ok_or("No 'fortran_order' in npy header")?
+            .starts_with("True");
+        let shape = header_value(&header, "shape")
+            .ok_or("No 'shape' in npy header")?
+            .trim_start_matches('(')
+            .split(')')
+            .next()
+            .unwrap_or("")
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<Vec<usize>, _>>()?;
+        let (row, col) = match shape.len() {
+            0 => (1, 1),
+            1 => (1, shape[0]),
+            2 => (shape[0], shape[1]),
+            n => return Err(format!("Only 1D or 2D array is supported (ndim = {})", n).into()),
+        };
+
+        // Shape comes from an untrusted header: check it against the file before allocating
+        let n_bytes = row
+            .checked_mul(col)
+            .and_then(|n| n.checked_mul(8))
+            .ok_or_else(|| format!("Array size overflows: shape = {:?}", shape))?;
+        let data_len = file_len.saturating_sub((preamble.len() + len_size + header_len) as u64);
+        if n_bytes as u64 > data_len {
+            return Err(format!(
+                "Data is shorter than shape {:?} requires ({} < {} bytes)",
+                shape, data_len, n_bytes
+            )
+            .into());
+        }
+
+        let mut buf = vec![0u8; n_bytes];
+        r.read_exact(&mut buf)?;
+        let data = buf
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+            .collect();
+
+        let shape = if fortran_order { Col } else { Row };
+        Ok(matrix(data, row, col, shape))
+    }
+}
+
+/// Header of `.npy` file (magic, version 1.0, header length & dictionary)
+///
+/// # Description
+/// The dictionary is padded with spaces (terminated by `\n`) so that the data starts at a multiple of 64 bytes.
+pub fn npy_header(m: &Matrix) -> Vec<u8> {
+    let fortran_order = match m.shape {
+        Row => "False",
+        Col => "True",
+    };
+    let mut dict = format!(
+        "{{'descr': '<f8', 'fortran_order': {}, 'shape': ({}, {}), }}",
+        fortran_order, m.row, m.col
+    );
+    // magic (6) + version (2) + header length (2) + dict + '\n'
+    let total = 10 + dict.len() + 1;
+    let pad = (64 - total % 64) % 64;
+    dict.push_str(&" ".repeat(pad));
+    dict.push('\n');
+
+    let mut header = MAGIC.to_vec();
+    header.extend_from_slice(&[1u8, 0u8]);
+    header.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    header.extend_from_slice(dict.as_bytes());
+    header
+}
+
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let key = format!("'{}'", key);
+    let start = header.find(&key)? + key.len();
+    let rest = header[start..].trim_start().strip_prefix(':')?;
+    Some(rest.trim_start())
+}
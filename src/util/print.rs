@@ -419,6 +419,73 @@ impl Printable for AD {
     }
 }
 
+/// Print `Vec<AD>` - one `AD` per line, with aligned components
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let v = vec![AD1(1.0, 0.5), AD1(-10.25, 3e-7)];
+/// v.print();
+/// // Result:
+/// // [AD1(     1,     0.5),
+/// //  AD1(-10.25, 3.00e-7)]
+/// ```
+impl Printable for Vec<AD> {
+    fn print(&self) {
+        println!("{}", format_ad_vec(self));
+    }
+}
+
+impl Printable for &Vec<AD> {
+    fn print(&self) {
+        println!("{}", format_ad_vec(self));
+    }
+}
+
+fn format_ad_vec(v: &[AD]) -> String {
+    // Same rule as `format_float_vec`
+    let fmt = |x: f64| {
+        let st1 = x.fmt_lower_exp(2);
+        let st2 = x.to_string();
+        if st1.len() < st2.len() {
+            st1
+        } else {
+            st2
+        }
+    };
+    let cells = v
+        .iter()
+        .map(|ad| ad.iter().map(|x| fmt(*x)).collect::<Vec<String>>())
+        .collect::<Vec<_>>();
+    let n_col = cells.iter().map(|c| c.len()).max().unwrap_or(0);
+    let width = (0..n_col)
+        .map(|j| {
+            cells
+                .iter()
+                .filter_map(|c| c.get(j))
+                .map(|st| st.len())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<usize>>();
+
+    let lines = v
+        .iter()
+        .zip(cells.iter())
+        .map(|(ad, c)| {
+            let body = c
+                .iter()
+                .zip(width.iter())
+                .map(|(st, w)| format!("{:>w$}", st, w = w))
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("AD{}({})", ad.order(), body)
+        })
+        .collect::<Vec<String>>();
+    format!("[{}]", lines.join(",\n "))
+}
+
 impl Printable for ConfusionMatrix {
     fn print(&self) {
         println!("{}", self.to_matrix())
@@ -0,0 +1,133 @@
+//! Portable SIMD-friendly vector kernels (`simd` feature)
+//!
+//! # Description
+//! Each kernel processes the input in fixed-width chunks of [`LANES`] elements
+//! with independent accumulators, which lets the compiler emit packed instructions
+//! on stable Rust. Remaining elements are handled by a scalar tail loop.
+//!
+//! Element-wise kernels (`add`, `sub`, `mul`, `axpy`) give exactly the same results as the scalar path.
+//! Like the scalar path, kernels of two vectors use the first `min(x.len(), y.len())` elements.
+//! Reductions (`dot`, `norm_l2`) change the order of summation, so the last few bits can differ.
+//! `sum` is compensated per lane (Neumaier), so it keeps the accuracy of [`ksum`](crate::structure::vector::ksum).
+//!
+//! # Examples
+//! ```
+//! # #[cfg(feature = "simd")] {
+//! use peroxide::util::simd;
+//!
+//! let x = vec![1f64, 2f64, 3f64, 4f64, 5f64];
+//! let y = vec![5f64, 4f64, 3f64, 2f64, 1f64];
+//! assert_eq!(simd::dot(&x, &y), 35f64);
+//! assert_eq!(simd::add(&x, &y), vec![6f64; 5]);
+//! # }
+//! ```
+
+use crate::structure::vector::ksum_iter;
+
+/// Width of a chunk
+pub const LANES: usize = 4;
+
+/// Sum of elements (compensated)
+///
+/// # Description
+/// Each lane keeps its own Neumaier correction.
+/// Lane sums and the tail are combined with `ksum`, then the corrections are added back.
+pub fn sum(x: &[f64]) -> f64 {
+    let mut acc = [0f64; LANES];
+    let mut comp = [0f64; LANES];
+    let chunks = x.chunks_exact(LANES);
+    let tail = chunks.remainder();
+    for c in chunks {
+        for ((s, e), &v) in acc.iter_mut().zip(comp.iter_mut()).zip(c) {
+            let t = *s + v;
+            *e += if s.abs() >= v.abs() { (*s - t) + v } else { (v - t) + *s };
+            *s = t;
+        }
+    }
+    let s = ksum_iter(acc.iter().chain(tail).copied());
+    if s.is_finite() {
+        s + comp.iter().sum::<f64>()
+    } else {
+        s
+    }
+}
+
+/// Dot product
+pub fn dot(x: &[f64], y: &[f64]) -> f64 {
+    let (x, y) = truncate(x, y);
+    let mut acc = [0f64; LANES];
+    let xs = x.chunks_exact(LANES);
+    let ys = y.chunks_exact(LANES);
+    let (xt, yt) = (xs.remainder(), ys.remainder());
+    for (a, b) in xs.zip(ys) {
+        for ((s, u), v) in acc.iter_mut().zip(a).zip(b) {
+            *s += u * v;
+        }
+    }
+    let mut s = (acc[0] + acc[1]) + (acc[2] + acc[3]);
+    for (a, b) in xt.iter().zip(yt) {
+        s += a * b;
+    }
+    s
+}
+
+/// L2 norm
+pub fn norm_l2(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+/// `y <- a * x + y`
+pub fn axpy(a: f64, x: &[f64], y: &mut [f64]) {
+    let n = x.len().min(y.len());
+    let (x, y) = (&x[..n], &mut y[..n]);
+    let mut ys = y.chunks_exact_mut(LANES);
+    let xs = x.chunks_exact(LANES);
+    let xt = xs.remainder();
+    for (c, b) in (&mut ys).zip(xs) {
+        c.iter_mut().zip(b).for_each(|(u, v)| *u += a * v);
+    }
+    for (c, b) in ys.into_remainder().iter_mut().zip(xt) {
+        *c += a * b;
+    }
+}
+
+/// Element-wise addition
+pub fn add(x: &[f64], y: &[f64]) -> Vec<f64> {
+    zip_kernel(x, y, |a, b| a + b)
+}
+
+/// Element-wise subtraction
+pub fn sub(x: &[f64], y: &[f64]) -> Vec<f64> {
+    zip_kernel(x, y, |a, b| a - b)
+}
+
+/// Element-wise multiplication
+pub fn mul(x: &[f64], y: &[f64]) -> Vec<f64> {
+    zip_kernel(x, y, |a, b| a * b)
+}
+
+#[inline(always)]
+fn zip_kernel<F: Fn(f64, f64) -> f64>(x: &[f64], y: &[f64], f: F) -> Vec<f64> {
+    let (x, y) = truncate(x, y);
+    let mut z = vec![0f64; x.len()];
+    let mut zs = z.chunks_exact_mut(LANES);
+    let xs = x.chunks_exact(LANES);
+    let ys = y.chunks_exact(LANES);
+    let (xt, yt) = (xs.remainder(), ys.remainder());
+    for ((c, a), b) in (&mut zs).zip(xs).zip(ys) {
+        for ((w, u), v) in c.iter_mut().zip(a).zip(b) {
+            *w = f(*u, *v);
+        }
+    }
+    for ((c, a), b) in zs.into_remainder().iter_mut().zip(xt).zip(yt) {
+        *c = f(*a, *b);
+    }
+    z
+}
+
+/// Common prefix of `x` & `y`
+#[inline(always)]
+fn truncate<'a>(x: &'a [f64], y: &'a [f64]) -> (&'a [f64], &'a [f64]) {
+    let n = x.len().min(y.len());
+    (&x[..n], &y[..n])
+}
@@ -34,3 +34,185 @@ fn ad_test() {
     println!("a.acosh: {:?}", a.acosh());
     println!("c.atanh: {:?}", c.atanh());
 }
+
+#[test]
+fn test_ad_format() {
+    let x = AD2(2.0, -0.5, 1e-3);
+    assert_eq!(x.to_string(), "AD2(2, -0.5, 0.001)");
+    assert_eq!(format!("{:.2}", x), "AD2(2.00, -0.50, 0.00)");
+    assert_eq!(format!("{:#.1e}", x), "(2.0e0, -5.0e-1, 1.0e-3)");
+
+    // Width aligns the whole AD (for tables)
+    let rows = [AD1(1.0, 2.0), AD1(-10.0, 0.25)];
+    let lines = rows
+        .iter()
+        .map(|a| format!("{:>18.2}|", a))
+        .collect::<Vec<_>>();
+    assert_eq!(lines[0], "   AD1(1.00, 2.00)|");
+    assert_eq!(lines[1], " AD1(-10.00, 0.25)|");
+}
+
+fn clamp<T: Real>(x: T, lo: f64, hi: f64) -> T {
+    x.max(T::from_f64(lo)).min(T::from_f64(hi))
+}
+
+#[test]
+fn test_real_clamp() {
+    for x in [-2f64, -0.3, 0.4, 0.9, 3f64] {
+        let y = clamp(x, -0.5, 1f64);
+        assert_eq!(y, x.max(-0.5).min(1f64));
+
+        // Derivative : 1 inside, 0 in the clamped region
+        let z = clamp(AD2(x, 1f64, 0f64), -0.5, 1f64);
+        assert_eq!(z.x(), y);
+        let slope = if (-0.5..=1f64).contains(&x) { 1f64 } else { 0f64 };
+        assert_eq!(z.dx(), slope);
+        assert_eq!(z.ddx(), 0f64);
+        assert_eq!(z.order(), 2);
+    }
+    assert!(AD1(1f64, 5f64) < AD1(2f64, -5f64));
+
+    // abs & signum
+    let f = |x: AD| (x * x - 1f64).abs();
+    assert_eq!(f(AD2(2f64, 1f64, 0f64)), AD2(3f64, 4f64, 2f64));
+    assert_eq!(f(AD2(0.5, 1f64, 0f64)), AD2(0.75, -1f64, -2f64));
+    assert_eq!(AD1(0f64, 1f64).abs(), AD1(0f64, 0f64));
+    assert_eq!(AD1(-2f64, 1f64).signum(), AD1(-1f64, 0f64));
+    assert_eq!(Real::signum(-2f64), -1f64);
+}
+
+#[test]
+fn test_ad_ln_at_e() {
+    let e = std::f64::consts::E;
+    let z = AD1(e, 1f64).ln();
+    assert_eq!(z.x(), 1f64);
+    assert!((z.dx() - 1f64 / e).abs() < 1e-15);
+
+    // (ln x)'' = -1/x², and ln ∘ exp = id
+    let z = AD2(e, 1f64, 0f64).ln();
+    assert!((z.ddx() + 1f64 / (e * e)).abs() < 1e-15);
+    let w = AD2(e, 1f64, 0f64).exp().ln();
+    assert!((w.x() - e).abs() < 1e-15);
+    assert!((w.dx() - 1f64).abs() < 1e-15);
+    assert!(w.ddx().abs() < 1e-14);
+}
+
+/// Apply every `ExpLogOps` method through the trait
+fn exp_log_all<T: ExpLogOps<Float = f64>>(x: &T) -> Vec<T> {
+    vec![x.exp(), x.ln(), x.log(3f64), x.log2(), x.log10()]
+}
+
+#[test]
+fn test_exp_log_ops() {
+    let x = 2.5f64;
+    // f(x), f'(x), f''(x) of exp, ln, log_3, log2, log10
+    let expected = [
+        (x.exp(), x.exp(), x.exp()),
+        (x.ln(), 1f64 / x, -1f64 / x.powi(2)),
+        (x.log(3f64), 1f64 / (x * 3f64.ln()), -1f64 / (x.powi(2) * 3f64.ln())),
+        (x.log2(), 1f64 / (x * 2f64.ln()), -1f64 / (x.powi(2) * 2f64.ln())),
+        (x.log10(), 1f64 / (x * 10f64.ln()), -1f64 / (x.powi(2) * 10f64.ln())),
+    ];
+    let close = |a: f64, b: f64| (a - b).abs() <= 1e-14 * b.abs().max(1f64);
+
+    // f64
+    for (y, e) in exp_log_all(&x).iter().zip(expected.iter()) {
+        assert!(close(*y, e.0));
+    }
+
+    // AD0, AD1, AD2
+    let ad0 = exp_log_all(&AD0(x));
+    let ad1 = exp_log_all(&AD1(x, 1f64));
+    let ad2 = exp_log_all(&AD2(x, 1f64, 0f64));
+    for i in 0..expected.len() {
+        let (f, df, ddf) = expected[i];
+        assert_eq!(ad0[i].order(), 0);
+        assert!(close(ad0[i].x(), f));
+        assert!(close(ad1[i].x(), f) && close(ad1[i].dx(), df));
+        assert!(close(ad2[i].x(), f) && close(ad2[i].dx(), df) && close(ad2[i].ddx(), ddf));
+    }
+
+    // Matrix (element-wise)
+    let m = ml_matrix("0.5 1;2.5 4");
+    for (k, y) in exp_log_all(&m).iter().enumerate() {
+        let elem = m.data.iter().map(|t| exp_log_all(t)[k]).collect::<Vec<f64>>();
+        assert_eq!(y.data, elem);
+    }
+
+    // Invalid input propagates as NaN instead of a value
+    for y in exp_log_all(&AD2(-1f64, 1f64, 0f64)).iter().skip(1) {
+        assert!(y.x().is_nan());
+    }
+    for y in exp_log_all(&AD1(f64::NAN, 1f64)) {
+        assert!(y.x().is_nan() && y.dx().is_nan());
+    }
+    for y in exp_log_all(&ml_matrix("-1 0")).iter().skip(1) {
+        assert!(y.data[0].is_nan());
+        assert_eq!(y.data[1], f64::NEG_INFINITY);
+    }
+}
+
+/// RK4 for `y' = f(t, y)` written only with the `Real` surface
+fn rk4_real<T: Real, F: Fn(f64, &[T]) -> Vec<T>>(f: F, y0: Vec<T>, t_end: f64, n: usize) -> Vec<T> {
+    let h = t_end / n as f64;
+    let mut y = y0;
+    for i in 0..n {
+        let t = i as f64 * h;
+        let shift = |y: &[T], k: &[T], c: f64| {
+            y.iter().zip(k).map(|(&a, &b)| a + b * c).collect::<Vec<T>>()
+        };
+        let k1 = f(t, &y);
+        let k2 = f(t + h / 2f64, &shift(&y, &k1, h / 2f64));
+        let k3 = f(t + h / 2f64, &shift(&y, &k2, h / 2f64));
+        let k4 = f(t + h, &shift(&y, &k3, h));
+        for j in 0..y.len() {
+            y[j] = y[j] + (k1[j] + k2[j] * 2f64 + k3[j] * 2f64 + k4[j]) * (h / 6f64);
+        }
+    }
+    y
+}
+
+#[test]
+fn test_real_second_order_ode_sensitivity() {
+    // y' = -k y, y(0) = 1 => y(T) = exp(-kT)
+    // dy/dk = -T exp(-kT), d²y/dk² = T² exp(-kT)
+    let (k, t_end) = (0.7, 2f64);
+    let k_ad = AD2(k, 1f64, 0f64);
+    let y0 = vec![AD::from_f64(1f64)];
+    let y = rk4_real(|_, y: &[AD]| vec![-(y[0] * k_ad)], y0, t_end, 200)[0];
+
+    let e = (-k * t_end).exp();
+    assert!((y.x() - e).abs() < 1e-10);
+    assert!((y.dx() + t_end * e).abs() < 1e-9);
+    assert!((y.ddx() - t_end.powi(2) * e).abs() < 1e-9);
+
+    // Same integrator with f64
+    let y_f64 = rk4_real(|_, y: &[f64]| vec![-y[0] * k], vec![1f64], t_end, 200)[0];
+    assert_eq!(y_f64, y.to_f64());
+
+    // Conversions keep the order
+    assert_eq!(y.to_ad(), y);
+    assert_eq!(AD::from_f64(y.to_f64()), AD0(y.x()));
+    assert_eq!(y.abs(), y);
+    assert_eq!((-y).abs(), y);
+    assert_eq!(y.max(AD0(0f64)), y);
+    assert_eq!(y.signum().order(), 2);
+}
+
+#[test]
+fn test_ode_state_to_second_order_ad() {
+    let state = ODEState::from_record(&[0f64, 0.1], &[vec![1f64, -2f64], vec![1.1, -2.2]], 0);
+    let y = state
+        .y
+        .to_ad_vec()
+        .into_iter()
+        .map(|t| t.to_order(2))
+        .collect::<Vec<AD>>();
+
+    assert_eq!(y.to_f64_vec(), state.y);
+    for t in y.iter() {
+        assert_eq!(t.order(), 2);
+        assert_eq!((t.dx(), t.ddx()), (0f64, 0f64));
+    }
+    assert_eq!(y[1], AD2(-2f64, 0f64, 0f64));
+}
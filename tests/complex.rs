@@ -0,0 +1,90 @@
+extern crate peroxide;
+#[allow(unused_imports)]
+use peroxide::fuga::*;
+
+#[cfg(feature = "complex")]
+use peroxide::complex::{matrix::*, C64};
+
+#[cfg(feature = "complex")]
+fn cnearly_eq(a: &ComplexMatrix, b: &ComplexMatrix) -> bool {
+    a.row == b.row
+        && a.col == b.col
+        && (0..a.row).all(|i| (0..a.col).all(|j| (a[(i, j)] - b[(i, j)]).norm() < 1e-10))
+}
+
+#[test]
+#[cfg(feature = "complex")]
+fn complex_matrix_inv_test() {
+    // A = [[1, i, 0], [-i, 2, 1], [0, 1, 3i]]
+    let re = ml_matrix("1 0 0;0 2 1;0 1 0");
+    let im = ml_matrix("0 1 0;-1 0 0;0 0 3");
+    let a = ComplexMatrix::from_parts(&re, &im);
+
+    // det = 1*(2*3i - 1) - i*(-i*3i - 0) = 6i - 1 - 3i = -1 + 3i
+    assert!((a.det() - C64::new(-1f64, 3f64)).norm() < 1e-12);
+
+    let a_inv = a.inv();
+    assert!(cnearly_eq(&(&a * &a_inv), &ceye(3)));
+    assert!(cnearly_eq(&(&a_inv * &a), &ceye(3)));
+
+    // Known inverse: adj(A) / det(A)
+    let d = C64::new(-1f64, 3f64);
+    let adj = cmatrix(
+        vec![
+            C64::new(-1f64, 6f64),
+            C64::new(3f64, 0f64),
+            C64::new(0f64, 1f64),
+            C64::new(-3f64, 0f64),
+            C64::new(0f64, 3f64),
+            C64::new(-1f64, 0f64),
+            C64::new(0f64, -1f64),
+            C64::new(-1f64, 0f64),
+            C64::new(1f64, 0f64),
+        ],
+        3,
+        3,
+        Row,
+    );
+    assert!(cnearly_eq(&a_inv, &(adj * (C64::new(1f64, 0f64) / d))));
+
+    // Column shaped input gives the same inverse
+    let b = ComplexMatrix::from_parts(&re.change_shape(), &im.change_shape());
+    assert!(cnearly_eq(&b.inv(), &a_inv));
+}
+
+#[test]
+#[cfg(feature = "complex")]
+fn complex_matrix_hermitian_test() {
+    let a = ComplexMatrix::from_parts(&rand(3, 4), &rand(3, 4));
+    let b = ComplexMatrix::from_parts(&rand(4, 2), &rand(4, 2));
+
+    let lhs = (&a * &b).hermitian();
+    let rhs = &b.hermitian() * &a.hermitian();
+    assert!(cnearly_eq(&lhs, &rhs));
+    assert_eq!(a.hermitian().hermitian(), a);
+    assert_eq!(a.re(), a.hermitian().re().t());
+    assert_eq!(a.im(), -a.hermitian().im().t());
+    assert!(cnearly_eq(&(&a - &a), &ComplexMatrix::from_real(&zeros(3, 4))));
+}
+
+#[test]
+#[cfg(feature = "complex")]
+fn complex_eigenvalues_test() {
+    // Rotation-scaling block & real eigenvalue: 2 +- 3i, 5
+    let a = ml_matrix("2 -3 0;3 2 0;1 1 5");
+    let ev = complex_eigenvalues(&a);
+    assert_eq!((ev.row, ev.col), (3, 1));
+    assert_eq!(ev.col(0), ev.data);
+    let mut ev = ev.data;
+    ev.sort_by(|x, y| x.im.partial_cmp(&y.im).unwrap());
+    assert!((ev[0] - C64::new(2f64, -3f64)).norm() < 1e-10);
+    assert!((ev[1] - C64::new(5f64, 0f64)).norm() < 1e-10);
+    assert!((ev[2] - C64::new(2f64, 3f64)).norm() < 1e-10);
+
+    // Random matrix: sum of eigenvalues = trace
+    let b = rand(6, 6);
+    let ev = complex_eigenvalues(&b);
+    let s = ev.data.iter().fold(C64::new(0f64, 0f64), |acc, x| acc + x);
+    let tr = b.diag().iter().sum::<f64>();
+    assert!((s.re - tr).abs() < 1e-10 && s.im.abs() < 1e-10);
+}
@@ -16,3 +16,103 @@ fn test_type_cast() {
 
     assert_eq!(a, b);
 }
+
+#[test]
+fn test_print_with() {
+    let mut df = DataFrame::new(vec![]);
+    df.push("x", Series::new(vec![1f64 / 3f64, 2f64]));
+    df.push("n", Series::new(vec![1i32, 2]));
+    assert_eq!(df.spread(), df.print_with(2));
+    assert_eq!(format!("{}", df), df.print_with(2));
+    assert!(df.spread().contains("3.33e-1"));
+    assert!(df.print_with(6).contains("3.333333e-1"));
+    assert!(!df.print_with(6).contains("3.3333333"));
+}
+
+#[test]
+fn test_attrs() {
+    let mut df = DataFrame::new(vec![Series::new(vec![1, 2, 3])]);
+    df.push("t", Series::new(vec![0.0, 0.5, 1.0]));
+    df.push("x", Series::new(vec![1.0, 2.0, 3.0]));
+    df.set_attr("t", "units", "s");
+    df.set_attr("x", "units", "cm");
+    df.set_attr("x", "long_name", "position");
+    df.set_attr("x", "units", "m");
+    df.set_global_attr("title", "test");
+
+    assert_eq!(df.get_attr("t", "units"), Some("s"));
+    assert_eq!(df.get_attr("x", "units"), Some("m"));
+    assert_eq!(df.get_attr("x", "long_name"), Some("position"));
+    assert_eq!(df.get_attr("0", "units"), None);
+    assert_eq!(df.get_attr("y", "units"), None);
+    assert_eq!(df.get_global_attr("title"), Some("test"));
+
+    // Attributes follow their columns
+    df.drop("t");
+    assert_eq!(df.get_attr("x", "units"), Some("m"));
+    assert_eq!(df.row(1).get_attr("x", "long_name"), Some("position"));
+
+    // Attributes are ignored in comparison
+    let mut dg = DataFrame::new(vec![Series::new(vec![1, 2, 3])]);
+    dg.push("x", Series::new(vec![1.0, 2.0, 3.0]));
+    assert_eq!(df, dg);
+    df.clear_attrs();
+    assert_eq!(df.get_attr("x", "units"), None);
+    assert_eq!(df.get_global_attr("title"), None);
+
+    // Struct literal with empty attributes
+    let dh = DataFrame {
+        data: dg.data.clone(),
+        ics: dg.ics.clone(),
+        attrs: Default::default(),
+    };
+    assert_eq!(dh, dg);
+}
+
+#[test]
+#[cfg(feature = "nc")]
+fn test_nc_attrs() -> Result<(), Box<dyn Error>> {
+    let mut df = DataFrame::new(vec![]);
+    df.push("t", Series::new(vec![0.0, 0.5, 1.0]));
+    df.push("x", Series::new(vec![1.0, 2.0, 3.0]));
+    df.push("n", Series::new(vec![1, 2, 3]));
+    df.set_attr("t", "units", "s");
+    df.set_attr("x", "units", "m");
+    df.set_attr("x", "long_name", "position");
+    df.set_global_attr("title", "attribute test");
+    df.write_nc("example_data/test_attrs.nc")?;
+
+    let dg = DataFrame::read_nc("example_data/test_attrs.nc")?;
+    assert_eq!(dg.get_attr("t", "units"), Some("s"));
+    assert_eq!(dg.get_attr("x", "units"), Some("m"));
+    assert_eq!(dg.get_attr("x", "long_name"), Some("position"));
+    assert_eq!(dg.get_attr("n", "units"), None);
+    assert_eq!(dg.get_global_attr("title"), Some("attribute test"));
+    assert_eq!(df, dg);
+
+    let dh = DataFrame::read_nc_by_header("example_data/test_attrs.nc", vec!["x"])?;
+    assert_eq!(dh.get_attr("x", "long_name"), Some("position"));
+    Ok(())
+}
+
+#[test]
+fn test_dataframe_matrix_round_trip() {
+    let mut df = DataFrame::new(vec![]);
+    df.push("time", Series::new(c!(0, 0.1, 0.2)));
+    df.push("count", Series::new(vec![3i32, 1, 4]));
+    df.push("value", Series::new(vec![1f32, 5f32, 9f32]));
+
+    let m = df.to_matrix();
+    assert_eq!((m.row, m.col), (3, 3));
+    assert_eq!(m.col(1), c!(3, 1, 4));
+    assert!(m.spread().contains("count"));
+
+    let dg = DataFrame::from_matrix(&m);
+    assert_eq!(dg.header(), df.header());
+    df.as_types(vec![F64, F64, F64]);
+    assert_eq!(dg, df);
+
+    // Labels follow transpose
+    let dt = DataFrame::from_matrix(&m.t().t());
+    assert_eq!(dt.header(), df.header());
+}
@@ -0,0 +1,97 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_derivative_sin() {
+    for x in linspace(-3, 3, 13) {
+        let df = derivative(|t| t.sin(), x, 0.1);
+        assert!((df - x.cos()).abs() < 1e-13, "x = {}, df = {}", x, df);
+
+        let d2f = second_derivative(|t| t.sin(), x, 0.1);
+        assert!((d2f + x.sin()).abs() < 1e-8, "x = {}, d2f = {}", x, d2f);
+    }
+}
+
+#[test]
+fn test_derivative_polynomial() {
+    let f = |x: f64| x.powi(5) - 3f64 * x.powi(2) + 1f64;
+    for x in [-2f64, -0.5, 0f64, 1f64, 3f64] {
+        let df = 5f64 * x.powi(4) - 6f64 * x;
+        let d2f = 20f64 * x.powi(3) - 6f64;
+        assert!((derivative(f, x, 0.5) - df).abs() < 1e-10 * df.abs().max(1f64));
+        assert!((second_derivative(f, x, 0.5) - d2f).abs() < 1e-7 * d2f.abs().max(1f64));
+    }
+}
+
+#[test]
+#[cfg(feature = "complex")]
+fn test_complex_step_derivative() {
+    // Squire & Trapp (1998)
+    let f = |z: peroxide::complex::C64| z.exp() / (z.sin().powi(3) + z.cos().powi(3)).sqrt();
+    let g = |x: f64| x.exp() / (x.sin().powi(3) + x.cos().powi(3)).sqrt();
+    let x = 1.5f64;
+    let s = x.sin().powi(3) + x.cos().powi(3);
+    let ds = 3f64 * x.sin() * x.cos() * (x.sin() - x.cos());
+    let exact = x.exp() / s.sqrt() * (1f64 - ds / (2f64 * s));
+
+    assert!((complex_step_derivative(f, x) - exact).abs() < 1e-14 * exact.abs());
+    for h in [1e-8, 1e-20, 1e-100, 1e-300] {
+        let df = complex_step_derivative_with_step(f, x, h);
+        assert!((df - exact).abs() < 1e-14 * exact.abs(), "h = {:e}, df = {}", h, df);
+    }
+
+    // Central difference suffers from cancellation for tiny h
+    let h = 1e-13;
+    let fd = (g(x + h) - g(x - h)) / (2f64 * h);
+    assert!((fd - exact).abs() > 1e-6 * exact.abs());
+}
+
+#[test]
+fn test_derivative_n_exp() {
+    for x in [-1f64, 0f64, 0.5, 2f64] {
+        let exact = x.exp();
+        let tol = [1e-12, 1e-9, 1e-7, 1e-5];
+        for (order, tol) in (1..=4).zip(tol) {
+            let (d, err) = derivative_n_with_err(|t| t.exp(), x, order, 0.5);
+            assert!(
+                (d - exact).abs() < tol * exact,
+                "order = {}, x = {}, d = {}",
+                order,
+                x,
+                d
+            );
+            assert!(err < tol * exact);
+        }
+    }
+    assert_eq!(derivative_n(|t| t.exp(), 0.5, 1, 0.5), derivative(|t| t.exp(), 0.5, 0.5));
+}
+
+#[test]
+fn test_derivative_log_edge() {
+    // Near the domain edge, `h` should be smaller than `x`
+    let x = 1e-8;
+    let (df, err) = derivative_with_err(|t| t.ln(), x, 5e-9);
+    assert!((df - 1f64 / x).abs() < 1e-9 / x, "df = {}", df);
+    assert!((df - 1f64 / x).abs() <= 10f64 * err.max(f64::EPSILON / x));
+
+    let d2f = derivative_n(|t| t.ln(), x, 2, 5e-9);
+    assert!((d2f + 1f64 / (x * x)).abs() < 1e-6 / (x * x), "d2f = {}", d2f);
+}
+
+#[test]
+fn test_derivative_noise() {
+    // Deterministic pseudo-noise of amplitude `eps`
+    let noisy = |eps: f64| {
+        move |t: f64| t.sin() + eps * ((t * 1e7).sin() * 43758.5453).fract()
+    };
+    let x = 0.7f64;
+    let (_, err_clean) = derivative_with_err(noisy(0f64), x, 0.1);
+    let mut prev = err_clean;
+    for eps in [1e-12, 1e-9, 1e-6] {
+        let (df, err) = derivative_with_err(noisy(eps), x, 0.1);
+        assert!(err > prev && err > 0.1 * eps, "eps = {:e}, err = {:e}", eps, err);
+        // Noise is detected, but the estimate is only an order of magnitude
+        assert!((df - x.cos()).abs() < 100f64 * err, "eps = {:e}, df = {}", eps, df);
+        prev = err;
+    }
+}
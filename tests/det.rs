@@ -26,3 +26,194 @@ fn test_det2() {
     );
     nearly_eq(test_mat.det(), 0.9999999915708137);
 }
+
+#[test]
+fn test_slogdet() {
+    let a = rand(8, 8) - 0.5 * matrix(vec![1f64; 64], 8, 8, Row);
+    let (sgn, logdet) = a.slogdet();
+    assert!((sgn * logdet.exp() - a.det()).abs() < 1e-12 * a.det().abs().max(1f64));
+
+    let b = ml_matrix("0 1;1 0");
+    assert_eq!(b.slogdet(), (-1f64, 0f64));
+
+    let c = ml_matrix("1 2;2 4");
+    assert_eq!(c.slogdet(), (0f64, f64::NEG_INFINITY));
+}
+
+#[test]
+fn test_lu_negative_pivot() {
+    let a = ml_matrix("-4 -3;-2 -1");
+    let pqlu = a.lu();
+    assert_eq!(pqlu.u[(0, 0)], -4f64);
+    assert_eq!(a.det(), -2f64);
+    assert_eq!(a.inv(), ml_matrix("0.5 -1.5;-1 2"));
+    assert_eq!(a.solve(&vec![-7f64, -3f64], SolveKind::LU), vec![1f64, 1f64]);
+
+    // Large negative entries
+    let b = ml_matrix("1 -2 3;-1e8 5 -6;7 -8 -9e7");
+    let det = 1f64 * (5f64 * -9e7 - 48f64) + 2f64 * (9e15 + 42f64) + 3f64 * (8e8 - 35f64);
+    assert!((b.det() - det).abs() < 1e-12 * det.abs());
+    assert!(eq_vec(&(b.clone() * b.inv()).data, &eye(3).data, 1e-12));
+    let x = vec![1f64, -1f64, 2f64];
+    let y = &b * &x;
+    assert!(eq_vec(&b.solve(&y, SolveKind::LU), &x, 1e-12));
+}
+
+#[test]
+fn test_lu_1x1() {
+    let a = ml_matrix("-3");
+    assert_eq!(a.det(), -3f64);
+    assert_eq!(a.inv(), ml_matrix("-0.3333333333333333"));
+    assert_eq!(a.solve(&vec![6f64], SolveKind::LU), vec![-2f64]);
+    assert_eq!(ml_matrix("0").det(), 0f64);
+}
+
+#[test]
+fn test_lu_singular() {
+    // Exactly singular in exact arithmetic, but not in floating point
+    let a = ml_matrix("1 2 3;4 5 6;7 8 9");
+    assert!(a.lu().is_singular());
+    assert_eq!(a.det(), 0f64);
+    assert_eq!(a.slogdet(), (0f64, f64::NEG_INFINITY));
+
+    // det is the product of pivots, so only rounding error remains
+    let b = ml_matrix("-1e10 -2e10;-3e-10 -6e-10");
+    assert!(b.lu().is_singular());
+    assert!(b.det().abs() < 1e-14);
+
+    // Nearly singular, but not singular
+    let c = ml_matrix("1 1;1 1.000000001");
+    assert!(!c.lu().is_singular());
+    assert!((c.det() - 1e-9).abs() < 1e-15);
+    let x = c.solve(&vec![2f64, 2.000000001], SolveKind::LU);
+    assert!(eq_vec(&x, &vec![1f64, 1f64], 1e-6));
+}
+
+#[test]
+fn test_lu_badly_scaled() {
+    // Widely scaled diagonals are regular : det is exact & inv works
+    for v in [vec![1e8, 1e-8, 1f64], vec![1e8, 1e-8, 1f64, 1e-4, 1e4]] {
+        let a = diag_mat(&v);
+        let lu = a.lu();
+        assert!(!lu.is_singular());
+        assert_eq!(lu.det(), 1f64);
+        assert_eq!(a.det(), 1f64);
+        assert_eq!(a.slogdet(), (1f64, 0f64));
+        let inv = a.inv();
+        for (i, x) in v.iter().enumerate() {
+            assert_eq!(inv[(i, i)], 1f64 / x);
+        }
+        let b = a.solve(&v, SolveKind::LU);
+        assert!(eq_vec(&b, &vec![1f64; v.len()], 1e-15));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_inv_singular() {
+    ml_matrix("1 2;2 4").inv();
+}
+
+#[test]
+#[should_panic]
+fn test_solve_singular() {
+    ml_matrix("-1 -2;-2 -4").solve(&vec![1f64, 1f64], SolveKind::LU);
+}
+
+#[test]
+fn test_small_det_inv() {
+    // Closed-form paths agree with LU on random small matrices
+    let mut rng = stdrng_from_seed(42);
+    let u = Uniform(-1f64, 1f64);
+    for n in 1..=4 {
+        for _ in 0..1000 {
+            let a = matrix(u.sample_with_rng(&mut rng, n * n), n, n, Row);
+            let lu = a.lu();
+            if lu.is_singular() {
+                continue;
+            }
+            assert!(nearly_eq(a.det(), lu.det()));
+            let inv = a.inv();
+            let inv_lu = lu.inv();
+            for i in 0..n {
+                for j in 0..n {
+                    assert!(nearly_eq(inv[(i, j)], inv_lu[(i, j)]));
+                }
+            }
+            let id = &a * &inv;
+            assert!((id - eye(n)).data.iter().all(|x| x.abs() < 1e-8));
+        }
+    }
+
+    // Column major input
+    let a = matrix(vec![2f64, 1f64, 1f64, 3f64, 2f64, 1f64, 2f64, 1f64, 3f64], 3, 3, Col);
+    assert!(nearly_eq(a.det(), a.lu().det()));
+    let inv = a.inv();
+    let inv_lu = a.lu().inv();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!(nearly_eq(inv[(i, j)], inv_lu[(i, j)]));
+        }
+    }
+
+    // 4x4 (Row & Col)
+    let a = ml_matrix("4 -2 1 3;3 6 -4 2;2 1 8 -5;1 -3 2 7");
+    for m in [a.clone(), a.change_shape()] {
+        assert!(nearly_eq(m.det(), m.lu().det()));
+        let id = &m * &m.inv();
+        assert!((id - eye(4)).data.iter().all(|x| x.abs() < 1e-12));
+    }
+
+    // 4x4 badly scaled (mixed units) : |det| far below n ε max|a_ij|^4
+    let s = vec![1e-4, 1e-3, 1f64, 1e2];
+    let a = ml_matrix("4 -2 1 3;3 6 -4 2;2 1 8 -5;1 -3 2 7");
+    let a = matrix(
+        (0..16).map(|k| a[(k / 4, k % 4)] * s[k / 4] * s[k % 4]).collect(),
+        4,
+        4,
+        Row,
+    );
+    let lu = a.lu();
+    assert!(!lu.is_singular());
+    assert!(nearly_eq(a.det(), lu.det()));
+    let inv = a.inv();
+    let inv_lu = lu.inv();
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!(nearly_eq(inv[(i, j)], inv_lu[(i, j)]));
+        }
+    }
+
+    // Singular
+    let b = ml_matrix("1 2 3;4 5 6;7 8 9");
+    assert_eq!(b.det(), 0f64);
+    let b = ml_matrix("1 2 3 4;5 6 7 8;9 10 11 12;13 14 15 16");
+    assert!(b.lu().is_singular());
+    assert!(b.det().abs() < 1e-10);
+    let b = b * 1e-10;
+    assert!(b.lu().is_singular());
+    assert!(b.det().abs() < 1e-50);
+
+    // Badly scaled, but well conditioned : tiny det is not singular
+    let d = ml_matrix("1e-8 0 0;0 1e-8 0;0 0 1");
+    assert!(!d.lu().is_singular());
+    assert!(nearly_eq(d.det(), 1e-16));
+    assert!(nearly_eq(d.det(), d.lu().det()));
+    let inv = d.inv();
+    assert_eq!(inv, d.lu().inv());
+    assert!(nearly_eq(inv[(0, 0)], 1e8));
+}
+
+#[test]
+#[should_panic]
+fn test_small_inv_singular() {
+    let b = ml_matrix("1 2;2 4");
+    b.inv();
+}
+
+#[test]
+#[should_panic]
+fn test_small_inv_singular_4x4() {
+    let b = ml_matrix("1 2 3 4;2 4 6 8;0 1 0 1;1 0 1 0");
+    b.inv();
+}
@@ -1,5 +1,6 @@
 extern crate peroxide;
 use peroxide::fuga::*;
+use std::f64::consts::PI;
 
 #[test]
 fn test_binomial() {
@@ -8,3 +9,374 @@ fn test_binomial() {
     assert!(nearly_eq(b.mean(), 80f64));
     assert!(nearly_eq(b.var(), 16f64));
 }
+
+#[test]
+fn test_student_t_cdf() {
+    let t = StudentT(10f64);
+    // Two-sided 95% critical value
+    assert!((t.cdf(2.228139) - 0.975).abs() < 1e-6);
+    assert!((t.cdf(-2.228139) - 0.025).abs() < 1e-6);
+    assert_eq!(t.cdf(0f64), 0.5);
+}
+
+#[test]
+fn test_chi_squared() {
+    // 95% critical values
+    for (k, x) in [(1f64, 3.841459), (3f64, 7.814728), (10f64, 18.307038)] {
+        let chi = ChiSquared(k);
+        assert!((chi.cdf(x) - 0.95).abs() < 1e-6);
+        assert_eq!(chi.mean(), k);
+        assert_eq!(chi.var(), 2f64 * k);
+    }
+
+    for k in [3f64, 10f64] {
+        let chi = ChiSquared(k);
+        let total = integrate(|x| chi.pdf(x), (0f64, 200f64), G7K15R(1e-10, 20));
+        assert!((total - 1f64).abs() < 1e-8);
+        assert!((integrate(|x| chi.pdf(x), (0f64, 5f64), G7K15R(1e-10, 20)) - chi.cdf(5f64)).abs() < 1e-8);
+    }
+    assert_eq!(ChiSquared(3f64).pdf(-1f64), 0f64);
+    assert_eq!(ChiSquared(3f64).cdf(-1f64), 0f64);
+
+    let mut rng = stdrng_from_seed(42);
+    let sample = ChiSquared(4f64).sample_with_rng(&mut rng, 100_000);
+    assert!((sample.mean() - 4f64).abs() < 0.05);
+}
+
+#[test]
+fn test_f_dist() {
+    // 95% critical values
+    for (d1, d2, x) in [(5f64, 10f64, 3.325835), (1f64, 1f64, 161.4476), (3f64, 20f64, 3.098391)] {
+        let f = FDist(d1, d2);
+        assert!((f.cdf(x) - 0.95).abs() < 1e-6);
+    }
+
+    let f = FDist(5f64, 10f64);
+    let total = integrate(|x| f.pdf(x), (0f64, 1000f64), G7K15R(1e-10, 20));
+    assert!((total - 1f64).abs() < 1e-6);
+    assert!((integrate(|x| f.pdf(x), (0f64, 2f64), G7K15R(1e-10, 20)) - f.cdf(2f64)).abs() < 1e-8);
+    assert_eq!(f.mean(), 1.25);
+    assert!((f.var() - 1.3541666666666667).abs() < 1e-12);
+
+    let mut rng = stdrng_from_seed(42);
+    let sample = f.sample_with_rng(&mut rng, 100_000);
+    assert!((sample.mean() - 1.25).abs() < 0.02);
+}
+
+#[test]
+fn test_t_test() {
+    // Student's sleep data (R : `sleep`)
+    let a = vec![0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    let b = vec![1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+    // t.test(a, b) : t = -1.8608, df = 17.776, p-value = 0.07939
+    let (t, p) = t_test_two(&a, &b, false);
+    assert!((t + 1.860813467).abs() < 1e-8);
+    assert!((p - 0.079394140).abs() < 1e-8);
+
+    // t.test(a, b, var.equal = TRUE) : t = -1.8608, df = 18, p-value = 0.07919
+    let (t, p) = t_test_two(&a, &b, true);
+    assert!((t + 1.860813467).abs() < 1e-8);
+    assert!((p - 0.079186714).abs() < 1e-8);
+
+    // t.test(a - b) : t = -4.0621, df = 9, p-value = 0.002833
+    let d = a.iter().zip(b.iter()).map(|(x, y)| x - y).collect::<Vec<f64>>();
+    let (t, p) = t_test_one(&d, 0f64);
+    assert!((t + 4.062127683).abs() < 1e-8);
+    assert!((p - 0.002832890).abs() < 1e-8);
+
+    // Symmetric
+    let (t2, p2) = t_test_two(&b, &a, false);
+    assert!((t2 + t_test_two(&a, &b, false).0).abs() < 1e-12);
+    assert!((p2 - 0.079394140).abs() < 1e-8);
+}
+
+#[test]
+fn test_t_test_small_t() {
+    // p-value -> 1 as t -> 0 and decreases monotonically in |t|
+    let base = vec![-1f64, 1f64, -1f64, 1f64, -1f64, 1f64, -1f64, 1f64, -1f64, 1f64];
+    let mut prev_p = 1f64;
+    for k in (0..10).rev() {
+        let shift = -10f64.powi(-k);
+        let x = base.iter().map(|t| t + shift).collect::<Vec<f64>>();
+        let (t, p) = t_test_one(&x, 0f64);
+        assert!(t < 0f64);
+        assert!(p <= prev_p, "p = {} at t = {}", p, t);
+        prev_p = p;
+    }
+    let x = base.iter().map(|t| t - 1e-9).collect::<Vec<f64>>();
+    assert!((t_test_one(&x, 0f64).1 - 1f64).abs() < 1e-8);
+}
+
+#[test]
+fn test_gamma_beta_sample() {
+    let mut rng = stdrng_from_seed(42);
+    let n = 200_000;
+
+    for (shape, rate) in [(0.5, 1f64), (0.5, 2f64), (5f64, 1f64), (5f64, 0.5)] {
+        let g = Gamma(shape, rate);
+        let x = g.sample_with_rng(&mut rng, n);
+        assert!(x.iter().all(|&t| t > 0f64));
+        let (m, v) = (g.mean(), g.var());
+        assert!((x.mean() - m).abs() < 0.02 * m, "Gamma({}, {}) mean : {}", shape, rate, x.mean());
+        assert!((x.var() - v).abs() < 0.05 * v, "Gamma({}, {}) var : {}", shape, rate, x.var());
+    }
+
+    for (a, b) in [(0.5, 0.5), (0.5, 5f64), (5f64, 2f64)] {
+        let be = Beta(a, b);
+        let x = be.sample_with_rng(&mut rng, n);
+        assert!(x.iter().all(|&t| (0f64..=1f64).contains(&t)));
+        let (m, v) = (be.mean(), be.var());
+        assert!((x.mean() - m).abs() < 0.02 * m, "Beta({}, {}) mean : {}", a, b, x.mean());
+        assert!((x.var() - v).abs() < 0.05 * v, "Beta({}, {}) var : {}", a, b, x.var());
+    }
+
+    // Very small shape does not underflow to NaN
+    let x = Beta(0.01, 0.01).sample_with_rng(&mut rng, 1000);
+    assert!(x.iter().all(|t| t.is_finite()));
+}
+
+/// Kolmogorov-Smirnov statistic `sup |F_n(x) - F(x)|`
+fn ks_statistic<F: Fn(f64) -> f64>(mut x: Vec<f64>, cdf: F) -> f64 {
+    x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = x.len() as f64;
+    x.iter().enumerate().fold(0f64, |d, (i, &t)| {
+        let f = cdf(t);
+        d.max(f - i as f64 / n).max((i + 1) as f64 / n - f)
+    })
+}
+
+#[test]
+fn test_sample_ks() {
+    let mut rng = stdrng_from_seed(42);
+    let n = 100_000;
+    // Critical value at the 1e-3 level : sqrt(-ln(1e-3 / 2) / 2) / sqrt(n)
+    let d_crit = (-(0.5e-3f64).ln() / 2f64).sqrt() / (n as f64).sqrt();
+
+    for shape in [0.3, 1f64, 10f64, 1000f64] {
+        let g = Gamma(shape, 2f64);
+        let d = ks_statistic(g.sample_with_rng(&mut rng, n), |x| g.cdf(x));
+        assert!(d < d_crit, "Gamma({}, 2) : D = {}", shape, d);
+    }
+
+    for (a, b) in [(0.3, 0.3), (0.3, 10f64), (10f64, 1000f64)] {
+        let be = Beta(a, b);
+        let d = ks_statistic(be.sample_with_rng(&mut rng, n), |x| be.cdf(x));
+        assert!(d < d_crit, "Beta({}, {}) : D = {}", a, b, d);
+    }
+
+    for nu in [0.3, 1f64, 2.5, 30f64] {
+        let t = StudentT(nu);
+        let d = ks_statistic(t.sample_with_rng(&mut rng, n), |x| t.cdf(x));
+        assert!(d < d_crit, "StudentT({}) : D = {}", nu, d);
+    }
+
+    for k in [0.3, 1f64, 10f64] {
+        let chi = ChiSquared(k);
+        let d = ks_statistic(chi.sample_with_rng(&mut rng, n), |x| chi.cdf(x));
+        assert!(d < d_crit, "ChiSquared({}) : D = {}", k, d);
+    }
+
+    // Sample variance at small shape (Var = shape / rate²)
+    let x = Gamma(0.3, 1f64).sample_with_rng(&mut rng, n);
+    assert!((x.var() - 0.3).abs() < 0.02, "Gamma(0.3, 1) var : {}", x.var());
+}
+
+#[test]
+fn test_log_pdf() {
+    // Same as pdf(x).ln() where pdf does not underflow
+    let tp = [Uniform(1f64, 3f64), Normal(1f64, 2f64), Beta(2.5, 0.7), Gamma(0.5, 2f64), Gamma(3f64, 1f64), FDist(5f64, 7f64)];
+    for d in tp.iter() {
+        for x in [0.2, 0.5, 0.9, 1.5, 2.5] {
+            let (l, p) = (d.log_pdf(x), d.pdf(x).ln());
+            if p.is_nan() {
+                // pdf of Beta is NaN out of [0, 1]
+                assert_eq!(l, f64::NEG_INFINITY);
+                continue;
+            }
+            assert!((l - p).abs() < 1e-9 * p.abs().max(1f64) || l == p, "{:?} at {} : {} vs {}", d, x, l, p);
+        }
+    }
+    let op = [Bernoulli(0.3), StudentT(3f64), ChiSquared(4f64)];
+    for d in op.iter() {
+        for x in [0f64, 0.5, 1f64, 2.5] {
+            let (l, p) = (d.log_pdf(x), d.pdf(x).ln());
+            assert!((l - p).abs() < 1e-9 * p.abs().max(1f64) || l == p, "{:?} at {} : {} vs {}", d, x, l, p);
+        }
+    }
+    let b = Binomial(10, 0.3);
+    for k in 0..=10 {
+        assert!((b.log_pdf(k as f64) - b.pdf(k as f64).ln()).abs() < 1e-9);
+    }
+    assert_eq!(b.log_pdf(2.5), f64::NEG_INFINITY);
+    assert_eq!(b.log_pdf(11f64), f64::NEG_INFINITY);
+    assert!(Binomial(5, 0f64).log_pdf(0f64).abs() < 1e-10);
+    assert_eq!(Bernoulli(0.3).log_pdf(0.5), f64::NEG_INFINITY);
+    assert_eq!(Bernoulli(0.3).log_pdf(2f64), f64::NEG_INFINITY);
+    assert_eq!(Bernoulli(0.3).pdf(-1f64), 0f64);
+
+    // Edges of support
+    assert!((Beta(1f64, 3f64).log_pdf(0f64) - 3f64.ln()).abs() < 1e-10);
+    assert_eq!(Beta(2f64, 3f64).log_pdf(0f64), f64::NEG_INFINITY);
+    assert_eq!(Gamma(2f64, 1f64).log_pdf(-1f64), f64::NEG_INFINITY);
+    assert_eq!(Uniform(0f64, 1f64).log_pdf(2f64), f64::NEG_INFINITY);
+
+    // Gamma near zero : pdf underflows (or overflows), log_pdf is finite & analytic
+    let x = 1e-300f64;
+    let l = Gamma(3f64, 1f64).log_pdf(x);
+    assert_eq!(Gamma(3f64, 1f64).pdf(x), 0f64);
+    assert!((l - (2f64 * x.ln() - 2f64.ln() - x)).abs() < 1e-9);
+    let l = Gamma(0.5, 2f64).log_pdf(x);
+    let exact = 0.5 * 2f64.ln() - 0.5 * PI.ln() - 0.5 * x.ln() - 2f64 * x;
+    assert!(l.is_finite() && (l - exact).abs() < 1e-9);
+
+    // Dirichlet
+    let d = Dirichlet::new(vec![2f64, 3f64, 4f64]).unwrap();
+    let x = [0.2, 0.3, 0.5];
+    assert!((d.log_pdf(&x) - d.pdf(&x).ln()).abs() < 1e-10);
+    assert_eq!(d.log_pdf(&[0.5, 0.5, 0.5]), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_log_likelihood() {
+    let n = 1_000_000;
+    let x = Normal(0f64, 1f64).sample_with_rng(&mut stdrng_from_seed(42), n);
+    let l = Normal(0f64, 1f64).log_likelihood(&x);
+
+    // E[log φ(Z)] = -(1 + ln 2π) / 2, sd[log φ(Z)] = 1 / √2
+    let expected = -(n as f64) * (1f64 + (2f64 * PI).ln()) / 2f64;
+    let sd = (n as f64 / 2f64).sqrt();
+    assert!((l - expected).abs() < 4f64 * sd, "{} vs {}", l, expected);
+
+    // Same as sum of log_pdf
+    let direct = x.iter().map(|&t| -0.5 * t * t).collect::<Vec<f64>>();
+    let direct = ksum(&direct) - (n as f64) * 0.5 * (2f64 * PI).ln();
+    assert!((l - direct).abs() < 1e-6);
+}
+
+#[test]
+fn test_normal_cdf_tail() {
+    let n = Normal(0f64, 1f64);
+    // Asymptotic expansion : Φ(-x) ≈ φ(x) / x (1 - 1/x² + 3/x⁴ - 15/x⁶)
+    for x in [10f64, 20f64, 37f64] {
+        let asym = (-x * x / 2f64).exp() / (2f64 * PI).sqrt() / x
+            * (1f64 - 1f64 / x.powi(2) + 3f64 / x.powi(4) - 15f64 / x.powi(6));
+        let c = n.cdf(-x);
+        assert!(c > 0f64);
+        assert!(((c - asym) / asym).abs() < 1e-6, "Φ(-{}) = {:e} vs {:e}", x, c, asym);
+    }
+    assert!((n.cdf(0f64) - 0.5).abs() < 1e-15);
+    assert_eq!(n.cdf(40f64), 1f64);
+}
+
+#[test]
+fn test_categorical() {
+    let probs = vec![0.1, 0.0, 0.25, 0.4, 0.25];
+    let c = Categorical::new(probs.clone()).unwrap();
+    assert_eq!(c.len(), 5);
+    assert_eq!(c.pmf(2), 0.25);
+    assert_eq!(c.pdf(2.5), 0f64);
+    assert!((c.cdf(3) - 0.75).abs() < 1e-12);
+    assert_eq!(c.cdf(-1), 0f64);
+    assert!((c.cdf(10) - 1f64).abs() < 1e-12);
+    assert!((c.mean() - 2.7).abs() < 1e-12);
+
+    let mut rng = stdrng_from_seed(42);
+    let n = 200_000;
+    let x = c.sample_with_rng(&mut rng, n);
+    let mut freq = vec![0f64; probs.len()];
+    for k in x.iter() {
+        freq[*k as usize] += 1f64 / n as f64;
+    }
+    for (f, p) in freq.iter().zip(probs.iter()) {
+        // 5 sigma
+        assert!((f - p).abs() <= 5f64 * (p * (1f64 - p) / n as f64).sqrt(), "{} vs {}", f, p);
+    }
+    assert_eq!(freq[1], 0f64);
+    assert!((x.mean() - c.mean()).abs() < 0.01);
+
+    assert!(Categorical::new(vec![]).is_err());
+    assert!(Categorical::new(vec![0.5, 0.4]).is_err());
+    assert!(Categorical::new(vec![1.1, -0.1]).is_err());
+}
+
+#[test]
+fn test_dirichlet() {
+    let alpha = vec![0.5, 2f64, 7.5];
+    let d = Dirichlet::new(alpha.clone()).unwrap();
+    let a0 = alpha.iter().sum::<f64>();
+
+    let mut rng = stdrng_from_seed(42);
+    let n = 100_000;
+    let x = d.sample_with_rng(&mut rng, n);
+    assert_eq!((x.row, x.col), (n, 3));
+    for row in x.row_iter() {
+        assert!(row.iter().all(|&t| t >= 0f64));
+        assert!((row.iter().sum::<f64>() - 1f64).abs() < 1e-12);
+    }
+
+    let m = d.mean();
+    let v = d.var();
+    for (i, (mi, vi)) in m.iter().zip(v.iter()).enumerate() {
+        assert!((mi - alpha[i] / a0).abs() < 1e-15);
+        let col = x.col(i);
+        assert!((col.mean() - mi).abs() < 5f64 * (vi / n as f64).sqrt());
+        assert!((col.var() - vi).abs() < 0.03 * vi);
+    }
+    let c = d.cov();
+    let cs = x.cov();
+    assert!(eq_vec(&c.data, &cs.data, 1e-3));
+    assert!((c.row(0).sum()).abs() < 1e-15);
+
+    // Dir(1, 1) is uniform on simplex : pdf = 1
+    let u = Dirichlet::new(vec![1f64, 1f64]).unwrap();
+    assert!((u.pdf(&[0.3, 0.7]) - 1f64).abs() < 1e-12);
+    assert_eq!(u.pdf(&[0.3, 0.6]), 0f64);
+
+    assert!(Dirichlet::new(vec![]).is_err());
+    assert!(Dirichlet::new(vec![1f64, -1f64]).is_err());
+}
+
+#[test]
+fn test_sample_int() {
+    let mut rng = stdrng_from_seed(42);
+
+    // Bernoulli : exactly 0 or 1
+    let b = Bernoulli(0.3);
+    let x = b.sample_with_rng(&mut rng, 10_000);
+    assert!(x.iter().all(|&t| t == 0f64 || t == 1f64));
+    assert!((x.mean() - 0.3).abs() < 0.02);
+    assert!(Bernoulli(0f64).sample_int_with_rng(&mut rng, 1000).unwrap().iter().all(|&k| k == 0));
+    assert!(Bernoulli(1f64).sample_int_with_rng(&mut rng, 1000).unwrap().iter().all(|&k| k == 1));
+
+    // Binomial & discrete uniform
+    let k = Binomial(10, 0.5).sample_int_with_rng(&mut rng, 1000).unwrap();
+    assert!(k.iter().all(|&t| (0..=10).contains(&t)));
+    let u = Uniform(-2, 3).sample_int_with_rng(&mut rng, 10_000).unwrap();
+    assert!(u.iter().all(|&t| (-2..=3).contains(&t)));
+    assert!((-2..=3).all(|t| u.contains(&t)));
+
+    // Categorical : frequencies over 1e6 samples
+    let weights = vec![1f64, 0f64, 2f64, 5f64, 2f64];
+    let c = Categorical::from_weights(weights.clone()).unwrap();
+    let n = 1_000_000;
+    let draws = c.sample_int_with_rng(&mut rng, n).unwrap();
+    let mut count = vec![0usize; weights.len()];
+    for d in draws {
+        count[d as usize] += 1;
+    }
+    for (cnt, w) in count.iter().zip(weights.iter()) {
+        let p = w / 10f64;
+        let f = *cnt as f64 / n as f64;
+        assert!((f - p).abs() <= 5f64 * (p * (1f64 - p) / n as f64).sqrt(), "{} vs {}", f, p);
+    }
+    assert_eq!(count[1], 0);
+}
+
+#[test]
+fn test_sample_int_continuous() {
+    let mut rng = stdrng_from_seed(42);
+    assert!(Normal(0f64, 1f64).sample_int_with_rng(&mut rng, 10).is_err());
+    assert!(Beta(1f64, 2f64).sample_int(10).is_err());
+    assert!(StudentT(3f64).sample_int(10).is_err());
+}
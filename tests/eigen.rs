@@ -0,0 +1,61 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+fn random_spd(n: usize) -> Matrix {
+    let b = rand(n, n);
+    &b.t() * &b + eye(n)
+}
+
+#[test]
+fn test_partial_eigen_lanczos() {
+    let a = random_spd(200);
+    let full = eigen(&a, Jacobi);
+    let n = full.eigenvalue.len();
+
+    let top = partial_eigen(&a, 5, Which::Largest, 1e-12, 200);
+    for i in 0..5 {
+        let rel = (top.eigenvalue[i] - full.eigenvalue[i]).abs() / full.eigenvalue[i];
+        assert!(
+            rel < 1e-8,
+            "{}: {} vs {}",
+            i,
+            top.eigenvalue[i],
+            full.eigenvalue[i]
+        );
+    }
+    // Eigenvector : |Av - λv| small
+    for i in 0..5 {
+        let v = top.eigenvector.col(i);
+        let r = (&a * &v).sub_vec(&v.mul_scalar(top.eigenvalue[i]));
+        assert!(r.norm(Norm::L2) < 1e-6 * top.eigenvalue[0]);
+    }
+
+    let bottom = partial_eigen(&a, 5, Which::Smallest, 1e-12, 200);
+    for i in 0..5 {
+        let expect = full.eigenvalue[n - 1 - i];
+        assert!((bottom.eigenvalue[i] - expect).abs() < 1e-8 * full.eigenvalue[0]);
+    }
+}
+
+#[test]
+fn test_partial_eigen_power() {
+    // Power iteration converges with rate λ_2 / λ_1 of the shifted matrix,
+    // so use a well-separated spectrum (1, 2, ..., 20) instead of a random one.
+    let q = rand_with_rng(20, 20, &mut stdrng_from_seed(42)).qr().q;
+    let d = Matrix::from_index(|i, j| q[(i, j)] * (j + 1) as f64, (20, 20));
+    let a = &d * &q.t();
+    let full = eigen(&a, Jacobi);
+    let n = full.eigenvalue.len();
+
+    let top = partial_eigen(&a, 3, Which::Largest, 1e-12, 100000);
+    let bottom = partial_eigen(&a, 2, Which::Smallest, 1e-12, 100000);
+    for i in 0..3 {
+        assert!((top.eigenvalue[i] - full.eigenvalue[i]).abs() < 1e-8 * full.eigenvalue[0]);
+    }
+    for i in 0..2 {
+        assert!(
+            (bottom.eigenvalue[i] - full.eigenvalue[n - 1 - i]).abs() < 1e-8 * full.eigenvalue[0]
+        );
+    }
+    assert_eq!((top.eigenvector.row, top.eigenvector.col), (20, 3));
+}
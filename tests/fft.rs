@@ -0,0 +1,90 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+fn assert_close(x: &[f64], y: &[f64], tol: f64) {
+    assert_eq!(x.len(), y.len());
+    for (a, b) in x.iter().zip(y.iter()) {
+        assert!((a - b).abs() < tol, "{} != {}", a, b);
+    }
+}
+
+#[test]
+fn test_fft_round_trip() {
+    for &n in &[8usize, 12, 1000] {
+        let x = Uniform(-1, 1).sample(n);
+        let y = ifft(&fft(&x));
+        let re = y.iter().map(|t| t.0).collect::<Vec<f64>>();
+        let im = y.iter().map(|t| t.1).collect::<Vec<f64>>();
+        assert_close(&re, &x, 1e-10);
+        assert_close(&im, &vec![0f64; n], 1e-10);
+    }
+}
+
+#[test]
+fn test_fft_naive_dft() {
+    let x = Uniform(-1, 1).sample(12);
+    let n = x.len();
+    let y = fft(&x);
+    for k in 0..n {
+        let (mut re, mut im) = (0f64, 0f64);
+        for (j, xj) in x.iter().enumerate() {
+            let theta = -2f64 * std::f64::consts::PI * (k * j) as f64 / n as f64;
+            re += xj * theta.cos();
+            im += xj * theta.sin();
+        }
+        assert!((y[k].0 - re).abs() < 1e-10);
+        assert!((y[k].1 - im).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_fft_parseval() {
+    for n in 1..64usize {
+        let x = Normal(0, 1).sample(n);
+        let y = fft(&x);
+        let time = x.iter().map(|t| t * t).sum::<f64>();
+        let freq = y.iter().map(|t| t.0 * t.0 + t.1 * t.1).sum::<f64>() / n as f64;
+        assert!((time - freq).abs() < 1e-9 * time.max(1f64));
+    }
+}
+
+#[test]
+fn test_rfft() {
+    for &n in &[8usize, 12, 15, 1000] {
+        let x = Uniform(-1, 1).sample(n);
+        let y = rfft(&x);
+        let z = fft(&x);
+        assert_eq!(y.len(), n / 2 + 1);
+        for k in 0..y.len() {
+            assert!((y[k].0 - z[k].0).abs() < 1e-9);
+            assert!((y[k].1 - z[k].1).abs() < 1e-9);
+        }
+        assert_close(&irfft(&y, n), &x, 1e-10);
+    }
+}
+
+#[test]
+fn test_fft2_round_trip() {
+    let m = rand(6, 8);
+    let (re, im) = fft2(&m);
+    assert!((re[(0, 0)] - m.data.iter().sum::<f64>()).abs() < 1e-10);
+    let (re2, im2) = ifft2(&re, &im);
+    assert_close(&re2.data, &m.data, 1e-10);
+    assert_close(&im2.data, &vec![0f64; 48], 1e-10);
+}
+
+#[test]
+fn test_periodogram() {
+    let dt = 1e-3;
+    let n = 1000;
+    let x = (0..n)
+        .map(|i| 3f64 * (2f64 * std::f64::consts::PI * 50f64 * i as f64 * dt).cos())
+        .collect::<Vec<f64>>();
+    let (f, p) = periodogram(&x, dt);
+    assert_eq!(f[p.arg_max()], 50f64);
+    // sum(psd) * df = mean square
+    let df = f[1] - f[0];
+    let ms = x.iter().map(|t| t * t).sum::<f64>() / n as f64;
+    assert!((p.iter().sum::<f64>() * df - ms).abs() < 1e-9);
+    assert_eq!(fftfreq(4, 0.25), vec![0f64, 1f64, -2f64, -1f64]);
+}
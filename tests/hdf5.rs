@@ -0,0 +1,84 @@
+#![cfg(feature = "hdf5")]
+extern crate hdf5;
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+fn tmp_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(name)
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_hdf5_matrix_round_trip() -> Result<(), Box<dyn Error>> {
+    let path = tmp_path("peroxide_hdf5_matrix.h5");
+    for (shape, gzip) in [(Row, None), (Col, Some(6))] {
+        let a = matrix(seq(1, 12, 1), 3, 4, shape);
+        let name = format!("{:?}", shape);
+        a.write_hdf5(&path, &name, gzip)?;
+        let b = Matrix::read_hdf5(&path, &name)?;
+        assert_eq!(b.shape, Row);
+        assert_eq!(a, b);
+
+        // On-disk layout is row-major regardless of Shape
+        let ds = hdf5::File::open(&path)?.dataset(&name)?;
+        assert_eq!(ds.shape(), vec![3, 4]);
+        assert_eq!(ds.read_raw::<f64>()?, seq(1, 12, 1));
+        assert_eq!(ds.is_chunked(), gzip.is_some());
+    }
+
+    // Overwrite dataset with different size
+    let c = zeros(2, 2);
+    c.write_hdf5(&path, "Row", None)?;
+    assert_eq!(Matrix::read_hdf5(&path, "Row")?, c);
+    assert_eq!(Matrix::read_hdf5(&path, "Col")?, matrix(seq(1, 12, 1), 3, 4, Col));
+    Ok(())
+}
+
+#[test]
+fn test_hdf5_matrix_errors() -> Result<(), Box<dyn Error>> {
+    let path = tmp_path("peroxide_hdf5_errors.h5");
+    let f = hdf5::File::create(&path)?;
+    f.new_dataset::<i32>().shape(3).create("int")?.write_raw(&[1, 2, 3])?;
+    f.new_dataset::<f64>().shape((2, 2, 2)).create("cube")?.write_raw(&[0f64; 8])?;
+    drop(f);
+
+    let missing = Matrix::read_hdf5(&path, "missing").unwrap_err().to_string();
+    assert!(missing.contains("missing"));
+    let dtype = Matrix::read_hdf5(&path, "int").unwrap_err().to_string();
+    assert!(dtype.contains("dtype"));
+    let rank = Matrix::read_hdf5(&path, "cube").unwrap_err().to_string();
+    assert!(rank.contains("rank 3"));
+    assert!(Matrix::read_hdf5(&tmp_path("peroxide_hdf5_no_file.h5"), "a").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_hdf5_dataframe_round_trip() -> Result<(), Box<dyn Error>> {
+    let path = tmp_path("peroxide_hdf5_df.h5");
+    let mut df = DataFrame::new(vec![]);
+    df.push("z", Series::new(c!(0.1, 0.2, 0.3)));
+    df.push("a", Series::new(vec![3i32, 1, 2]));
+    df.push("flag", Series::new(vec![true, false, true]));
+    df.push(
+        "name",
+        Series::new(vec!["x".to_string(), "y".to_string(), "z".to_string()]),
+    );
+    df.push("n", Series::new(vec![1usize, 2, 3]));
+    df.set_attr("z", "units", "m");
+    df.set_global_attr("title", "hdf5 test");
+    df.write_hdf5(&path, "df", Some(4))?;
+
+    let mut dg = DataFrame::read_hdf5(&path, "df")?;
+    // Column order comes from the `columns` attribute, not from the (sorted) links
+    assert_eq!(dg.header(), df.header());
+    assert_eq!(dg["n"].dtype, U64);
+    dg["n"].as_type(USIZE);
+    assert_eq!(dg.get_attr("z", "units"), Some("m"));
+    assert_eq!(dg.get_global_attr("title"), Some("hdf5 test"));
+    assert_eq!(df, dg);
+    Ok(())
+}
@@ -0,0 +1,196 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+use std::f64::consts::PI;
+
+#[test]
+fn test_sampled_integral_uniform() -> Result<(), Box<dyn std::error::Error>> {
+    let b = 2f64;
+    let exact = 1f64 - b.cos();
+
+    // Odd & even number of samples
+    for n in [101, 100] {
+        let x = linspace(0, b, n);
+        let y = x.fmap(|t| t.sin());
+        let dx = x[1] - x[0];
+
+        assert!((trapz(&x, &y)? - exact).abs() < dx * dx);
+        assert!((simpson(&y, dx)? - exact).abs() < dx.powi(4));
+
+        let cum = cumtrapz(&x, &y)?;
+        assert_eq!(cum.len(), n);
+        assert_eq!(cum[0], 0f64);
+        for (t, c) in x.iter().zip(cum.iter()) {
+            assert!((c - (1f64 - t.cos())).abs() < dx * dx);
+        }
+        assert_eq!(cum[n - 1], trapz(&x, &y)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sampled_integral_nonuniform() -> Result<(), Box<dyn std::error::Error>> {
+    let b = 3f64;
+    // Dense near 0, sparse near b
+    let x = linspace(0, 1, 200).fmap(|t| b * t * t);
+    let y = x.fmap(|t| t.sin());
+    assert!((trapz(&x, &y)? - (1f64 - b.cos())).abs() < 1e-4);
+
+    let cum = cumtrapz(&x, &y)?;
+    for (t, c) in x.iter().zip(cum.iter()) {
+        assert!((c - (1f64 - t.cos())).abs() < 1e-4);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sampled_integral_error() {
+    let x = vec![0f64, 1f64, 2f64];
+    let y = vec![0f64, 1f64];
+    let err = trapz(&x, &y).unwrap_err();
+    assert_eq!(err.downcast_ref::<SampledIntegralError>(), Some(&SampledIntegralError::DifferentLength));
+    assert!(cumtrapz(&x, &y).is_err());
+    assert!(trapz(&x[..1], &y[..1]).is_err());
+    assert!(simpson(&y[..1], 0.1).is_err());
+    assert_eq!(simpson(&y, 0.5).unwrap(), 0.25);
+    assert_eq!(simpson(&[0f64, 1f64, 2f64, 3f64], 1f64).unwrap(), 4.5);
+}
+
+#[test]
+fn test_gauss_hermite_moments() {
+    // ∫ x^{2k} exp(-x²) dx = Γ(k + 1/2), exact for 2k <= 2n - 1
+    for n in [1usize, 2, 5, 10, 20, 40] {
+        let (x, w) = gauss_hermite_nodes(n);
+        assert_eq!(x.len(), n);
+        let mut exact = std::f64::consts::PI.sqrt();
+        for k in 0..n {
+            if k > 0 {
+                exact *= k as f64 - 0.5;
+            }
+            let s = gauss_hermite_quadrature(|x| x.powi(2 * k as i32), n);
+            assert!(((s - exact) / exact).abs() < 1e-13, "n = {}, k = {}", n, k);
+            // Odd moments vanish
+            let odd: f64 = x.iter().zip(w.iter()).map(|(x, w)| w * x.powi(2 * k as i32 + 1)).sum();
+            assert!(odd.abs() < 1e-12 * exact.max(1f64));
+        }
+    }
+}
+
+#[test]
+fn test_gauss_laguerre_moments() {
+    // ∫_0^∞ x^k exp(-x) dx = k!, exact for k <= 2n - 1
+    for n in [1usize, 2, 5, 10, 20] {
+        let mut exact = 1f64;
+        for k in 0..2 * n {
+            if k > 0 {
+                exact *= k as f64;
+            }
+            let s = gauss_laguerre_quadrature(|x| x.powi(k as i32), n);
+            assert!(((s - exact) / exact).abs() < 1e-13, "n = {}, k = {}", n, k);
+        }
+    }
+}
+
+#[test]
+fn test_gauss_legendre_nodes() {
+    // Agrees with the tabulated rule
+    let f = |x: f64| x.exp() * x.cos();
+    for n in [2usize, 7, 16, 30] {
+        let (x, w) = gauss_legendre_nodes(n);
+        let s: f64 = x.iter().zip(w.iter()).map(|(&x, &w)| w * f(x)).sum();
+        assert!((s - integrate(f, (-1f64, 1f64), GaussLegendre(n))).abs() < 1e-13);
+    }
+
+    // Beyond the table
+    let (x, w) = gauss_legendre_nodes(64);
+    assert!((w.iter().sum::<f64>() - 2f64).abs() < 1e-14);
+    let s: f64 = x.iter().zip(w.iter()).map(|(x, w)| w * x.powi(126)).sum();
+    assert!((s - 2f64 / 127f64).abs() < 1e-14);
+    let s = integrate(|x| x.sin(), (0f64, std::f64::consts::PI), GaussLegendre(45));
+    assert!((s - 2f64).abs() < 1e-13);
+}
+
+#[test]
+fn test_gauss_legendre_nodes_polynomial_exactness() {
+    // n nodes integrate x^k exactly for k <= 2n - 1
+    for n in [1usize, 2, 5, 17, 31, 40] {
+        let (x, w) = gauss_legendre_nodes(n);
+        assert!(x.windows(2).all(|p| p[0] < p[1]));
+        for k in 0..2 * n {
+            let s: f64 = x.iter().zip(w.iter()).map(|(x, w)| w * x.powi(k as i32)).sum();
+            let exact = if k % 2 == 0 { 2f64 / (k as f64 + 1f64) } else { 0f64 };
+            assert!((s - exact).abs() < 1e-14, "n = {}, k = {}", n, k);
+        }
+    }
+
+    // Also on a general interval through integrate (∫_0^2 x^79 dx = 2^80 / 80)
+    let s = integrate(|x| x.powi(79), (0f64, 2f64), GaussLegendre(40));
+    let exact = 2f64.powi(80) / 80f64;
+    assert!(((s - exact) / exact).abs() < 1e-13);
+}
+
+#[test]
+fn test_integrate_multi() {
+    let s = integrate2d(|x, y| x * y, (0f64, 1f64), (0f64, 1f64), GaussLegendre(2));
+    assert!((s - 0.25).abs() < 1e-15);
+    let s = integrate2d(|x, y| x * y, (0f64, 1f64), (0f64, 1f64), G7K15(1e-12, 20));
+    assert!((s - 0.25).abs() < 1e-15);
+
+    // Gaussian over a large box
+    let g = |x: f64, y: f64| (-(x * x + y * y) / 2f64).exp();
+    let s = integrate2d(g, (-10f64, 10f64), (-10f64, 10f64), G7K15(1e-10, 20));
+    assert!((s - 2f64 * PI).abs() < 1e-8);
+    let inf = (f64::NEG_INFINITY, f64::INFINITY);
+    let s = integrate2d(|x, y| (-(x * x + y * y)).exp() * x * x, inf, inf, GaussHermite(10));
+    assert!((s - PI / 2f64).abs() < 1e-13);
+
+    // Non-square box & order of arguments : ∫_0^1 ∫_0^2 x y² dy dx = 1/2 · 8/3
+    let s = integrate2d(|x, y| x * y * y, (0f64, 1f64), (0f64, 2f64), GaussLegendre(3));
+    assert!((s - 4f64 / 3f64).abs() < 1e-14);
+
+    // 3D Gaussian : (2π)^(3/2)
+    let s = integrate3d(
+        |x, y, z| (-(x * x + y * y + z * z) / 2f64).exp(),
+        (-10f64, 10f64),
+        (-10f64, 10f64),
+        (-10f64, 10f64),
+        GaussLegendre(60),
+    );
+    assert!((s - (2f64 * PI).powf(1.5)).abs() < 1e-8);
+}
+
+#[test]
+fn test_monte_carlo_integrate() {
+    // Volume of 6-dimensional unit ball : π³ / 6
+    let ball = |x: &[f64]| if x.iter().map(|t| t * t).sum::<f64>() <= 1f64 { 1f64 } else { 0f64 };
+    let bounds = [(-1f64, 1f64); 6];
+    let exact = PI.powi(3) / 6f64;
+
+    let (s, se) = monte_carlo_integrate(ball, &bounds, 200_000, Some(42));
+    assert!((s - exact).abs() < 4f64 * se, "{} ± {}", s, se);
+    assert_eq!((s, se), monte_carlo_integrate(ball, &bounds, 200_000, Some(42)));
+
+    // Standard error ~ 1 / √n
+    let (_, se_small) = monte_carlo_integrate(ball, &bounds, 2_000, Some(1));
+    let ratio = se_small / se;
+    assert!((ratio - 10f64).abs() < 1f64, "ratio = {}", ratio);
+
+    // Constant integrand : exact with zero error
+    let (s, se) = monte_carlo_integrate(|_| 2f64, &[(0f64, 3f64), (1f64, 2f64)], 10, None);
+    assert!((s - 6f64).abs() < 1e-14);
+    assert_eq!(se, 0f64);
+}
+
+#[test]
+fn test_integrate_infinite() {
+    let s = integrate(|x| (-x * x).exp() * x * x, (f64::NEG_INFINITY, f64::INFINITY), GaussHermite(10));
+    assert!((s - std::f64::consts::PI.sqrt() / 2f64).abs() < 1e-13);
+
+    let s = integrate(|x| (-x).exp() * x, (0f64, f64::INFINITY), GaussLaguerre(10));
+    assert!((s - 1f64).abs() < 1e-13);
+    let s = integrate(|x| x.exp(), (f64::NEG_INFINITY, 2f64), GaussLaguerre(10));
+    assert!((s - 2f64.exp()).abs() < 1e-12);
+
+    // Outer nodes of large n : exp(x) overflows, w underflows
+    let s = integrate(|x| (-x).exp(), (0f64, f64::INFINITY), GaussLaguerre(200));
+    assert!((s - 1f64).abs() < 1e-10);
+}
@@ -21,3 +21,31 @@ fn f(xs: &Vec<AD>) -> Vec<AD> {
     let y = xs[1];
     vec![x.powi(2) * y, 5f64 * x + y.sin()]
 }
+
+#[test]
+fn test_numerical_jacobian() {
+    let x = c!(1.5, -0.7);
+    let j = numerical_jacobian(g, &x);
+    let (a, b) = (x[0], x[1]);
+    let j_exact = matrix(
+        c!(2f64 * a * b, a * a, 5f64, b.cos()),
+        2,
+        2,
+        Row,
+    );
+    assert!(eq_vec(&j.data, &j_exact.data, 1e-9));
+    assert!(eq_vec(&j.data, &jacobian(f, &x).data, 1e-9));
+
+    // Non-square : R^2 -> R^3
+    let h = |xs: &Vec<f64>| vec![xs[0] + xs[1], xs[0] * xs[1], xs[0].exp()];
+    let j = numerical_jacobian(h, &x);
+    assert_eq!((j.row, j.col), (3, 2));
+    let j_exact = ml_matrix(&format!("1 1;{} {};{} 0", b, a, a.exp()));
+    assert!(eq_vec(&j.data, &j_exact.data, 1e-9));
+}
+
+fn g(xs: &Vec<f64>) -> Vec<f64> {
+    let x = xs[0];
+    let y = xs[1];
+    vec![x.powi(2) * y, 5f64 * x + y.sin()]
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "json")]
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_matrix_json_round_trip() -> Result<(), Box<dyn Error>> {
+    for shape in [Row, Col] {
+        let a = matrix(rand(3, 4).data, 3, 4, shape);
+        let b = Matrix::from_json(&a.to_json())?;
+        assert_eq!(b.row, 3);
+        assert_eq!(b.col, 4);
+        assert_eq!(b.shape, shape);
+        assert_eq!(a.data, b.data);
+    }
+    assert!(Matrix::from_json(r#"{"row":2,"col":2,"shape":"Row","data":[1,2,3]}"#).is_err());
+    // row * col overflows usize
+    assert!(Matrix::from_json(r#"{"row":4294967296,"col":4294967296,"shape":"Row","data":[]}"#).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dataframe_json_round_trip() -> Result<(), Box<dyn Error>> {
+    let mut df = DataFrame::new(vec![]);
+    df.push("x", Series::new(rand(1, 5).data));
+    df.push(
+        "name",
+        Series::new(
+            vec!["a", "b", "c"]
+                .into_iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>(),
+        ),
+    );
+    df.push("flag", Series::new(vec![true, false]));
+    df.push("n", Series::new(vec![1i32, -2, 3]));
+
+    let dg = DataFrame::from_json(&df.to_json())?;
+    assert_eq!(dg.header(), df.header());
+    assert_eq!(dg["x"], df["x"]);
+    assert_eq!(dg["name"], df["name"]);
+    assert_eq!(dg["flag"], df["flag"]);
+    assert_eq!(dg["n"].len(), 3);
+    assert_eq!(dg["n"].to_type(I32), df["n"]);
+    Ok(())
+}
+
+#[test]
+fn test_dataframe_json_format() {
+    let mut df = DataFrame::new(vec![]);
+    df.push("b", Series::new(c!(1.5, 2)));
+    df.push("a", Series::new(vec!['x']));
+    assert_eq!(df.to_json(), r#"{"b":[1.5,2],"a":["x"]}"#);
+}
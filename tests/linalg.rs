@@ -113,3 +113,191 @@ fn test_solve() {
         assert!(eq_vec(&x, &d, 1e-6));
     }
 }
+
+#[test]
+fn test_triangular_solve() {
+    let u = ml_matrix("1 2 3 4;0 5 6 7;0 0 8 9;0 0 0 10");
+    let x = vec![1f64, -2f64, 3f64, -4f64];
+    let b = &u * &x;
+    assert!(eq_vec(&u.solve_upper(&b), &x, 1e-12));
+
+    let l = u.t();
+    assert!(l.is_lower_triangular() && !l.is_upper_triangular());
+    let b = &l * &x;
+    assert!(eq_vec(&l.solve_lower(&b), &x, 1e-12));
+
+    // Col shape
+    let mut l_col = l.clone();
+    l_col.change_shape_mut();
+    assert!(eq_vec(&l_col.solve_lower(&b), &x, 1e-12));
+}
+
+#[test]
+#[should_panic]
+fn test_triangular_solve_not_triangular() {
+    let a = ml_matrix("1 2;3 4");
+    a.solve_upper(&vec![1f64, 2f64]);
+}
+
+#[test]
+fn test_tridiag_solve() {
+    let n = 50;
+    let lower = Uniform(-1, 1).sample(n - 1);
+    let upper = Uniform(-1, 1).sample(n - 1);
+    let diag = Uniform(2.5, 4.0).sample(n); // diagonally dominant
+    let b = Uniform(-1, 1).sample(n);
+
+    let mut a = zeros(n, n);
+    for i in 0..n {
+        a[(i, i)] = diag[i];
+        if i > 0 {
+            a[(i, i - 1)] = lower[i - 1];
+            a[(i - 1, i)] = upper[i - 1];
+        }
+    }
+
+    let x = tridiag_solve(&lower, &diag, &upper, &b);
+    let y = a.solve(&b, LU);
+    assert!(eq_vec(&x, &y, 1e-12));
+    assert!(eq_vec(&(&a * &x), &b, 1e-12));
+}
+
+#[test]
+fn test_positive_definite() {
+    // Symmetric positive definite
+    let a = ml_matrix("4 1 2;1 5 3;2 3 6");
+    assert!(a.is_symmetric_with_tol(1e-12));
+    assert!(a.is_positive_definite());
+
+    // Symmetric indefinite (eigenvalues : 3, -1, 1)
+    let b = ml_matrix("1 2 0;2 1 0;0 0 1");
+    assert!(b.is_symmetric_with_tol(1e-12));
+    assert!(!b.is_positive_definite());
+
+    // Asymmetric (positive eigenvalues, but not symmetric)
+    let c = ml_matrix("4 1 0;0 5 3;0 0 6");
+    assert!(!c.is_symmetric_with_tol(1e-12));
+    assert!(!c.is_positive_definite());
+    let d = ml_matrix("2 1;-1 2");
+    assert!(!d.is_positive_definite());
+}
+
+#[test]
+fn test_gram_schmidt() {
+    let examples = vec![
+        ml_matrix("1 2;3 4"),
+        ml_matrix("1 1 0;1 0 1;0 1 1;1 1 1"), // tall
+        ml_matrix("1 2 1;2 4 0;3 6 1"),       // rank 2
+    ];
+    let ranks = vec![2, 3, 2];
+    for (a, &r) in examples.iter().zip(ranks.iter()) {
+        let q = a.gram_schmidt();
+        assert_eq!((q.row, q.col), (a.row, r));
+
+        // Orthonormal
+        let qtq = &q.t() * &q;
+        let id = eye(r);
+        for i in 0..r {
+            for j in 0..r {
+                assert!(nearly_eq(qtq[(i, j)], id[(i, j)]));
+            }
+        }
+
+        // Same column space : projection of A onto span(Q) is A itself
+        let p = &(&q * &q.t()) * a;
+        for i in 0..a.row {
+            for j in 0..a.col {
+                assert!(nearly_eq(p[(i, j)], a[(i, j)]));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_qr() {
+    let a = ml_matrix("1 2 3;4 5 7;7 8 10;2 1 5");
+    let qr = a.qr();
+    for i in 0..a.row {
+        for j in 0..i.min(a.col) {
+            assert!(qr.r[(i, j)].abs() < 1e-12);
+        }
+    }
+    assert!(eq_vec(&(&qr.q * &qr.r).data, &a.data, 1e-12));
+}
+
+#[test]
+fn test_lstsq() {
+    // Overdetermined Vandermonde system
+    let x = seq(0, 2, 0.1);
+    let y = x.fmap(|t| 1f64 - 2f64 * t + 0.5 * t * t + (7f64 * t).sin() * 0.01);
+    let mut v = matrix(vec![0f64; x.len() * 3], x.len(), 3, Row);
+    for (i, &t) in x.iter().enumerate() {
+        v[(i, 0)] = 1f64;
+        v[(i, 1)] = t;
+        v[(i, 2)] = t * t;
+    }
+    let sol = v.lstsq(&y, true);
+    let vt = v.t();
+    let normal = (&vt * &v).solve(&(&vt * &y), SolveKind::LU);
+    assert!(eq_vec(&sol.x, &normal, 1e-10));
+    assert_eq!(sol.rank, 3);
+    let r = &v * &sol.x;
+    let res = y.sub_vec(&r).norm(Norm::L2);
+    assert!((sol.residual - res).abs() < 1e-12);
+
+    // Variance : s^2 (V^T V)^{-1}_ii
+    let s2 = res * res / (x.len() - 3) as f64;
+    let cov = (&vt * &v).inv() * s2;
+    assert!(eq_vec(sol.variance.as_ref().unwrap(), &cov.diag(), 1e-12));
+
+    // Rank deficient : minimum norm solution (col3 = col1 + col2)
+    let a = ml_matrix("1 2 3;2 4 6;1 0 1;0 1 1;3 1 4");
+    let b = vec![1f64, 2f64, 3f64, 4f64, 5f64];
+    let sol = a.lstsq(&b, true);
+    assert_eq!(sol.rank, 2);
+    assert!(eq_vec(&sol.x, &vec![76f64 / 69f64, -41f64 / 69f64, 35f64 / 69f64], 1e-12));
+    assert!((sol.residual - 4.4330772509672644166).abs() < 1e-12);
+    assert_eq!(sol.variance.unwrap().len(), 3);
+
+    // Underdetermined : minimum norm solution
+    let a = ml_matrix("1 2 3;4 5 6");
+    let sol = a.lstsq(&vec![1f64, 2f64], false);
+    assert_eq!(sol.rank, 2);
+    assert!(eq_vec(&sol.x, &vec![-1f64 / 18f64, 1f64 / 9f64, 5f64 / 18f64], 1e-12));
+    assert!(sol.residual < 1e-12);
+    assert!(sol.variance.is_none());
+}
+
+#[test]
+fn test_factorized_solve() {
+    let mut rng = stdrng_from_seed(42);
+    let n = 20;
+    let a = rand_with_rng(n, n, &mut rng);
+    let f = Factorized::new(&a);
+
+    // 100 right-hand sides with one factorization
+    let bs = rand_with_rng(n, 100, &mut rng);
+    for i in 0..bs.col {
+        let b = bs.col(i);
+        let x = f.solve(&b);
+        assert_eq!(x, a.solve(&b, SolveKind::LU));
+        assert!(eq_vec(&(&a * &x), &b, 1e-10));
+    }
+
+    // Matrix right-hand side
+    let x = f.solve_mat(&bs);
+    for i in 0..bs.col {
+        assert_eq!(x.col(i), f.solve(&bs.col(i)));
+    }
+    assert!(eq_vec(&(&a * &x).data, &bs.data, 1e-10));
+    assert!(eq_vec(&a.solve_mat(&bs, SolveKind::LU).data, &x.data, 1e-10));
+
+    assert!(nearly_eq(f.det(), a.det()));
+    assert!(eq_vec(&f.inv().data, &a.inv().data, 1e-10));
+}
+
+#[test]
+#[should_panic]
+fn test_factorized_singular() {
+    Factorized::new(&ml_matrix("1 2;2 4"));
+}
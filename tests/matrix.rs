@@ -111,3 +111,491 @@ fn test_kronecker() {
     let c1 = a1.kronecker(&b1);
     assert_eq!(c1, ml_matrix("0 5 0 10;6 7 12 14;0 15 0 20;18 21 24 28"));
 }
+
+#[test]
+fn test_submat_round_trip() {
+    let mut a = matrix!(1;16;1, 4, 4, Row);
+    let b = a.submat((1, 1), (2, 2));
+    assert_eq!(b, ml_matrix("6 7;10 11"));
+
+    let c = ml_matrix("-1 -2;-3 -4");
+    a.set_submat(1, 1, &c);
+    assert_eq!(a.submat((1, 1), (2, 2)), c);
+    assert_eq!(a.row(0), c!(1, 2, 3, 4));
+    assert_eq!(a.row(3), c!(13, 14, 15, 16));
+
+    a.set_submat(1, 1, &b);
+    assert_eq!(a, matrix!(1;16;1, 4, 4, Row));
+}
+
+#[test]
+fn test_row_col_iter() {
+    let a = rand(5, 3);
+
+    let row_sums = a.row_iter().map(|r| r.sum()).collect::<Vec<f64>>();
+    let mut expected = vec![0f64; a.row];
+    for i in 0..a.row {
+        for j in 0..a.col {
+            expected[i] += a[(i, j)];
+        }
+    }
+    // `sum` is compensated, so it can differ from the naive loop in the last bit
+    assert!(eq_vec(&row_sums, &expected, 1e-14));
+
+    let cols = a.col_iter().collect::<Vec<Vec<f64>>>();
+    assert_eq!(cols.len(), a.col);
+    for (j, c) in cols.iter().enumerate() {
+        assert_eq!(c, &a.col(j));
+    }
+}
+
+#[test]
+fn test_matrix_norm() {
+    let a = ml_matrix("1 -2;-3 4");
+    assert_eq!(a.norm(Norm::L1), 6f64);
+    assert_eq!(a.norm(Norm::LInf), 7f64);
+    assert_eq!(a.norm(Norm::F), 30f64.sqrt());
+    // AᵀA = [10 -14; -14 20] => σ₁² = 15 + √221
+    let sigma = (15f64 + 221f64.sqrt()).sqrt();
+    assert!((a.norm(Norm::L2) - sigma).abs() < 1e-12);
+
+    // Shape does not matter
+    let b = a.change_shape();
+    assert_eq!(b.norm(Norm::L1), 6f64);
+    assert_eq!(b.norm(Norm::LInf), 7f64);
+
+    // Diagonal : L1 = L2 = LInf = max |d|
+    let d = ml_matrix("3 0 0;0 -5 0;0 0 1");
+    assert_eq!(d.norm(Norm::L1), 5f64);
+    assert_eq!(d.norm(Norm::LInf), 5f64);
+    assert!((d.norm(Norm::L2) - 5f64).abs() < 1e-12);
+    assert_eq!(d.norm(Norm::F), 35f64.sqrt());
+
+    // Tall matrix
+    let c = ml_matrix("1 0;0 2;0 0");
+    assert!((c.norm(Norm::L2) - 2f64).abs() < 1e-12);
+
+    let n = a.normalize(Norm::LInf);
+    assert_eq!(n.norm(Norm::LInf), 1f64);
+
+    // Simple normalize : unit Frobenius norm
+    let n = peroxide::prelude::SimpleNorm::normalize(&a);
+    assert!((n.norm(Norm::F) - 1f64).abs() < 1e-15);
+    assert_eq!(n * 30f64.sqrt(), a);
+    let z = zeros(2, 3);
+    assert_eq!(peroxide::prelude::SimpleNorm::normalize(&z), z);
+
+    // Lp : p = 1, 2, ∞ are same as L1, L2, LInf
+    assert_eq!(a.norm(Norm::Lp(1f64)), 6f64);
+    assert!((a.norm(Norm::Lp(2f64)) - sigma).abs() < 1e-12);
+    assert_eq!(a.norm(Norm::Lp(f64::INFINITY)), 7f64);
+
+    // Other p : lower bound estimate
+    for p in [1.5f64, 3f64, 10f64] {
+        assert!((d.opnorm_estimate(p, 1e-14, 1000) - 5f64).abs() < 1e-12);
+        assert!((d.change_shape().opnorm_estimate(p, 1e-14, 1000) - 5f64).abs() < 1e-12);
+    }
+
+    // Rank one : |u vᵀ|_p = |u|_p |v|_q
+    let (u, v) = (c!(1, -2, 3), c!(2, 1));
+    let uv = matrix(u.clone(), 3, 1, Col) * matrix(v.clone(), 1, 2, Row);
+    let p = 3f64;
+    let q = p / (p - 1f64);
+    let expected = u.norm(Norm::Lp(p)) * v.norm(Norm::Lp(q));
+    assert!((uv.opnorm_estimate(p, 1e-14, 1000) - expected).abs() < 1e-10 * expected);
+
+    // Upper bound from L1 & LInf (Riesz-Thorin), lower bound from columns (|A e_j|_p)
+    let lp = a.opnorm_estimate(3f64, 1e-14, 1000);
+    assert!(lp <= 6f64.powf(1f64 / 3f64) * 7f64.powf(2f64 / 3f64) + 1e-12);
+    assert!(lp >= c!(-2, 4).norm(Norm::Lp(3f64)) - 1e-12);
+
+    // Estimate never exceeds the exact 2-norm
+    assert!(a.opnorm_estimate(2f64, 1e-14, 1000) <= sigma + 1e-12);
+
+    // Entrywise L_{p,q}
+    assert!((a.norm(Norm::Lpq(2f64, 2f64)) - 30f64.sqrt()).abs() < 1e-12);
+    assert!((a.norm(Norm::Lpq(1f64, 1f64)) - 10f64).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn test_matrix_norm_lp_general() {
+    ml_matrix("1 2;3 4").norm(Norm::Lp(3f64));
+}
+
+#[test]
+fn test_vector_lp_norm() {
+    let v = c!(3, -4);
+    assert_eq!(v.norm(Norm::Lp(2f64)), 5f64);
+    assert_eq!(v.norm(Norm::Lp(1f64)), 7f64);
+    assert!((v.norm(Norm::Lp(3f64)) - 91f64.powf(1f64 / 3f64)).abs() < 1e-14);
+    assert_eq!(v.norm(Norm::Lp(f64::INFINITY)), 4f64);
+    assert_eq!(v.norm(Norm::L1), 7f64);
+    assert_eq!(v.norm(Norm::LInf), 4f64);
+    assert_eq!(v.norm(Norm::F), 5f64);
+}
+
+#[test]
+fn test_take_skip() {
+    let a = matrix!(1;12;1, 3, 4, Row);
+    for m in [a.clone(), a.change_shape()] {
+        assert_eq!(m.take_row(2), matrix!(1;8;1, 2, 4, Row));
+        assert_eq!(m.skip_row(1), matrix!(5;12;1, 2, 4, Row));
+        assert_eq!(m.take_col(1), matrix(c!(1, 5, 9), 3, 1, Col));
+        assert_eq!(m.skip_col(2), ml_matrix("3 4;7 8;11 12"));
+        assert_eq!(m.take_row(2).shape, m.shape);
+        assert_eq!(m.skip_col(2).shape, m.shape);
+
+        // Clamped
+        assert_eq!(m.take_row(10), a);
+        assert_eq!(m.take_col(10), a);
+        let empty = m.skip_row(10);
+        assert_eq!((empty.row, empty.col, empty.data.len()), (0, 4, 0));
+        let empty = m.take_col(0);
+        assert_eq!((empty.row, empty.col, empty.data.len()), (3, 0, 0));
+
+        let (top, bottom) = m.split_row(1);
+        assert_eq!(top, m.take_row(1));
+        assert_eq!(bottom, m.skip_row(1));
+        let (all, rest) = m.split_row(5);
+        assert_eq!(all, a);
+        assert_eq!(rest.row, 0);
+        assert_eq!(all.take_row(0).skip_row(0).data.len(), 0);
+    }
+}
+
+#[test]
+fn test_structural_predicates() {
+    let a = ml_matrix("4 1 0;1 3 1;0 1 2");
+    for m in [a.clone(), a.change_shape()] {
+        assert!(m.is_square());
+        assert_eq!(m.trace(), 9f64);
+        assert!(m.is_symmetric_with_tol(0f64));
+        assert!(m.is_positive_definite());
+        assert!(!m.is_diagonal(1e-12));
+        assert_eq!(m.rank(), 3);
+    }
+
+    // Non-square
+    let b = ml_matrix("1 2 3;2 4 6");
+    for m in [b.clone(), b.change_shape()] {
+        assert!(!m.is_square());
+        assert!(!m.is_symmetric_with_tol(1e10));
+        assert!(!m.is_diagonal(1e10));
+        assert!(!m.is_positive_definite());
+        assert_eq!(m.rank(), 1);
+        assert_eq!(m.t().rank(), 1);
+    }
+
+    // Nonsymmetric : stride-based comparison must respect shape
+    let c = ml_matrix("1 2;3 4");
+    for m in [c.clone(), c.change_shape()] {
+        assert_eq!(m.trace(), 5f64);
+        assert!(!m.is_symmetric_with_tol(0.5));
+        assert!(!m.is_positive_definite());
+        assert_eq!(m.rank(), 2);
+    }
+
+    let d = diag_mat(&c!(1, -2, 3));
+    assert!(d.is_diagonal(0f64));
+    assert!(!d.is_positive_definite());
+    assert_eq!(d.trace(), 2f64);
+    assert_eq!(d.diag(), c!(1, -2, 3));
+    assert_eq!(d.rank(), 3);
+    assert_eq!(zeros(2, 3).rank(), 0);
+}
+
+#[test]
+fn test_matrix_power() {
+    let a = ml_matrix("2 1;1 3");
+    assert_eq!(a.matrix_power(2), &a * &a);
+    assert_eq!(a.matrix_power(5), &(&(&(&a * &a) * &a) * &a) * &a);
+    assert_eq!(a.matrix_power(0), eye(2));
+    assert_eq!(a.matrix_power(1), a);
+
+    let a_inv = a.inv();
+    let b = a.matrix_power(-3);
+    assert!(eq_vec(&b.data, &(&(&a_inv * &a_inv) * &a_inv).data, 1e-12));
+    assert!(eq_vec(&(&b * &a.matrix_power(3)).data, &eye(2).data, 1e-12));
+
+    // Markov chain : rows of P^n converge to the stationary distribution
+    let p = ml_matrix("0.9 0.1;0.5 0.5");
+    let p_inf = p.matrix_power(100);
+    for i in 0..2 {
+        assert!((p_inf[(i, 0)] - 5f64 / 6f64).abs() < 1e-12);
+        assert!((p_inf[(i, 1)] - 1f64 / 6f64).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_reshape() {
+    let a = ml_matrix("1 2 3;4 5 6");
+    let b = a.reshape((3, 2), a.shape);
+    assert_eq!((b.row, b.col), (3, 2));
+    assert_eq!(b.shape, Row);
+    assert_eq!(b.data, a.data);
+    assert_eq!(b.row(2), c!(5, 6));
+    assert_eq!(b.reshape((2, 3), b.shape), a);
+    assert_eq!(a.reshape((1, 6), Row).row(0), c!(1, 2, 3, 4, 5, 6));
+
+    // Col : column major order is preserved
+    let c = a.change_shape();
+    let d = c.reshape((3, 2), c.shape);
+    assert_eq!(d.shape, Col);
+    assert_eq!(d.col(0), c!(1, 4, 2));
+    assert_eq!(d.reshape((2, 3), d.shape), a);
+}
+
+#[test]
+#[should_panic]
+fn test_reshape_wrong_size() {
+    ml_matrix("1 2 3;4 5 6").reshape((4, 2), Row);
+}
+
+#[test]
+fn test_change_shape_non_square() {
+    for (r, c) in [(2, 3), (3, 5), (5, 3), (1, 4), (4, 1), (1, 1)] {
+        for shape in [Row, Col] {
+            let a = matrix(seq(1, (r * c) as i32, 1), r, c, shape);
+            let b = a.change_shape();
+            assert_ne!(b.shape, a.shape);
+            assert_eq!((b.row, b.col), (r, c));
+            for i in 0..r {
+                for j in 0..c {
+                    assert_eq!(a[(i, j)], b[(i, j)]);
+                }
+            }
+            let round_trip = b.change_shape();
+            assert_eq!(round_trip.shape, a.shape);
+            assert_eq!(round_trip.data, a.data);
+
+            let mut m = a.clone();
+            m.change_shape_mut();
+            assert_eq!(m.data, b.data);
+            m.change_shape_mut();
+            assert_eq!(m.data, a.data);
+        }
+    }
+
+    // Empty matrix
+    let e = matrix(Vec::<f64>::new(), 0, 3, Row);
+    assert_eq!(e.change_shape().shape, Col);
+    let mut e = e;
+    e.change_shape_mut();
+    assert_eq!((e.row, e.col, e.shape), (0, 3, Col));
+}
+
+#[test]
+fn test_print_with() {
+    let a = matrix(vec![1f64 / 3f64, 2f64, -1f64 / 7f64, 1e-10], 2, 2, Row);
+    // Default : 4 decimal places
+    assert_eq!(a.spread(), a.print_with(4));
+    assert_eq!(format!("{}", a), a.print_with(4));
+    assert!(a.spread().contains("0.3333 "));
+    assert!(!a.spread().contains("0.33333"));
+
+    let s = a.print_with(8);
+    assert!(s.contains("0.33333333 "));
+    assert!(!s.contains("0.333333333"));
+    assert!(s.contains("-0.14285714"));
+    // Shorter representation is kept
+    assert!(s.contains(" 2\n"));
+}
+
+#[test]
+fn test_print_truncate() {
+    let a = seq(0, 39999, 1).reshape((200, 200), Row);
+    let s = a.spread();
+    assert!(s.contains("..."));
+    assert!(s.contains("r[199]") && s.contains("c[199]"));
+    assert!(!s.contains("r[100]") && !s.contains("c[100]"));
+    assert_eq!(s.lines().count(), 22);
+    assert!(s.len() < 5000);
+
+    // Only long dimension is truncated
+    let b = seq(0, 299, 1).reshape((150, 2), Row);
+    let s = b.spread();
+    assert_eq!(s.lines().count(), 22);
+    assert!(s.lines().all(|l| !l.contains("c[2]")));
+
+    // Small matrix is printed entirely
+    let c = seq(0, 399, 1).reshape((20, 20), Row);
+    assert!(!c.spread().contains("..."));
+}
+
+#[test]
+fn test_indexed_iteration() {
+    let a = ml_matrix("1 2 3;4 5 6");
+    let b = a.change_shape();
+    assert_eq!(b.shape, Col);
+
+    let ia = a.iter_indexed().collect::<Vec<_>>();
+    let ib = b.iter_indexed().collect::<Vec<_>>();
+    assert_eq!(ia, ib);
+    assert_eq!(ia[1], (0, 1, 2f64));
+    assert_eq!(ia[3], (1, 0, 4f64));
+
+    let va = a.into_iter().cloned().collect::<Vec<f64>>();
+    let vb = (&b).into_iter().cloned().collect::<Vec<f64>>();
+    assert_eq!(va, vec![1f64, 2f64, 3f64, 4f64, 5f64, 6f64]);
+    assert_eq!(va, vb);
+    assert_eq!((&b).into_iter().len(), 6);
+
+    let mut visited = vec![];
+    b.for_each_indexed(|i, j, x| visited.push((i, j, x)));
+    assert_eq!(visited, ia);
+
+    // Finite-difference operator : -1, 2, -1 stencil
+    let n = 4;
+    let lap = zeros(n, n).fmap_with_index(|i, j, _| match i.abs_diff(j) {
+        0 => 2f64,
+        1 => -1f64,
+        _ => 0f64,
+    });
+    assert_eq!(lap, ml_matrix("2 -1 0 0;-1 2 -1 0;0 -1 2 -1;0 0 -1 2"));
+    let fa = a.fmap_with_index(|i, j, x| x * (i + j) as f64);
+    let fb = b.fmap_with_index(|i, j, x| x * (i + j) as f64);
+    assert_eq!(fb.shape, Col);
+    assert_eq!(fa, fb);
+    assert_eq!(fa, ml_matrix("0 2 6;4 10 18"));
+}
+
+#[test]
+fn test_matmul_shapes() {
+    let naive = |a: &Matrix, b: &Matrix| {
+        let mut c = zeros(a.row, b.col);
+        for i in 0..a.row {
+            for j in 0..b.col {
+                c[(i, j)] = (0..a.col).map(|k| a[(i, k)] * b[(k, j)]).sum();
+            }
+        }
+        c
+    };
+    for (m, k, n) in [(1, 1, 1), (2, 3, 4), (7, 5, 3), (1, 8, 1), (8, 1, 8), (17, 33, 9), (65, 70, 66)] {
+        for sa in [Row, Col] {
+            for sb in [Row, Col] {
+                let a = matrix(rand(m, k).data, m, k, sa);
+                let b = matrix(rand(k, n).data, k, n, sb);
+                let c = &a * &b;
+                let d = naive(&a, &b);
+                assert_eq!((c.row, c.col, c.shape), (m, n, sa));
+                for i in 0..m {
+                    for j in 0..n {
+                        assert!(nearly_eq(c[(i, j)], d[(i, j)]));
+                    }
+                }
+            }
+        }
+    }
+
+    // Inner dimension 0
+    let a = matrix(Vec::<f64>::new(), 3, 0, Row);
+    let b = matrix(Vec::<f64>::new(), 0, 2, Col);
+    assert_eq!(&a * &b, zeros(3, 2));
+}
+
+#[test]
+fn test_reference_operators() {
+    let same = |x: &Matrix, y: &Matrix| {
+        assert_eq!((x.row, x.col), (y.row, y.col));
+        for i in 0..x.row {
+            for j in 0..x.col {
+                assert_eq!(x[(i, j)], y[(i, j)]);
+            }
+        }
+    };
+    for (r, c) in [(3, 4), (1, 5), (5, 1), (0, 2)] {
+        for sa in [Row, Col] {
+            for sb in [Row, Col] {
+                let a = matrix(rand(r, c).data, r, c, sa);
+                let b = matrix(rand(r, c).data, r, c, sb);
+
+                let sum = &a + &b;
+                same(&sum, &(a.clone() + b.clone()));
+                same(&sum, &(a.clone() + &b));
+                same(&sum, &(&a + b.clone()));
+                assert_eq!(sum.shape, sa);
+                assert_eq!((a.clone() + &b).shape, sa);
+                assert_eq!((&a + b.clone()).shape, sa);
+
+                let diff = &a - &b;
+                same(&diff, &(a.clone() - b.clone()));
+                same(&diff, &(a.clone() - &b));
+                same(&diff, &(&a - b.clone()));
+                assert_eq!((&a - b.clone()).shape, sa);
+
+                same(&a.hadamard(&b), &a.zip_with(|x, y| x * y, &b));
+                same(&(-&a), &(-a.clone()));
+                same(&(&a * 2f64), &(a.clone() * 2f64));
+                same(&(&a * 2), &(a.clone() * 2));
+                same(&(&a + 1f64), &(a.clone() + 1f64));
+                same(&(&a - 1f64), &(a.clone() - 1f64));
+                same(&(&a / 2f64), &(a.clone() / 2f64));
+                for i in 0..r {
+                    for j in 0..c {
+                        assert_eq!(sum[(i, j)], a[(i, j)] + b[(i, j)]);
+                        assert_eq!(diff[(i, j)], a[(i, j)] - b[(i, j)]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "dimension mismatch")]
+fn test_zip_with_dimension_mismatch() {
+    // Same length, transposed dimensions
+    let a = ml_matrix("1 2 3;4 5 6");
+    let b = a.t();
+    a.zip_with(|x, y| x + y, &b);
+}
+
+#[test]
+fn test_matrix_labels() {
+    let s = |v: &[&str]| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+    let mut a = LabeledMatrix::new(ml_matrix("1 2 3;4 5 6"));
+    a.set_row_labels(s(&["first", "second"]))
+        .set_col_labels(s(&["x", "y", "z"]));
+
+    // Printing
+    let st = a.spread();
+    assert!(st.contains("second") && st.contains("z") && !st.contains("c[0]"));
+    assert_eq!(a.to_string(), st);
+    assert!(a.matrix().spread().contains("c[0]"));
+
+    // Structural operations
+    let b = a.t();
+    assert_eq!(b.row_labels(), Some(&s(&["x", "y", "z"])));
+    assert_eq!(b.col_labels(), Some(&s(&["first", "second"])));
+    assert_eq!(b.t(), a);
+    let c = a.submat((0, 1), (1, 2));
+    assert_eq!(c.row_labels(), a.row_labels());
+    assert_eq!(c.col_labels(), Some(&s(&["y", "z"])));
+    assert_eq!(c.matrix(), &ml_matrix("2 3;5 6"));
+
+    // Computations work on the underlying Matrix
+    assert_eq!(&*a + &*a, ml_matrix("2 4 6;8 10 12"));
+    assert_eq!(a.clone().into_matrix(), ml_matrix("1 2 3;4 5 6"));
+    assert_eq!(LabeledMatrix::from(ml_matrix("1 2")).col_labels(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_matrix_labels_wrong_length() {
+    LabeledMatrix::new(ml_matrix("1 2;3 4")).set_col_labels(vec!["x".to_string()]);
+}
+
+#[test]
+fn test_elementwise_ops() {
+    let m = ml_matrix("-2.5 0.5;1.6 -0.4");
+    assert_eq!(m.abs(), ml_matrix("2.5 0.5;1.6 0.4"));
+    assert_eq!(m.signum(), ml_matrix("-1 1;1 -1"));
+    assert_eq!(m.round(), ml_matrix("-3 1;2 0"));
+    assert_eq!(m.floor(), ml_matrix("-3 0;1 -1"));
+    assert_eq!(m.ceil(), ml_matrix("-2 1;2 0"));
+
+    let c = m.change_shape().clamp(-1f64, 1f64);
+    assert_eq!(c.shape, Col);
+    assert_eq!(c, ml_matrix("-1 0.5;1 -0.4"));
+}
@@ -0,0 +1,41 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_mh_correlated_gaussian() {
+    let mu = c!(1, -1);
+    let sigma = matrix(c!(1, 0.8, 0.8, 1), 2, 2, Row);
+    let prec = sigma.inv();
+    let log_target = |x: &Vec<f64>| {
+        let d = x.sub_vec(&mu);
+        -0.5 * d.dot(&(&prec * &d))
+    };
+
+    let res = adaptive_metropolis_hastings(log_target, c!(0, 0), c!(1, 1), 20000, 2000, 1, Some(42));
+    assert!((0.2..0.4).contains(&res.acceptance_rate));
+
+    let samples = &res.samples;
+    for i in 0..2 {
+        let chain = samples.col(i);
+        let ess = effective_sample_size(&chain);
+        assert!(ess > 500f64 && ess < chain.len() as f64);
+        // 4 standard errors of the Monte Carlo mean
+        let mc_err = (sigma[(i, i)] / ess).sqrt();
+        assert!((chain.mean() - mu[i]).abs() < 4f64 * mc_err);
+    }
+    let cov = samples.cov();
+    assert!(cov.data.iter().zip(sigma.data.iter()).all(|(a, b)| (a - b).abs() < 0.15));
+
+    // Reproducible with seed
+    let res2 = adaptive_metropolis_hastings(log_target, c!(0, 0), c!(1, 1), 20000, 2000, 1, Some(42));
+    assert_eq!(res.samples, res2.samples);
+}
+
+#[test]
+fn test_autocorrelation() {
+    let x = Normal(0f64, 1f64).sample_with_rng(&mut stdrng_from_seed(42), 2000);
+    assert!((autocorrelation(&x, 0) - 1f64).abs() < 1e-12);
+    assert!(autocorrelation(&x, 1).abs() < 0.1);
+    assert_eq!(autocorrelation(&x, 2000), 0f64);
+}
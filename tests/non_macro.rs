@@ -0,0 +1,131 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_seq_descending() {
+    assert_eq!(seq(5, 1, -1), c!(5, 4, 3, 2, 1));
+    assert_eq!(seq(1, 0, -0.25), c!(1, 0.75, 0.5, 0.25, 0));
+    assert_eq!(seq!(10, 4, -3), c!(10, 7, 4));
+    assert_eq!(seq_with_precision(0, -0.4, -0.1, 1), c!(0, -0.1, -0.2, -0.3, -0.4));
+}
+
+#[test]
+#[should_panic]
+fn test_seq_wrong_direction() {
+    seq(1, 5, -1);
+}
+
+#[test]
+fn test_linspace_exact() {
+    assert_eq!(linspace(0, 1, 3), vec![0f64, 0.5, 1f64]);
+    assert_eq!(linspace!(0, 1, 3), vec![0f64, 0.5, 1f64]);
+
+    let x = linspace(0.1, 0.7, 7);
+    assert_eq!(x[6], 0.7);
+    let y = linspace!(0.1, 0.7, 7);
+    assert_eq!(x, y);
+
+    assert_eq!(linspace(3, 3, 1), vec![3f64]);
+    assert!(linspace(0, 1, 0).is_empty());
+}
+
+#[test]
+fn test_logspace_geomspace() {
+    assert_eq!(logspace(0, 3, 4, 10), vec![1f64, 10f64, 100f64, 1000f64]);
+    let g = geomspace(1, 1000, 4);
+    assert!(eq_vec(&g, &vec![1f64, 10f64, 100f64, 1000f64], 1e-12));
+    assert_eq!(g[3], 1000f64);
+
+    let h = geomspace(1000, 1, 4);
+    assert!(eq_vec(&h, &vec![1000f64, 100f64, 10f64, 1f64], 1e-12));
+}
+
+#[test]
+#[should_panic]
+fn test_geomspace_sign() {
+    geomspace(-1, 1, 3);
+}
+
+#[test]
+fn test_rand_seed() {
+    assert_eq!(rand!(3, 4; 7), rand!(3, 4; 7));
+    assert_eq!(rand!(3, 4; 7), rand_with_rng(3, 4, &mut stdrng_from_seed(7)));
+
+    let mut rng = stdrng_from_seed(7);
+    let a = randn_with_rng(200, 200, -1f64, 0.5, &mut rng);
+    assert!((a.data.mean() + 1f64).abs() < 0.01);
+    assert!((a.data.sd() - 0.5).abs() < 0.01);
+    assert_ne!(a, randn_with_rng(200, 200, -1f64, 0.5, &mut rng));
+}
+
+#[test]
+fn test_rand_spd_orth() {
+    let mut rng = stdrng_from_seed(42);
+    for n in [1, 5, 50] {
+        let s = rand_symm_with_rng(n, &mut rng);
+        assert!(s.is_symmetric());
+
+        let a = rand_spd_with_rng(n, &mut rng);
+        assert!(a.is_symmetric());
+        let eig = eigen(&a, Jacobi);
+        assert!(eig.eigenvalue.iter().all(|&l| l > 1f64 - 1e-8));
+        #[cfg(feature = "O3")]
+        {
+            let l = a.cholesky(Lower);
+            assert!(eq_vec(&(&l * &l.t()).data, &a.data, 1e-8));
+        }
+
+        let q = rand_orth_with_rng(n, &mut rng);
+        assert!(eq_vec(&(&q.t() * &q).data, &eye(n).data, 1e-12));
+        assert!((q.det().abs() - 1f64).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_matrix_generators() {
+    // Inverse of Hilbert matrix has integer entries
+    let h_inv = hilbert(3).inv();
+    assert!(eq_vec(&h_inv.data, &ml_matrix("9 -36 30;-36 192 -180;30 -180 180").data, 1e-9));
+
+    let a = Matrix::from_fn(3, 4, |i, j| (i as f64 - j as f64).abs(), Row);
+    assert_eq!(a, Matrix::from_fn(3, 4, |i, j| (i as f64 - j as f64).abs(), Col));
+    assert_eq!(a, toeplitz(&[0f64, 1f64, 2f64], &[0f64, 1f64, 2f64, 3f64]));
+
+    let x = c!(-1, 0.5, 2);
+    let (xx, yy) = meshgrid(&x, &x);
+    assert_eq!(xx, yy.t());
+    assert_eq!(vandermonde(&x, 1), cbind(x.into(), vec![1f64; 3].into()).unwrap());
+}
+
+#[test]
+fn test_hstack_vstack() {
+    // Mixed shapes
+    let a = ml_matrix("1 2;3 4");
+    let b = matrix(vec![5f64, 6f64, 7f64, 8f64, 9f64, 10f64], 2, 3, Col);
+    let c = ml_matrix("0;-1");
+    let mats = vec![a.clone(), b.clone(), c];
+    let h = hstack(&mats).unwrap();
+    assert_eq!((h.row, h.col), (2, 6));
+    assert_eq!(h.row(0), c!(1, 2, 5, 7, 9, 0));
+    assert_eq!(h.row(1), c!(3, 4, 6, 8, 10, -1));
+    assert_eq!(hstack(&[a.clone(), b.clone()]).unwrap(), cbind(a.clone(), b.clone()).unwrap());
+
+    let mats = vec![a.clone(), b.t(), ml_matrix("-1 -2")];
+    let v = vstack(&mats).unwrap();
+    assert_eq!((v.row, v.col), (6, 2));
+    assert_eq!(v.col(0), c!(1, 3, 5, 7, 9, -1));
+    assert_eq!(v[(3, 1)], 8f64);
+    assert_eq!(vstack(&[a.clone(), b.t()]).unwrap(), rbind(a.clone(), b.t()).unwrap());
+
+    // Assemble in a loop
+    let blocks = (0..4).map(|i| eye(2) * i as f64).collect::<Vec<_>>();
+    let d = vstack(&blocks).unwrap();
+    assert_eq!((d.row, d.col), (8, 2));
+    assert_eq!(d[(7, 1)], 3f64);
+
+    assert!(hstack(&[a.clone(), b.t()]).is_err());
+    assert!(vstack(&[a, b]).is_err());
+    assert!(hstack(&[]).is_err());
+    assert!(vstack(&[]).is_err());
+}
@@ -0,0 +1,65 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+fn tmp_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(name)
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_npy_round_trip() -> Result<(), Box<dyn Error>> {
+    for shape in [Row, Col] {
+        let a = matrix(seq(1, 12, 1), 3, 4, shape);
+        let path = tmp_path(&format!("peroxide_npy_{:?}.npy", shape));
+        a.write_npy(&path)?;
+        let b = Matrix::read_npy(&path)?;
+        assert_eq!(b.shape, shape);
+        assert_eq!(a, b);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_npy_header() {
+    let a = matrix(vec![0f64; 6], 2, 3, Row);
+    let h = npy_header(&a);
+    assert_eq!(&h[..8], b"\x93NUMPY\x01\x00");
+    assert_eq!(h.len() % 64, 0);
+    assert_eq!(u16::from_le_bytes([h[8], h[9]]) as usize, h.len() - 10);
+    let dict = std::str::from_utf8(&h[10..]).unwrap();
+    assert!(dict.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }"));
+    assert!(dict.ends_with(" \n"));
+
+    let b = matrix(vec![0f64; 6], 2, 3, Col);
+    let dict = String::from_utf8(npy_header(&b)[10..].to_vec()).unwrap();
+    assert!(dict.contains("'fortran_order': True"));
+}
+
+fn write_raw_npy(path: &str, shape: &str, n_data: usize) -> std::io::Result<()> {
+    let dict = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}\n", shape);
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(dict.as_bytes());
+    bytes.extend_from_slice(&vec![0u8; n_data * 8]);
+    std::fs::write(path, bytes)
+}
+
+#[test]
+fn test_npy_bad_shape() -> Result<(), Box<dyn Error>> {
+    let path = tmp_path("peroxide_npy_bad_shape.npy");
+
+    write_raw_npy(&path, "(2, 3)", 6)?;
+    assert_eq!(Matrix::read_npy(&path)?, matrix(vec![0f64; 6], 2, 3, Row));
+
+    // Shape larger than the data in the file
+    write_raw_npy(&path, "(9, 9)", 6)?;
+    assert!(Matrix::read_npy(&path).is_err());
+
+    // row * col * 8 overflows usize
+    write_raw_npy(&path, &format!("({}, 2)", usize::MAX), 6)?;
+    assert!(Matrix::read_npy(&path).is_err());
+    Ok(())
+}
@@ -68,3 +68,129 @@ fn test_cubic_spline_extension() -> Result<(), Box<dyn std::error::Error>>{
 
     Ok(())
 }
+
+#[test]
+fn test_b_spline_line() -> Result<(), Box<dyn std::error::Error>> {
+    // Degree 3 clamped B-Spline with control points on y = 2x + 1
+    let knots = linspace(0, 1, 5);
+    let greville = vec![0f64, 1f64 / 12f64, 0.25, 0.5, 0.75, 11f64 / 12f64, 1f64];
+    let control_points = greville.iter().map(|&x| vec![x, 2f64 * x + 1f64]).collect();
+    let spline = BSpline::clamped(3, knots, control_points)?;
+
+    for t in linspace(0, 1, 101) {
+        let p = spline.de_boor(t);
+        assert!((p[0] - t).abs() < 1e-12);
+        assert!((p[1] - (2f64 * t + 1f64)).abs() < 1e-12);
+        assert!(eq_vec(&spline.deriv(t), &vec![1f64, 2f64], 1e-10));
+    }
+    // Final knot is inside the domain
+    assert_eq!(spline.eval(1f64), (1f64, 3f64));
+
+    let mut refined = spline.clone();
+    refined.insert_knot(0.3)?;
+    refined.insert_knot(0.3)?;
+    for t in linspace(0, 1, 11) {
+        assert!(eq_vec(&refined.de_boor(t), &spline.de_boor(t), 1e-12));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_b_spline_fit() -> Result<(), Box<dyn std::error::Error>> {
+    let x = linspace(0, 2f64 * std::f64::consts::PI, 200);
+    let noise = Normal(0f64, 0.05).sample_with_rng(&mut stdrng_from_seed(42), x.len());
+    let y = x.iter().zip(noise.iter()).map(|(t, e)| t.sin() + e).collect::<Vec<f64>>();
+
+    let spline = BSpline::fit(&x, &y, 8, 3)?;
+    let err = x
+        .iter()
+        .map(|&t| (spline.eval(t).1 - t.sin()).powi(2))
+        .sum::<f64>()
+        / x.len() as f64;
+    assert!(err.sqrt() < 0.02);
+    assert!(BSpline::fit(&x[..5], &y[..5], 8, 3).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_linear_interp_extrapolation() -> Result<(), Box<dyn std::error::Error>> {
+    let x = vec![0f64, 1f64, 3f64];
+    let y = vec![1f64, 3f64, 2f64];
+
+    let lin = LinearInterp::new(x.clone(), y.clone(), Extrapolation::Linear)?;
+    let clamp = LinearInterp::new(x.clone(), y.clone(), Extrapolation::Clamp)?;
+    let err = LinearInterp::new(x.clone(), y.clone(), Extrapolation::Error)?;
+
+    // Inside & on nodes : all policies agree
+    for t in [0f64, 0.5, 1f64, 2f64, 3f64] {
+        assert_eq!(lin.eval(t), clamp.eval(t));
+        assert_eq!(lin.eval(t), err.eval(t));
+    }
+    assert_eq!(lin.eval(3f64), 2f64);
+    assert_eq!(lin.eval(2f64), 2.5);
+
+    // Outside
+    assert_eq!(lin.eval(-1f64), -1f64);
+    assert_eq!(lin.eval(5f64), 1f64);
+    assert_eq!(clamp.eval(-1f64), 1f64);
+    assert_eq!(clamp.eval(5f64), 2f64);
+    assert!(std::panic::catch_unwind(|| err.eval(3.5)).is_err());
+    assert!(std::panic::catch_unwind(|| err.eval(-0.1)).is_err());
+    assert!(lin.eval(f64::NAN).is_nan());
+
+    // Invalid nodes
+    assert!(LinearInterp::new(vec![0f64], vec![0f64], Extrapolation::Linear).is_err());
+    assert!(LinearInterp::new(x.clone(), vec![0f64; 2], Extrapolation::Linear).is_err());
+    assert!(LinearInterp::new(vec![0f64, 1f64, 1f64], y.clone(), Extrapolation::Linear).is_err());
+    assert!(LinearInterp::new(vec![0f64, 2f64, 1f64], y, Extrapolation::Linear).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_linear_interp_eval_vec() -> Result<(), Box<dyn std::error::Error>> {
+    let x = seq(0, 10, 1);
+    let y = x.fmap(f);
+    for extrap in [Extrapolation::Linear, Extrapolation::Clamp] {
+        let interp = LinearInterp::new(x.clone(), y.clone(), extrap)?;
+
+        // Sorted (with duplicates & extrapolation) : fast path
+        let sorted = linspace(-2, 12, 57);
+        let mut xs = sorted.clone();
+        xs.insert(10, xs[10]);
+        let fast = interp.eval_vec(&xs);
+        let slow = xs.iter().map(|&t| interp.eval(t)).collect::<Vec<f64>>();
+        assert_eq!(fast, slow);
+
+        // Unsorted
+        let xs = sorted.iter().rev().cloned().collect::<Vec<f64>>();
+        let ys = interp.eval_vec(&xs);
+        let expected = xs.iter().map(|&t| interp.eval(t)).collect::<Vec<f64>>();
+        assert_eq!(ys, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_linear_interp_inverse() -> Result<(), Box<dyn std::error::Error>> {
+    // Decreasing
+    let x = seq(0, 10, 1);
+    let y = x.fmap(f);
+    let interp = LinearInterp::new(x.clone(), y.clone(), Extrapolation::Linear)?;
+    for t in linspace(0.05, 9.95, 100) {
+        assert!((interp.inverse(interp.eval(t)) - t).abs() < 1e-10);
+    }
+    for (xi, yi) in x.iter().zip(y.iter()) {
+        assert_eq!(interp.inverse(*yi), *xi);
+    }
+
+    // Increasing with clamp
+    let interp = LinearInterp::new(x.clone(), x.fmap(|t| t * t), Extrapolation::Clamp)?;
+    assert_eq!(interp.inverse(-1f64), 0f64);
+    assert_eq!(interp.inverse(200f64), 10f64);
+    assert_eq!(interp.inverse(2.5), 1.5);
+
+    // Not monotone
+    let interp = LinearInterp::new(x.clone(), x.fmap(|t| (t - 5f64).powi(2)), Extrapolation::Linear)?;
+    assert!(std::panic::catch_unwind(|| interp.inverse(1f64)).is_err());
+    Ok(())
+}
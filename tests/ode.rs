@@ -0,0 +1,592 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+/// Stiff linear ODE : y' = -k (y - cos t), y(0) = 0
+struct Stiff {
+    k: f64,
+}
+
+impl Stiff {
+    fn exact(&self, t: f64) -> f64 {
+        let k = self.k;
+        k / (k * k + 1f64) * (k * t.cos() + t.sin()) - k * k / (k * k + 1f64) * (-k * t).exp()
+    }
+}
+
+impl ODEProblem for Stiff {
+    fn initial_conditions(&self) -> Vec<f64> {
+        vec![0f64]
+    }
+
+    fn rhs(&self, t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        dy[0] = -self.k * (y[0] - t.cos());
+        Ok(())
+    }
+}
+
+/// Harmonic oscillator : x'' = -x
+struct Oscillator;
+
+impl ODEProblem for Oscillator {
+    fn initial_conditions(&self) -> Vec<f64> {
+        vec![1f64, 0f64]
+    }
+
+    fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        dy[0] = y[1];
+        dy[1] = -y[0];
+        Ok(())
+    }
+}
+
+fn last_error<I: ODEIntegrator>(integrator: I, problem: &Stiff, dt: f64) -> f64 {
+    let (t, y) = BasicODESolver::new(integrator)
+        .solve(problem, (0f64, 2f64), dt)
+        .unwrap();
+    let n = t.len() - 1;
+    (y[n][0] - problem.exact(t[n])).abs()
+}
+
+#[test]
+fn test_stiff_stability() {
+    let problem = Stiff { k: 50f64 };
+    let dt = 0.1;
+
+    // Explicit Euler : |1 - k dt| = 4 > 1
+    let mut y = 0f64;
+    let mut t = 0f64;
+    let mut dy = vec![0f64];
+    for _ in 0..20 {
+        problem.rhs(t, &[y], &mut dy).unwrap();
+        y += dt * dy[0];
+        t += dt;
+    }
+    assert!(y.abs() > 1e6);
+
+    // RK4 is also unstable for k dt = 5
+    assert!(last_error(RK4, &problem, dt) > 1e6);
+
+    // Implicit methods are stable
+    assert!(last_error(BackwardEuler::default(), &problem, dt) < 1e-3);
+    assert!(last_error(GL4::new(ImplicitSolver::Newton, 1e-12, 50), &problem, dt) < 1e-4);
+}
+
+#[test]
+fn test_gl4_order() {
+    // Error ratio of 4th order method : 2^4
+    for solver in [ImplicitSolver::FixedPoint, ImplicitSolver::Newton] {
+        let gl4 = GL4::new(solver, 1e-14, 100);
+        let e1 = last_error(gl4, &Stiff { k: 1f64 }, 0.1);
+        let e2 = last_error(gl4, &Stiff { k: 1f64 }, 0.05);
+        assert!((e1 / e2 - 16f64).abs() < 2f64);
+    }
+
+    // Backward Euler : 1st order
+    let be = BackwardEuler::default();
+    let e1 = last_error(be, &Stiff { k: 1f64 }, 0.02);
+    let e2 = last_error(be, &Stiff { k: 1f64 }, 0.01);
+    assert!((e1 / e2 - 2f64).abs() < 0.2);
+}
+
+/// Exponential growth : y' = y, y(0) = 1
+struct Growth;
+
+impl ODEProblem for Growth {
+    fn initial_conditions(&self) -> Vec<f64> {
+        vec![1f64]
+    }
+
+    fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        dy[0] = y[0];
+        Ok(())
+    }
+}
+
+/// Observed order of convergence `log2(e(dt) / e(dt/2))`
+fn explicit_order<I: ODEIntegrator + Clone>(integrator: I, dt: f64) -> (f64, f64) {
+    let growth_error = |dt: f64| {
+        let (t, y) = BasicODESolver::new(integrator.clone())
+            .solve(&Growth, (0f64, 1f64), dt)
+            .unwrap();
+        let n = t.len() - 1;
+        (y[n][0] - t[n].exp()).abs()
+    };
+    let stiff = Stiff { k: 1f64 };
+    let growth = (growth_error(dt) / growth_error(dt / 2f64)).log2();
+    let time_dependent = (last_error(integrator.clone(), &stiff, dt) / last_error(integrator, &stiff, dt / 2f64)).log2();
+    (growth, time_dependent)
+}
+
+#[test]
+fn test_explicit_order() {
+    let orders = [
+        explicit_order(Midpoint, 0.01),
+        explicit_order(Heun, 0.01),
+        explicit_order(RK3, 0.02),
+        explicit_order(RALS3, 0.02),
+        explicit_order(RK4, 0.05),
+    ];
+    let expected = [2f64, 2f64, 3f64, 3f64, 4f64];
+    for ((growth, time_dependent), p) in orders.iter().zip(expected.iter()) {
+        assert!((growth - p).abs() < 0.15, "{} vs {}", growth, p);
+        assert!((time_dependent - p).abs() < 0.15, "{} vs {}", time_dependent, p);
+    }
+
+    // Two-component system
+    let (_, y) = BasicODESolver::new(RK4)
+        .solve(&Oscillator, (0f64, 1f64), 0.01)
+        .unwrap();
+    let y = y.last().unwrap();
+    assert!((y[0] - 1f64.cos()).abs() < 1e-9);
+    assert!((y[1] + 1f64.sin()).abs() < 1e-9);
+}
+
+/// Explicit method with tableau of `T` and weights `b`
+fn with_weights<T: ButcherTableau>(b: &[f64]) -> ExplicitRK {
+    let a = T::A.iter().map(|row| row.to_vec()).collect();
+    ExplicitRK::new(a, b.to_vec(), T::C.to_vec()).unwrap()
+}
+
+#[test]
+fn test_rals4_order() {
+    // Rows of A sum to c_i, so the order holds for time-dependent RHS
+    validate(&RALS4);
+    let (growth, time_dependent) = explicit_order(RALS4, 0.05);
+    assert!((growth - 4f64).abs() < 0.15, "{}", growth);
+    assert!((time_dependent - 4f64).abs() < 0.15, "{}", time_dependent);
+}
+
+#[test]
+fn test_rk5_order() {
+    // 5th order weights of Dormand-Prince (the 4th order ones gave RK4-like convergence)
+    let (growth, time_dependent) = explicit_order(RK5, 0.05);
+    assert!((growth - 5f64).abs() < 0.15, "{}", growth);
+    assert!((time_dependent - 5f64).abs() < 0.15, "{}", time_dependent);
+}
+
+#[test]
+fn test_tsit45_order() {
+    // Solution weights : 5th order (small principal error, so not yet asymptotic)
+    let (growth, time_dependent) = explicit_order(with_weights::<TSIT45>(TSIT45::BU), 0.05);
+    assert!(growth > 4.6, "{}", growth);
+    assert!(time_dependent > 4.6, "{}", time_dependent);
+
+    // Embedded weights : 4th order (not the error coefficients themselves)
+    let (growth, time_dependent) = explicit_order(with_weights::<TSIT45>(TSIT45::BE), 0.05);
+    assert!((growth - 4f64).abs() < 0.2, "{}", growth);
+    assert!((time_dependent - 4f64).abs() < 0.2, "{}", time_dependent);
+}
+
+#[test]
+fn test_adaptive_substep() {
+    // A rejected step is retried with smaller steps until `dt` is covered
+    let rkf = RKF45 {
+        tol: 1e-12,
+        ..Default::default()
+    };
+    let mut y = vec![1f64];
+    let dt_next = rkf.step(&Growth, 0f64, &mut y, 0.5).unwrap();
+    assert!(dt_next < 0.5);
+    assert!((y[0] - 0.5f64.exp()).abs() < 1e-10, "{}", y[0]);
+
+    // Accuracy improves with the tolerance (5th order solution is propagated)
+    let error = |tol: f64| {
+        let rkf = RKF45 { tol, ..Default::default() };
+        let (t, y) = BasicODESolver::new(rkf).solve(&Growth, (0f64, 1f64), 0.1).unwrap();
+        let n = t.len() - 1;
+        (y[n][0] - t[n].exp()).abs()
+    };
+    assert!(error(1e-10) < error(1e-6));
+    assert!(error(1e-10) < 1e-9);
+}
+
+fn validate<T: ButcherTableau>(method: &T) {
+    ExplicitRK::from_tableau(method).validate().unwrap();
+}
+
+#[test]
+fn test_custom_tableau() {
+    // SSPRK3 (Shu-Osher)
+    let ssprk3 = ExplicitRK::new(
+        vec![vec![], vec![1.0], vec![0.25, 0.25]],
+        vec![1.0 / 6.0, 1.0 / 6.0, 2.0 / 3.0],
+        vec![0.0, 1.0, 0.5],
+    )
+    .unwrap();
+    assert_eq!(ssprk3.stages(), 3);
+    let (growth, time_dependent) = explicit_order(ssprk3, 0.02);
+    assert!((growth - 3f64).abs() < 0.15);
+    assert!((time_dependent - 3f64).abs() < 0.15);
+
+    // Built-in tableaus are consistent
+    validate(&Midpoint);
+    validate(&Heun);
+    validate(&RK3);
+    validate(&RALS3);
+    validate(&RK4);
+    validate(&RALS4);
+    validate(&RK5);
+    validate(&BS23::default());
+    validate(&RKF45::default());
+    validate(&DP45::default());
+    validate(&TSIT45::default());
+
+    // Same as built-in methods
+    let problem = Stiff { k: 1f64 };
+    let e_builtin = last_error(RK4, &problem, 0.1);
+    let e_custom = last_error(ExplicitRK::from_tableau(&RK4), &problem, 0.1);
+    assert_eq!(e_builtin, e_custom);
+
+    let dp45 = DP45::new(1e-8, 0.9, 1e-6, 1e-1, 100);
+    let (t1, y1) = BasicODESolver::new(dp45).solve(&Oscillator, (0f64, 5f64), 0.1).unwrap();
+    let (t2, y2) = BasicODESolver::new(ExplicitRK::from_tableau(&dp45))
+        .solve(&Oscillator, (0f64, 5f64), 0.1)
+        .unwrap();
+    assert_eq!(t1, t2);
+    assert_eq!(y1, y2);
+
+    // Invalid tableaus
+    let not_lower = ExplicitRK::new(vec![vec![0.5], vec![0.5]], vec![0.5, 0.5], vec![0.5, 0.5]);
+    assert!(not_lower.is_err());
+    let wrong_c = ExplicitRK::new(vec![vec![], vec![1.0]], vec![0.5, 0.5], vec![0.0, 0.5]);
+    assert!(wrong_c.is_err());
+    let wrong_b = ExplicitRK::new(vec![vec![], vec![1.0]], vec![0.5, 0.6], vec![0.0, 1.0]);
+    assert!(wrong_b.is_err());
+    let wrong_len = ExplicitRK::embedded(vec![vec![], vec![1.0]], vec![0.5, 0.5], vec![1.0], vec![0.0, 1.0]);
+    assert!(wrong_len.is_err());
+}
+
+/// Kepler problem (GM = 1) : y = (x, y, p_x, p_y), starting at perihelion
+struct Kepler {
+    e: f64,
+}
+
+impl Kepler {
+    fn energy(y: &[f64]) -> f64 {
+        0.5 * (y[2].powi(2) + y[3].powi(2)) - 1f64 / (y[0].powi(2) + y[1].powi(2)).sqrt()
+    }
+}
+
+impl ODEProblem for Kepler {
+    fn initial_conditions(&self) -> Vec<f64> {
+        vec![1f64 - self.e, 0f64, 0f64, ((1f64 + self.e) / (1f64 - self.e)).sqrt()]
+    }
+
+    fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        let r3 = (y[0].powi(2) + y[1].powi(2)).powf(1.5);
+        dy[0] = y[2];
+        dy[1] = y[3];
+        dy[2] = -y[0] / r3;
+        dy[3] = -y[1] / r3;
+        Ok(())
+    }
+}
+
+/// Maximum energy error on the first & second halves of 10^5 steps
+fn kepler_energy_error<I: ODEIntegrator>(integrator: I) -> (f64, f64) {
+    let dt = 0.02;
+    let (_, y) = BasicODESolver::new(integrator)
+        .solve(&Kepler { e: 0.5 }, (0f64, 1e5 * dt), dt)
+        .unwrap();
+    let e0 = Kepler::energy(&y[0]);
+    let de = y.iter().map(|yi| (Kepler::energy(yi) - e0).abs()).collect::<Vec<f64>>();
+    let n = de.len();
+    let max = |v: &[f64]| v.iter().fold(0f64, |a, b| a.max(*b));
+    (max(&de[.. n / 2]), max(&de[n / 2 ..]))
+}
+
+#[test]
+fn test_symplectic_kepler() {
+    // Symplectic : energy error oscillates, but does not grow
+    let (first, second) = kepler_energy_error(Verlet);
+    assert!(second < 1e-3);
+    assert!(second < 1.01 * first);
+
+    let (first, second) = kepler_energy_error(Yoshida4);
+    assert!(second < 1e-5);
+    assert!(second < 1.01 * first);
+
+    // RK4 : secular (linear) drift
+    let (first, second) = kepler_energy_error(RK4);
+    assert!(second > 1.8 * first);
+
+    // Yoshida4 is 4th order
+    let error = |dt: f64| {
+        let (t, y) = BasicODESolver::new(Yoshida4).solve(&Oscillator, (0f64, 1f64), dt).unwrap();
+        let n = t.len() - 1;
+        (y[n][0] - t[n].cos()).abs()
+    };
+    assert!(((error(0.05) / error(0.025)).log2() - 4f64).abs() < 0.15);
+
+    // State should be (q, p)
+    let odd = BasicODESolver::new(Verlet).solve(&Growth, (0f64, 1f64), 0.1);
+    assert!(odd.is_err());
+}
+
+#[test]
+fn test_gl4_newton_system() {
+    // Gauss-Legendre is symplectic : energy is conserved for linear problems
+    let gl4 = GL4::new(ImplicitSolver::Newton, 1e-12, 50);
+    let (_, y) = BasicODESolver::new(gl4)
+        .solve(&Oscillator, (0f64, 100f64), 0.5)
+        .unwrap();
+    for yi in y.iter() {
+        let energy = yi[0].powi(2) + yi[1].powi(2);
+        assert!((energy - 1f64).abs() < 1e-10);
+    }
+}
+
+static CALLS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn count_steps(step: usize, t: f64, y: &[f64]) {
+    assert!(step.is_multiple_of(10));
+    assert!((t - step as f64 * 0.1).abs() < 1e-9);
+    assert_eq!(y.len(), 2);
+    CALLS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+}
+
+#[test]
+fn test_callback_and_record() {
+    let (t, y) = BasicODESolver::new(RK4)
+        .solve(&Oscillator, (0f64, 10f64), 0.1)
+        .unwrap();
+    assert_eq!(t.len(), y.len());
+
+    let mut solver = BasicODESolver::new(RK4);
+    solver.set_callback(10, count_steps).set_record(false);
+    let (t_last, y_last) = solver.solve(&Oscillator, (0f64, 10f64), 0.1).unwrap();
+    assert_eq!(t_last, vec![*t.last().unwrap()]);
+    assert_eq!(y_last, vec![y.last().unwrap().clone()]);
+    // Steps 0, 10, ..., 100
+    assert_eq!(CALLS.load(std::sync::atomic::Ordering::SeqCst), (t.len() - 1) / 10 + 1);
+}
+
+/// Van der Pol oscillator : x'' = mu (1 - x^2) x' - x
+struct VanDerPol {
+    mu: f64,
+    calls: std::cell::Cell<usize>,
+}
+
+impl ODEProblem for VanDerPol {
+    fn initial_conditions(&self) -> Vec<f64> {
+        vec![2f64, 0f64]
+    }
+
+    fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        self.calls.set(self.calls.get() + 1);
+        dy[0] = y[1];
+        dy[1] = self.mu * (1f64 - y[0].powi(2)) * y[1] - y[0];
+        Ok(())
+    }
+}
+
+#[test]
+fn test_rosenbrock_van_der_pol() {
+    let problem = VanDerPol {
+        mu: 100f64,
+        calls: std::cell::Cell::new(0),
+    };
+    // Reference : RK4 with h = 2e-5
+    let reference = [
+        (25f64, 1.8196292203393565, -0.007873327217718832),
+        (50f64, 1.5968240409770813, -0.010302125564534187),
+        (75f64, 1.2475195047975762, -0.022392068488597382),
+        (100f64, -1.8689241598839843, 0.007496838315127137),
+        (125f64, -1.6600988694472874, 0.009453596645128669),
+        (150f64, -1.3660604919944626, 0.015765221372653068),
+    ];
+
+    let ros = ROS23::new(1e-6, 0.9, 1e-12, 1e1, 1000);
+    let mut y = problem.initial_conditions();
+    let mut t = 0f64;
+    for (t_ref, y1, y2) in reference {
+        ros.step(&problem, t, &mut y, t_ref - t).unwrap();
+        t = t_ref;
+        assert!((y[0] - y1).abs() < 1e-3);
+        assert!((y[1] - y2).abs() < 1e-4);
+    }
+    // Explicit RK4 needs about 6e4 evaluations (stability limit : dt < 2.8 / 300)
+    assert!(problem.calls.get() < 20_000);
+}
+
+/// y'(t) = -y(t - 1), y(t) = 1 for t <= 0
+struct LinearDDE;
+
+impl DDEProblem for LinearDDE {
+    fn history(&self, _t: f64) -> Vec<f64> {
+        vec![1f64]
+    }
+
+    fn rhs(&self, _t: f64, _y: &[f64], past: &DelayContext, dy: &mut [f64]) -> anyhow::Result<()> {
+        dy[0] = -past.delayed(0)[0];
+        Ok(())
+    }
+}
+
+#[test]
+fn test_dde() {
+    // Reference : method of steps (exact piecewise polynomial)
+    let y10 = 0.020241126543209878;
+
+    // Step size does not divide the delay (but reaches t = 10)
+    for n in [333, 667, 1333] {
+        let dt = 10f64 / n as f64;
+        let mut solver = DDESolver::new(RK4);
+        solver.set_delays(vec![1f64]);
+        let (t, y) = solver.solve(&LinearDDE, (0f64, 10f64), dt).unwrap();
+        assert!((t[n] - 10f64).abs() < 1e-9);
+        assert!((y[n][0] - y10).abs() < 1e-7);
+    }
+
+    // Step size should not exceed delay
+    let mut solver = DDESolver::new(RK4);
+    solver.set_delays(vec![1f64]);
+    assert!(solver.solve(&LinearDDE, (0f64, 10f64), 1.5).is_err());
+}
+
+#[test]
+fn test_dde_adaptive() {
+    // Without clamping, RKF45 grows the step beyond the delay
+    let rkf = RKF45 {
+        tol: 1e-10,
+        max_step_size: 5f64,
+        ..Default::default()
+    };
+    let mut solver = DDESolver::new(rkf);
+    solver.set_delays(vec![1f64]);
+    let (t, y) = solver.solve(&LinearDDE, (0f64, 3f64), 0.01).unwrap();
+    assert!(t.windows(2).all(|w| w[1] - w[0] <= 1f64 + 1e-12));
+    assert!(t.windows(2).any(|w| w[1] - w[0] > 0.5));
+
+    // Method of steps on [0, 2]
+    let exact = |t: f64| {
+        if t <= 1f64 {
+            1f64 - t
+        } else {
+            1f64 - t + (t - 1f64).powi(2) / 2f64
+        }
+    };
+    for (ti, yi) in t.iter().zip(y.iter()).filter(|(ti, _)| **ti <= 2f64) {
+        assert!((yi[0] - exact(*ti)).abs() < 1e-8, "t = {}, y = {}", ti, yi[0]);
+    }
+}
+
+/// y'' = 3/2 y^2 : y = 4 / (1 + t)^2 satisfies y(0) = 4, y'(0) = -8, y(1) = 1
+struct Nonlinear {
+    guess: Vec<f64>,
+}
+
+impl ODEProblem for Nonlinear {
+    fn initial_conditions(&self) -> Vec<f64> {
+        self.guess.clone()
+    }
+
+    fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        dy[0] = y[1];
+        dy[1] = 1.5 * y[0].powi(2);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_shooting() {
+    // y'' = -y, y(0) = 0, y(π/2) = 1 => y = sin(t)
+    let mut solver = ShootingSolver::new(RK4);
+    solver.set_boundary_condition(vec![(0, 0f64)], vec![(0, 1f64)]);
+    let (t, y) = solver.solve_bvp(&Oscillator, (0f64, std::f64::consts::FRAC_PI_2), 0.01).unwrap();
+    assert!((y[0][1] - 1f64).abs() < 1e-8);
+    assert_eq!(*t.last().unwrap(), std::f64::consts::FRAC_PI_2);
+    for (ti, yi) in t.iter().zip(y.iter()) {
+        assert!((yi[0] - ti.sin()).abs() < 1e-8);
+    }
+
+    // Nonlinear : unknown slope at t0
+    let exact = |t: f64| 4f64 / (1f64 + t).powi(2);
+    let mut solver = ShootingSolver::new(RK4);
+    solver.set_boundary_condition(vec![(0, 4f64)], vec![(0, 1f64)]);
+    let (t, y) = solver.solve_bvp(&Nonlinear { guess: vec![0f64, -10f64] }, (0f64, 1f64), 0.001).unwrap();
+    assert!((y[0][1] + 8f64).abs() < 1e-6);
+    assert!(t.iter().zip(y.iter()).all(|(ti, yi)| (yi[0] - exact(*ti)).abs() < 1e-6));
+
+    // Mixed : y'(0) given at t0, y(1) given at t1 (unknown y(0))
+    solver.set_boundary_condition(vec![(1, -8f64)], vec![(0, 1f64)]);
+    let (_, y) = solver.solve_bvp(&Nonlinear { guess: vec![3f64, 0f64] }, (0f64, 1f64), 0.001).unwrap();
+    assert!((y[0][0] - 4f64).abs() < 1e-6);
+
+    // Adaptive integrator : steps follow the returned dt
+    let mut solver = ShootingSolver::new(DP45::new(1e-10, 0.9, 1e-6, 1e-1, 100));
+    solver.set_boundary_condition(vec![(0, 0f64)], vec![(0, 1f64)]);
+    let (t, y) = solver.solve_bvp(&Oscillator, (0f64, std::f64::consts::FRAC_PI_2), 1e-3).unwrap();
+    assert!(t.len() < 100);
+    assert_eq!(*t.last().unwrap(), std::f64::consts::FRAC_PI_2);
+    for (ti, yi) in t.iter().zip(y.iter()) {
+        assert!((yi[0] - ti.sin()).abs() < 1e-8);
+    }
+
+    // Errors
+    let solver = ShootingSolver::new(RK4);
+    assert!(solver.solve_bvp(&Oscillator, (0f64, 1f64), 0.01).is_err());
+    let mut solver = ShootingSolver::new(RK4);
+    solver.set_boundary_condition(vec![], vec![(0, 1f64)]);
+    assert!(solver.solve_bvp(&Oscillator, (0f64, 1f64), 0.01).is_err());
+}
+
+/// Oscillator with damping switched on : x'' = -x - c x'
+struct Damped {
+    c: f64,
+}
+
+impl ODEProblem for Damped {
+    fn initial_conditions(&self) -> Vec<f64> {
+        unreachable!("Damped is only used as a second phase")
+    }
+
+    fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        dy[0] = y[1];
+        dy[1] = -y[0] - self.c * y[1];
+        Ok(())
+    }
+}
+
+#[test]
+fn test_two_phase() {
+    let solver = BasicODESolver::new(RK4);
+    let dt = 0.01;
+
+    // Splitting without changing RHS reproduces single-phase integration
+    let (t_full, y_full) = solver.solve(&Oscillator, (0f64, 2f64), dt).unwrap();
+    let (t1, y1, state) = solver.solve_with_final(&Oscillator, (0f64, 1f64), dt).unwrap();
+    assert_eq!(state.t, *t1.last().unwrap());
+    assert_eq!(&state.y, y1.last().unwrap());
+    assert_eq!(state.dt, dt);
+    let (t2, y2, end) = solver.continue_from(&Oscillator, &state, 2f64).unwrap();
+    assert_eq!(t2[0], state.t);
+    assert_eq!(y2[0], state.y);
+    assert_eq!(end.t, *t_full.last().unwrap());
+    assert!(end.y.iter().zip(y_full.last().unwrap()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+    // Switch RHS halfway : solution is continuous across the splice
+    let damped = Damped { c: 0.5 };
+    let (_, y2, _) = solver.continue_from(&damped, &state, 2f64).unwrap();
+    let (x, v) = (y2[0][0], y2[0][1]);
+    assert!((x - state.t.cos()).abs() < 1e-8);
+    assert!((v + state.t.sin()).abs() < 1e-8);
+    // Velocity is continuous, but acceleration jumps by -c v
+    let mut a1 = vec![0f64; 2];
+    let mut a2 = vec![0f64; 2];
+    Oscillator.rhs(state.t, &state.y, &mut a1).unwrap();
+    damped.rhs(state.t, &y2[0], &mut a2).unwrap();
+    assert_eq!(a1[0], a2[0]);
+    assert!((a2[1] - a1[1] + 0.5 * v).abs() < 1e-12);
+    // Continue from an intermediate record
+    let mid = ODEState::from_record(&t1, &y1, 50);
+    assert_eq!(mid.t, t1[50]);
+    assert!((mid.dt - dt).abs() < 1e-12);
+    let (t3, y3, _) = solver.continue_from(&Oscillator, &mid, 1f64).unwrap();
+    let n = t3.len() - 1;
+    assert!((y3[n][0] - t3[n].cos()).abs() < 1e-8);
+
+    // Single record has no step size
+    let single = ODEState::from_record(&t1[..1], &y1[..1], 0);
+    assert!(solver.continue_from(&Oscillator, &single, 1f64).is_err());
+}
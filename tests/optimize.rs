@@ -49,4 +49,132 @@ fn f(x: &Vec<f64>, p: Vec<AD>) -> Option<Vec<AD>> {
             .map(|t| p[0] * t.powi(2) + p[1] * t + p[2])
             .collect()
     )
-}
\ No newline at end of file
+}
+#[test]
+#[allow(non_snake_case)]
+fn test_LM_weighted() {
+    // Heteroscedastic data : sigma grows with x
+    let x = seq(0, 10, 0.1);
+    let sigma = x.fmap(|t| 0.01 + 0.1 * t * t);
+    let p_true = vec![2f64, -0.3];
+    let w = sigma.fmap(|s| 1f64 / s);
+
+    let mut err_unweighted = 0f64;
+    let mut err_weighted = 0f64;
+    for seed in 0..10 {
+        let mut rng = stdrng_from_seed(seed);
+        let y = x
+            .iter()
+            .zip(sigma.iter())
+            .map(|(t, s)| p_true[0] * (p_true[1] * t).exp() + Normal(0f64, *s).sample_with_rng(&mut rng, 1)[0])
+            .collect::<Vec<f64>>();
+        let data = hstack!(x.clone(), y);
+
+        let mut opt = Optimizer::new(data.clone(), exp_model);
+        let p = opt
+            .set_init_param(vec![1f64, -1f64])
+            .set_max_iter(100)
+            .set_method(LevenbergMarquardt)
+            .optimize();
+        err_unweighted += (p[0] - p_true[0]).abs() + (p[1] - p_true[1]).abs();
+
+        // Explicit Jacobian with weights
+        let mut opt = Optimizer::new(data, exp_model);
+        let p = opt
+            .set_init_param(vec![1f64, -1f64])
+            .set_max_iter(100)
+            .set_method(LevenbergMarquardt)
+            .set_jacobian(exp_jacobian)
+            .set_weights(w.clone())
+            .optimize();
+        err_weighted += (p[0] - p_true[0]).abs() + (p[1] - p_true[1]).abs();
+        // Weighted residuals ~ N(0, 1)
+        assert!(opt.get_error() > 0.5 && opt.get_error() < 1.5);
+    }
+    assert!(err_weighted < 0.2 * err_unweighted);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn test_LM_explicit_jacobian() {
+    let x = seq(0, 10, 0.1);
+    let y = x.fmap(|t| t.powi(2) + 2f64 * t + 3f64);
+    let data = hstack!(x, y);
+
+    // Model without derivative information : explicit Jacobian is required
+    let mut opt = Optimizer::new(data, f_value);
+    let p = opt
+        .set_init_param(vec![1f64, 1f64, 1f64])
+        .set_max_iter(50)
+        .set_jacobian(quad_jacobian)
+        .optimize();
+    assert!(eq_vec(&p, &vec![1f64, 2f64, 3f64], 1e-6));
+}
+
+#[test]
+fn test_global_rastrigin() {
+    let rastrigin = |x: &Vec<f64>| {
+        10f64 * x.len() as f64
+            + x.iter()
+                .map(|t| t * t - 10f64 * (2f64 * std::f64::consts::PI * t).cos())
+                .sum::<f64>()
+    };
+    // Asymmetric bounds : global minimum is not at the center
+    let bounds = vec![
+        (-5.12f64, 4f64),
+        (-3f64, 5.12f64),
+        (-5.12f64, 5.12f64),
+        (-1f64, 5f64),
+        (-4f64, 2f64),
+    ];
+    let in_bounds = |x: &Vec<f64>| {
+        x.iter()
+            .zip(bounds.iter())
+            .all(|(t, (a, b))| a <= t && t <= b)
+    };
+
+    let de = differential_evolution(rastrigin, &bounds, 50, 0.2, 0.5, 1000, 42);
+    assert!(de.value < 1e-3);
+    assert!(de.x.iter().all(|t| t.abs() < 1e-3));
+    assert!(in_bounds(&de.x));
+    assert_eq!(de.n_eval, 50 * 1001);
+
+    let schedule = AnnealingSchedule {
+        t_init: 5f64,
+        cooling: Cooling::Exponential(0.99998),
+        n_iter: 500_000,
+        step: 0.1,
+    };
+    let sa = simulated_annealing(rastrigin, &bounds, schedule, 1);
+    assert!(sa.value < 1e-3);
+    assert!(sa.x.iter().all(|t| t.abs() < 1e-3));
+    assert!(in_bounds(&sa.x));
+}
+
+fn f_value(x: &Vec<f64>, p: Vec<AD>) -> Option<Vec<AD>> {
+    let p = p.to_f64_vec();
+    Some(x.iter().map(|t| AD0(p[0] * t * t + p[1] * t + p[2])).collect())
+}
+
+fn quad_jacobian(x: &Vec<f64>, _p: &Vec<f64>) -> Matrix {
+    let mut j = zeros(x.len(), 3);
+    for (i, t) in x.iter().enumerate() {
+        j[(i, 0)] = t * t;
+        j[(i, 1)] = *t;
+        j[(i, 2)] = 1f64;
+    }
+    j
+}
+
+fn exp_model(x: &Vec<f64>, p: Vec<AD>) -> Option<Vec<AD>> {
+    Some(x.iter().map(|t| p[0] * (p[1] * *t).exp()).collect())
+}
+
+fn exp_jacobian(x: &Vec<f64>, p: &Vec<f64>) -> Matrix {
+    let mut j = zeros(x.len(), 2);
+    for (i, t) in x.iter().enumerate() {
+        j[(i, 0)] = (p[1] * t).exp();
+        j[(i, 1)] = p[0] * t * (p[1] * t).exp();
+    }
+    j
+}
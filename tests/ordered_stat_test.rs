@@ -77,3 +77,114 @@ fn quantile_length_test() {
     
     assert!(q1.iter().zip(q2.iter()).all(|(x, y)| *x == *y));
 }
+
+#[test]
+fn histogram_test() {
+    let x = rand_with(1, 1000, &Normal(0f64, 1f64)).data;
+    let (edges, counts) = x.histogram(20);
+    assert_eq!(edges.len(), 21);
+    assert_eq!(counts.len(), 20);
+    assert_eq!(counts.iter().sum::<usize>(), x.len());
+    assert_eq!(edges[0], x.iter().cloned().fold(f64::INFINITY, f64::min));
+    assert_eq!(edges[20], x.iter().cloned().fold(f64::NEG_INFINITY, f64::max));
+
+    let a = c!(0, 0.1, 0.99, 1, 1.5, 2, 2.2, 3, 4);
+    let (edges, counts) = a.histogram(4);
+    assert_eq!(edges, c!(0, 1, 2, 3, 4));
+    assert_eq!(counts, vec![3, 2, 2, 2]);
+    assert_eq!(a.histogram_with_edges(&c!(1, 2, 3)), vec![2, 3]);
+    assert_eq!(a.histogram_with_edges(&c!(-1, 0.5, 10)), vec![2, 7]);
+
+    let (edges, counts) = c!(2, 2, 2).histogram(2);
+    assert_eq!(edges, c!(1.5, 2, 2.5));
+    assert_eq!(counts, vec![0, 3]);
+}
+
+#[test]
+fn ecdf_test() {
+    let x = rand_with_dist_rng(1, 500, &Normal(0f64, 1f64), &mut stdrng_from_seed(7)).data;
+    let (v, p) = x.ecdf();
+    assert_eq!(v.len(), p.len());
+    assert!(v.windows(2).all(|w| w[0] < w[1]));
+    assert!(p.windows(2).all(|w| w[0] < w[1]));
+    assert!(p[0] > 0f64);
+    assert_eq!(p[p.len() - 1], 1f64);
+}
+
+#[test]
+fn bootstrap_ci_test() {
+    let mut rng = stdrng_from_seed(42);
+    let x = rand_with_dist_rng(1, 5000, &Normal(3f64, 2f64), &mut rng).data;
+    let (lo, hi) = x.bootstrap_ci_with_rng(|v| v.mean(), 500, 0.05, &mut rng);
+    assert!(lo < 3f64 && 3f64 < hi);
+    // Standard error of mean = 2 / sqrt(5000) ~ 0.028
+    assert!(hi - lo < 0.2);
+}
+
+#[test]
+fn bootstrap_ci_matches_bootstrap() {
+    let x = c!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    let ci = x.bootstrap_ci_with_rng(|v| v.mean(), 200, 0.1, &mut stdrng_from_seed(1));
+    let seed: u64 = stdrng_from_seed(1).gen();
+    let boot = bootstrap(&x, |v| v.mean(), 200, Some(seed));
+    assert_eq!(ci, boot.percentile_ci(0.1));
+}
+
+#[test]
+fn summary_test() {
+    let x = c!(4, 1, f64::NAN, 3, 2, 5);
+    let s = x.summary();
+    assert_eq!(s.count, 5);
+    assert_eq!(s.n_nan, 1);
+    assert_eq!(s.mean, 3f64);
+    assert!(approx_eq!(f64, s.sd, 2.5f64.sqrt(), ulps = 2));
+    assert_eq!(s.to_vec(), vec![5f64, 3f64, s.sd, 1f64, 2f64, 3f64, 4f64, 5f64, 1f64]);
+
+    let empty = Vec::<f64>::new().summary();
+    assert_eq!(empty.count, 0);
+    assert!(empty.mean.is_nan() && empty.median.is_nan());
+
+    let m = matrix(c!(1, 2, 3, 4, 5, 6), 3, 2, Col);
+    let ms = m.summary();
+    assert_eq!(ms.len(), 2);
+    assert_eq!(ms[1], m.col(1).summary());
+    assert_eq!(ms[1].median, 5f64);
+}
+
+#[test]
+fn outlier_test() {
+    let mut x = (0..50).map(|i| (i as f64 * 0.7).sin()).collect::<Vec<f64>>();
+    x[17] = 1e6;
+
+    for method in [
+        OutlierMethod::IQR(1.5),
+        OutlierMethod::ZScore(3f64),
+        OutlierMethod::MAD(3.5),
+    ] {
+        let mask = outlier_mask(&x, method);
+        let flagged = (0..x.len()).filter(|&i| mask[i]).collect::<Vec<_>>();
+        assert_eq!(flagged, vec![17], "{:?}", method);
+    }
+
+    let w = winsorize(&x, 0.05, 0.95);
+    let t = trim(&x, 0.05, 0.95);
+    let q = x.quantiles(vec![0.05, 0.95], QType::Type2);
+    assert_eq!(w.len(), x.len());
+    assert_eq!(w[17], q[1]);
+    assert!(w.iter().all(|&v| q[0] <= v && v <= q[1]));
+    assert!(t.len() < x.len() && !t.contains(&1e6));
+    assert!(t.iter().all(|&v| q[0] <= v && v <= q[1]));
+
+    let z = zscore(&x);
+    assert!(z.mean().abs() < 1e-12);
+    assert!((z.sd() - 1f64).abs() < 1e-12);
+    assert!(mad(&x) < 2f64);
+
+    let mut df = DataFrame::new(vec![]);
+    df.push("x", Series::new(x.clone()));
+    df.push("i", Series::new((0..x.len() as i32).collect::<Vec<i32>>()));
+    let dg = df.filter_outliers("x", OutlierMethod::MAD(3.5));
+    let i: Vec<i32> = dg["i"].to_vec();
+    assert_eq!(i.len(), x.len() - 1);
+    assert!(!i.contains(&17));
+}
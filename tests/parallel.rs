@@ -0,0 +1,102 @@
+#[macro_use]
+extern crate peroxide;
+#[allow(unused_imports)]
+use peroxide::fuga::*;
+
+#[test]
+#[cfg(feature = "parallel")]
+fn par_fmap_test() {
+    let v = seq(0, 1000, 0.5);
+    assert_eq!(v.par_fmap(|x| x.sin()), v.fmap(|x| x.sin()));
+
+    let m = rand(100, 50);
+    assert_eq!(m.par_fmap(|x| x.exp()), m.fmap(|x| x.exp()));
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn par_zip_with_test() {
+    let v = seq(0, 1000, 1);
+    let w = v.fmap(|x| x * 2f64);
+    assert_eq!(v.par_zip_with(|x, y| x * y, &w), v.zip_with(|x, y| x * y, &w));
+
+    let a = rand(30, 40);
+    let b = rand(30, 40).change_shape();
+    assert_eq!(a.par_zip_with(|x, y| x - y, &b), a.zip_with(|x, y| x - y, &b));
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn par_reduce_test() {
+    let v = seq(1, 10000, 1);
+    assert_eq!(v.par_reduce(0, |x, y| x + y), 50005000f64);
+
+    let m = matrix(seq(1, 100, 1), 10, 10, Col);
+    assert_eq!(m.par_reduce(0, |x, y| x + y), 5050f64);
+    assert_eq!(m.par_reduce(f64::MIN, |x, y| x.max(y)), 100f64);
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn par_product_test() {
+    let a = rand(37, 23);
+    let b = rand(23, 41);
+    let v = rand(23, 1).data;
+
+    let c1 = par_matmul(&a, &b);
+    let c2 = &a * &b;
+    assert!(c1.data.iter().zip(c2.data.iter()).all(|(x, y)| nearly_eq(*x, *y)));
+
+    let d = par_matmul(&a.change_shape(), &b.change_shape());
+    assert!(d.data.iter().zip(c1.data.iter()).all(|(x, y)| nearly_eq(*x, *y)));
+
+    // Large enough to split rows into blocks (row count not divisible by block)
+    for (shape_a, shape_b) in [(Row, Row), (Col, Row), (Row, Col), (Col, Col)] {
+        let mut a = rand(257, 130);
+        let mut b = rand(130, 99);
+        if shape_a == Col {
+            a = a.change_shape();
+        }
+        if shape_b == Col {
+            b = b.change_shape();
+        }
+        let c1 = par_matmul(&a, &b);
+        let c2 = &a * &b;
+        assert_eq!(c1.shape, Row);
+        assert_eq!((c1.row, c1.col), (257, 99));
+        for i in 0..c1.row {
+            for j in 0..c1.col {
+                assert!(nearly_eq(c1[(i, j)], c2[(i, j)]));
+            }
+        }
+    }
+    let e = par_matmul(&zeros(0, 3), &rand(3, 4));
+    assert_eq!((e.row, e.col), (0, 4));
+
+    let w1 = a.par_apply(&v);
+    let w2 = a.apply(&v);
+    let w3 = a.change_shape().par_apply(&v);
+    assert!(w1.iter().zip(w2.iter()).all(|(x, y)| nearly_eq(*x, *y)));
+    assert!(w3.iter().zip(w2.iter()).all(|(x, y)| nearly_eq(*x, *y)));
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn par_statistics_test() {
+    let m = rand(100, 5);
+    assert_eq!(m.par_mean(), m.mean());
+    assert_eq!(m.par_var(), m.var());
+    assert_eq!(m.par_sd(), m.sd());
+    assert_eq!(m.par_cov(), m.cov());
+    assert_eq!(m.par_cor(), m.cor());
+    assert_eq!(m.par_col_reduce(|c| c.max()), m.col_reduce(|c| c.max()));
+    assert_eq!(m.par_row_reduce(|r| r.sum()), m.row_reduce(|r| r.sum()));
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+#[should_panic(expected = "dimension mismatch")]
+fn par_zip_with_dimension_mismatch_test() {
+    let a = ml_matrix("1 2 3;4 5 6");
+    a.par_zip_with(|x, y| x + y, &a.t());
+}
@@ -20,4 +20,124 @@ fn test_translate_x() {
     for i in -10..10 {
         assert_eq!(a.eval(i), b.eval(i - 6));
     }
-}
\ No newline at end of file
+}
+#[test]
+fn test_div_rem() {
+    // (x^3 - 1) / (x - 1) = x^2 + x + 1
+    let a = Polynomial::new(vec![1f64, 0f64, 0f64, -1f64]);
+    let b = Polynomial::new(vec![1f64, -1f64]);
+    let (q, r) = a.div_rem(&b);
+    assert_eq!(q.coef, vec![1f64, 1f64, 1f64]);
+    assert_eq!(r.coef, vec![0f64]);
+
+    // Leading zeros are normalized
+    let c = Polynomial::new(vec![0f64, 0f64, 1f64, -1f64]);
+    let (q, r) = a.div_rem(&c);
+    assert_eq!(q.coef, vec![1f64, 1f64, 1f64]);
+    assert_eq!(r.coef, vec![0f64]);
+
+    // deg(self) < deg(other)
+    let (q, r) = b.div_rem(&a);
+    assert_eq!(q.coef, vec![0f64]);
+    assert_eq!(r.coef, b.coef);
+}
+
+#[test]
+fn test_gcd() {
+    // (x - 1)(x - 2)(x + 1) & (x - 1)(x + 1)(x + 5)
+    let a = Polynomial::new(vec![1f64, -2f64, -1f64, 2f64]);
+    let b = Polynomial::new(vec![1f64, 5f64, -1f64, -5f64]);
+    let g = a.gcd(&b);
+    assert_eq!(g.degree(), 2);
+    for (x, y) in g.coef.iter().zip(vec![1f64, 0f64, -1f64]) {
+        assert!((x - y).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_roots() {
+    // Wilkinson-lite : (x - 1)(x - 2)...(x - 8)
+    let mut w = Polynomial::new(vec![1f64]);
+    for i in 1..=8 {
+        w = w * Polynomial::new(vec![1f64, -(i as f64)]);
+    }
+    let r = w.roots();
+    assert_eq!(r.len(), 8);
+    for (i, (re, im)) in r.iter().enumerate() {
+        assert!((re - (i + 1) as f64).abs() < 1e-6);
+        assert!(im.abs() < 1e-6);
+    }
+
+    // x^3 (x^2 + 2x + 5) with leading zero
+    let a = Polynomial::new(vec![0f64, 1f64, 2f64, 5f64, 0f64, 0f64, 0f64]);
+    let r = a.roots();
+    assert_eq!(r.len(), 5);
+    let complex = r.iter().filter(|t| t.1.abs() > 1e-6).collect::<Vec<_>>();
+    assert_eq!(complex.len(), 2);
+    for t in complex {
+        assert!((t.0 + 1f64).abs() < 1e-10 && (t.1.abs() - 2f64).abs() < 1e-10);
+    }
+    assert_eq!(r.iter().filter(|t| **t == (0f64, 0f64)).count(), 3);
+}
+
+#[test]
+fn test_derivative_of_integral() {
+    let a = Polynomial::new(vec![3f64, -2f64, 5f64, 1f64]);
+    let b = a.integral().derivative();
+    assert_eq!(a.coef, b.coef);
+    assert_eq!(Polynomial::new(vec![4f64]).derivative().coef, vec![0f64]);
+}
+
+#[test]
+fn test_chebyshev_series_exp() {
+    let cs = ChebyshevSeries::fit(|x: f64| x.exp(), (-1f64, 1f64), 15);
+    for i in 0..=100 {
+        let x = -1f64 + i as f64 / 50f64;
+        assert!((cs.eval(x) - x.exp()).abs() < 1e-14);
+    }
+    // Coefficients decay fast enough to be trimmed
+    assert!(cs.trim(1e-14).coef.len() < 15);
+    // Derivative & integral of exp
+    let d = cs.derivative();
+    let s = cs.integral();
+    for x in [-0.9, -0.3, 0.2, 0.7] {
+        assert!((d.eval(x) - x.exp()).abs() < 1e-12);
+        assert!((s.eval(x) - (x.exp() - (-1f64).exp())).abs() < 1e-14);
+    }
+    // Monomial form at low degree agrees
+    let p = cs.to_polynomial();
+    assert!((p.eval(0.3) - cs.eval(0.3)).abs() < 1e-13);
+}
+
+#[test]
+fn test_chebyshev_series_high_degree() {
+    let f = |x: f64| (20f64 * x).sin();
+    let cs = ChebyshevSeries::fit(f, (-1f64, 1f64), 51);
+    let p = cs.to_polynomial();
+    assert_eq!(p.coef.len(), 51);
+    let xs = (0..=40).map(|i| -1f64 + i as f64 / 20f64).collect::<Vec<f64>>();
+    let err_clenshaw = xs.iter().fold(0f64, |m, &x| m.max((cs.eval(x) - f(x)).abs()));
+    let err_monomial = xs.iter().fold(0f64, |m, &x| m.max((p.eval(x) - f(x)).abs()));
+    assert!(err_clenshaw < 1e-10);
+    assert!(err_monomial > 1e3 * err_clenshaw);
+}
+
+#[test]
+fn test_legendre_series() {
+    let f = |x: f64| 1f64 / (2f64 + x);
+    let ls = LegendreSeries::fit(f, (-1f64, 3f64), 40);
+    for i in 0..=20 {
+        let x = -1f64 + i as f64 / 5f64;
+        assert!((ls.eval(x) - f(x)).abs() < 1e-13);
+    }
+    assert!((ls.integrate((-1, 3)) - 5f64.ln()).abs() < 1e-13);
+    assert!((ls.derivative().eval(1f64) + 1f64 / 9f64).abs() < 1e-10);
+
+    // Legendre expansion of x^2 : (P_0 + 2 P_2) / 3
+    let q = LegendreSeries::fit(|x| x * x, (-1f64, 1f64), 5).trim(1e-14);
+    assert_eq!(q.coef.len(), 3);
+    assert!((q.coef[0] - 1f64 / 3f64).abs() < 1e-14);
+    assert!((q.coef[2] - 2f64 / 3f64).abs() < 1e-14);
+    let p = q.to_polynomial();
+    assert!((p.coef[0] - 1f64).abs() < 1e-14 && p.coef[2].abs() < 1e-14);
+}
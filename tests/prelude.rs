@@ -0,0 +1,114 @@
+//! Same computations through each import style (`prelude`, `fuga` & module paths)
+#[macro_use]
+extern crate peroxide;
+
+mod prelude_style {
+    use peroxide::prelude::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_prelude_linear_algebra() {
+        let a = ml_matrix("4 1;1 3");
+        let b = c!(1, 2);
+        let x = a.solve(&b);
+        assert!(eq_vec(&(&a * &x), &b, 1e-12));
+        assert!((a.det() - 11f64).abs() < 1e-12);
+        assert!((c!(3, 4).norm() - 5f64).abs() < 1e-15);
+        assert!((a.norm() - 27f64.sqrt()).abs() < 1e-12);
+
+        let e = eigen(&a);
+        assert!((e.eigenvalue.sum() - 7f64).abs() < 1e-10);
+
+        let f = Factorized::new(&a);
+        assert_eq!(f.solve(&b), x);
+        assert_eq!(solve(&a, &eye(2)), a.inv());
+    }
+
+    #[test]
+    fn test_prelude_calculus() {
+        assert!((integrate(|x| x.sin(), (0f64, PI)) - 2f64).abs() < 1e-8);
+        assert!((integrate2d(|x, y| x * y, (0f64, 1f64), (0f64, 1f64)) - 0.25).abs() < 1e-8);
+        assert!((integrate3d(|x, y, z| x + y + z, (0f64, 1f64), (0f64, 1f64), (0f64, 1f64)) - 1.5).abs() < 1e-8);
+
+        let x = linspace(0, 1, 11);
+        let y = x.fmap(|t| t * t);
+        assert!((trapz(&x, &y).unwrap() - 0.335).abs() < 1e-12);
+        let (nodes, weights) = gauss_legendre_nodes(3);
+        assert_eq!(nodes.len(), weights.len());
+
+        let cs = cubic_spline(&x, &y).unwrap();
+        assert!((cs.eval(0.55) - 0.3025).abs() < 1e-3);
+        let ch = cubic_hermite_spline(&x, &y).unwrap();
+        assert!((ch.eval(0.55) - 0.3025).abs() < 1e-3);
+
+        let p = least_square(x.clone(), x.fmap(|t| 2f64 * t + 1f64));
+        assert!((p.eval(3f64) - 7f64).abs() < 1e-10);
+        assert!((chebyshev_polynomial(2).eval(0.5) + 0.5).abs() < 1e-15);
+        assert!((derivative(|t| t.powi(3), 2f64, 1e-3) - 12f64).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_prelude_statistics() {
+        let x = Normal(1f64, 2f64).sample_with_rng(&mut stdrng_from_seed(42), 1000);
+        assert!((x.mean() - 1f64).abs() < 0.2);
+        assert!((ksum(&x) / 1000f64 - x.mean()).abs() < 1e-14);
+
+        let mut df = DataFrame::new(vec![]);
+        df.push("x", Series::new(x));
+        assert_eq!(df.to_matrix().col, 1);
+
+        let y = AD1(2f64, 1f64).powi(2);
+        assert_eq!(y.dx(), 4f64);
+    }
+}
+
+mod fuga_style {
+    use peroxide::fuga::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_fuga_linear_algebra() {
+        let a = ml_matrix("4 1;1 3");
+        let b = c!(1, 2);
+        let x = a.solve(&b, LU);
+        assert!(eq_vec(&(&a * &x), &b, 1e-12));
+        assert!((a.det() - 11f64).abs() < 1e-12);
+        assert!((c!(3, 4).norm(Norm::L2) - 5f64).abs() < 1e-15);
+        assert!((a.norm(Norm::F) - 27f64.sqrt()).abs() < 1e-12);
+
+        let e = eigen(&a, Jacobi);
+        assert!((e.eigenvalue.sum() - 7f64).abs() < 1e-10);
+        assert_eq!(Factorized::new(&a).solve(&b), x);
+    }
+
+    #[test]
+    fn test_fuga_calculus() {
+        assert!((integrate(|x| x.sin(), (0f64, PI), G7K15R(1e-4, 20)) - 2f64).abs() < 1e-8);
+        assert!((integrate2d(|x, y| x * y, (0f64, 1f64), (0f64, 1f64), GaussLegendre(2)) - 0.25).abs() < 1e-15);
+
+        let x = linspace(0, 1, 11);
+        let y = x.fmap(|t| t * t);
+        let ch = cubic_hermite_spline(&x, &y, Quadratic).unwrap();
+        assert!((ch.eval(0.55) - 0.3025).abs() < 1e-3);
+        assert!((chebyshev_polynomial(2, SpecialKind::First).eval(0.5) + 0.5).abs() < 1e-15);
+    }
+}
+
+mod path_style {
+    // No glob import : user types with the same names do not collide
+    use peroxide::numerical::integral::{integrate, Integral};
+    use peroxide::structure::matrix::{ml_matrix, Matrix as PMatrix};
+    use peroxide::traits::math::LinearOp;
+
+    #[allow(dead_code)]
+    struct Matrix(Vec<f64>);
+
+    #[test]
+    fn test_path_imports() {
+        let a: PMatrix = ml_matrix("1 2;3 4");
+        assert_eq!(a.apply(&vec![1f64, 1f64]), vec![3f64, 7f64]);
+        let s = integrate(|x| x * x, (0f64, 1f64), Integral::GaussLegendre(2));
+        assert!((s - 1f64 / 3f64).abs() < 1e-15);
+        assert_eq!(c!(1, 2), vec![1f64, 2f64]);
+    }
+}
@@ -0,0 +1,23 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_ridge_reg() {
+    let x = ml_matrix("1 0.5 1.2;1 1.5 0.3;1 2.1 2.2;1 3.3 1.1;1 4.2 0.7;1 5.0 2.5");
+    let y = vec![1.1, 2.9, 4.2, 5.8, 7.6, 9.4];
+
+    // lambda = 0 : Ordinary least square
+    let ols = &x.pseudo_inv() * &y;
+    let beta = ridge_reg(&x, &y, 0f64);
+    assert!(eq_vec(&beta, &ols, 1e-10));
+
+    // Large lambda shrinks coefficients toward zero
+    let mut prev = beta.norm(Norm::L2);
+    for &lambda in [1f64, 1e2, 1e4, 1e8].iter() {
+        let b = ridge_reg(&x, &y, lambda);
+        let norm = b.norm(Norm::L2);
+        assert!(norm < prev);
+        prev = norm;
+    }
+    assert!(prev < 1e-5);
+}
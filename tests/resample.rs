@@ -0,0 +1,52 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_bootstrap_se_of_mean() {
+    let sigma = 2f64;
+    let n = 400;
+    let x = Normal(1f64, sigma).sample_with_rng(&mut stdrng_from_seed(42), n);
+
+    let boot = bootstrap(&x, |v| v.mean(), 2000, Some(42));
+    let expected = sigma / (n as f64).sqrt();
+    assert!((boot.se - expected).abs() < 0.1 * expected);
+
+    let (lo, hi) = boot.percentile_ci(0.05);
+    assert!(lo < boot.estimate && boot.estimate < hi);
+    let (lo, hi) = boot.bca_ci(0.05);
+    assert!(lo < boot.estimate && boot.estimate < hi);
+
+    // Reproducible with seed
+    assert_eq!(boot, bootstrap(&x, |v| v.mean(), 2000, Some(42)));
+    assert_ne!(boot.replicates, bootstrap(&x, |v| v.mean(), 2000, Some(43)).replicates);
+}
+
+#[test]
+fn test_permutation_uniform_p() {
+    let mut rng = stdrng_from_seed(42);
+    let dist = Normal(0f64, 1f64);
+    let n_trials = 200;
+
+    let ps = (0..n_trials)
+        .map(|i| {
+            let x = dist.sample_with_rng(&mut rng, 15);
+            let y = dist.sample_with_rng(&mut rng, 15);
+            permutation_test(&x, &y, |a, b| a.mean() - b.mean(), 199, Some(i))
+        })
+        .collect::<Vec<f64>>();
+
+    // Under H0, p-values are approximately Uniform(0, 1)
+    assert!(ps.iter().all(|&p| p > 0f64 && p <= 1f64));
+    assert!((ps.mean() - 0.5).abs() < 0.05);
+    for q in [0.1, 0.25, 0.5, 0.75, 0.9] {
+        let frac = ps.iter().filter(|&&p| p <= q).count() as f64 / n_trials as f64;
+        assert!((frac - q).abs() < 0.1);
+    }
+
+    // Reproducible with seed
+    let x = dist.sample_with_rng(&mut rng, 15);
+    let y = dist.sample_with_rng(&mut rng, 15);
+    let p1 = permutation_test(&x, &y, |a, b| a.mean() - b.mean(), 199, Some(7));
+    let p2 = permutation_test(&x, &y, |a, b| a.mean() - b.mean(), 199, Some(7));
+    assert_eq!(p1, p2);
+}
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+fn assert_close(x: &[f64], y: &[f64], tol: f64) {
+    assert_eq!(x.len(), y.len());
+    for (a, b) in x.iter().zip(y.iter()) {
+        assert!((a - b).abs() < tol, "{:?} != {:?}", x, y);
+    }
+}
+
+#[test]
+fn test_convolve_numpy() {
+    // np.convolve([1, 2, 3, 4, 5], [1, -1, 2, 0.5], mode)
+    let x = c!(1, 2, 3, 4, 5);
+    let k = c!(1, -1, 2, 0.5);
+    assert_eq!(convolve(&x, &k, ConvMode::Full), c!(1, 1, 3, 5.5, 8, 4.5, 12, 2.5));
+    assert_eq!(convolve(&x, &k, ConvMode::Same), c!(1, 3, 5.5, 8, 4.5));
+    assert_eq!(convolve(&x, &k, ConvMode::Valid), c!(5.5, 8));
+
+    // Kernel longer than signal : np.convolve([1, 2], [1, 2, 3, 4, 5], 'same') = [1, 4, 7, 10, 13]
+    let y = c!(1, 2);
+    assert_eq!(convolve(&y, &x, ConvMode::Same), c!(1, 4, 7, 10, 13));
+    assert_eq!(convolve(&x, &y, ConvMode::Same), convolve(&y, &x, ConvMode::Same));
+    assert_eq!(convolve(&y, &x, ConvMode::Valid), c!(4, 7, 10, 13));
+}
+
+#[test]
+fn test_cross_correlate_numpy() {
+    // np.correlate([1, 2, 3, 4, 5], [1, 0, -1], mode)
+    let x = c!(1, 2, 3, 4, 5);
+    let y = c!(1, 0, -1);
+    assert_eq!(cross_correlate(&x, &y, ConvMode::Full), c!(-1, -2, -2, -2, -2, 4, 5));
+    assert_eq!(cross_correlate(&x, &y, ConvMode::Same), c!(-2, -2, -2, -2, 4));
+    assert_eq!(cross_correlate(&x, &y, ConvMode::Valid), c!(-2, -2, -2));
+}
+
+#[test]
+fn test_convolve_fft_path() {
+    let mut rng = stdrng_from_seed(42);
+    let x = Uniform(-1, 1).sample_with_rng(&mut rng, 500);
+    let k = Uniform(-1, 1).sample_with_rng(&mut rng, 100);
+    let mut direct = vec![0f64; 599];
+    for i in 0..500 {
+        for j in 0..100 {
+            direct[i + j] += x[i] * k[j];
+        }
+    }
+    assert_close(&convolve(&x, &k, ConvMode::Full), &direct, 1e-10);
+    assert_close(&convolve(&x, &k, ConvMode::Valid), &direct[99..500], 1e-10);
+}
+
+#[test]
+fn test_savgol() {
+    // scipy.signal.savgol_coeffs(5, 2) = [-3, 12, 17, 12, -3] / 35
+    let mut impulse = vec![0f64; 11];
+    impulse[5] = 1f64;
+    let y = savgol(&impulse, 5, 2);
+    assert_close(&y[3..8], &c!(-3, 12, 17, 12, -3).fmap(|t| t / 35f64), 1e-10);
+
+    // Cubic is preserved by polyorder 3 (including edges)
+    let x = seq(0, 20, 1).fmap(|t| 0.1 * t.powi(3) - t * t + 2f64);
+    assert_close(&savgol(&x, 7, 3), &x, 1e-8);
+
+    assert_eq!(moving_average(&c!(3, 6, 9, 12), 3), c!(3, 6, 9, 7));
+}
@@ -0,0 +1,106 @@
+extern crate peroxide;
+#[allow(unused_imports)]
+use peroxide::fuga::*;
+
+#[cfg(feature = "simd")]
+use peroxide::util::simd;
+
+#[test]
+#[cfg(feature = "simd")]
+fn simd_reduction_test() {
+    for n in [0usize, 1, 3, 4, 7, 13, 1001] {
+        let x = (0..n).map(|i| (i as f64).sin()).collect::<Vec<f64>>();
+        let y = (0..n).map(|i| (i as f64).cos()).collect::<Vec<f64>>();
+
+        let s: f64 = x.iter().sum();
+        let d: f64 = x.iter().zip(&y).map(|(a, b)| a * b).sum();
+        let nrm = x.iter().map(|a| a * a).sum::<f64>().sqrt();
+
+        assert!(nearly_eq(simd::sum(&x), s));
+        assert!(nearly_eq(simd::dot(&x, &y), d));
+        assert!(nearly_eq(simd::norm_l2(&x), nrm));
+        assert!(nearly_eq(x.sum(), s));
+        assert!(nearly_eq(x.dot(&y), d));
+        assert!(nearly_eq(x.norm(Norm::L2), nrm));
+    }
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn simd_compensated_sum_test() {
+    let mut x = vec![1e10];
+    x.extend(vec![1e-3; 100000]);
+    assert_eq!(simd::sum(&x), ksum(&x));
+    assert_eq!(x.sum(), 1e10 + 100f64);
+
+    let y = vec![1e16, 1f64, -1e16, 0f64, 1f64];
+    assert_eq!(simd::sum(&y), 2f64);
+    assert_eq!(simd::sum(&[1f64, f64::INFINITY, 2f64, 3f64, 4f64]), f64::INFINITY);
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn simd_elementwise_test() {
+    for n in [1usize, 5, 11, 1003] {
+        let x = (0..n).map(|i| (i as f64).sin()).collect::<Vec<f64>>();
+        let y = (0..n).map(|i| (i as f64).cos()).collect::<Vec<f64>>();
+
+        let add = x.iter().zip(&y).map(|(a, b)| a + b).collect::<Vec<f64>>();
+        let sub = x.iter().zip(&y).map(|(a, b)| a - b).collect::<Vec<f64>>();
+        let mul = x.iter().zip(&y).map(|(a, b)| a * b).collect::<Vec<f64>>();
+        let axpy = x.iter().zip(&y).map(|(a, b)| 2f64 * a + b).collect::<Vec<f64>>();
+
+        assert_eq!(simd::add(&x, &y), add);
+        assert_eq!(simd::sub(&x, &y), sub);
+        assert_eq!(simd::mul(&x, &y), mul);
+        assert_eq!(x.add_v(&y), add);
+        assert_eq!((x.clone().ox() * y.clone().ox()).red(), mul);
+
+        let mut z = y.clone();
+        simd::axpy(2f64, &x, &mut z);
+        assert_eq!(z, axpy);
+        let mut z = y.clone();
+        peroxide::structure::vector::axpy(2f64, &x, &mut z);
+        assert_eq!(z, axpy);
+    }
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn simd_length_mismatch_test() {
+    // Same as the scalar path : only the common prefix is used
+    let x = (0..11).map(|i| (i as f64).sin()).collect::<Vec<f64>>();
+    let y = (0..6).map(|i| (i as f64).cos()).collect::<Vec<f64>>();
+    let add = x.iter().zip(&y).map(|(a, b)| a + b).collect::<Vec<f64>>();
+    let d: f64 = x.iter().zip(&y).map(|(a, b)| a * b).sum();
+
+    assert_eq!(simd::add(&x, &y), add);
+    assert_eq!(simd::add(&y, &x), add);
+    assert_eq!(x.add_vec(&y), add);
+    assert!(nearly_eq(simd::dot(&x, &y), d));
+
+    let mut z = y.clone();
+    simd::axpy(1f64, &x, &mut z);
+    assert_eq!(z, add);
+    let mut z = x.clone();
+    simd::axpy(1f64, &y, &mut z);
+    assert_eq!(&z[..6], &add[..]);
+    assert_eq!(&z[6..], &x[6..]);
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn simd_matvec_test() {
+    let a = rand(7, 13);
+    let v = rand(13, 1).data;
+    let mut expected = vec![0f64; 7];
+    for i in 0..7 {
+        for j in 0..13 {
+            expected[i] += a[(i, j)] * v[j];
+        }
+    }
+    let w1 = &a * &v;
+    let w2 = &a.change_shape() * &v;
+    assert!(w1.iter().zip(&expected).all(|(x, y)| nearly_eq(*x, *y)));
+    assert!(w2.iter().zip(&expected).all(|(x, y)| nearly_eq(*x, *y)));
+}
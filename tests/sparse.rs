@@ -0,0 +1,209 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+fn laplacian(n: usize, diag: f64) -> SPMatrix {
+    let mut rows = vec![];
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for i in 0..n {
+        rows.push(i);
+        cols.push(i);
+        vals.push(diag);
+        if i > 0 {
+            rows.push(i);
+            cols.push(i - 1);
+            vals.push(-1f64);
+        }
+        if i < n - 1 {
+            rows.push(i);
+            cols.push(i + 1);
+            vals.push(-1f64);
+        }
+    }
+    SPMatrix::from_triplets(&rows, &cols, &vals, (n, n))
+}
+
+#[test]
+fn test_from_triplets() {
+    let rows = [2, 0, 1, 0, 2, 2];
+    let cols = [0, 0, 2, 0, 0, 1];
+    let vals = [1f64, 2f64, 3f64, 4f64, 5f64, 6f64];
+    let a = SPMatrix::from_triplets(&rows, &cols, &vals, (3, 3));
+    assert_eq!(a.nnz, 4);
+    assert_eq!(a.to_dense(), ml_matrix("6 0 0;0 0 3;6 6 0"));
+    assert_eq!(a.diag(), vec![6f64, 0f64, 0f64]);
+
+    // Default slogdet of LinearAlgebra (from det)
+    let (sgn, logdet) = a.slogdet();
+    assert_eq!(sgn, -1f64);
+    assert!((logdet - 108f64.ln()).abs() < 1e-12);
+
+    let b = ml_matrix("1 0 2;0 3 0;4 0 5;0 0 6");
+    let s = SPMatrix::from_dense(&b);
+    let x = vec![1f64, 2f64, 3f64];
+    assert_eq!(s.matvec(&x), &b * &x);
+    assert_eq!(s.transpose().to_dense(), b.transpose());
+}
+
+#[test]
+fn test_cg_laplacian() {
+    let n = 1000;
+    let a = laplacian(n, 2f64);
+    let b = vec![1f64; n];
+    let sol = cg(&a, &b, 1e-8, n, None);
+    assert!(sol.residual < 1e-8);
+    assert!(sol.iter <= n);
+
+    // Exact solution of -u'' = 1 on grid : u_i = i (n + 1 - i) / 2 (i = 1..n)
+    for (i, x) in sol.x.iter().enumerate() {
+        let k = (i + 1) as f64;
+        let u = k * (n as f64 + 1f64 - k) / 2f64;
+        assert!((x - u).abs() < 1e-6 * u.max(1f64) * n as f64);
+    }
+}
+
+#[test]
+fn test_cg_large() {
+    let n = 100_000;
+    let a = laplacian(n, 3f64);
+    let x_true = (0..n).map(|i| (i as f64 * 0.01).sin()).collect::<Vec<f64>>();
+    let b = a.matvec(&x_true);
+    let d = a.diag();
+    let sol = cg(&a, &b, 1e-10, 1000, Some(&d));
+    assert!(sol.residual < 1e-10);
+    assert!(sol.iter < 100);
+    assert!(eq_vec(&sol.x, &x_true, 1e-7));
+}
+
+#[test]
+fn test_bicgstab() {
+    // Nonsymmetric convection-diffusion matrix
+    let n = 50;
+    let mut rows = vec![];
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for i in 0..n {
+        rows.push(i);
+        cols.push(i);
+        vals.push(2.5f64 + i as f64 * 0.01);
+        if i > 0 {
+            rows.push(i);
+            cols.push(i - 1);
+            vals.push(-1.5f64);
+        }
+        if i < n - 1 {
+            rows.push(i);
+            cols.push(i + 1);
+            vals.push(-0.5f64);
+        }
+    }
+    let a = SPMatrix::from_triplets(&rows, &cols, &vals, (n, n));
+    let b = (0..n).map(|i| (i as f64).cos()).collect::<Vec<f64>>();
+    let x = a.to_dense().solve(&b, SolveKind::LU);
+
+    let sol = bicgstab(&a, &b, 1e-12, 500, None);
+    assert!(sol.residual < 1e-12);
+    assert!(eq_vec(&sol.x, &x, 1e-9));
+
+    let d = a.diag();
+    let sol = bicgstab(&a, &b, 1e-12, 500, Some(&d));
+    assert!(sol.residual < 1e-12);
+    assert!(eq_vec(&sol.x, &x, 1e-9));
+}
+
+/// 2D convection-diffusion `-Δu + c·∇u` on `k x k` interior grid (upwind convection)
+fn convection_diffusion(k: usize, c: (f64, f64)) -> SPMatrix {
+    let h = 1f64 / (k + 1) as f64;
+    let idx = |i: usize, j: usize| i * k + j;
+    let mut rows = vec![];
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for i in 0..k {
+        for j in 0..k {
+            let p = idx(i, j);
+            rows.push(p);
+            cols.push(p);
+            vals.push(4f64 + h * (c.0 + c.1));
+            let mut push = |q: usize, v: f64| {
+                rows.push(p);
+                cols.push(q);
+                vals.push(v);
+            };
+            if i > 0 {
+                push(idx(i - 1, j), -1f64 - h * c.0);
+            }
+            if i < k - 1 {
+                push(idx(i + 1, j), -1f64);
+            }
+            if j > 0 {
+                push(idx(i, j - 1), -1f64 - h * c.1);
+            }
+            if j < k - 1 {
+                push(idx(i, j + 1), -1f64);
+            }
+        }
+    }
+    SPMatrix::from_triplets(&rows, &cols, &vals, (k * k, k * k))
+}
+
+#[test]
+fn test_gmres() {
+    let a = convection_diffusion(15, (40f64, 10f64));
+    let n = a.size();
+    let b = (0..n).map(|i| 1f64 + (i as f64 * 0.1).sin()).collect::<Vec<f64>>();
+    let x = a.to_dense().solve(&b, SolveKind::LU);
+
+    let sol = gmres(&a, &b, 30, 1e-10, 1000, None);
+    assert!(sol.residual < 1e-10);
+    assert!(eq_vec(&sol.x, &x, 1e-8));
+    assert_eq!(sol.history.len(), sol.iter + 1);
+    assert!(sol.history.windows(2).all(|w| w[1] <= w[0] * (1f64 + 1e-12)));
+
+    // Restart length does not change the solution
+    let sol = gmres(&a, &b, 5, 1e-10, 5000, None);
+    assert!(eq_vec(&sol.x, &x, 1e-8));
+
+    // Matrix-free operator with right Jacobi preconditioner
+    let d = a.diag();
+    let op = FnOp::new(n, |v: &Vec<f64>| a.matvec(v));
+    let m = FnOp::new(n, |v: &Vec<f64>| v.iter().zip(d.iter()).map(|(s, t)| s / t).collect());
+    let sol = gmres(&op, &b, 30, 1e-10, 1000, Some(&m));
+    assert!(sol.residual < 1e-10);
+    assert!(eq_vec(&sol.x, &x, 1e-8));
+}
+
+#[test]
+fn test_stationary() {
+    // Strictly diagonally dominant tridiagonal : 4 on diagonal, -1 off diagonal
+    let n = 30;
+    let a = laplacian(n, 4f64).to_dense();
+    let b = (0..n).map(|i| 1f64 + (i as f64).sin()).collect::<Vec<f64>>();
+    let x = a.solve(&b, SolveKind::LU);
+    let x0 = vec![0f64; n];
+
+    let jac = jacobi_solve(&a, &b, &x0, 1e-10, 1000).unwrap();
+    let gs = gauss_seidel(&a, &b, &x0, 1e-10, 1000).unwrap();
+    // Spectral radius of Jacobi iteration matrix
+    let rho = 0.5 * (std::f64::consts::PI / (n + 1) as f64).cos();
+    let omega = 2f64 / (1f64 + (1f64 - rho * rho).sqrt());
+    let sr = sor(&a, &b, omega, &x0, 1e-10, 1000).unwrap();
+
+    for sol in [&jac, &gs, &sr] {
+        assert!(sol.residual < 1e-10);
+        assert!(eq_vec(&sol.x, &x, 1e-9));
+        assert_eq!(sol.history.len(), sol.iter + 1);
+        assert_eq!(sol.history[0], 1f64);
+    }
+    assert!(sr.iter < gs.iter);
+    assert!(gs.iter < jac.iter);
+
+    // Not converged : partial solution is returned
+    match jacobi_solve(&a, &b, &x0, 1e-10, 3) {
+        Err(IterativeError::NotConverged(partial)) => {
+            assert_eq!(partial.iter, 3);
+            assert_eq!(partial.history.len(), 4);
+            assert!(partial.residual < 1f64);
+        }
+        Ok(_) => panic!("Should not converge in 3 iterations"),
+    }
+}
@@ -0,0 +1,200 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+use std::f64::consts::PI;
+
+fn assert_rel(x: f64, y: f64, tol: f64) {
+    assert!(
+        (x - y).abs() <= tol * y.abs().max(1f64),
+        "{} != {} (tol = {})",
+        x,
+        y,
+        tol
+    );
+}
+
+#[test]
+fn test_bessel_table() {
+    // (n, x, J_n, Y_n, I_n, K_n)
+    let table = [
+        (
+            0,
+            1f64,
+            0.76519768655796655,
+            0.088256964215676958,
+            1.2660658777520083,
+            0.42102443824070833,
+        ),
+        (
+            1,
+            1f64,
+            0.44005058574493352,
+            -0.78121282130028872,
+            0.56515910399248503,
+            0.60190723019723457,
+        ),
+        (
+            0,
+            10f64,
+            -0.24593576445134834,
+            0.055671167283599391,
+            2815.7166284662545,
+            1.7780062316167652e-5,
+        ),
+        (
+            1,
+            10f64,
+            0.043472746168861437,
+            0.24901542420695388,
+            2670.9883037012547,
+            1.8648773453825585e-5,
+        ),
+        (
+            3,
+            30f64,
+            0.12921122875972498,
+            -0.068035690253198723,
+            671140461797.43962,
+            2.4713310636589929e-14,
+        ),
+        (
+            0,
+            40f64,
+            0.0073668905842372896,
+            0.12593641705826093,
+            14894774793419900.0,
+            8.392861100099567e-19,
+        ),
+    ];
+    for &(n, x, j, y, i, k) in table.iter() {
+        assert_rel(besselj(n, x), j, 1e-12);
+        assert_rel(bessely(n, x), y, 1e-12);
+        assert_rel(besseli(n, x) / i, 1f64, 1e-12);
+        assert_rel(besselk(n, x) / k, 1f64, 1e-12);
+    }
+}
+
+#[test]
+fn test_bessel_transition() {
+    // x ≈ n
+    let table = [
+        (
+            5,
+            5f64,
+            0.26114054612017009,
+            -0.45369482249110188,
+            2.1579745473225465,
+            0.032706273712031858,
+        ),
+        (
+            10,
+            10f64,
+            0.20748610663335886,
+            -0.35981415218340272,
+            21.891706163723371,
+            0.00161425530039067,
+        ),
+        (
+            20,
+            19.5,
+            0.13766970611956109,
+            -0.33650377729335513,
+            1574.8668163524563,
+            1.1364656361553308e-5,
+        ),
+        (
+            20,
+            20f64,
+            0.16474777377532653,
+            -0.28548945860020349,
+            3188.7503288536148,
+            5.5431116361258163e-6,
+        ),
+        (
+            50,
+            50f64,
+            0.12140902189761506,
+            -0.21031655464397741,
+            17650802430.016712,
+            4.0060134766400895e-13,
+        ),
+    ];
+    for &(n, x, j, y, i, k) in table.iter() {
+        assert_rel(besselj(n, x), j, 1e-12);
+        assert_rel(bessely(n, x), y, 1e-12);
+        assert_rel(besseli(n, x) / i, 1f64, 1e-12);
+        assert_rel(besselk(n, x) / k, 1f64, 1e-12);
+    }
+}
+
+#[test]
+fn test_bessel_wronskian() {
+    // J_{n+1} Y_n - J_n Y_{n+1} = 2 / (πx)
+    for &x in [0.3, 2f64, 7.5, 24.9, 25.1, 60f64].iter() {
+        for n in 0..5 {
+            let w = besselj(n + 1, x) * bessely(n, x) - besselj(n, x) * bessely(n + 1, x);
+            assert_rel(w * PI * x / 2f64, 1f64, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_bessel_ad() {
+    let x = AD2(2f64, 1f64, 0f64);
+    let j0 = besselj_ad(0, x);
+    assert_rel(j0.dx(), -besselj(1, 2f64), 1e-14);
+    assert_rel(j0.ddx(), -besselj(0, 2f64) + besselj(1, 2f64) / 2f64, 1e-14);
+
+    let k0 = besselk_ad(0, x);
+    assert_rel(k0.dx(), -besselk(1, 2f64), 1e-14);
+
+    // Bessel equation : x^2 y'' + x y' + (x^2 - n^2) y = 0
+    for n in 0..4 {
+        let y = bessely_ad(n, x);
+        let r = 4f64 * y.ddx() + 2f64 * y.dx() + (4f64 - (n * n) as f64) * y.x();
+        assert!(r.abs() < 1e-13);
+        let i = besseli_ad(n, x);
+        let r = 4f64 * i.ddx() + 2f64 * i.dx() - (4f64 + (n * n) as f64) * i.x();
+        assert!(r.abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_lambert_w() {
+    assert_rel(lambert_w0(1f64), 0.56714329040978387, 1e-15);
+    assert_rel(lambert_w0(10f64), 1.7455280027406994, 1e-15);
+    assert_rel(lambert_w0(-2f64.ln() / 2f64), -2f64.ln(), 1e-15);
+    assert_rel(lambert_wm1(-2f64.ln() / 2f64), -4f64.ln(), 1e-15);
+    assert_rel(lambert_wm1(-0.1), -3.5771520639572972, 1e-15);
+    assert_eq!(lambert_w0(0f64), 0f64);
+    assert_eq!(lambert_w0(-(-1f64).exp()), -1f64);
+    assert!(lambert_w0(-0.5).is_nan());
+
+    for &x in [-0.36, -0.2, 0.5, 3f64, 1e3, 1e100].iter() {
+        let w = lambert_w0(x);
+        assert_rel(w * w.exp() / x, 1f64, 1e-14);
+    }
+    for &x in [-0.36, -0.2, -1e-3, -1e-10].iter() {
+        let w = lambert_wm1(x);
+        assert!(w <= -1f64);
+        assert_rel(w * w.exp() / x, 1f64, 1e-13);
+    }
+
+    let x = AD1(1f64, 1f64);
+    let w = lambert_w0(1f64);
+    assert_rel(lambert_w0_ad(x).dx(), w / (1f64 + w), 1e-15);
+}
+
+#[test]
+fn test_zeta() {
+    assert_rel(zeta(2f64), PI.powi(2) / 6f64, 1e-15);
+    assert_rel(zeta(4f64), PI.powi(4) / 90f64, 1e-15);
+    assert_rel(zeta(3f64), 1.2020569031595942, 1e-15);
+    assert_rel(zeta(0.5), -1.4603545088095868, 1e-14);
+    assert_rel(zeta(1.1), 10.584448464950810, 1e-13);
+    assert_eq!(zeta(0f64), -0.5);
+    assert_rel(zeta(-1f64), -1f64 / 12f64, 1e-15);
+    assert_eq!(zeta(-2f64), 0f64);
+    assert_rel(zeta(-2.5) / 0.008516928777850331, 1f64, 1e-12);
+    assert_rel(zeta(-20.5) / -108.21747505877606, 1f64, 1e-12);
+    assert!(zeta(1f64).is_infinite());
+}
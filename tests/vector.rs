@@ -0,0 +1,134 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_searchsorted() {
+    // numpy.searchsorted([1, 2, 2, 2, 5, 7], [0, 1, 2, 3, 7, 8], side=...)
+    let a = c!(1, 2, 2, 2, 5, 7);
+    let values = c!(0, 1, 2, 3, 7, 8);
+
+    let left = values
+        .iter()
+        .map(|&x| a.searchsorted(x, Side::Left))
+        .collect::<Vec<usize>>();
+    let right = values
+        .iter()
+        .map(|&x| a.searchsorted(x, Side::Right))
+        .collect::<Vec<usize>>();
+    assert_eq!(left, vec![0, 0, 1, 4, 5, 6]);
+    assert_eq!(right, vec![0, 1, 4, 4, 6, 6]);
+
+    let empty: Vec<f64> = vec![];
+    assert_eq!(empty.searchsorted(1f64, Side::Left), 0);
+    assert_eq!(empty.searchsorted(1f64, Side::Right), 0);
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_searchsorted_unsorted() {
+    let a = c!(3, 1, 2);
+    a.searchsorted(2f64, Side::Left);
+}
+
+#[test]
+fn test_diff_gradient() {
+    let v = c!(1, 4, 9, 16);
+    assert_eq!(v.diff(), c!(3, 5, 7));
+
+    // numpy.gradient([1, 4, 9, 16], 0.5)
+    let g = v.gradient(0.5);
+    assert_eq!(g, c!(6, 8, 12, 14));
+    assert_eq!(g[0], (v[1] - v[0]) / 0.5);
+    assert_eq!(g[3], (v[3] - v[2]) / 0.5);
+
+    assert_eq!(c!(2, 5).gradient(1f64), c!(3, 3));
+
+    // Velocity of x(t) = t^2 : exact for interior points
+    let t = seq(0, 1, 0.1);
+    let x = t.fmap(|t| t * t);
+    let v = x.gradient(0.1);
+    for i in 1..t.len() - 1 {
+        assert!((v[i] - 2f64 * t[i]).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_redox_borrowed_ops() {
+    let x = seq(1, 100, 1).fmap(|t| t.sin());
+    let y = seq(1, 100, 1).fmap(|t| t.cos() + 2f64);
+    let (a, b) = (x.clone().ox(), y.clone().ox());
+
+    // Same as eager computation, element by element
+    let expected = x.zip_with(|s, t| s + 2f64 * t, &y);
+    assert_eq!((&a + 2f64 * &b).red(), expected);
+    assert_eq!((&a + y.clone().ox() * 2f64).red(), expected);
+    assert_eq!((&a - &b).red(), x.sub_v(&y));
+    assert_eq!((&a * &b).red(), x.mul_v(&y));
+    assert_eq!((&a / &b).red(), x.div_v(&y));
+    assert_eq!((&a * 3f64 - 1f64).red(), x.fmap(|t| t * 3f64 - 1f64));
+    assert_eq!((1f64 / &a).red(), x.fmap(|t| 1f64 / t));
+    assert_eq!((2f64 - x.clone().ox()).red(), x.fmap(|t| 2f64 - t));
+    assert_eq!(((&a + &b) / 2f64).red(), x.zip_with(|s, t| (s + t) / 2f64, &y));
+
+    // Operands are still usable
+    assert_eq!(a.dot(&b), x.dot(&y));
+    assert_eq!(a.outer(&b), x.outer(&y));
+
+    // AD
+    let u = Redox::<Vec<AD>>::from_vec(vec![AD1(1f64, 1f64), AD1(2f64, 1f64)]);
+    let v = (2f64 * &u + &u).red();
+    assert_eq!(v, vec![AD1(3f64, 3f64), AD1(6f64, 3f64)]);
+}
+
+#[test]
+fn test_compensated_sum() {
+    // 10^7 values of 1e-3 offset by 1e10
+    let mut x = vec![1e10];
+    x.extend(vec![1e-3; 10_000_000]);
+    let exact = 1e10 + 1e4;
+    let ulp = exact * f64::EPSILON;
+
+    let naive = x.iter().fold(0f64, |s, t| s + t);
+    assert!((naive - exact).abs() > 1f64);
+    assert!((ksum(&x) - exact).abs() <= ulp);
+    assert!((x.sum() - exact).abs() <= ulp);
+    assert!((matrix(x.clone(), 1, x.len(), Row).ksum() - exact).abs() <= ulp);
+    assert!((x.mean() * x.len() as f64 - exact).abs() <= 2f64 * ulp);
+
+    // Non-finite values are kept
+    assert_eq!(ksum(&[1f64, f64::INFINITY, 2f64]), f64::INFINITY);
+    assert!(ksum(&[f64::INFINITY, f64::NEG_INFINITY]).is_nan());
+    assert_eq!(ksum(&[f64::MAX, f64::MAX]), f64::INFINITY);
+    assert_eq!(ksum(&[]), 0f64);
+}
+
+#[test]
+fn test_var_large_offset() {
+    // 1e9 + {0, 1, 0, 1, ...} : var = n / (4 (n - 1))
+    let n = 1_000_000;
+    let x = (0..n).map(|i| 1e9 + (i % 2) as f64).collect::<Vec<f64>>();
+    let exact = n as f64 / (4f64 * (n as f64 - 1f64));
+    assert!(((x.var() - exact) / exact).abs() < 1e-14);
+    assert!(((cov(&x, &x) - exact) / exact).abs() < 1e-14);
+    assert!(((x.sd() - exact.sqrt()) / exact.sqrt()).abs() < 1e-14);
+}
+
+#[test]
+fn test_elementwise_ops() {
+    let v = c!(-2.5, -0.4, 0, 0.5, 1.6);
+    assert_eq!(v.abs(), c!(2.5, 0.4, 0, 0.5, 1.6));
+    assert_eq!(v.signum(), c!(-1, -1, 1, 1, 1));
+    assert_eq!(v.round(), c!(-3, 0, 0, 1, 2));
+    assert_eq!(v.floor(), c!(-3, -1, 0, 0, 1));
+    assert_eq!(v.ceil(), c!(-2, 0, 0, 1, 2));
+    assert_eq!(v.clamp(-1f64, 1f64), c!(-1, -0.4, 0, 0.5, 1));
+    assert_eq!(v.clamp(-1f64, 1f64), v.clamp_vec(-1f64, 1f64));
+}
+
+#[test]
+#[should_panic]
+fn test_elementwise_clamp_invalid_bounds() {
+    c!(1, 2, 3).clamp(2f64, 1f64);
+}